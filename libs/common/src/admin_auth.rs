@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Request, State},
+    http::header,
+    middleware::Next,
+    response::Response,
+};
+use std::sync::Arc;
+
+use crate::error::AppError;
+
+/// `Authorization: Bearer <ADMIN_TOKEN>` を要求する（トークン未設定なら常に拒否）
+///
+/// 管理 API のルーター全体に `middleware::from_fn_with_state` で掛ける。
+pub async fn require_admin_token(
+    State(token): State<Option<Arc<str>>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let Some(token) = token else {
+        return Err(AppError::Unauthorized(
+            "Admin API is disabled (ADMIN_TOKEN is not set)".to_string(),
+        ));
+    };
+    let provided = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match provided {
+        Some(provided) if constant_time_eq(provided.as_bytes(), token.as_bytes()) => {
+            Ok(next.run(request).await)
+        }
+        _ => Err(AppError::Unauthorized("Invalid admin token".to_string())),
+    }
+}
+
+/// 一致までの時間から内容を推測させない比較（トークン・API キー用）
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+        assert!(constant_time_eq(b"", b""));
+    }
+}
@@ -0,0 +1,72 @@
+use tower_http::compression::{
+    predicate::{And, NotForContentType, Predicate, SizeAbove},
+    CompressionLayer,
+};
+
+/// 圧縮する応答の最小サイズの既定値（バイト）
+pub const DEFAULT_COMPRESSION_MIN_SIZE: u16 = 1024;
+
+/// [`compression_layer`] が圧縮する応答の条件
+pub type CompressionPredicate =
+    And<And<And<SizeAbove, NotForContentType>, NotForContentType>, NotForContentType>;
+
+/// Accept-Encoding に応じて応答を gzip / brotli で圧縮するレイヤー
+///
+/// `min_size` バイト未満の応答と、gRPC・画像・SSE は圧縮しない（既定の条件と同じ）。
+/// 試算表や元帳の JSON はよく縮むので、ルーター全体の一番外側に置く。
+pub fn compression_layer(min_size: u16) -> CompressionLayer<CompressionPredicate> {
+    let predicate = SizeAbove::new(min_size)
+        .and(NotForContentType::GRPC)
+        .and(NotForContentType::IMAGES)
+        .and(NotForContentType::SSE);
+    CompressionLayer::new()
+        .gzip(true)
+        .br(true)
+        .compress_when(predicate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{header, Request},
+        routing::get,
+        Router,
+    };
+    use tower::ServiceExt;
+
+    async fn get_encoding(path: &str, accept_encoding: &str) -> Option<String> {
+        let app = Router::new()
+            .route("/small", get(|| async { "ok" }))
+            .route("/large", get(|| async { "0".repeat(4096) }))
+            .layer(compression_layer(DEFAULT_COMPRESSION_MIN_SIZE));
+        let response = app
+            .oneshot(
+                Request::get(path)
+                    .header(header::ACCEPT_ENCODING, accept_encoding)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        response
+            .headers()
+            .get(header::CONTENT_ENCODING)
+            .map(|value| value.to_str().unwrap().to_string())
+    }
+
+    #[tokio::test]
+    async fn test_compresses_only_large_responses() {
+        assert_eq!(
+            get_encoding("/large", "gzip").await.as_deref(),
+            Some("gzip")
+        );
+        assert_eq!(
+            get_encoding("/large", "br, gzip;q=0.5").await.as_deref(),
+            Some("br")
+        );
+        assert_eq!(get_encoding("/large", "identity").await, None);
+        assert_eq!(get_encoding("/small", "gzip").await, None);
+    }
+}
@@ -0,0 +1,143 @@
+use figment::providers::{Env, Format, Toml};
+use figment::Figment;
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::path::Path;
+use validator::Validate;
+
+use crate::error::{AppError, FieldError};
+
+pub use figment::util::bool_from_str_or_int;
+
+/// 設定ファイル（TOML）のパスを指定する環境変数
+pub const CONFIG_FILE_ENV: &str = "CONFIG_FILE";
+
+/// 設定の読み込み・検証エラー（不足・不正な項目をまとめて保持する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    pub errors: Vec<FieldError>,
+}
+
+impl ConfigError {
+    fn single(field: impl Into<String>, code: &str, message: impl Into<String>) -> Self {
+        Self {
+            errors: vec![FieldError {
+                field: field.into(),
+                code: code.to_string(),
+                message: message.into(),
+            }],
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid configuration ({} errors)", self.errors.len())?;
+        for error in &self.errors {
+            write!(f, "\n  - {}: {}", error.field, error.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<figment::Error> for ConfigError {
+    fn from(error: figment::Error) -> Self {
+        let errors = error
+            .into_iter()
+            .map(|e| FieldError {
+                field: e.path.join("."),
+                code: "invalid".to_string(),
+                message: e.kind.to_string(),
+            })
+            .collect();
+        Self { errors }
+    }
+}
+
+/// 環境変数と設定ファイルから型付きの設定を読み込む
+///
+/// 優先順位は 環境変数 > `CONFIG_FILE` で指定した TOML ファイル。
+/// 環境変数名は小文字にしてフィールド名と対応させる（`DATABASE_URL` → `database_url`）。
+pub fn load<T: DeserializeOwned + Validate>() -> Result<T, ConfigError> {
+    let mut figment = Figment::new();
+    if let Some(path) = Env::var(CONFIG_FILE_ENV) {
+        if !Path::new(&path).is_file() {
+            return Err(ConfigError::single(
+                CONFIG_FILE_ENV,
+                "not_found",
+                format!("Config file not found: {}", path),
+            ));
+        }
+        figment = figment.merge(Toml::file_exact(path));
+    }
+    extract(figment.merge(Env::raw()))
+}
+
+/// 設定を取り出して検証する（検証エラーはすべての項目を報告する）
+pub fn extract<T: DeserializeOwned + Validate>(figment: Figment) -> Result<T, ConfigError> {
+    let config: T = figment.extract()?;
+    config
+        .validate()
+        .map_err(|errors| match AppError::from(errors) {
+            AppError::Validation { errors, .. } => ConfigError { errors },
+            other => ConfigError::single("", other.code(), other.to_string()),
+        })?;
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct TestConfig {
+        #[validate(required(message = "nameを指定してください"))]
+        name: Option<String>,
+        #[validate(range(min = 1, message = "portは1以上で指定してください"))]
+        #[serde(default = "default_port")]
+        port: u16,
+        #[validate(required(message = "urlを指定してください"))]
+        url: Option<String>,
+        #[serde(default, deserialize_with = "bool_from_str_or_int")]
+        read_only: bool,
+    }
+
+    fn default_port() -> u16 {
+        8080
+    }
+
+    #[test]
+    fn test_extract_with_defaults() {
+        let config: TestConfig = extract(Figment::from(Toml::string(
+            r#"
+            name = "accounting"
+            url = "postgres://localhost/accounting"
+            read_only = 1
+            "#,
+        )))
+        .unwrap();
+
+        assert_eq!(config.port, 8080);
+        assert!(config.read_only);
+    }
+
+    #[test]
+    fn test_reports_all_invalid_keys() {
+        let err = extract::<TestConfig>(Figment::from(Toml::string("port = 0"))).unwrap_err();
+
+        let fields: Vec<&str> = err.errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["name", "port", "url"]);
+        assert!(err.to_string().contains("nameを指定してください"));
+    }
+
+    #[test]
+    fn test_reports_type_errors() {
+        let err =
+            extract::<TestConfig>(Figment::from(Toml::string(r#"port = "abc""#))).unwrap_err();
+
+        assert_eq!(err.errors[0].field, "port");
+    }
+}
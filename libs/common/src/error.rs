@@ -0,0 +1,360 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+use crate::i18n::{self, current_locale, Locale};
+use crate::trace::current_trace_id;
+use crate::ErrorResponse;
+
+/// 入力項目ごとのエラー
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+/// 全サービス共通のアプリケーションエラー
+///
+/// ハンドラーはこの型を返し、レスポンスは常に `ErrorResponse` の形になる。
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("{message}")]
+    BadRequest { code: &'static str, message: String },
+
+    #[error("{message}")]
+    Validation {
+        message: String,
+        errors: Vec<FieldError>,
+    },
+
+    #[error("{0}")]
+    Unauthorized(String),
+
+    #[error("{message}")]
+    Forbidden { code: &'static str, message: String },
+
+    #[error("{0}")]
+    NotFound(String),
+
+    #[error("{message}")]
+    Conflict { code: &'static str, message: String },
+
+    #[error("{0}")]
+    PreconditionFailed(String),
+
+    #[error("{0}")]
+    PayloadTooLarge(String),
+
+    #[error("{0}")]
+    UnsupportedMediaType(String),
+
+    #[error("{message}")]
+    ServiceUnavailable { code: &'static str, message: String },
+
+    /// 詳細はログにのみ出力し、クライアントには汎用メッセージを返す
+    #[error("{detail}")]
+    Internal { code: &'static str, detail: String },
+}
+
+impl AppError {
+    /// 項目別の詳細を伴わない入力エラー
+    pub fn validation(message: impl Into<String>) -> Self {
+        AppError::Validation {
+            message: message.into(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn internal(code: &'static str, detail: impl Into<String>) -> Self {
+        AppError::Internal {
+            code,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            AppError::BadRequest { .. } | AppError::Validation { .. } => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden { .. } => StatusCode::FORBIDDEN,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict { .. } => StatusCode::CONFLICT,
+            AppError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+            AppError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            AppError::UnsupportedMediaType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            AppError::ServiceUnavailable { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::Internal { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::BadRequest { code, .. }
+            | AppError::Forbidden { code, .. }
+            | AppError::Conflict { code, .. }
+            | AppError::ServiceUnavailable { code, .. }
+            | AppError::Internal { code, .. } => code,
+            AppError::Validation { .. } => "VALIDATION_ERROR",
+            AppError::Unauthorized(_) => "UNAUTHORIZED",
+            AppError::NotFound(_) => "NOT_FOUND",
+            AppError::PreconditionFailed(_) => "PRECONDITION_FAILED",
+            AppError::PayloadTooLarge(_) => "PAYLOAD_TOO_LARGE",
+            AppError::UnsupportedMediaType(_) => "UNSUPPORTED_MEDIA_TYPE",
+        }
+    }
+
+    /// クライアントに返すエラー本文
+    ///
+    /// `Accept-Language` で言語が指定されていれば、共通のメッセージをその言語で返す。
+    pub fn to_error_response(&self) -> ErrorResponse {
+        let localized = |code: &str, default: &str| {
+            current_locale()
+                .zip(i18n::error_message(code))
+                .map_or(default.to_string(), |(locale, label)| {
+                    label.get(locale).to_string()
+                })
+        };
+        let message = match self {
+            AppError::Internal { .. } => localized("INTERNAL_ERROR", "An internal error occurred"),
+            AppError::Validation { message, errors } if !errors.is_empty() => {
+                localized("VALIDATION_ERROR", message)
+            }
+            other => other.to_string(),
+        };
+        let errors = match self {
+            AppError::Validation { errors, .. } => errors.clone(),
+            _ => Vec::new(),
+        };
+
+        ErrorResponse {
+            errors,
+            trace_id: current_trace_id(),
+            ..ErrorResponse::new(message, self.code())
+        }
+    }
+}
+
+/// メッセージ未指定の検証エラーの既定の文言
+fn invalid_value_message(path: &str) -> String {
+    match current_locale().unwrap_or_default() {
+        Locale::Ja => format!("{}の入力値が不正です", path),
+        Locale::En => format!("Invalid value for {}", path),
+    }
+}
+
+/// 入れ子の検証エラーを `lines[0].quantity` 形式のパスで平坦化する
+fn collect_field_errors(errors: &ValidationErrors, prefix: &str, out: &mut Vec<FieldError>) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+        match kind {
+            ValidationErrorsKind::Field(items) => out.extend(items.iter().map(|item| {
+                FieldError {
+                    field: path.clone(),
+                    code: item.code.to_string(),
+                    message: item
+                        .message
+                        .as_ref()
+                        .map(|m| m.to_string())
+                        .unwrap_or_else(|| invalid_value_message(&path)),
+                }
+            })),
+            ValidationErrorsKind::Struct(nested) => collect_field_errors(nested, &path, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    collect_field_errors(nested, &format!("{}[{}]", path, index), out);
+                }
+            }
+        }
+    }
+}
+
+impl From<ValidationErrors> for AppError {
+    fn from(errors: ValidationErrors) -> Self {
+        let mut fields = Vec::new();
+        collect_field_errors(&errors, "", &mut fields);
+        fields.sort_by(|a, b| a.field.cmp(&b.field));
+
+        AppError::Validation {
+            message: "Validation failed".to_string(),
+            errors: fields,
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        if let AppError::Internal { code, detail } = &self {
+            tracing::error!(code, "{}", detail);
+        }
+        (self.status(), Json(self.to_error_response())).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::trace_id_middleware;
+    use axum::{body::Body, extract::Request, middleware, routing::get, Router};
+    use tower::ServiceExt;
+    use validator::Validate;
+
+    #[derive(Validate)]
+    struct Line {
+        #[validate(range(min = 0, message = "枚数は0以上で入力してください"))]
+        quantity: i64,
+    }
+
+    #[derive(Validate)]
+    struct Payload {
+        #[validate(length(min = 3, message = "科目コードは3文字以上で入力してください"))]
+        code: String,
+        #[validate(length(min = 1))]
+        name: String,
+        #[validate(nested)]
+        lines: Vec<Line>,
+    }
+
+    #[test]
+    fn test_status_and_code() {
+        let err = AppError::Conflict {
+            code: "DUPLICATE_CODE",
+            message: "Account code already exists: 101".to_string(),
+        };
+        assert_eq!(err.status(), StatusCode::CONFLICT);
+        assert_eq!(err.code(), "DUPLICATE_CODE");
+
+        let err = AppError::validation("Validation failed");
+        assert_eq!(err.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(err.code(), "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_internal_error_hides_detail() {
+        let body = AppError::internal("DATABASE_ERROR", "connection refused").to_error_response();
+
+        assert_eq!(body.error, "An internal error occurred");
+        assert_eq!(body.code, "DATABASE_ERROR");
+    }
+
+    #[test]
+    fn test_error_response_shape() {
+        let body = AppError::Validation {
+            message: "Validation failed".to_string(),
+            errors: vec![FieldError {
+                field: "code".to_string(),
+                code: "length".to_string(),
+                message: "科目コードは3〜10文字で入力してください".to_string(),
+            }],
+        }
+        .to_error_response();
+        let json = serde_json::to_value(&body).unwrap();
+        assert_eq!(json["code"], "VALIDATION_ERROR");
+        assert_eq!(json["errors"][0]["field"], "code");
+        assert!(json.get("trace_id").is_none());
+
+        let json =
+            serde_json::to_value(AppError::NotFound("x".into()).to_error_response()).unwrap();
+        assert!(json.get("errors").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_error_response_is_localized() {
+        let app = Router::new()
+            .route(
+                "/",
+                get(|| async { AppError::internal("DATABASE_ERROR", "connection refused") }),
+            )
+            .layer(middleware::from_fn(crate::i18n::locale_middleware));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header("accept-language", "ja")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body.error, "サーバー内部でエラーが発生しました");
+        assert_eq!(body.code, "DATABASE_ERROR");
+    }
+
+    #[tokio::test]
+    async fn test_error_response_includes_trace_id() {
+        let app = Router::new()
+            .route(
+                "/",
+                get(|| async { AppError::NotFound("Account not found".to_string()) }),
+            )
+            .layer(middleware::from_fn(trace_id_middleware));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header("x-request-id", "req-1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body.code, "NOT_FOUND");
+        assert_eq!(body.trace_id.as_deref(), Some("req-1"));
+    }
+
+    #[test]
+    fn test_validation_errors_are_listed_per_field() {
+        let payload = Payload {
+            code: "x".to_string(),
+            name: String::new(),
+            lines: vec![Line { quantity: 1 }, Line { quantity: -1 }],
+        };
+
+        let err = AppError::from(payload.validate().unwrap_err());
+        let body = err.to_error_response();
+
+        assert_eq!(body.code, "VALIDATION_ERROR");
+        assert_eq!(
+            body.errors,
+            vec![
+                FieldError {
+                    field: "code".to_string(),
+                    code: "length".to_string(),
+                    message: "科目コードは3文字以上で入力してください".to_string(),
+                },
+                FieldError {
+                    field: "lines[1].quantity".to_string(),
+                    code: "range".to_string(),
+                    message: "枚数は0以上で入力してください".to_string(),
+                },
+                FieldError {
+                    field: "name".to_string(),
+                    code: "length".to_string(),
+                    message: "nameの入力値が不正です".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Query},
+    http::request::Parts,
+};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::convert::Infallible;
+
+use crate::error::AppError;
+use crate::negotiate::serialize_error;
+
+/// 応答の 1 件（項目の順はシリアライズした順のまま）
+pub type Projected = IndexMap<String, Value>;
+
+/// `?fields=id,code,name` で選んだ最上位の項目だけを返す（sparse fieldsets）
+///
+/// 未指定・空なら全項目を返す。存在しない項目名は無視する。
+/// ドロップダウンのように一部の項目しか使わない利用者の応答を小さくするために使う。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fields(Option<Vec<String>>);
+
+#[derive(Deserialize)]
+struct FieldsQuery {
+    fields: Option<String>,
+}
+
+impl Fields {
+    /// カンマ区切りの項目名
+    pub fn parse(fields: &str) -> Self {
+        let names: Vec<String> = fields
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect();
+        Self((!names.is_empty()).then_some(names))
+    }
+
+    /// 選んだ項目だけを残す（[`crate::negotiate::Negotiate::respond`] にそのまま渡せる）
+    pub fn project<T: Serialize>(&self, items: &[T]) -> Result<Vec<Projected>, AppError> {
+        items
+            .iter()
+            .map(|item| {
+                // 構造体のフィールド順を保つため IndexMap で読み直す
+                let text = serde_json::to_string(item).map_err(serialize_error)?;
+                let mut fields: Projected = serde_json::from_str(&text).map_err(serialize_error)?;
+                if let Some(names) = &self.0 {
+                    fields.retain(|key, _| names.contains(key));
+                }
+                Ok(fields)
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Fields {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Query::<FieldsQuery>::try_from_uri(&parts.uri)
+            .ok()
+            .and_then(|Query(query)| query.fields)
+            .map_or_else(Self::default, |fields| Self::parse(&fields)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Row {
+        id: u32,
+        code: &'static str,
+        name: &'static str,
+        description: Option<&'static str>,
+    }
+
+    #[test]
+    fn test_project_keeps_selected_fields_in_order() {
+        let rows = [Row {
+            id: 1,
+            code: "101",
+            name: "現金",
+            description: None,
+        }];
+
+        let projected = Fields::parse("name, id,unknown").project(&rows).unwrap();
+        assert_eq!(
+            serde_json::to_string(&projected).unwrap(),
+            r#"[{"id":1,"name":"現金"}]"#
+        );
+
+        assert_eq!(Fields::parse(" , "), Fields::default());
+        let projected = Fields::default().project(&rows).unwrap();
+        assert_eq!(projected[0].len(), 4);
+    }
+}
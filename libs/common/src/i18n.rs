@@ -0,0 +1,169 @@
+use axum::{extract::Request, http::header::ACCEPT_LANGUAGE, middleware::Next, response::Response};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 対応している表示言語
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Locale {
+    #[default]
+    Ja,
+    En,
+}
+
+impl Locale {
+    /// 言語タグ（`ja-JP` など）に対応する言語
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next()?.trim();
+        if primary.eq_ignore_ascii_case("ja") {
+            Some(Locale::Ja)
+        } else if primary.eq_ignore_ascii_case("en") {
+            Some(Locale::En)
+        } else {
+            None
+        }
+    }
+
+    /// `Accept-Language` から品質値が最も高い対応言語を選ぶ（同値なら先に書かれた方）
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        let mut best: Option<(Locale, f32)> = None;
+        for item in header.split(',') {
+            let mut parts = item.split(';');
+            let Some(locale) = parts.next().and_then(Locale::from_tag) else {
+                continue;
+            };
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())
+                .unwrap_or(0.0);
+            if quality > 0.0 && best.is_none_or(|(_, q)| quality > q) {
+                best = Some((locale, quality));
+            }
+        }
+        best.map(|(locale, _)| locale)
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Locale::Ja => "ja",
+            Locale::En => "en",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 言語ごとの表示名
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Label {
+    pub ja: &'static str,
+    pub en: &'static str,
+}
+
+impl Label {
+    pub const fn new(ja: &'static str, en: &'static str) -> Self {
+        Self { ja, en }
+    }
+
+    pub fn get(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Ja => self.ja,
+            Locale::En => self.en,
+        }
+    }
+}
+
+/// 表示名を持つ値（列挙型の画面表示用）
+pub trait Localize {
+    fn label(&self) -> Label;
+
+    fn localized(&self, locale: Locale) -> &'static str {
+        self.label().get(locale)
+    }
+}
+
+/// 共通のエラーメッセージ（エラーコードごと）
+pub fn error_message(code: &str) -> Option<Label> {
+    let label = match code {
+        "VALIDATION_ERROR" => Label::new("入力内容に誤りがあります", "Validation failed"),
+        "INTERNAL_ERROR" => Label::new(
+            "サーバー内部でエラーが発生しました",
+            "An internal error occurred",
+        ),
+        _ => return None,
+    };
+    Some(label)
+}
+
+tokio::task_local! {
+    static LOCALE: Locale;
+}
+
+/// 処理中のリクエストの表示言語（`Accept-Language` で対応言語を指定した場合のみ）
+pub fn current_locale() -> Option<Locale> {
+    LOCALE.try_with(|locale| *locale).ok()
+}
+
+/// `Accept-Language` から表示言語を決めるミドルウェア
+pub async fn locale_middleware(request: Request, next: Next) -> Response {
+    let locale = request
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(Locale::from_accept_language);
+
+    match locale {
+        Some(locale) => LOCALE.scope(locale, next.run(request)).await,
+        None => next.run(request).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_from_accept_language() {
+        assert_eq!(Locale::from_accept_language("ja-JP"), Some(Locale::Ja));
+        assert_eq!(
+            Locale::from_accept_language("fr-FR, en-US;q=0.8, ja;q=0.5"),
+            Some(Locale::En)
+        );
+        assert_eq!(
+            Locale::from_accept_language("en;q=0.3, ja;q=0.9"),
+            Some(Locale::Ja)
+        );
+        assert_eq!(Locale::from_accept_language("en;q=0, de"), None);
+        assert_eq!(Locale::from_accept_language("*"), None);
+    }
+
+    #[tokio::test]
+    async fn test_locale_middleware() {
+        let app = Router::new()
+            .route(
+                "/",
+                get(|| async { current_locale().map(|l| l.to_string()).unwrap_or_default() }),
+            )
+            .layer(middleware::from_fn(locale_middleware));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header("accept-language", "en-GB,en;q=0.9")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"en");
+        assert_eq!(current_locale(), None);
+    }
+}
@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+use super::{Job, JobQueue, JobQueueError, JobQueueResult, JobStatus, NewJob};
+
+/// インメモリのジョブキュー（DB なしの起動とテスト用）
+#[derive(Default, Clone)]
+pub struct InMemoryJobQueue {
+    jobs: Arc<RwLock<HashMap<Uuid, Job>>>,
+}
+
+impl InMemoryJobQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn lock_error(e: impl std::fmt::Display) -> JobQueueError {
+    JobQueueError::Storage(e.to_string())
+}
+
+#[async_trait]
+impl JobQueue for InMemoryJobQueue {
+    async fn enqueue(&self, job: NewJob) -> JobQueueResult<Option<Job>> {
+        let mut jobs = self.jobs.write().map_err(lock_error)?;
+        if job.unique_key.is_some() && jobs.values().any(|j| j.unique_key == job.unique_key) {
+            return Ok(None);
+        }
+
+        let job = job.into_job(Utc::now());
+        jobs.insert(job.id, job.clone());
+        Ok(Some(job))
+    }
+
+    async fn claim(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+        visibility_timeout: chrono::Duration,
+    ) -> JobQueueResult<Vec<Job>> {
+        let mut jobs = self.jobs.write().map_err(lock_error)?;
+
+        let mut result: Vec<Job> = jobs
+            .values()
+            .filter(|j| j.status == JobStatus::Pending && j.run_at <= now)
+            .cloned()
+            .collect();
+        result.sort_by_key(|j| (j.run_at, j.created_at));
+        result.truncate(usize::try_from(limit).unwrap_or(0));
+
+        for job in &mut result {
+            job.attempts += 1;
+            job.run_at = now + visibility_timeout;
+            job.updated_at = now;
+            jobs.insert(job.id, job.clone());
+        }
+
+        Ok(result)
+    }
+
+    async fn complete(&self, id: Uuid) -> JobQueueResult<()> {
+        let mut jobs = self.jobs.write().map_err(lock_error)?;
+        let job = jobs.get_mut(&id).ok_or(JobQueueError::NotFound(id))?;
+        job.status = JobStatus::Succeeded;
+        job.last_error = None;
+        job.updated_at = Utc::now();
+        Ok(())
+    }
+
+    async fn fail(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> JobQueueResult<()> {
+        let mut jobs = self.jobs.write().map_err(lock_error)?;
+        let job = jobs.get_mut(&id).ok_or(JobQueueError::NotFound(id))?;
+        match retry_at {
+            Some(retry_at) => job.run_at = retry_at,
+            None => job.status = JobStatus::Failed,
+        }
+        job.last_error = Some(error.to_string());
+        job.updated_at = Utc::now();
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> JobQueueResult<Option<Job>> {
+        let jobs = self.jobs.read().map_err(lock_error)?;
+        Ok(jobs.get(&id).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_claim_hides_job_until_visibility_timeout() {
+        let queue = InMemoryJobQueue::new();
+        let job = queue
+            .enqueue(NewJob::new("test", serde_json::json!({})))
+            .await
+            .unwrap()
+            .unwrap();
+        let now = Utc::now();
+
+        let claimed = queue.claim(now, 10, Duration::seconds(30)).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].attempts, 1);
+        assert!(queue
+            .claim(now + Duration::seconds(10), 10, Duration::seconds(30))
+            .await
+            .unwrap()
+            .is_empty());
+
+        // 完了が記録されないまま期限を過ぎると再び取り出される
+        let reclaimed = queue
+            .claim(now + Duration::seconds(31), 10, Duration::seconds(30))
+            .await
+            .unwrap();
+        assert_eq!(reclaimed[0].id, job.id);
+        assert_eq!(reclaimed[0].attempts, 2);
+
+        queue.complete(job.id).await.unwrap();
+        assert!(queue
+            .claim(now + Duration::hours(1), 10, Duration::seconds(30))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_skips_duplicate_unique_key() {
+        let queue = InMemoryJobQueue::new();
+        let job = NewJob::new("test", serde_json::json!({})).unique_key("daily:2026-01-01");
+
+        assert!(queue.enqueue(job.clone()).await.unwrap().is_some());
+        assert!(queue.enqueue(job).await.unwrap().is_none());
+    }
+}
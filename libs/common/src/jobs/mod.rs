@@ -0,0 +1,227 @@
+//! バックグラウンドジョブ
+//!
+//! ジョブはキュー（[`JobQueue`]）に登録し、[`JobRunner`] が種別ごとの [`JobHandler`] で
+//! 実行する。定期実行は [`Scheduler`] が cron 形式の [`Schedule`] に従ってキューへ登録する。
+
+mod memory;
+mod runner;
+mod schedule;
+
+pub use memory::InMemoryJobQueue;
+pub use runner::{spawn_job_runner, JobRunner, Scheduler};
+pub use schedule::{Schedule, ScheduleParseError};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use uuid::Uuid;
+
+pub type DynJobQueue = Arc<dyn JobQueue>;
+
+/// 既定の最大試行回数
+pub const DEFAULT_MAX_ATTEMPTS: i32 = 5;
+
+/// 再試行の間隔
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// 初回を含む最大試行回数
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS as u32,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// attempt 回目の失敗後の待機時間（指数バックオフ）
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// ジョブの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// 実行待ち（実行中で可視性タイムアウト前のものを含む）
+    Pending,
+    Succeeded,
+    /// 再試行しない失敗
+    Failed,
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            JobStatus::Pending => "pending",
+            JobStatus::Succeeded => "succeeded",
+            JobStatus::Failed => "failed",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for JobStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(JobStatus::Pending),
+            "succeeded" => Ok(JobStatus::Succeeded),
+            "failed" => Ok(JobStatus::Failed),
+            other => Err(format!("Invalid job status: {}", other)),
+        }
+    }
+}
+
+/// キューに登録されたジョブ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Job {
+    pub id: Uuid,
+    /// 実行するハンドラーの種別
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub status: JobStatus,
+    /// 取り出された回数（実行中の試行を含む）
+    pub attempts: i32,
+    pub max_attempts: i32,
+    /// 次に実行できる時刻（実行中は可視性タイムアウトの期限）
+    pub run_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    /// 同じキーのジョブは一度だけ登録される
+    pub unique_key: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// ジョブの登録内容
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewJob {
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub run_at: Option<DateTime<Utc>>,
+    pub unique_key: Option<String>,
+    pub max_attempts: i32,
+}
+
+impl NewJob {
+    pub fn new(kind: impl Into<String>, payload: serde_json::Value) -> Self {
+        Self {
+            kind: kind.into(),
+            payload,
+            run_at: None,
+            unique_key: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+
+    /// 指定した時刻以降に実行する（省略時は登録時刻）
+    pub fn run_at(mut self, run_at: DateTime<Utc>) -> Self {
+        self.run_at = Some(run_at);
+        self
+    }
+
+    pub fn unique_key(mut self, key: impl Into<String>) -> Self {
+        self.unique_key = Some(key.into());
+        self
+    }
+
+    pub fn max_attempts(mut self, max_attempts: i32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// 登録時刻 `now` のジョブを作る
+    pub fn into_job(self, now: DateTime<Utc>) -> Job {
+        Job {
+            id: Uuid::new_v4(),
+            kind: self.kind,
+            payload: self.payload,
+            status: JobStatus::Pending,
+            attempts: 0,
+            max_attempts: self.max_attempts,
+            run_at: self.run_at.unwrap_or(now),
+            last_error: None,
+            unique_key: self.unique_key,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum JobQueueError {
+    #[error("Job not found: {0}")]
+    NotFound(Uuid),
+
+    #[error("Job queue error: {0}")]
+    Storage(String),
+}
+
+pub type JobQueueResult<T> = Result<T, JobQueueError>;
+
+/// ジョブキュー
+///
+/// 取り出したジョブは可視性タイムアウトまで他のワーカーに渡さない。完了も失敗も
+/// 記録されないまま期限を過ぎたジョブ（ワーカーの停止など）は再び取り出される。
+#[async_trait]
+pub trait JobQueue: Send + Sync {
+    /// ジョブを登録する（同じ `unique_key` のジョブがあれば登録せず None）
+    async fn enqueue(&self, job: NewJob) -> JobQueueResult<Option<Job>>;
+
+    /// 実行時刻を迎えたジョブを取り出す（試行回数を 1 増やす）
+    async fn claim(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+        visibility_timeout: chrono::Duration,
+    ) -> JobQueueResult<Vec<Job>>;
+
+    /// 成功を記録する
+    async fn complete(&self, id: Uuid) -> JobQueueResult<()>;
+
+    /// 失敗を記録する（`retry_at` が None なら再試行しない）
+    async fn fail(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> JobQueueResult<()>;
+
+    /// IDでジョブを取得
+    async fn find_by_id(&self, id: Uuid) -> JobQueueResult<Option<Job>>;
+}
+
+/// ジョブの失敗
+#[derive(Debug, Error)]
+pub enum JobError {
+    /// 一時的な失敗（最大試行回数まで再試行する）
+    #[error("{0}")]
+    Retryable(String),
+
+    /// 再試行しても成功しない失敗
+    #[error("{0}")]
+    Permanent(String),
+}
+
+/// 種別ごとのジョブの処理
+#[async_trait]
+pub trait JobHandler: Send + Sync {
+    async fn run(&self, job: &Job) -> Result<(), JobError>;
+}
@@ -0,0 +1,374 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use super::{
+    DynJobQueue, Job, JobError, JobHandler, JobQueueError, JobQueueResult, NewJob, RetryPolicy,
+    Schedule,
+};
+
+/// 一度に取り出すジョブの件数
+const DEFAULT_BATCH_SIZE: i64 = 20;
+/// 取り出したジョブを他のワーカーに渡さない期間
+const DEFAULT_VISIBILITY_TIMEOUT: chrono::Duration = chrono::Duration::minutes(5);
+
+struct ScheduledEntry {
+    name: String,
+    schedule: Schedule,
+    job: NewJob,
+    /// 前回確認した時刻
+    checked_at: Option<DateTime<Utc>>,
+}
+
+/// 定期実行するジョブをキューへ登録する
+///
+/// 実行時刻ごとに `名前:時刻` を `unique_key` にするため、複数のインスタンスで
+/// 動かしても一度しか登録されない。停止中に過ぎた実行時刻は最新の 1 回だけ登録する。
+pub struct Scheduler {
+    queue: DynJobQueue,
+    entries: Mutex<Vec<ScheduledEntry>>,
+}
+
+impl Scheduler {
+    pub fn new(queue: DynJobQueue) -> Self {
+        Self {
+            queue,
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// `schedule` に従って `job` を登録する（`name` はスケジュールごとに一意）
+    pub fn add(self, name: impl Into<String>, schedule: Schedule, job: NewJob) -> Self {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.push(ScheduledEntry {
+                name: name.into(),
+                schedule,
+                job,
+                checked_at: None,
+            });
+        }
+        self
+    }
+
+    /// 実行時刻を迎えたジョブを登録する（登録した件数を返す）
+    pub async fn run_once(&self, now: DateTime<Utc>) -> JobQueueResult<usize> {
+        let due: Vec<NewJob> = {
+            let mut entries = self
+                .entries
+                .lock()
+                .map_err(|e| JobQueueError::Storage(e.to_string()))?;
+            entries
+                .iter_mut()
+                .filter_map(|entry| {
+                    // 初回は現在の分の実行時刻を含める
+                    let mut cursor = entry
+                        .checked_at
+                        .unwrap_or(now - chrono::Duration::minutes(1));
+                    entry.checked_at = Some(now);
+
+                    let mut latest = None;
+                    while let Some(next) = entry.schedule.next_after(cursor) {
+                        if next > now {
+                            break;
+                        }
+                        latest = Some(next);
+                        cursor = next;
+                    }
+                    latest.map(|at| {
+                        entry.job.clone().run_at(at).unique_key(format!(
+                            "{}:{}",
+                            entry.name,
+                            at.to_rfc3339()
+                        ))
+                    })
+                })
+                .collect()
+        };
+
+        let mut enqueued = 0;
+        for job in due {
+            if self.queue.enqueue(job).await?.is_some() {
+                enqueued += 1;
+            }
+        }
+        Ok(enqueued)
+    }
+}
+
+/// キューのジョブを種別ごとのハンドラーで実行する
+pub struct JobRunner {
+    queue: DynJobQueue,
+    handlers: HashMap<String, Arc<dyn JobHandler>>,
+    scheduler: Option<Scheduler>,
+    retry: RetryPolicy,
+    batch_size: i64,
+    visibility_timeout: chrono::Duration,
+}
+
+impl JobRunner {
+    pub fn new(queue: DynJobQueue) -> Self {
+        Self {
+            queue,
+            handlers: HashMap::new(),
+            scheduler: None,
+            retry: RetryPolicy::default(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            visibility_timeout: DEFAULT_VISIBILITY_TIMEOUT,
+        }
+    }
+
+    /// `kind` のジョブを処理するハンドラーを登録する
+    pub fn register(mut self, kind: impl Into<String>, handler: Arc<dyn JobHandler>) -> Self {
+        self.handlers.insert(kind.into(), handler);
+        self
+    }
+
+    /// 実行前に定期実行のジョブを登録する
+    pub fn with_scheduler(mut self, scheduler: Scheduler) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
+    /// 再試行までの待機時間（試行回数の上限はジョブごとの `max_attempts`）
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn with_visibility_timeout(mut self, timeout: chrono::Duration) -> Self {
+        self.visibility_timeout = timeout;
+        self
+    }
+
+    /// 実行時刻を迎えたジョブを処理する（処理した件数を返す）
+    pub async fn run_once(&self, now: DateTime<Utc>) -> JobQueueResult<usize> {
+        if let Some(scheduler) = &self.scheduler {
+            scheduler.run_once(now).await?;
+        }
+
+        let jobs = self
+            .queue
+            .claim(now, self.batch_size, self.visibility_timeout)
+            .await?;
+        for job in &jobs {
+            self.run(job, now).await?;
+        }
+        Ok(jobs.len())
+    }
+
+    async fn run(&self, job: &Job, now: DateTime<Utc>) -> JobQueueResult<()> {
+        let result = match self.handlers.get(&job.kind) {
+            None => Err(JobError::Permanent(format!(
+                "No handler for job kind: {}",
+                job.kind
+            ))),
+            // 実行中に停止を繰り返したジョブ
+            Some(_) if job.attempts > job.max_attempts => Err(JobError::Permanent(format!(
+                "Gave up after {} attempts",
+                job.max_attempts
+            ))),
+            Some(handler) => handler.run(job).await,
+        };
+
+        let error = match result {
+            Ok(()) => return self.queue.complete(job.id).await,
+            Err(error) => error,
+        };
+        let attempt = u32::try_from(job.attempts).unwrap_or(0);
+        let retry_at = (matches!(error, JobError::Retryable(_)) && job.attempts < job.max_attempts)
+            .then(|| chrono::Duration::from_std(self.retry.backoff(attempt)).ok())
+            .flatten()
+            .map(|delay| now + delay);
+        match retry_at {
+            Some(_) => tracing::debug!(job_id = %job.id, kind = %job.kind, attempt, "{}", error),
+            None => tracing::warn!(
+                job_id = %job.id,
+                kind = %job.kind,
+                attempt,
+                "Giving up job: {}",
+                error
+            ),
+        }
+        self.queue.fail(job.id, &error.to_string(), retry_at).await
+    }
+}
+
+/// ジョブを定期的に処理するワーカーを起動する
+///
+/// `should_run` が false の間（スタンバイなど）は処理しない。
+pub fn spawn_job_runner<F>(runner: JobRunner, interval: Duration, should_run: F) -> JoinHandle<()>
+where
+    F: Fn() -> bool + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if !should_run() {
+                continue;
+            }
+            if let Err(err) = runner.run_once(Utc::now()).await {
+                tracing::error!("Failed to process job queue: {}", err);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jobs::{InMemoryJobQueue, JobQueue, JobStatus};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// 最初の `failures` 回は一時的な失敗を返すハンドラー
+    struct FlakyHandler {
+        failures: usize,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl JobHandler for FlakyHandler {
+        async fn run(&self, _job: &Job) -> Result<(), JobError> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                Err(JobError::Retryable("temporary".to_string()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn runner(queue: &Arc<InMemoryJobQueue>, failures: usize) -> (JobRunner, Arc<FlakyHandler>) {
+        let handler = Arc::new(FlakyHandler {
+            failures,
+            calls: AtomicUsize::new(0),
+        });
+        let runner = JobRunner::new(queue.clone())
+            .register("flaky", handler.clone())
+            .with_retry_policy(RetryPolicy {
+                max_attempts: 3,
+                initial_backoff: Duration::from_secs(1),
+                max_backoff: Duration::from_secs(1),
+            });
+        (runner, handler)
+    }
+
+    #[tokio::test]
+    async fn test_retryable_failure_is_retried_after_backoff() {
+        let queue = Arc::new(InMemoryJobQueue::new());
+        let (runner, handler) = runner(&queue, 1);
+        let job = queue
+            .enqueue(NewJob::new("flaky", serde_json::json!({})))
+            .await
+            .unwrap()
+            .unwrap();
+        let now = Utc::now();
+
+        assert_eq!(runner.run_once(now).await.unwrap(), 1);
+        let failed = queue.find_by_id(job.id).await.unwrap().unwrap();
+        assert_eq!(failed.status, JobStatus::Pending);
+        assert_eq!(failed.last_error.as_deref(), Some("temporary"));
+        assert_eq!(runner.run_once(now).await.unwrap(), 0);
+
+        let later = now + chrono::Duration::seconds(2);
+        assert_eq!(runner.run_once(later).await.unwrap(), 1);
+        let done = queue.find_by_id(job.id).await.unwrap().unwrap();
+        assert_eq!(done.status, JobStatus::Succeeded);
+        assert_eq!(done.attempts, 2);
+        assert_eq!(handler.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts_and_unknown_kind() {
+        let queue = Arc::new(InMemoryJobQueue::new());
+        let (runner, handler) = runner(&queue, usize::MAX);
+        let flaky = queue
+            .enqueue(NewJob::new("flaky", serde_json::json!({})).max_attempts(2))
+            .await
+            .unwrap()
+            .unwrap();
+        let unknown = queue
+            .enqueue(NewJob::new("unknown", serde_json::json!({})))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mut now = Utc::now();
+        for _ in 0..3 {
+            runner.run_once(now).await.unwrap();
+            now += chrono::Duration::minutes(1);
+        }
+
+        let flaky = queue.find_by_id(flaky.id).await.unwrap().unwrap();
+        assert_eq!(flaky.status, JobStatus::Failed);
+        assert_eq!(handler.calls.load(Ordering::SeqCst), 2);
+        let unknown = queue.find_by_id(unknown.id).await.unwrap().unwrap();
+        assert_eq!(unknown.status, JobStatus::Failed);
+        assert_eq!(unknown.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_scheduler_enqueues_each_occurrence_once() {
+        let queue = Arc::new(InMemoryJobQueue::new());
+        let scheduler = Scheduler::new(queue.clone()).add(
+            "hourly-report",
+            "0 * * * *".parse().unwrap(),
+            NewJob::new("report", serde_json::json!({})),
+        );
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+
+        assert_eq!(
+            scheduler
+                .run_once(at("2026-01-01T09:30:00Z"))
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            scheduler
+                .run_once(at("2026-01-01T10:00:10Z"))
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            scheduler
+                .run_once(at("2026-01-01T10:00:40Z"))
+                .await
+                .unwrap(),
+            0
+        );
+        // 停止中に過ぎた実行時刻は最新の 1 回だけ
+        assert_eq!(
+            scheduler
+                .run_once(at("2026-01-01T13:05:00Z"))
+                .await
+                .unwrap(),
+            1
+        );
+
+        // 別のインスタンスが同じ時刻を登録しても重複しない
+        let other = Scheduler::new(queue.clone()).add(
+            "hourly-report",
+            "0 * * * *".parse().unwrap(),
+            NewJob::new("report", serde_json::json!({})),
+        );
+        assert_eq!(other.run_once(at("2026-01-01T13:00:30Z")).await.unwrap(), 0);
+
+        let jobs = queue
+            .claim(at("2026-01-01T14:00:00Z"), 10, chrono::Duration::minutes(1))
+            .await
+            .unwrap();
+        assert_eq!(jobs.len(), 2);
+        assert!(jobs.iter().all(|j| j.kind == "report"));
+        assert_eq!(
+            jobs.iter()
+                .filter_map(|j| j.unique_key.as_deref())
+                .filter(|k| k.starts_with("hourly-report:2026-01-01T13:00:00"))
+                .count(),
+            1
+        );
+    }
+}
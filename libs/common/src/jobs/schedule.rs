@@ -0,0 +1,211 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc};
+use std::fmt;
+use std::str::FromStr;
+
+/// 次回の実行時刻を探す範囲（うるう日の指定でも見つかるよう 4 年分）
+const SEARCH_DAYS: i64 = 366 * 4;
+
+/// cron 形式（`分 時 日 月 曜日`、UTC）の実行スケジュール
+///
+/// 各フィールドは `*`、数値、範囲（`1-5`）、列挙（`1,15`）、間隔（`*/15`、`0-30/10`）を
+/// 指定できる。曜日は 0（日曜）〜 6。日と曜日の両方を指定した場合はどちらかに一致すれば
+/// 実行する。`@hourly`・`@daily`・`@weekly`・`@monthly` も使える。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleParseError(String);
+
+impl fmt::Display for ScheduleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid schedule: {}", self.0)
+    }
+}
+
+impl std::error::Error for ScheduleParseError {}
+
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, ScheduleParseError> {
+    let invalid = || ScheduleParseError(field.to_string());
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().map_err(|_| invalid())?),
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (
+                    start.parse().map_err(|_| invalid())?,
+                    end.parse().map_err(|_| invalid())?,
+                ),
+                None => {
+                    let value = range.parse().map_err(|_| invalid())?;
+                    // `5/10` は 5 から最大値まで 10 ごと
+                    (value, if part.contains('/') { max } else { value })
+                }
+            },
+        };
+        if step == 0 || start < min || end > max || start > end {
+            return Err(invalid());
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+fn contains(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+impl Schedule {
+    /// 指定した時刻より後の最初の実行時刻（分単位）
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = after.with_second(0).and_then(|t| t.with_nanosecond(0))? + Duration::minutes(1);
+        let first_day = start.date_naive();
+
+        for offset in 0..SEARCH_DAYS {
+            let date = first_day + Duration::days(offset);
+            if !self.matches_date(date) {
+                continue;
+            }
+            for hour in (0..24).filter(|h| contains(self.hours, *h)) {
+                for minute in (0..60).filter(|m| contains(self.minutes, *m)) {
+                    let candidate = Utc.from_utc_datetime(&date.and_hms_opt(hour, minute, 0)?);
+                    if candidate >= start {
+                        return Some(candidate);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !contains(self.months, date.month()) {
+            return false;
+        }
+        let day = contains(self.days, date.day());
+        let weekday = contains(self.weekdays, date.weekday().num_days_from_sunday());
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day || weekday,
+            _ => day && weekday,
+        }
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = ScheduleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expression = match s.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(ScheduleParseError(s.to_string()));
+        };
+        Ok(Self {
+            expression: s.trim().to_string(),
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)?,
+            days: parse_field(day, 1, 31)?,
+            months: parse_field(month, 1, 12)?,
+            weekdays: parse_field(weekday, 0, 6)?,
+            days_restricted: day != "*",
+            weekdays_restricted: weekday != "*",
+        })
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn next(expression: &str, after: &str) -> DateTime<Utc> {
+        expression
+            .parse::<Schedule>()
+            .unwrap()
+            .next_after(at(after))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_next_after() {
+        assert_eq!(
+            next("*/15 * * * *", "2026-01-01T10:07:30Z"),
+            at("2026-01-01T10:15:00Z")
+        );
+        assert_eq!(
+            next("30 2 * * *", "2026-01-01T02:30:00Z"),
+            at("2026-01-02T02:30:00Z")
+        );
+        // 月初の深夜 1 時（月をまたぐ）
+        assert_eq!(
+            next("0 1 1 * *", "2026-01-31T12:00:00Z"),
+            at("2026-02-01T01:00:00Z")
+        );
+        // 平日の 9 時（2026-01-03 は土曜日）
+        assert_eq!(
+            next("0 9 * * 1-5", "2026-01-03T00:00:00Z"),
+            at("2026-01-05T09:00:00Z")
+        );
+        // 日と曜日の両方を指定した場合はどちらか
+        assert_eq!(
+            next("0 0 15 * 0", "2026-01-05T00:00:00Z"),
+            at("2026-01-11T00:00:00Z")
+        );
+        assert_eq!(
+            next("@monthly", "2026-12-15T00:00:00Z"),
+            at("2027-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            next("0 0 29 2 *", "2026-03-01T00:00:00Z"),
+            at("2028-02-29T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        for expression in [
+            "",
+            "* * * *",
+            "60 * * * *",
+            "* * 0 * *",
+            "*/0 * * * *",
+            "5-1 * * * *",
+        ] {
+            assert!(expression.parse::<Schedule>().is_err(), "{}", expression);
+        }
+        assert!("0 0 31 2 *"
+            .parse::<Schedule>()
+            .unwrap()
+            .next_after(Utc::now())
+            .is_none());
+    }
+}
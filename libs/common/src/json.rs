@@ -0,0 +1,446 @@
+use std::marker::PhantomData;
+
+use axum::{
+    async_trait,
+    extract::{FromRequest, Request},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use validator::Validate;
+
+use crate::error::AppError;
+use crate::msgpack;
+
+/// JSON 入力制限（エンドポイントごとに型で指定）
+pub trait JsonLimits: Send + Sync + 'static {
+    /// ボディの最大バイト数
+    const MAX_BODY_BYTES: usize = 64 * 1024;
+    /// オブジェクト・配列の最大ネスト深さ
+    const MAX_DEPTH: usize = 8;
+    /// 文字列（キーを含む）の最大文字数
+    const MAX_STRING_LENGTH: usize = 2_000;
+    /// 未知のフィールドを拒否するか
+    const DENY_UNKNOWN_FIELDS: bool = false;
+}
+
+/// 既定の JSON 入力制限
+pub struct DefaultJsonLimits;
+
+impl JsonLimits for DefaultJsonLimits {}
+
+/// 未知のフィールドを拒否する JSON 入力制限
+pub struct StrictJsonLimits;
+
+impl JsonLimits for StrictJsonLimits {
+    const DENY_UNKNOWN_FIELDS: bool = true;
+}
+
+/// サイズ・深さ制限付き JSON エクストラクタ
+///
+/// `Content-Type: application/msgpack` の本文も受け付け、JSON と同じ制限で検査する
+/// （サービス間 API で大きな本文を小さく送るため）。
+pub struct HardenedJson<T, L = DefaultJsonLimits>(pub T, pub PhantomData<L>);
+
+impl<T, L> HardenedJson<T, L> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// HardenedJson の制限でデシリアライズし、validator の検証まで行うエクストラクタ
+///
+/// 失敗時は `AppError` として共通のエラーレスポンスを返す。
+pub struct ValidatedJson<T, L = DefaultJsonLimits>(pub T, pub PhantomData<L>);
+
+impl<T, L> ValidatedJson<T, L> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// HardenedJson の拒否理由
+#[derive(Debug)]
+pub enum JsonRejection {
+    UnsupportedMediaType,
+    PayloadTooLarge(usize),
+    InvalidJson(String),
+    InvalidMsgPack(String),
+    TooDeep(usize),
+    StringTooLong(usize),
+    UnknownField(String),
+}
+
+impl JsonRejection {
+    fn code(&self) -> &'static str {
+        match self {
+            JsonRejection::UnsupportedMediaType => "UNSUPPORTED_MEDIA_TYPE",
+            JsonRejection::PayloadTooLarge(_) => "PAYLOAD_TOO_LARGE",
+            JsonRejection::InvalidJson(_) => "INVALID_JSON",
+            JsonRejection::InvalidMsgPack(_) => "INVALID_MSGPACK",
+            JsonRejection::TooDeep(_) => "PAYLOAD_TOO_DEEP",
+            JsonRejection::StringTooLong(_) => "STRING_TOO_LONG",
+            JsonRejection::UnknownField(_) => "UNKNOWN_FIELD",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            JsonRejection::UnsupportedMediaType => {
+                "Expected request with `Content-Type: application/json` or `application/msgpack`"
+                    .to_string()
+            }
+            JsonRejection::PayloadTooLarge(max) => {
+                format!("Request body exceeds {} bytes", max)
+            }
+            JsonRejection::InvalidJson(msg) => format!("Invalid JSON: {}", msg),
+            JsonRejection::InvalidMsgPack(msg) => format!("Invalid MessagePack: {}", msg),
+            JsonRejection::TooDeep(max) => format!("JSON nesting exceeds depth {}", max),
+            JsonRejection::StringTooLong(max) => {
+                format!("JSON string exceeds {} characters", max)
+            }
+            JsonRejection::UnknownField(path) => format!("Unknown field: {}", path),
+        }
+    }
+}
+
+impl From<JsonRejection> for AppError {
+    fn from(rejection: JsonRejection) -> Self {
+        match rejection {
+            JsonRejection::UnsupportedMediaType => {
+                AppError::UnsupportedMediaType(rejection.message())
+            }
+            JsonRejection::PayloadTooLarge(_) => AppError::PayloadTooLarge(rejection.message()),
+            _ => AppError::BadRequest {
+                code: rejection.code(),
+                message: rejection.message(),
+            },
+        }
+    }
+}
+
+impl IntoResponse for JsonRejection {
+    fn into_response(self) -> Response {
+        AppError::from(self).into_response()
+    }
+}
+
+/// 本文の形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BodyFormat {
+    Json,
+    MsgPack,
+}
+
+fn body_format(req: &Request) -> Option<BodyFormat> {
+    let mime = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())?
+        .trim();
+    if mime == "application/json" || mime.ends_with("+json") {
+        Some(BodyFormat::Json)
+    } else if msgpack::is_msgpack_mime(mime) {
+        Some(BodyFormat::MsgPack)
+    } else {
+        None
+    }
+}
+
+/// ネスト深さと文字列長を検査する
+fn check_value(
+    value: &Value,
+    depth: usize,
+    max_depth: usize,
+    max_len: usize,
+) -> Result<(), JsonRejection> {
+    match value {
+        Value::String(s) if s.chars().count() > max_len => {
+            Err(JsonRejection::StringTooLong(max_len))
+        }
+        Value::Array(items) => {
+            if depth >= max_depth {
+                return Err(JsonRejection::TooDeep(max_depth));
+            }
+            items
+                .iter()
+                .try_for_each(|item| check_value(item, depth + 1, max_depth, max_len))
+        }
+        Value::Object(map) => {
+            if depth >= max_depth {
+                return Err(JsonRejection::TooDeep(max_depth));
+            }
+            map.iter().try_for_each(|(key, item)| {
+                if key.chars().count() > max_len {
+                    return Err(JsonRejection::StringTooLong(max_len));
+                }
+                check_value(item, depth + 1, max_depth, max_len)
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 制限を適用して JSON バイト列をデシリアライズする
+pub fn parse_json<T, L>(bytes: &[u8]) -> Result<T, JsonRejection>
+where
+    T: DeserializeOwned,
+    L: JsonLimits,
+{
+    if bytes.len() > L::MAX_BODY_BYTES {
+        return Err(JsonRejection::PayloadTooLarge(L::MAX_BODY_BYTES));
+    }
+
+    let value: Value =
+        serde_json::from_slice(bytes).map_err(|e| JsonRejection::InvalidJson(e.to_string()))?;
+    parse_value::<T, L>(value)
+}
+
+/// 制限を適用して MessagePack のバイト列をデシリアライズする
+///
+/// いったん JSON の値に読み替えるので、深さ・文字列長・未知のフィールドの検査は JSON と同じ。
+pub fn parse_msgpack<T, L>(bytes: &[u8]) -> Result<T, JsonRejection>
+where
+    T: DeserializeOwned,
+    L: JsonLimits,
+{
+    if bytes.len() > L::MAX_BODY_BYTES {
+        return Err(JsonRejection::PayloadTooLarge(L::MAX_BODY_BYTES));
+    }
+
+    let value: Value =
+        msgpack::from_slice(bytes).map_err(|e| JsonRejection::InvalidMsgPack(e.to_string()))?;
+    parse_value::<T, L>(value)
+}
+
+fn parse_value<T, L>(value: Value) -> Result<T, JsonRejection>
+where
+    T: DeserializeOwned,
+    L: JsonLimits,
+{
+    check_value(&value, 0, L::MAX_DEPTH, L::MAX_STRING_LENGTH)?;
+
+    if L::DENY_UNKNOWN_FIELDS {
+        let mut unknown = None;
+        let parsed: T = serde_ignored::deserialize(value, |path| {
+            unknown.get_or_insert_with(|| path.to_string());
+        })
+        .map_err(|e| JsonRejection::InvalidJson(e.to_string()))?;
+
+        match unknown {
+            Some(path) => Err(JsonRejection::UnknownField(path)),
+            None => Ok(parsed),
+        }
+    } else {
+        serde_json::from_value(value).map_err(|e| JsonRejection::InvalidJson(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl<T, L, S> FromRequest<S> for HardenedJson<T, L>
+where
+    T: DeserializeOwned,
+    L: JsonLimits,
+    S: Send + Sync,
+{
+    type Rejection = JsonRejection;
+
+    async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        let format = body_format(&req).ok_or(JsonRejection::UnsupportedMediaType)?;
+
+        // 上限を超えた時点で読み込みをやめる（axum の既定の上限まで受信しない）
+        let bytes = Limited::new(req.into_body(), L::MAX_BODY_BYTES)
+            .collect()
+            .await
+            .map_err(|e| {
+                if e.is::<LengthLimitError>() {
+                    JsonRejection::PayloadTooLarge(L::MAX_BODY_BYTES)
+                } else {
+                    JsonRejection::InvalidJson(e.to_string())
+                }
+            })?
+            .to_bytes();
+
+        match format {
+            BodyFormat::Json => parse_json::<T, L>(&bytes),
+            BodyFormat::MsgPack => parse_msgpack::<T, L>(&bytes),
+        }
+        .map(|value| HardenedJson(value, PhantomData))
+    }
+}
+
+#[async_trait]
+impl<T, L, S> FromRequest<S> for ValidatedJson<T, L>
+where
+    T: DeserializeOwned + Validate,
+    L: JsonLimits,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let HardenedJson(value, _) = HardenedJson::<T, L>::from_request(req, state).await?;
+        value.validate()?;
+        Ok(ValidatedJson(value, PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::{Body, Bytes},
+        http::{Request as HttpRequest, StatusCode},
+        routing::post,
+        Router,
+    };
+    use serde::Deserialize;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Payload {
+        name: String,
+        tags: Option<Vec<String>>,
+    }
+
+    struct ShallowLimits;
+
+    impl JsonLimits for ShallowLimits {
+        const MAX_DEPTH: usize = 2;
+        const MAX_STRING_LENGTH: usize = 5;
+    }
+
+    #[test]
+    fn test_parse_json_accepts_valid_payload() {
+        let parsed = parse_json::<Payload, DefaultJsonLimits>(br#"{"name": "abc"}"#).unwrap();
+        assert_eq!(parsed.name, "abc");
+    }
+
+    #[test]
+    fn test_parse_json_rejects_deep_nesting() {
+        let result = parse_json::<Value, ShallowLimits>(br#"{"a": {"b": {"c": 1}}}"#);
+        assert!(matches!(result, Err(JsonRejection::TooDeep(2))));
+    }
+
+    #[test]
+    fn test_parse_json_rejects_long_string() {
+        let result = parse_json::<Payload, ShallowLimits>(br#"{"name": "abcdef"}"#);
+        assert!(matches!(result, Err(JsonRejection::StringTooLong(5))));
+    }
+
+    #[test]
+    fn test_parse_json_unknown_fields() {
+        let body = br#"{"name": "abc", "extra": true}"#;
+
+        assert!(parse_json::<Payload, DefaultJsonLimits>(body).is_ok());
+
+        let result = parse_json::<Payload, StrictJsonLimits>(body);
+        assert!(matches!(result, Err(JsonRejection::UnknownField(path)) if path == "extra"));
+    }
+
+    #[test]
+    fn test_parse_msgpack_applies_json_limits() {
+        let body = crate::msgpack::to_vec(&serde_json::json!({ "name": "abc" })).unwrap();
+        let parsed = parse_msgpack::<Payload, StrictJsonLimits>(&body).unwrap();
+        assert_eq!(parsed.name, "abc");
+
+        let body = crate::msgpack::to_vec(&serde_json::json!({ "name": "abcdef" })).unwrap();
+        let result = parse_msgpack::<Payload, ShallowLimits>(&body);
+        assert!(matches!(result, Err(JsonRejection::StringTooLong(5))));
+
+        let body =
+            crate::msgpack::to_vec(&serde_json::json!({ "name": "abc", "extra": 1 })).unwrap();
+        let result = parse_msgpack::<Payload, StrictJsonLimits>(&body);
+        assert!(matches!(result, Err(JsonRejection::UnknownField(path)) if path == "extra"));
+
+        let result = parse_msgpack::<Payload, DefaultJsonLimits>(b"\xc1");
+        assert!(matches!(result, Err(JsonRejection::InvalidMsgPack(_))));
+    }
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct NamedPayload {
+        #[validate(length(min = 1, message = "名前を入力してください"))]
+        name: String,
+    }
+
+    async fn post_validated(body: &'static str) -> (StatusCode, Value) {
+        let app = Router::new().route(
+            "/",
+            post(|ValidatedJson(payload, _): ValidatedJson<NamedPayload>| async move {
+                payload.name
+            }),
+        );
+
+        let response = app
+            .oneshot(
+                HttpRequest::builder()
+                    .method("POST")
+                    .uri("/")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_validated_json_rejections_are_json() {
+        let (status, body) = post_validated(r#"{"name": "#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_JSON");
+
+        let (status, body) = post_validated(r#"{"name": ""}"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        assert_eq!(body["errors"][0]["field"], "name");
+        assert_eq!(body["errors"][0]["message"], "名前を入力してください");
+
+        let (status, _) = post_validated(r#"{"name": "abc"}"#).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_is_not_read_to_the_end() {
+        // 終わりのない本文を 1 KiB ずつ送る
+        let chunks = Arc::new(AtomicUsize::new(0));
+        let sent = chunks.clone();
+        let body = Body::from_stream(futures_util::stream::repeat_with(move || {
+            sent.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, std::convert::Infallible>(Bytes::from(vec![b' '; 1024]))
+        }));
+        let app = Router::new().route(
+            "/",
+            post(|HardenedJson(_, _): HardenedJson<Value>| async { "" }),
+        );
+
+        let response = app
+            .oneshot(
+                HttpRequest::builder()
+                    .method("POST")
+                    .uri("/")
+                    .header("Content-Type", "application/json")
+                    .body(body)
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert!(chunks.load(Ordering::SeqCst) <= DefaultJsonLimits::MAX_BODY_BYTES / 1024 + 1);
+    }
+}
@@ -1,3 +1,22 @@
+pub mod admin_auth;
+pub mod compression;
+pub mod config;
+pub mod error;
+pub mod fields;
+pub mod i18n;
+pub mod jobs;
+pub mod json;
+pub mod lifecycle;
+pub mod log_level;
+pub mod money;
+pub mod msgpack;
+pub mod negotiate;
+pub mod panic;
+pub mod patch;
+pub mod reporting;
+pub mod startup;
+pub mod trace;
+
 use serde::{Deserialize, Serialize};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -16,15 +35,37 @@ impl HealthResponse {
 }
 
 /// 共有型: エラーレスポンス
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorResponse {
     pub error: String,
+    pub code: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<error::FieldError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+}
+
+impl ErrorResponse {
+    pub fn new(error: impl Into<String>, code: impl Into<String>) -> Self {
+        Self {
+            error: error.into(),
+            code: code.into(),
+            errors: Vec::new(),
+            trace_id: None,
+        }
+    }
 }
 
-/// tracing初期化
+/// tracing初期化（フィルターは [`log_level::set_log_filter`] で実行中に変更できる）
+///
+/// パニックもトレース ID とバックトレース付きでログに出力する（[`panic::install_panic_hook`]）。
 pub fn init_tracing() {
+    let (filter, handle) =
+        tracing_subscriber::reload::Layer::new(tracing_subscriber::EnvFilter::from_default_env());
     tracing_subscriber::registry()
+        .with(filter)
         .with(tracing_subscriber::fmt::layer())
-        .with(tracing_subscriber::EnvFilter::from_default_env())
         .init();
+    log_level::install(handle);
+    panic::install_panic_hook();
 }
@@ -0,0 +1,106 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::ErrorResponse;
+
+/// リクエストを受け付けられるか（Kubernetes の readiness probe に返す）
+///
+/// 起動処理（マイグレーション・接続プールの準備）の完了後に立て、
+/// 停止シグナルを受けたら下ろしてロードバランサーから外してもらう。
+#[derive(Debug, Clone, Default)]
+pub struct Readiness(Arc<AtomicBool>);
+
+impl Readiness {
+    /// 受け付け可能な状態で作成
+    pub fn ready() -> Self {
+        Self(Arc::new(AtomicBool::new(true)))
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    pub fn set_ready(&self, ready: bool) {
+        self.0.store(ready, Ordering::SeqCst);
+    }
+}
+
+/// GET /ready（受け付け不可なら 503）
+pub async fn ready(State(readiness): State<Readiness>) -> Response {
+    if readiness.is_ready() {
+        "OK".into_response()
+    } else {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ErrorResponse::new("Service is not ready", "NOT_READY")),
+        )
+            .into_response()
+    }
+}
+
+/// `/ready` のみのルーター
+pub fn readiness_router(readiness: Readiness) -> Router {
+    Router::new()
+        .route("/ready", get(ready))
+        .with_state(readiness)
+}
+
+/// SIGTERM（または Ctrl+C）を待ち、readiness を下ろしてから `delay` だけ待つ
+///
+/// `axum::serve(..).with_graceful_shutdown` に渡す。待っている間もリクエストは処理するため、
+/// Pod の削除がエンドポイントに反映されるまでに届いたリクエストを取りこぼさない。
+pub async fn shutdown_signal(readiness: Readiness, delay: Duration) {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Failed to listen for Ctrl+C");
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to listen for SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    readiness.set_ready(false);
+    tracing::info!("Shutdown requested, draining for {:?}", delay);
+    tokio::time::sleep(delay).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_ready_follows_readiness() {
+        let readiness = Readiness::default();
+        let app = readiness_router(readiness.clone());
+        let request = || Request::get("/ready").body(Body::empty()).unwrap();
+
+        let response = app.clone().oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        readiness.set_ready(true);
+        let response = app.clone().oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
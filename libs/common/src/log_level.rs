@@ -0,0 +1,131 @@
+use axum::{middleware, routing::get, Json, Router};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, OnceLock};
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+use crate::admin_auth::require_admin_token;
+use crate::error::AppError;
+use crate::json::{HardenedJson, StrictJsonLimits};
+
+static LOG_FILTER: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// `init_tracing` で作成したフィルターの差し替え用ハンドルを登録する
+pub(crate) fn install(handle: reload::Handle<EnvFilter, Registry>) {
+    let _ = LOG_FILTER.set(handle);
+}
+
+/// 現在のフィルター（`init_tracing` を呼んでいなければ None）
+pub fn current_log_filter() -> Option<String> {
+    LOG_FILTER
+        .get()?
+        .with_current(|filter| filter.to_string())
+        .ok()
+}
+
+/// フィルターを差し替える（`RUST_LOG` と同じ書式、例: `info,accounting_service::repository=debug`）
+pub fn set_log_filter(directives: &str) -> Result<String, AppError> {
+    let filter = EnvFilter::try_new(directives)
+        .map_err(|err| AppError::validation(format!("Invalid log filter: {}", err)))?;
+    let handle = LOG_FILTER.get().ok_or_else(|| {
+        AppError::internal("LOG_RELOAD_UNAVAILABLE", "Tracing is not initialized")
+    })?;
+    handle
+        .reload(filter)
+        .map_err(|err| AppError::internal("LOG_RELOAD_FAILED", err.to_string()))?;
+    Ok(current_log_filter().unwrap_or_else(|| directives.to_string()))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LogLevelRequest {
+    pub filter: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LogLevelResponse {
+    pub filter: String,
+}
+
+/// GET /api/admin/log-level - 現在のフィルター
+pub async fn get_log_level() -> Json<LogLevelResponse> {
+    Json(LogLevelResponse {
+        filter: current_log_filter().unwrap_or_default(),
+    })
+}
+
+/// PUT /api/admin/log-level - フィルターを差し替える（再起動すると `RUST_LOG` に戻る）
+pub async fn put_log_level(
+    HardenedJson(request, _): HardenedJson<LogLevelRequest, StrictJsonLimits>,
+) -> Result<Json<LogLevelResponse>, AppError> {
+    let filter = set_log_filter(&request.filter)?;
+    tracing::warn!(filter = %filter, "Log filter changed");
+    Ok(Json(LogLevelResponse { filter }))
+}
+
+/// ログレベル変更用ルーター（管理トークンで保護）
+pub fn log_level_router(admin_token: Option<String>) -> Router {
+    Router::new()
+        .route(
+            "/api/admin/log-level",
+            get(get_log_level).put(put_log_level),
+        )
+        .layer(middleware::from_fn_with_state(
+            admin_token.map(Arc::from),
+            require_admin_token,
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::{header, StatusCode};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn put(app: &Router, authorization: Option<&str>, filter: &str) -> StatusCode {
+        let mut request = Request::put("/api/admin/log-level")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                serde_json::json!({ "filter": filter }).to_string(),
+            ))
+            .unwrap();
+        if let Some(value) = authorization {
+            request
+                .headers_mut()
+                .insert(header::AUTHORIZATION, value.parse().unwrap());
+        }
+        app.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_log_level_requires_admin_token() {
+        let app = log_level_router(Some("secret".to_string()));
+        assert_eq!(put(&app, None, "debug").await, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            put(&app, Some("Bearer wrong"), "debug").await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            put(&app, Some("Bearer secret"), "not a=filter=").await,
+            StatusCode::BAD_REQUEST
+        );
+
+        let request = Request::put("/api/admin/log-level")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::AUTHORIZATION, "Bearer secret")
+            .body(Body::from("{\"filter\":"))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "INVALID_JSON");
+
+        let disabled = log_level_router(None);
+        assert_eq!(
+            put(&disabled, Some("Bearer secret"), "debug").await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+}
@@ -0,0 +1,336 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// 金額計算のエラー
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MoneyError {
+    #[error("Currency mismatch: {0} and {1}")]
+    CurrencyMismatch(Currency, Currency),
+
+    #[error("Amount out of range")]
+    Overflow,
+
+    #[error("Invalid allocation ratios")]
+    InvalidRatios,
+}
+
+/// ISO 4217 通貨コード
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Currency([u8; 3]);
+
+impl Currency {
+    pub const JPY: Currency = Currency(*b"JPY");
+    pub const USD: Currency = Currency(*b"USD");
+    pub const EUR: Currency = Currency(*b"EUR");
+
+    pub fn as_str(&self) -> &str {
+        // 構築時に ASCII 英大文字のみを保証している
+        std::str::from_utf8(&self.0).unwrap_or("???")
+    }
+
+    /// 補助単位の桁数（JPY は 0、USD は 2 など）
+    pub fn minor_units(&self) -> u32 {
+        match &self.0 {
+            b"JPY" | b"KRW" | b"VND" | b"CLP" | b"ISK" | b"PYG" | b"UGX" => 0,
+            b"BHD" | b"KWD" | b"OMR" | b"JOD" | b"TND" | b"IQD" | b"LYD" => 3,
+            _ => 2,
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 3 || !bytes.iter().all(u8::is_ascii_uppercase) {
+            return Err(format!("Invalid currency code: {}", s));
+        }
+        Ok(Currency([bytes[0], bytes[1], bytes[2]]))
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Currency::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// 補助単位未満の端数処理
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingPolicy {
+    /// 偶数丸め（銀行丸め）
+    #[default]
+    HalfEven,
+    /// 四捨五入
+    HalfUp,
+    /// 切り捨て（0 方向）
+    Down,
+    /// 切り上げ（0 から離れる方向）
+    Up,
+}
+
+impl RoundingPolicy {
+    fn strategy(&self) -> RoundingStrategy {
+        match self {
+            RoundingPolicy::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingPolicy::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingPolicy::Down => RoundingStrategy::ToZero,
+            RoundingPolicy::Up => RoundingStrategy::AwayFromZero,
+        }
+    }
+}
+
+/// 金額（補助単位の整数 + 通貨）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Money {
+    /// 補助単位での金額（JPY なら円、USD ならセント）
+    pub amount: i64,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: i64, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(0, currency)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.amount < 0
+    }
+
+    /// 主単位の金額（1234 セント → 12.34 USD）
+    pub fn to_decimal(&self) -> Decimal {
+        Decimal::new(self.amount, self.currency.minor_units())
+    }
+
+    /// 主単位の金額から作成（補助単位未満は偶数丸め）
+    pub fn from_decimal(value: Decimal, currency: Currency) -> Result<Self, MoneyError> {
+        Self::from_decimal_with(value, currency, RoundingPolicy::default())
+    }
+
+    /// 主単位の金額から作成（端数処理を指定）
+    pub fn from_decimal_with(
+        value: Decimal,
+        currency: Currency,
+        rounding: RoundingPolicy,
+    ) -> Result<Self, MoneyError> {
+        let scale = currency.minor_units();
+        let mut rounded = value.round_dp_with_strategy(scale, rounding.strategy());
+        rounded.rescale(scale);
+        i64::try_from(rounded.mantissa())
+            .map(|amount| Self::new(amount, currency))
+            .map_err(|_| MoneyError::Overflow)
+    }
+
+    fn ensure_same_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch(self.currency, other.currency));
+        }
+        Ok(())
+    }
+
+    pub fn checked_add(&self, other: Money) -> Result<Money, MoneyError> {
+        self.ensure_same_currency(&other)?;
+        self.amount
+            .checked_add(other.amount)
+            .map(|amount| Money::new(amount, self.currency))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    pub fn checked_sub(&self, other: Money) -> Result<Money, MoneyError> {
+        self.ensure_same_currency(&other)?;
+        self.amount
+            .checked_sub(other.amount)
+            .map(|amount| Money::new(amount, self.currency))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    pub fn checked_neg(&self) -> Result<Money, MoneyError> {
+        self.amount
+            .checked_neg()
+            .map(|amount| Money::new(amount, self.currency))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// 係数を掛ける（税率・為替レートなど）
+    pub fn multiply(&self, factor: Decimal, rounding: RoundingPolicy) -> Result<Money, MoneyError> {
+        let value = self
+            .to_decimal()
+            .checked_mul(factor)
+            .ok_or(MoneyError::Overflow)?;
+        Money::from_decimal_with(value, self.currency, rounding)
+    }
+
+    /// 同一通貨の金額を合計する
+    pub fn sum<I>(currency: Currency, items: I) -> Result<Money, MoneyError>
+    where
+        I: IntoIterator<Item = Money>,
+    {
+        items
+            .into_iter()
+            .try_fold(Money::zero(currency), |acc, item| acc.checked_add(item))
+    }
+
+    /// 比率で按分する（最大剰余法で端数を配分し、合計は元の金額と一致する）
+    ///
+    /// 10,000 円を 1:1:1 で按分すると 3,334 / 3,333 / 3,333 円になる。
+    pub fn allocate(&self, ratios: &[u32]) -> Result<Vec<Money>, MoneyError> {
+        let total: u128 = ratios.iter().map(|&r| r as u128).sum();
+        if total == 0 {
+            return Err(MoneyError::InvalidRatios);
+        }
+
+        let amount = self.amount as i128;
+        let mut shares: Vec<i128> = Vec::with_capacity(ratios.len());
+        let mut remainders: Vec<(usize, i128)> = Vec::with_capacity(ratios.len());
+        for (index, &ratio) in ratios.iter().enumerate() {
+            let numerator = amount * ratio as i128;
+            shares.push(numerator / total as i128);
+            remainders.push((index, (numerator % total as i128).abs()));
+        }
+
+        // 端数（補助単位）を剰余の大きい順に 1 ずつ配分（同値は先頭優先）
+        let leftover = amount - shares.iter().sum::<i128>();
+        let step = leftover.signum();
+        remainders.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        for (index, _) in remainders.iter().take(leftover.unsigned_abs() as usize) {
+            shares[*index] += step;
+        }
+
+        Ok(shares
+            .into_iter()
+            .map(|share| Money::new(share as i64, self.currency))
+            .collect())
+    }
+
+    /// 均等に分割する
+    pub fn split(&self, parts: usize) -> Result<Vec<Money>, MoneyError> {
+        self.allocate(&vec![1; parts])
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal(), self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn yen(amount: i64) -> Money {
+        Money::new(amount, Currency::JPY)
+    }
+
+    #[test]
+    fn test_currency_from_str() {
+        assert_eq!(Currency::from_str("JPY").unwrap(), Currency::JPY);
+        assert!(Currency::from_str("jpy").is_err());
+        assert!(Currency::from_str("JP").is_err());
+        assert_eq!(Currency::JPY.minor_units(), 0);
+        assert_eq!(Currency::USD.minor_units(), 2);
+    }
+
+    #[test]
+    fn test_money_decimal_conversion() {
+        let usd = Money::new(1234, Currency::USD);
+        assert_eq!(usd.to_decimal(), Decimal::new(1234, 2));
+        assert_eq!(usd.to_string(), "12.34 USD");
+
+        let value = Decimal::new(10005, 1);
+        assert_eq!(
+            Money::from_decimal(value, Currency::JPY).unwrap(),
+            yen(1000)
+        );
+        assert_eq!(
+            Money::from_decimal_with(value, Currency::JPY, RoundingPolicy::HalfUp).unwrap(),
+            yen(1001)
+        );
+        assert_eq!(
+            Money::from_decimal_with(Decimal::new(10009, 1), Currency::JPY, RoundingPolicy::Down)
+                .unwrap(),
+            yen(1000)
+        );
+        assert_eq!(
+            Money::from_decimal_with(Decimal::new(10001, 1), Currency::JPY, RoundingPolicy::Up)
+                .unwrap(),
+            yen(1001)
+        );
+    }
+
+    #[test]
+    fn test_money_arithmetic() {
+        assert_eq!(yen(1000).checked_add(yen(500)).unwrap(), yen(1500));
+        assert_eq!(yen(1000).checked_sub(yen(1500)).unwrap(), yen(-500));
+        assert_eq!(
+            yen(1000).checked_add(Money::new(100, Currency::USD)),
+            Err(MoneyError::CurrencyMismatch(Currency::JPY, Currency::USD))
+        );
+        assert_eq!(yen(i64::MAX).checked_add(yen(1)), Err(MoneyError::Overflow));
+        assert_eq!(
+            Money::sum(Currency::JPY, [yen(100), yen(200), yen(300)]).unwrap(),
+            yen(600)
+        );
+        assert_eq!(
+            yen(1000)
+                .multiply(Decimal::new(108, 3), RoundingPolicy::Down)
+                .unwrap(),
+            yen(108)
+        );
+    }
+
+    #[test]
+    fn test_allocate_keeps_total() {
+        let shares = yen(10_000).split(3).unwrap();
+        assert_eq!(shares, vec![yen(3334), yen(3333), yen(3333)]);
+
+        let shares = yen(10_000).allocate(&[5, 3, 2]).unwrap();
+        assert_eq!(shares, vec![yen(5000), yen(3000), yen(2000)]);
+
+        let shares = yen(-100).allocate(&[1, 1, 1]).unwrap();
+        assert_eq!(
+            Money::sum(Currency::JPY, shares.clone()).unwrap(),
+            yen(-100)
+        );
+        assert_eq!(shares, vec![yen(-34), yen(-33), yen(-33)]);
+
+        assert_eq!(yen(100).allocate(&[0, 0]), Err(MoneyError::InvalidRatios));
+    }
+
+    #[test]
+    fn test_money_serde() {
+        let money = yen(5000);
+        let json = serde_json::to_value(money).unwrap();
+        assert_eq!(json, serde_json::json!({"amount": 5000, "currency": "JPY"}));
+        assert_eq!(serde_json::from_value::<Money>(json).unwrap(), money);
+
+        let policy: RoundingPolicy = serde_json::from_str("\"half_up\"").unwrap();
+        assert_eq!(policy, RoundingPolicy::HalfUp);
+    }
+}
@@ -0,0 +1,87 @@
+use axum::{
+    http::header,
+    response::{IntoResponse, Response},
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::AppError;
+
+/// MessagePack の Content-Type
+pub const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+
+/// MessagePack で返すレスポンス（サービス間 API 向け）
+///
+/// JSON と同じ形になるよう、構造体はフィールド名付きのマップ、UUID などは文字列で書き出す。
+pub struct MsgPack<T>(pub T);
+
+impl<T: Serialize> IntoResponse for MsgPack<T> {
+    fn into_response(self) -> Response {
+        match to_vec(&self.0) {
+            Ok(body) => ([(header::CONTENT_TYPE, MSGPACK_CONTENT_TYPE)], body).into_response(),
+            Err(err) => err.into_response(),
+        }
+    }
+}
+
+/// JSON と同じ形の MessagePack にシリアライズする
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, AppError> {
+    let mut body = Vec::new();
+    let mut serializer = rmp_serde::Serializer::new(&mut body)
+        .with_struct_map()
+        .with_human_readable();
+    value
+        .serialize(&mut serializer)
+        .map_err(|e| AppError::internal("SERIALIZATION_ERROR", e.to_string()))?;
+    Ok(body)
+}
+
+/// [`to_vec`] と同じ形の MessagePack をデシリアライズする
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, rmp_serde::decode::Error> {
+    let mut deserializer = rmp_serde::Deserializer::from_read_ref(bytes).with_human_readable();
+    T::deserialize(&mut deserializer)
+}
+
+/// MessagePack のメディアタイプか（`application/x-msgpack` などの別名を含む）
+pub fn is_msgpack_mime(mime: &str) -> bool {
+    matches!(
+        mime.trim().to_ascii_lowercase().as_str(),
+        "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+    use serde::Deserialize;
+    use uuid::Uuid;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Transfer {
+        id: Uuid,
+        amount: i64,
+        memo: Option<String>,
+    }
+
+    #[tokio::test]
+    async fn test_msgpack_round_trip_matches_json_shape() {
+        let transfer = Transfer {
+            id: Uuid::new_v4(),
+            amount: 1500,
+            memo: None,
+        };
+
+        let response = MsgPack(&transfer).into_response();
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            MSGPACK_CONTENT_TYPE
+        );
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(from_slice::<Transfer>(&body).unwrap(), transfer);
+
+        // UUID は JSON と同じ文字列で入る
+        let value: serde_json::Value = from_slice(&body).unwrap();
+        assert_eq!(value, serde_json::to_value(&transfer).unwrap());
+        assert!(body.len() < serde_json::to_vec(&transfer).unwrap().len());
+    }
+}
@@ -0,0 +1,295 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderValue},
+    response::{IntoResponse, Response},
+    Json,
+};
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::Value;
+use std::convert::Infallible;
+
+use crate::error::AppError;
+use crate::msgpack::{self, MSGPACK_CONTENT_TYPE};
+
+/// Accept ヘッダーで選んだ一覧の応答形式（未指定・非対応なら JSON）
+///
+/// CSV は入れ子のオブジェクトを `parent.child` の列に展開し、配列は JSON 文字列のまま 1 列に入れる。
+/// MessagePack はサービス間の大きな一覧の転送向けで、JSON と同じ形で返す。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Negotiate {
+    #[default]
+    Json,
+    Csv,
+    Ndjson,
+    MsgPack,
+}
+
+impl Negotiate {
+    /// Accept ヘッダーの値から品質値の高い順に対応している形式を選ぶ
+    pub fn from_accept(accept: &str) -> Self {
+        let mut candidates: Vec<(f32, Self)> = accept
+            .split(',')
+            .filter_map(|range| {
+                let mut params = range.split(';').map(str::trim);
+                let format = match params.next()?.to_ascii_lowercase().as_str() {
+                    "application/json" | "application/*" | "*/*" => Self::Json,
+                    "text/csv" | "text/*" => Self::Csv,
+                    "application/x-ndjson" => Self::Ndjson,
+                    mime if msgpack::is_msgpack_mime(mime) => Self::MsgPack,
+                    _ => return None,
+                };
+                let quality = params
+                    .find_map(|param| param.strip_prefix("q="))
+                    .and_then(|q| q.parse().ok())
+                    .unwrap_or(1.0);
+                (quality > 0.0).then_some((quality, format))
+            })
+            .collect();
+        // 同じ品質値なら先に書かれたものを優先する
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+        candidates.first().map_or(Self::Json, |(_, format)| *format)
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Ndjson => "application/x-ndjson",
+            Self::MsgPack => MSGPACK_CONTENT_TYPE,
+        }
+    }
+
+    /// 一覧を選んだ形式で返す（`Vary: Accept` を付ける）
+    pub fn respond<T: Serialize>(self, items: &[T]) -> Result<Response, AppError> {
+        let mut response = match self {
+            Self::Json => Json(items).into_response(),
+            Self::Csv => (
+                [(header::CONTENT_TYPE, self.content_type())],
+                to_csv(items)?,
+            )
+                .into_response(),
+            Self::Ndjson => {
+                let mut body = String::new();
+                for item in items {
+                    body.push_str(&serde_json::to_string(item).map_err(serialize_error)?);
+                    body.push('\n');
+                }
+                ([(header::CONTENT_TYPE, self.content_type())], body).into_response()
+            }
+            Self::MsgPack => (
+                [(header::CONTENT_TYPE, self.content_type())],
+                msgpack::to_vec(items)?,
+            )
+                .into_response(),
+        };
+        response
+            .headers_mut()
+            .insert(header::VARY, HeaderValue::from_static("accept"));
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Negotiate {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .headers
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .map_or(Self::Json, Self::from_accept))
+    }
+}
+
+/// CSV の先頭に付ける BOM
+const UTF8_BOM: &str = "\u{feff}";
+
+pub(crate) fn serialize_error(err: serde_json::Error) -> AppError {
+    AppError::internal("SERIALIZATION_ERROR", err.to_string())
+}
+
+/// 1 行目を見出しにした CSV（列は 1 件目の項目の順）
+///
+/// 日本語版 Excel が UTF-8 と判別できるよう、先頭に BOM を付ける。
+fn to_csv<T: Serialize>(items: &[T]) -> Result<String, AppError> {
+    let mut rows = Vec::with_capacity(items.len());
+    for item in items {
+        // 構造体のフィールド順を保つため、最上位は IndexMap で読み直す
+        let text = serde_json::to_string(item).map_err(serialize_error)?;
+        let fields: IndexMap<String, Value> =
+            serde_json::from_str(&text).map_err(serialize_error)?;
+        let mut row = IndexMap::new();
+        for (key, value) in fields {
+            flatten(key, value, &mut row);
+        }
+        rows.push(row);
+    }
+
+    let Some(columns) = rows
+        .first()
+        .map(|row| row.keys().cloned().collect::<Vec<_>>())
+    else {
+        return Ok(UTF8_BOM.to_string());
+    };
+    let mut csv = UTF8_BOM.to_string();
+    write_record(&mut csv, columns.iter().map(String::as_str));
+    for row in &rows {
+        write_record(
+            &mut csv,
+            columns
+                .iter()
+                .map(|column| row.get(column).map_or("", String::as_str)),
+        );
+    }
+    Ok(csv)
+}
+
+fn flatten(key: String, value: Value, row: &mut IndexMap<String, String>) {
+    let cell = match value {
+        Value::Object(map) => {
+            for (child, value) in map {
+                flatten(format!("{}.{}", key, child), value, row);
+            }
+            return;
+        }
+        Value::Null => String::new(),
+        Value::String(s) => neutralize_formula(s),
+        other => other.to_string(),
+    };
+    row.insert(key, cell);
+}
+
+/// 表計算ソフトが数式として評価する文字列は `'` を前置して文字列として開かせる
+///
+/// 利用者の入力（科目名・摘要など）から数式を実行させない。数値の列はそのまま残す。
+fn neutralize_formula(cell: String) -> String {
+    if cell.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", cell)
+    } else {
+        cell
+    }
+}
+
+fn write_record<'a>(csv: &mut String, cells: impl Iterator<Item = &'a str>) {
+    for (index, cell) in cells.enumerate() {
+        if index > 0 {
+            csv.push(',');
+        }
+        if cell.contains([',', '"', '\n', '\r']) {
+            csv.push('"');
+            csv.push_str(&cell.replace('"', "\"\""));
+            csv.push('"');
+        } else {
+            csv.push_str(cell);
+        }
+    }
+    csv.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+
+    #[derive(Serialize)]
+    struct Row {
+        code: &'static str,
+        name: &'static str,
+        amount: Amount,
+        tags: Vec<&'static str>,
+        note: Option<&'static str>,
+    }
+
+    #[derive(Serialize)]
+    struct Amount {
+        minor: i64,
+        currency: &'static str,
+    }
+
+    #[test]
+    fn test_from_accept() {
+        assert_eq!(Negotiate::from_accept("text/csv"), Negotiate::Csv);
+        assert_eq!(
+            Negotiate::from_accept("application/json;q=0.5, application/x-ndjson"),
+            Negotiate::Ndjson
+        );
+        assert_eq!(Negotiate::from_accept("text/csv;q=0, */*"), Negotiate::Json);
+        assert_eq!(Negotiate::from_accept("image/png"), Negotiate::Json);
+        assert_eq!(
+            Negotiate::from_accept("application/msgpack, application/json;q=0.9"),
+            Negotiate::MsgPack
+        );
+    }
+
+    #[tokio::test]
+    async fn test_csv_keeps_field_order_and_escapes() {
+        let rows = [Row {
+            code: "101",
+            name: "現金, \"小口\"",
+            amount: Amount {
+                minor: 1500,
+                currency: "JPY",
+            },
+            tags: vec!["a"],
+            note: None,
+        }];
+
+        let response = Negotiate::Csv.respond(&rows).unwrap();
+        assert_eq!(response.headers()[header::VARY], "accept");
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/csv; charset=utf-8"
+        );
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(
+            std::str::from_utf8(&body).unwrap(),
+            "\u{feff}code,name,amount.currency,amount.minor,tags,note\r\n\
+             101,\"現金, \"\"小口\"\"\",JPY,1500,\"[\"\"a\"\"]\",\r\n"
+        );
+
+        let response = Negotiate::Ndjson.respond(&rows).unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(std::str::from_utf8(&body).unwrap().lines().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_csv_neutralizes_formulas() {
+        #[derive(Serialize)]
+        struct Entry {
+            name: &'static str,
+            amount: i64,
+        }
+        let rows = [
+            Entry {
+                name: "=HYPERLINK(\"http://example.com\")",
+                amount: -500,
+            },
+            Entry {
+                name: "@SUM(A1)",
+                amount: 0,
+            },
+            Entry {
+                name: "\tcmd",
+                amount: 0,
+            },
+            Entry {
+                name: "献金 -3 月分",
+                amount: 0,
+            },
+        ];
+
+        let response = Negotiate::Csv.respond(&rows).unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(
+            std::str::from_utf8(&body).unwrap(),
+            "\u{feff}name,amount\r\n\
+             \"'=HYPERLINK(\"\"http://example.com\"\")\",-500\r\n\
+             '@SUM(A1),0\r\n\
+             '\tcmd,0\r\n\
+             献金 -3 月分,0\r\n"
+        );
+    }
+}
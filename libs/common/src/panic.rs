@@ -0,0 +1,81 @@
+use axum::response::{IntoResponse, Response};
+use std::any::Any;
+use std::backtrace::Backtrace;
+use tower_http::catch_panic::CatchPanicLayer;
+
+use crate::error::AppError;
+use crate::trace::current_trace_id;
+
+/// パニックをリクエストのトレース ID とバックトレース付きでログに出力するフックを設定する
+///
+/// フックはパニックしたタスク上で動くため、`trace_id_middleware` の内側ならトレース ID を取得できる。
+pub fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        tracing::error!(
+            trace_id = current_trace_id().as_deref().unwrap_or("-"),
+            location = info.location().map(ToString::to_string).as_deref().unwrap_or("-"),
+            backtrace = %Backtrace::force_capture(),
+            "Panic: {}",
+            panic_message(info.payload())
+        );
+    }));
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+fn panic_response(payload: Box<dyn Any + Send + 'static>) -> Response {
+    AppError::internal(
+        "INTERNAL_ERROR",
+        format!("Handler panicked: {}", panic_message(payload.as_ref())),
+    )
+    .into_response()
+}
+
+/// ハンドラーのパニックを `ErrorResponse` 形式の 500 に変換するレイヤー
+///
+/// 変換がなければ接続が空のレスポンスのまま切れる。トレース ID と言語を反映するため、
+/// `trace_id_middleware` と `locale_middleware` の内側に置く。
+pub fn catch_panic_layer() -> CatchPanicLayer<fn(Box<dyn Any + Send + 'static>) -> Response> {
+    CatchPanicLayer::custom(panic_response as fn(_) -> _)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::{trace_id_middleware, TRACE_ID_HEADER};
+    use crate::ErrorResponse;
+    use axum::{body::Body, extract::Request, http::StatusCode, middleware, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn panicking() -> &'static str {
+        panic!("boom")
+    }
+
+    #[tokio::test]
+    async fn test_panic_becomes_json_500_with_trace_id() {
+        let app: Router = Router::new()
+            .route("/panic", get(panicking))
+            .layer(catch_panic_layer())
+            .layer(middleware::from_fn(trace_id_middleware));
+
+        let request = Request::get("/panic")
+            .header(TRACE_ID_HEADER, "req-1")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let error: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "INTERNAL_ERROR");
+        assert_eq!(error.trace_id.as_deref(), Some("req-1"));
+        assert!(!error.error.contains("boom"));
+    }
+}
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use validator::ValidateLength;
+
+/// 部分更新リクエストの省略可能なフィールド
+///
+/// JSON でフィールドを省略した場合（`Absent`、変更しない）と `null` を指定した場合
+/// （`Null`、値を消す）を区別する。省略を受け付けるためフィールドには `#[serde(default)]`
+/// を、出力時に省略するには `skip_serializing_if = "Patch::is_absent"` を付ける。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Patch<T> {
+    #[default]
+    Absent,
+    Null,
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Patch::Absent)
+    }
+
+    /// 指定された値（`null` と省略は None）
+    pub fn as_value(&self) -> Option<&T> {
+        match self {
+            Patch::Value(value) => Some(value),
+            Patch::Absent | Patch::Null => None,
+        }
+    }
+
+    /// 現在の値に適用した結果
+    pub fn apply(self, current: Option<T>) -> Option<T> {
+        match self {
+            Patch::Absent => current,
+            Patch::Null => None,
+            Patch::Value(value) => Some(value),
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Patch<T> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Patch::Null, Patch::Value)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Patch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(Patch::from)
+    }
+}
+
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_value().serialize(serializer)
+    }
+}
+
+/// `#[validate(length(...))]` は指定された値のみ検証する
+impl<T: ValidateLength<u64>> ValidateLength<u64> for Patch<T> {
+    fn length(&self) -> Option<u64> {
+        self.as_value().and_then(T::length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use validator::Validate;
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct Request {
+        #[serde(default, skip_serializing_if = "Patch::is_absent")]
+        #[validate(length(max = 3))]
+        note: Patch<String>,
+    }
+
+    #[test]
+    fn test_absent_and_null_are_distinguished() {
+        let absent: Request = serde_json::from_str("{}").unwrap();
+        assert_eq!(absent.note, Patch::Absent);
+        let null: Request = serde_json::from_str(r#"{"note":null}"#).unwrap();
+        assert_eq!(null.note, Patch::Null);
+        let value: Request = serde_json::from_str(r#"{"note":"abc"}"#).unwrap();
+        assert_eq!(value.note, Patch::Value("abc".to_string()));
+
+        let current = Some("old".to_string());
+        assert_eq!(absent.note.apply(current.clone()), current);
+        assert_eq!(null.note.apply(current.clone()), None);
+        assert_eq!(value.note.apply(current), Some("abc".to_string()));
+    }
+
+    #[test]
+    fn test_serialize_and_validate() {
+        let request = Request {
+            note: Patch::Absent,
+        };
+        assert_eq!(serde_json::to_string(&request).unwrap(), "{}");
+        assert!(request.validate().is_ok());
+
+        let request = Request { note: Patch::Null };
+        assert_eq!(serde_json::to_string(&request).unwrap(), r#"{"note":null}"#);
+        assert!(request.validate().is_ok());
+
+        let request = Request {
+            note: Patch::Value("abcd".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use axum::{extract::Request, middleware::Next, response::Response};
+use sentry::{ClientInitGuard, ClientOptions, Hub, Level, SentryFutureExt};
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::Arc;
+use validator::{Validate, ValidationError};
+
+use crate::trace::current_trace_id;
+
+/// エラー通知（Sentry）の設定
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+pub struct ErrorReportingConfig {
+    /// 送信先の DSN（未指定なら通知しない）
+    #[validate(custom(function = "validate_dsn"))]
+    pub sentry_dsn: Option<String>,
+    /// 通知に付ける環境名（production / staging など）
+    pub sentry_environment: Option<String>,
+}
+
+fn validate_dsn(dsn: &str) -> Result<(), ValidationError> {
+    sentry::types::Dsn::from_str(dsn).map(|_| ()).map_err(|_| {
+        let mut error = ValidationError::new("dsn");
+        error.message = Some("SENTRY_DSN の形式が不正です".into());
+        error
+    })
+}
+
+/// SENTRY_DSN が設定されていればエラー通知を有効にする
+///
+/// `release` は `concat!(env!("CARGO_PKG_NAME"), "@", env!("CARGO_PKG_VERSION"))` を渡す。
+/// パニックは自動で通知される。戻り値は main の終わりまで保持する（破棄時に未送信分を送る）。
+pub fn init_error_reporting(release: &'static str) -> Option<ClientInitGuard> {
+    let config: ErrorReportingConfig = match crate::config::load() {
+        Ok(config) => config,
+        Err(err) => {
+            tracing::warn!("Error reporting disabled: {}", err);
+            return None;
+        }
+    };
+    let guard = sentry::init((
+        config.sentry_dsn?,
+        ClientOptions {
+            release: Some(release.into()),
+            environment: config.sentry_environment.map(Into::into),
+            ..Default::default()
+        },
+    ));
+    tracing::info!(release, "Error reporting enabled");
+    Some(guard)
+}
+
+/// 5xx のレスポンスとハンドラー内のパニックをリクエストの情報付きで通知するミドルウェア
+///
+/// `trace_id_middleware` の内側に置く（通知にトレース ID を含めるため）。
+/// エラー通知が無効なら何もしない。
+pub async fn error_reporting_middleware(request: Request, next: Next) -> Response {
+    if Hub::current().client().is_none() {
+        return next.run(request).await;
+    }
+
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let hub = Arc::new(Hub::new_from_top(Hub::current()));
+    hub.configure_scope(|scope| {
+        scope.set_tag("http.method", &method);
+        scope.set_tag("http.path", &path);
+        if let Some(trace_id) = current_trace_id() {
+            scope.set_tag("trace_id", trace_id);
+        }
+    });
+
+    let response = next.run(request).bind_hub(hub.clone()).await;
+    if response.status().is_server_error() {
+        hub.configure_scope(|scope| scope.set_tag("http.status", response.status().as_u16()));
+        hub.capture_message(
+            &format!("{} {} returned {}", method, path, response.status()),
+            Level::Error,
+        );
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::StatusCode, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_server_errors_are_reported_with_request_context() {
+        let events = sentry::test::with_captured_events(|| {
+            let app = Router::new()
+                .route("/ok", get(|| async { "OK" }))
+                .route("/fail", get(|| async { StatusCode::INTERNAL_SERVER_ERROR }))
+                .layer(middleware::from_fn(error_reporting_middleware));
+            tokio::runtime::Builder::new_current_thread()
+                .build()
+                .unwrap()
+                .block_on(async {
+                    for uri in ["/ok", "/fail"] {
+                        let request = Request::get(uri).body(Body::empty()).unwrap();
+                        app.clone().oneshot(request).await.unwrap();
+                    }
+                });
+        });
+
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.level, Level::Error);
+        assert_eq!(event.tags["http.path"], "/fail");
+        assert_eq!(event.tags["http.status"], "500");
+    }
+
+    #[test]
+    fn test_invalid_dsn_is_rejected() {
+        let config = ErrorReportingConfig {
+            sentry_dsn: Some("not a dsn".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,97 @@
+const MASK: &str = "****";
+
+/// 起動時ログに出力する設定項目
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigEntry {
+    pub key: String,
+    pub value: String,
+    pub secret: bool,
+}
+
+impl ConfigEntry {
+    pub fn new(key: impl Into<String>, value: impl ToString) -> Self {
+        Self {
+            key: key.into(),
+            value: value.to_string(),
+            secret: false,
+        }
+    }
+
+    /// 値をマスクして出力する項目
+    pub fn secret(key: impl Into<String>, value: impl ToString) -> Self {
+        Self {
+            secret: true,
+            ..Self::new(key, value)
+        }
+    }
+
+    /// ログ出力用の値（秘密情報はマスク、URL はパスワード部分のみマスク）
+    pub fn display_value(&self) -> String {
+        if self.secret {
+            MASK.to_string()
+        } else {
+            mask_url_password(&self.value)
+        }
+    }
+}
+
+/// URL 中の `user:password@` のパスワード部分をマスクする
+pub fn mask_url_password(value: &str) -> String {
+    let Some(scheme_end) = value.find("://") else {
+        return value.to_string();
+    };
+    let authority_start = scheme_end + 3;
+    let rest = &value[authority_start..];
+    let authority_end = rest.find('/').unwrap_or(rest.len());
+    let Some(at) = rest[..authority_end].rfind('@') else {
+        return value.to_string();
+    };
+    let Some(colon) = rest[..at].find(':') else {
+        return value.to_string();
+    };
+
+    format!(
+        "{}{}{}{}",
+        &value[..authority_start],
+        &rest[..=colon],
+        MASK,
+        &rest[at..]
+    )
+}
+
+/// 起動バナーと有効な設定を構造化ログに出力する
+pub fn log_startup(service: &str, version: &str, entries: &[ConfigEntry]) {
+    tracing::info!(service, version, "starting {}", service);
+    for entry in entries {
+        tracing::info!(
+            service,
+            key = %entry.key,
+            value = %entry.display_value(),
+            "effective config"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_url_password() {
+        assert_eq!(
+            mask_url_password("postgres://app:s3cret@db:5432/accounting"),
+            "postgres://app:****@db:5432/accounting"
+        );
+        assert_eq!(
+            mask_url_password("postgres://db:5432/accounting"),
+            "postgres://db:5432/accounting"
+        );
+        assert_eq!(mask_url_password("0.0.0.0:8082"), "0.0.0.0:8082");
+    }
+
+    #[test]
+    fn test_secret_entry_is_masked() {
+        let entry = ConfigEntry::secret("api_key", "abc");
+        assert_eq!(entry.display_value(), "****");
+    }
+}
@@ -0,0 +1,106 @@
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// トレース ID を受け渡すヘッダー
+pub const TRACE_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// 受け付けるトレース ID の最大長
+const MAX_TRACE_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static TRACE_ID: String;
+}
+
+/// 処理中のリクエストのトレース ID（ミドルウェア外では None）
+pub fn current_trace_id() -> Option<String> {
+    TRACE_ID.try_with(|id| id.clone()).ok()
+}
+
+fn incoming_trace_id(request: &Request) -> Option<String> {
+    let value = request.headers().get(&TRACE_ID_HEADER)?.to_str().ok()?;
+    let valid = !value.is_empty()
+        && value.len() <= MAX_TRACE_ID_LENGTH
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    valid.then(|| value.to_string())
+}
+
+/// リクエストごとにトレース ID を割り当てるミドルウェア
+///
+/// `x-request-id` が指定されていれば引き継ぎ、なければ生成する。
+/// ID はログのスパンとエラーレスポンスに含め、レスポンスヘッダーで返す。
+pub async fn trace_id_middleware(request: Request, next: Next) -> Response {
+    let trace_id = incoming_trace_id(&request).unwrap_or_else(|| Uuid::new_v4().to_string());
+    let span = tracing::info_span!(
+        "request",
+        trace_id = %trace_id,
+        method = %request.method(),
+        path = %request.uri().path()
+    );
+
+    let mut response = TRACE_ID
+        .scope(trace_id.clone(), next.run(request).instrument(span))
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(&trace_id) {
+        response.headers_mut().insert(TRACE_ID_HEADER, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    fn create_test_app() -> Router {
+        Router::new()
+            .route(
+                "/",
+                get(|| async { current_trace_id().unwrap_or_default() }),
+            )
+            .layer(middleware::from_fn(trace_id_middleware))
+    }
+
+    #[tokio::test]
+    async fn test_trace_id_is_propagated() {
+        let response = create_test_app()
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header("x-request-id", "abc-123")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.headers()["x-request-id"], "abc-123");
+    }
+
+    #[tokio::test]
+    async fn test_trace_id_is_generated() {
+        let response = create_test_app()
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header("x-request-id", "bad id\twith spaces")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let trace_id = response.headers()["x-request-id"].to_str().unwrap();
+        assert!(Uuid::parse_str(trace_id).is_ok());
+        assert_eq!(current_trace_id(), None);
+    }
+}
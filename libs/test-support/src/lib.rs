@@ -0,0 +1,141 @@
+use sqlx::migrate::Migrator;
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+use sqlx::{Connection, Executor, PgConnection, PgPool};
+use std::future::Future;
+use std::str::FromStr;
+use testcontainers_modules::postgres::Postgres;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use testcontainers_modules::testcontainers::{ContainerAsync, ImageExt};
+use uuid::Uuid;
+
+/// コンテナで起動する PostgreSQL のバージョン
+const POSTGRES_TAG: &str = "15-alpine";
+
+/// テスト専用のデータベース
+///
+/// `DATABASE_URL` があればそのサーバーにテストごとのデータベースを作り、終了時に削除する。
+/// 未設定なら testcontainers で PostgreSQL を起動する（Docker が必要）。
+struct TestDatabase {
+    pool: PgPool,
+    /// `DATABASE_URL` のサーバーに作ったデータベース（終了時に削除する）
+    created: Option<(PgConnectOptions, String)>,
+    _container: Option<ContainerAsync<Postgres>>,
+}
+
+impl TestDatabase {
+    async fn start() -> Self {
+        match std::env::var("DATABASE_URL") {
+            Ok(url) => Self::create_on(&url).await,
+            Err(_) => Self::start_container().await,
+        }
+    }
+
+    async fn create_on(url: &str) -> Self {
+        let options = PgConnectOptions::from_str(url).expect("invalid DATABASE_URL");
+        let name = format!("_test_{}", Uuid::new_v4().simple());
+        let mut conn = PgConnection::connect_with(&options)
+            .await
+            .expect("failed to connect to DATABASE_URL");
+        conn.execute(format!(r#"CREATE DATABASE "{name}""#).as_str())
+            .await
+            .expect("failed to create test database");
+        conn.close().await.ok();
+
+        Self {
+            pool: connect(options.clone().database(&name)).await,
+            created: Some((options, name)),
+            _container: None,
+        }
+    }
+
+    async fn start_container() -> Self {
+        let container = Postgres::default()
+            .with_tag(POSTGRES_TAG)
+            .start()
+            .await
+            .expect("failed to start PostgreSQL container (is Docker running?)");
+        let host = container.get_host().await.expect("container host");
+        let port = container
+            .get_host_port_ipv4(5432)
+            .await
+            .expect("container port");
+        let options = PgConnectOptions::new()
+            .host(&host.to_string())
+            .port(port)
+            .username("postgres")
+            .password("postgres")
+            .database("postgres");
+
+        Self {
+            pool: connect(options).await,
+            created: None,
+            _container: Some(container),
+        }
+    }
+
+    async fn cleanup(self) {
+        self.pool.close().await;
+        if let Some((options, name)) = self.created {
+            let mut conn = PgConnection::connect_with(&options)
+                .await
+                .expect("failed to connect to DATABASE_URL");
+            conn.execute(format!(r#"DROP DATABASE IF EXISTS "{name}" WITH (FORCE)"#).as_str())
+                .await
+                .expect("failed to drop test database");
+            conn.close().await.ok();
+        }
+    }
+}
+
+async fn connect(options: PgConnectOptions) -> PgPool {
+    PgPoolOptions::new()
+        .max_connections(5)
+        .connect_with(options)
+        .await
+        .expect("failed to connect to test database")
+}
+
+/// マイグレーション済みのテスト専用データベースで `test` を実行する
+///
+/// テストが失敗（パニック）した場合、データベースは調査用に残る。
+pub async fn with_database<F, Fut>(migrator: &Migrator, test: F)
+where
+    F: FnOnce(PgPool) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let database = TestDatabase::start().await;
+    migrator
+        .run(&database.pool)
+        .await
+        .expect("failed to run migrations");
+    test(database.pool.clone()).await;
+    database.cleanup().await;
+}
+
+/// マイグレーションを適用していないテスト専用データベースで `test` を実行する
+pub async fn with_empty_database<F, Fut>(test: F)
+where
+    F: FnOnce(PgPool) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let database = TestDatabase::start().await;
+    test(database.pool.clone()).await;
+    database.cleanup().await;
+}
+
+/// マイグレーション済みのデータベースに接続したリポジトリで `test` を実行する
+///
+/// ```ignore
+/// with_repo(&MIGRATOR, PostgresAccountRepository::new, |repo| async move {
+///     assert!(repo.find_all().await.unwrap().is_empty());
+/// })
+/// .await;
+/// ```
+pub async fn with_repo<R, N, F, Fut>(migrator: &Migrator, new_repo: N, test: F)
+where
+    N: FnOnce(PgPool) -> R,
+    F: FnOnce(R) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    with_database(migrator, |pool| test(new_repo(pool))).await;
+}
@@ -0,0 +1,34 @@
+//! ビルド情報を環境変数として埋め込む（変数名は vergen に合わせる）
+
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    // Docker などリポジトリを含まないビルドでは GIT_SHA で指定する
+    println!("cargo:rerun-if-env-changed=GIT_SHA");
+    let git_sha = std::env::var("GIT_SHA")
+        .ok()
+        .or_else(|| {
+            Command::new("git")
+                .args(["rev-parse", "HEAD"])
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .and_then(|output| String::from_utf8(output.stdout).ok())
+                .map(|sha| sha.trim().to_string())
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=VERGEN_GIT_SHA={}", git_sha);
+    println!(
+        "cargo:rustc-env=VERGEN_BUILD_TIMESTAMP={}",
+        chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+    );
+
+    // コミットやブランチの切り替えで再生成する
+    let git_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../.git");
+    for path in ["HEAD", "refs"] {
+        if git_dir.join(path).exists() {
+            println!("cargo:rerun-if-changed={}", git_dir.join(path).display());
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//! 起動中の accounting-service に負荷をかけ、エンドポイントごとのレイテンシを報告する
+//!
+//! ```text
+//! cargo run --release -p accounting-service --example load_test -- \
+//!     --base-url http://localhost:8082 --requests 2000 --concurrency 32
+//! ```
+//!
+//! 実行ごとに新しい組織（`X-Org-Id`）で勘定科目を作成するため、既存のデータには影響しない。
+
+use clap::Parser;
+use reqwest::{header, Client, Method};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+use uuid::Uuid;
+
+/// 1 組織で作成できる科目コードの数（`101-00000`〜`101-99999`）
+const MAX_CREATES: usize = 100_000;
+
+#[derive(Debug, Parser)]
+#[command(about = "accounting-service の作成・一覧・詳細取得に負荷をかける")]
+struct Args {
+    /// 対象のベース URL
+    #[arg(long, default_value = "http://localhost:8082")]
+    base_url: String,
+    /// シナリオごとのリクエスト数
+    #[arg(long, default_value_t = 1000)]
+    requests: usize,
+    /// 同時に送るリクエスト数
+    #[arg(long, default_value_t = 16)]
+    concurrency: usize,
+    /// リクエストのタイムアウト（秒）
+    #[arg(long, default_value_t = 30)]
+    timeout: u64,
+}
+
+/// シナリオの計測結果
+struct Report {
+    name: &'static str,
+    latencies: Vec<Duration>,
+    errors: usize,
+    elapsed: Duration,
+}
+
+impl Report {
+    /// 最近傍順位法によるパーセンタイル
+    fn percentile(&self, p: f64) -> Duration {
+        if self.latencies.is_empty() {
+            return Duration::ZERO;
+        }
+        let rank = ((p / 100.0) * self.latencies.len() as f64).ceil() as usize;
+        self.latencies[rank.clamp(1, self.latencies.len()) - 1]
+    }
+
+    fn print(&self) {
+        let total = self.latencies.len() + self.errors;
+        println!(
+            "{:<8} {:>7} {:>7} {:>9.1} {:>9.2} {:>9.2} {:>9.2} {:>9.2}",
+            self.name,
+            total,
+            self.errors,
+            total as f64 / self.elapsed.as_secs_f64(),
+            ms(self.percentile(50.0)),
+            ms(self.percentile(95.0)),
+            ms(self.percentile(99.0)),
+            ms(self.latencies.last().copied().unwrap_or_default()),
+        );
+    }
+}
+
+fn ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// 負荷をかける対象（リクエストはすべて同じ組織で送る）
+#[derive(Clone)]
+struct Target {
+    client: Client,
+    base_url: String,
+    organization_id: Uuid,
+}
+
+impl Target {
+    /// 成功ならレスポンスの JSON、失敗ならステータスと本文を返す
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, String> {
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", self.base_url, path))
+            .header("x-org-id", self.organization_id.to_string());
+        if let Some(body) = body {
+            request = request
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(body.to_string());
+        }
+        let response = request.send().await.map_err(|e| e.to_string())?;
+        let status = response.status();
+        let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+        if !status.is_success() {
+            return Err(format!("{} {}", status, String::from_utf8_lossy(&bytes)));
+        }
+        Ok(serde_json::from_slice(&bytes).unwrap_or_default())
+    }
+}
+
+/// `requests` 件のリクエストを `concurrency` 並列で送り、レイテンシを集計する
+async fn run<F, Fut>(
+    name: &'static str,
+    requests: usize,
+    concurrency: usize,
+    request: F,
+) -> (Report, Vec<serde_json::Value>)
+where
+    F: Fn(usize) -> Fut + Send + Sync + 'static,
+    Fut: std::future::Future<Output = Result<serde_json::Value, String>> + Send,
+{
+    let request = Arc::new(request);
+    let next = Arc::new(AtomicUsize::new(0));
+    let started = Instant::now();
+    let mut workers = JoinSet::new();
+    for _ in 0..concurrency.max(1) {
+        let request = request.clone();
+        let next = next.clone();
+        workers.spawn(async move {
+            let mut results = Vec::new();
+            loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= requests {
+                    break;
+                }
+                let sent = Instant::now();
+                let result = request(i).await;
+                results.push((sent.elapsed(), result));
+            }
+            results
+        });
+    }
+
+    let mut latencies = Vec::with_capacity(requests);
+    let mut bodies = Vec::new();
+    let mut errors = 0;
+    let mut first_error = None;
+    while let Some(results) = workers.join_next().await {
+        for (latency, result) in results.expect("worker panicked") {
+            match result {
+                Ok(body) => {
+                    latencies.push(latency);
+                    bodies.push(body);
+                }
+                Err(err) => {
+                    errors += 1;
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+    }
+    if let Some(err) = first_error {
+        eprintln!("{name}: {errors} failed (first: {err})");
+    }
+    latencies.sort();
+
+    let report = Report {
+        name,
+        latencies,
+        errors,
+        elapsed: started.elapsed(),
+    };
+    (report, bodies)
+}
+
+#[tokio::main]
+async fn main() {
+    let args = Args::parse();
+    let requests = args.requests.min(MAX_CREATES);
+    let target = Target {
+        client: Client::builder()
+            .timeout(Duration::from_secs(args.timeout))
+            .build()
+            .expect("failed to build HTTP client"),
+        base_url: args.base_url.trim_end_matches('/').to_string(),
+        organization_id: Uuid::new_v4(),
+    };
+    println!(
+        "target: {} (organization {}), {} requests x {} concurrent",
+        target.base_url, target.organization_id, requests, args.concurrency
+    );
+
+    let create_target = target.clone();
+    let (create, created) = run("create", requests, args.concurrency, move |i| {
+        let target = create_target.clone();
+        async move {
+            let body = serde_json::json!({
+                "code": format!("101-{i:05}"),
+                "name": format!("負荷試験 {i}"),
+                "category": "cash",
+            });
+            target.send(Method::POST, "/api/accounts", Some(body)).await
+        }
+    })
+    .await;
+
+    let list_target = target.clone();
+    let (list, _) = run("list", requests, args.concurrency, move |_| {
+        let target = list_target.clone();
+        async move { target.send(Method::GET, "/api/accounts", None).await }
+    })
+    .await;
+
+    let ids: Arc<Vec<String>> = Arc::new(
+        created
+            .iter()
+            .filter_map(|account| account["id"].as_str().map(str::to_string))
+            .collect(),
+    );
+    let get_target = target.clone();
+    let get_requests = if ids.is_empty() { 0 } else { requests };
+    let (get, _) = run("get", get_requests, args.concurrency, move |i| {
+        let target = get_target.clone();
+        let ids = ids.clone();
+        async move {
+            let path = format!("/api/accounts/{}", ids[i % ids.len()]);
+            target.send(Method::GET, &path, None).await
+        }
+    })
+    .await;
+
+    println!(
+        "{:<8} {:>7} {:>7} {:>9} {:>9} {:>9} {:>9} {:>9}",
+        "scenario", "total", "errors", "req/s", "p50 ms", "p95 ms", "p99 ms", "max ms"
+    );
+    for report in [create, list, get] {
+        report.print();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let report = Report {
+            name: "test",
+            latencies: (1..=100).map(Duration::from_millis).collect(),
+            errors: 0,
+            elapsed: Duration::from_secs(1),
+        };
+
+        assert_eq!(report.percentile(50.0), Duration::from_millis(50));
+        assert_eq!(report.percentile(95.0), Duration::from_millis(95));
+        assert_eq!(report.percentile(99.0), Duration::from_millis(99));
+        assert_eq!(report.percentile(100.0), Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_accounts_code;
+DROP INDEX IF EXISTS uq_accounts_active_code;
+
+ALTER TABLE accounts ADD CONSTRAINT accounts_code_key UNIQUE (code);
@@ -0,0 +1,5 @@
+-- 科目コードの一意性を有効な勘定科目のみに限定（CodeReusePolicy::Allow 用）
+ALTER TABLE accounts DROP CONSTRAINT IF EXISTS accounts_code_key;
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_accounts_active_code ON accounts (code) WHERE is_active;
+CREATE INDEX IF NOT EXISTS idx_accounts_code ON accounts (code);
@@ -0,0 +1,4 @@
+-- 勘定科目の階層構造（親科目）
+ALTER TABLE accounts ADD COLUMN IF NOT EXISTS parent_id UUID REFERENCES accounts (id);
+
+CREATE INDEX IF NOT EXISTS idx_accounts_parent_id ON accounts (parent_id);
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS exchange_rates (
+    id              UUID            PRIMARY KEY DEFAULT gen_random_uuid(),
+    base_currency   VARCHAR(3)      NOT NULL,
+    quote_currency  VARCHAR(3)      NOT NULL,
+    rate            NUMERIC(20, 10) NOT NULL,
+    effective_date  DATE            NOT NULL,
+    created_at      TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_exchange_rates_pair_date UNIQUE (base_currency, quote_currency, effective_date),
+    CONSTRAINT chk_exchange_rates_rate CHECK (rate > 0),
+    CONSTRAINT chk_exchange_rates_pair CHECK (base_currency <> quote_currency)
+);
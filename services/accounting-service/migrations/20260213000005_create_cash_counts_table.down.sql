@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS cash_count_lines;
+DROP TABLE IF EXISTS cash_counts;
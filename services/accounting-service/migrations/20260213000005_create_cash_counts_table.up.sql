@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS cash_counts (
+    id          UUID            PRIMARY KEY DEFAULT gen_random_uuid(),
+    safe        VARCHAR(50)     NOT NULL,
+    counted_at  TIMESTAMPTZ     NOT NULL,
+    total       BIGINT          NOT NULL,
+    counted_by  VARCHAR(100),
+    note        TEXT,
+    created_at  TIMESTAMPTZ     NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_cash_counts_safe_counted_at ON cash_counts (safe, counted_at DESC);
+
+CREATE TABLE IF NOT EXISTS cash_count_lines (
+    cash_count_id   UUID    NOT NULL REFERENCES cash_counts(id) ON DELETE CASCADE,
+    denomination    BIGINT  NOT NULL,
+    quantity        BIGINT  NOT NULL,
+    PRIMARY KEY (cash_count_id, denomination),
+    CONSTRAINT chk_cash_count_lines_quantity CHECK (quantity >= 0)
+);
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS webhooks (
+    id          UUID            PRIMARY KEY DEFAULT gen_random_uuid(),
+    url         VARCHAR(2048)   NOT NULL,
+    events      TEXT[]          NOT NULL,
+    secret      VARCHAR(255)    NOT NULL,
+    is_active   BOOLEAN         NOT NULL DEFAULT TRUE,
+    created_at  TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    CONSTRAINT chk_webhooks_events CHECK (cardinality(events) > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhooks_events ON webhooks USING GIN (events);
@@ -0,0 +1,10 @@
+ALTER TABLE accounts DROP CONSTRAINT IF EXISTS fk_accounts_org_parent;
+ALTER TABLE accounts ADD CONSTRAINT accounts_parent_id_fkey FOREIGN KEY (parent_id) REFERENCES accounts (id);
+ALTER TABLE accounts DROP CONSTRAINT IF EXISTS uq_accounts_org_id;
+
+DROP INDEX IF EXISTS idx_accounts_org_code;
+DROP INDEX IF EXISTS uq_accounts_org_active_code;
+CREATE UNIQUE INDEX IF NOT EXISTS uq_accounts_active_code ON accounts (code) WHERE is_active;
+CREATE INDEX IF NOT EXISTS idx_accounts_code ON accounts (code);
+
+ALTER TABLE accounts DROP COLUMN IF EXISTS organization_id;
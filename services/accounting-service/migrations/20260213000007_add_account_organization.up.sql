@@ -0,0 +1,15 @@
+-- マルチテナント化：勘定科目を組織ごとに分離する（既存データは既定の組織に属する）
+ALTER TABLE accounts
+    ADD COLUMN IF NOT EXISTS organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000';
+
+-- 科目コードの一意性を組織ごとにする
+DROP INDEX IF EXISTS uq_accounts_active_code;
+DROP INDEX IF EXISTS idx_accounts_code;
+CREATE UNIQUE INDEX IF NOT EXISTS uq_accounts_org_active_code ON accounts (organization_id, code) WHERE is_active;
+CREATE INDEX IF NOT EXISTS idx_accounts_org_code ON accounts (organization_id, code);
+
+-- 親科目は同じ組織の勘定科目に限る
+ALTER TABLE accounts ADD CONSTRAINT uq_accounts_org_id UNIQUE (organization_id, id);
+ALTER TABLE accounts DROP CONSTRAINT IF EXISTS accounts_parent_id_fkey;
+ALTER TABLE accounts ADD CONSTRAINT fk_accounts_org_parent
+    FOREIGN KEY (organization_id, parent_id) REFERENCES accounts (organization_id, id);
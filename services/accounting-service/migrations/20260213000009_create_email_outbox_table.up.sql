@@ -0,0 +1,16 @@
+-- メール送信キュー（アウトボックス）
+CREATE TABLE IF NOT EXISTS email_outbox (
+    id              UUID            PRIMARY KEY DEFAULT gen_random_uuid(),
+    recipient       VARCHAR(320)    NOT NULL,
+    subject         VARCHAR(255)    NOT NULL,
+    body            TEXT            NOT NULL,
+    status          VARCHAR(20)     NOT NULL DEFAULT 'pending',
+    attempts        INTEGER         NOT NULL DEFAULT 0,
+    last_error      TEXT,
+    next_attempt_at TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    created_at      TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    sent_at         TIMESTAMPTZ,
+    CONSTRAINT chk_email_outbox_status CHECK (status IN ('pending', 'sent', 'failed'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_email_outbox_due ON email_outbox (next_attempt_at) WHERE status = 'pending';
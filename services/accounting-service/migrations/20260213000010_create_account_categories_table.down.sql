@@ -0,0 +1,14 @@
+-- 組織定義のカテゴリを使う勘定科目が残っている場合は失敗する
+ALTER TABLE accounts ADD CONSTRAINT chk_category
+    CHECK (category IN (
+        'cash', 'bank_deposit', 'fixed_deposit', 'accounts_receivable',
+        'accounts_payable', 'deposits_received', 'borrowings',
+        'capital', 'retained_surplus',
+        'tithe_offering', 'thank_offering', 'special_offering', 'building_offering',
+        'interest_income', 'other_revenue',
+        'personnel_expense', 'utility_expense', 'communication_expense', 'supplies_expense',
+        'worship_expense', 'education_expense', 'mission_expense', 'maintenance_expense',
+        'other_expense'
+    ));
+
+DROP TABLE IF EXISTS account_categories;
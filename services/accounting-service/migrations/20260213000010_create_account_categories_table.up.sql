@@ -0,0 +1,48 @@
+-- 勘定科目カテゴリ（organization_id が NULL の行は全組織共通の組み込みカテゴリ）
+CREATE TABLE IF NOT EXISTS account_categories (
+    id              UUID            PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID,
+    code            VARCHAR(30)     NOT NULL,
+    name            VARCHAR(100)    NOT NULL,
+    account_type    VARCHAR(20)     NOT NULL,
+    is_active       BOOLEAN         NOT NULL DEFAULT TRUE,
+    created_at      TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    CONSTRAINT chk_account_categories_account_type
+        CHECK (account_type IN ('asset', 'liability', 'equity', 'revenue', 'expense'))
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_account_categories_built_in_code
+    ON account_categories (code) WHERE organization_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS uq_account_categories_org_active_code
+    ON account_categories (organization_id, code) WHERE organization_id IS NOT NULL AND is_active;
+
+INSERT INTO account_categories (code, name, account_type) VALUES
+    ('cash', '現金', 'asset'),
+    ('bank_deposit', '普通預金', 'asset'),
+    ('fixed_deposit', '定期預金', 'asset'),
+    ('accounts_receivable', '未収金', 'asset'),
+    ('accounts_payable', '未払金', 'liability'),
+    ('deposits_received', '預り金', 'liability'),
+    ('borrowings', '借入金', 'liability'),
+    ('capital', '基本金', 'equity'),
+    ('retained_surplus', '繰越金', 'equity'),
+    ('tithe_offering', '什一献金', 'revenue'),
+    ('thank_offering', '感謝献金', 'revenue'),
+    ('special_offering', '特別献金', 'revenue'),
+    ('building_offering', '会堂献金', 'revenue'),
+    ('interest_income', '受取利息', 'revenue'),
+    ('other_revenue', '雑収入', 'revenue'),
+    ('personnel_expense', '人件費', 'expense'),
+    ('utility_expense', '水道光熱費', 'expense'),
+    ('communication_expense', '通信費', 'expense'),
+    ('supplies_expense', '消耗品費', 'expense'),
+    ('worship_expense', '礼拝費', 'expense'),
+    ('education_expense', '教育費', 'expense'),
+    ('mission_expense', '伝道費', 'expense'),
+    ('maintenance_expense', '営繕費', 'expense'),
+    ('other_expense', '雑費', 'expense')
+ON CONFLICT DO NOTHING;
+
+-- 組織定義のカテゴリを使えるよう、組み込みカテゴリに限定する制約を外す
+ALTER TABLE accounts DROP CONSTRAINT IF EXISTS chk_category;
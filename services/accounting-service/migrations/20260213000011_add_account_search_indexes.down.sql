@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_accounts_description_trgm;
+DROP INDEX IF EXISTS idx_accounts_name_trgm;
+DROP INDEX IF EXISTS idx_accounts_search_vector;
+
+ALTER TABLE accounts DROP COLUMN IF EXISTS search_vector;
+
+-- pg_trgm は他で使われている可能性があるため残す
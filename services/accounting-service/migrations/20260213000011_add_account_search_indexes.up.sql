@@ -0,0 +1,12 @@
+-- 横断検索：全文検索（tsvector）と部分一致（pg_trgm）の索引
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE accounts
+    ADD COLUMN search_vector TSVECTOR
+    GENERATED ALWAYS AS (
+        to_tsvector('simple', name || ' ' || COALESCE(description, ''))
+    ) STORED;
+
+CREATE INDEX idx_accounts_search_vector ON accounts USING GIN (search_vector);
+CREATE INDEX idx_accounts_name_trgm ON accounts USING GIN (name gin_trgm_ops);
+CREATE INDEX idx_accounts_description_trgm ON accounts USING GIN (description gin_trgm_ops);
@@ -0,0 +1,20 @@
+-- バックグラウンドジョブのキュー
+CREATE TABLE IF NOT EXISTS jobs (
+    id              UUID            PRIMARY KEY DEFAULT gen_random_uuid(),
+    kind            VARCHAR(100)    NOT NULL,
+    payload         JSONB           NOT NULL DEFAULT '{}',
+    status          VARCHAR(20)     NOT NULL DEFAULT 'pending',
+    attempts        INTEGER         NOT NULL DEFAULT 0,
+    max_attempts    INTEGER         NOT NULL DEFAULT 5,
+    -- 実行中のジョブは可視性タイムアウトの期限
+    run_at          TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    last_error      TEXT,
+    unique_key      VARCHAR(255),
+    created_at      TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    CONSTRAINT chk_jobs_status CHECK (status IN ('pending', 'succeeded', 'failed')),
+    CONSTRAINT chk_jobs_max_attempts CHECK (max_attempts > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_jobs_due ON jobs (run_at) WHERE status = 'pending';
+CREATE UNIQUE INDEX IF NOT EXISTS idx_jobs_unique_key ON jobs (unique_key) WHERE unique_key IS NOT NULL;
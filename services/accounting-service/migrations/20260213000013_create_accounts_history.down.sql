@@ -0,0 +1,3 @@
+DROP TRIGGER IF EXISTS trg_accounts_history ON accounts;
+DROP FUNCTION IF EXISTS record_account_history();
+DROP TABLE IF EXISTS accounts_history;
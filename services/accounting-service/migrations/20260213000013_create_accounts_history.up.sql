@@ -0,0 +1,49 @@
+-- 勘定科目の変更履歴（時点指定の参照用）
+CREATE TABLE IF NOT EXISTS accounts_history (
+    history_id      BIGSERIAL       PRIMARY KEY,
+    id              UUID            NOT NULL,
+    organization_id UUID            NOT NULL,
+    code            VARCHAR(10)     NOT NULL,
+    name            VARCHAR(100)    NOT NULL,
+    account_type    VARCHAR(20)     NOT NULL,
+    category        VARCHAR(30)     NOT NULL,
+    description     TEXT,
+    is_active       BOOLEAN         NOT NULL,
+    display_order   INTEGER         NOT NULL,
+    parent_id       UUID,
+    created_at      TIMESTAMPTZ     NOT NULL,
+    updated_at      TIMESTAMPTZ     NOT NULL,
+    -- この状態が有効になった日時
+    valid_from      TIMESTAMPTZ     NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_accounts_history_lookup
+    ON accounts_history (organization_id, id, valid_from);
+
+CREATE OR REPLACE FUNCTION record_account_history() RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO accounts_history (
+        id, organization_id, code, name, account_type, category, description,
+        is_active, display_order, parent_id, created_at, updated_at
+    )
+    VALUES (
+        NEW.id, NEW.organization_id, NEW.code, NEW.name, NEW.account_type, NEW.category,
+        NEW.description, NEW.is_active, NEW.display_order, NEW.parent_id, NEW.created_at,
+        NEW.updated_at
+    );
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_accounts_history
+    AFTER INSERT OR UPDATE ON accounts
+    FOR EACH ROW EXECUTE FUNCTION record_account_history();
+
+-- 既存の勘定科目は最終更新日時から有効とする
+INSERT INTO accounts_history (
+    id, organization_id, code, name, account_type, category, description,
+    is_active, display_order, parent_id, created_at, updated_at, valid_from
+)
+SELECT id, organization_id, code, name, account_type, category, description,
+       is_active, display_order, parent_id, created_at, updated_at, updated_at
+FROM accounts;
@@ -0,0 +1,23 @@
+-- 固定資産台帳（金額は円）
+CREATE TABLE IF NOT EXISTS fixed_assets (
+    id                  UUID            PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id     UUID            NOT NULL,
+    name                VARCHAR(100)    NOT NULL,
+    acquisition_date    DATE            NOT NULL,
+    acquisition_cost    BIGINT          NOT NULL,
+    salvage_value       BIGINT          NOT NULL DEFAULT 0,
+    useful_life_months  INTEGER         NOT NULL,
+    expense_account_id  UUID            NOT NULL,
+    is_active           BOOLEAN         NOT NULL DEFAULT TRUE,
+    note                TEXT,
+    created_at          TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    CONSTRAINT fk_fixed_assets_expense_account
+        FOREIGN KEY (organization_id, expense_account_id) REFERENCES accounts (organization_id, id),
+    CONSTRAINT chk_fixed_assets_amounts
+        CHECK (acquisition_cost > 0 AND salvage_value BETWEEN 0 AND acquisition_cost),
+    CONSTRAINT chk_fixed_assets_useful_life CHECK (useful_life_months BETWEEN 1 AND 1200)
+);
+
+CREATE INDEX IF NOT EXISTS idx_fixed_assets_org_acquisition
+    ON fixed_assets (organization_id, acquisition_date);
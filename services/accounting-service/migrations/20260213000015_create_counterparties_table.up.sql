@@ -0,0 +1,14 @@
+-- 取引先（支払先・業者など）
+CREATE TABLE IF NOT EXISTS counterparties (
+    id              UUID            PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID            NOT NULL,
+    name            VARCHAR(100)    NOT NULL,
+    contact         VARCHAR(200),
+    note            TEXT,
+    is_active       BOOLEAN         NOT NULL DEFAULT TRUE,
+    created_at      TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ     NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_counterparties_org_name
+    ON counterparties (organization_id, name);
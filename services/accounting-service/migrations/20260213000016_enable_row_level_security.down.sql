@@ -0,0 +1,21 @@
+DROP POLICY IF EXISTS tenant_isolation ON counterparties;
+ALTER TABLE counterparties NO FORCE ROW LEVEL SECURITY;
+ALTER TABLE counterparties DISABLE ROW LEVEL SECURITY;
+
+DROP POLICY IF EXISTS tenant_isolation ON fixed_assets;
+ALTER TABLE fixed_assets NO FORCE ROW LEVEL SECURITY;
+ALTER TABLE fixed_assets DISABLE ROW LEVEL SECURITY;
+
+DROP POLICY IF EXISTS tenant_isolation ON account_categories;
+ALTER TABLE account_categories NO FORCE ROW LEVEL SECURITY;
+ALTER TABLE account_categories DISABLE ROW LEVEL SECURITY;
+
+DROP POLICY IF EXISTS tenant_isolation ON accounts_history;
+ALTER TABLE accounts_history NO FORCE ROW LEVEL SECURITY;
+ALTER TABLE accounts_history DISABLE ROW LEVEL SECURITY;
+
+DROP POLICY IF EXISTS tenant_isolation ON accounts;
+ALTER TABLE accounts NO FORCE ROW LEVEL SECURITY;
+ALTER TABLE accounts DISABLE ROW LEVEL SECURITY;
+
+DROP FUNCTION IF EXISTS app_current_org();
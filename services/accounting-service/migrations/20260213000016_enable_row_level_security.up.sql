@@ -0,0 +1,39 @@
+-- 組織ごとの行レベルセキュリティ（TENANT_ISOLATION=rls で使う）
+--
+-- 接続に app.current_org が設定されている場合はその組織の行だけを参照・変更できる。
+-- 未設定の接続（アプリケーション側で分離する既定の方式やマイグレーション）は従来どおり全行を扱う。
+-- スーパーユーザーと BYPASSRLS 権限を持つロールにはポリシーが適用されない。
+CREATE OR REPLACE FUNCTION app_current_org() RETURNS UUID AS $$
+    SELECT NULLIF(current_setting('app.current_org', true), '')::uuid;
+$$ LANGUAGE sql STABLE;
+
+ALTER TABLE accounts ENABLE ROW LEVEL SECURITY;
+ALTER TABLE accounts FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON accounts
+    USING (app_current_org() IS NULL OR organization_id = app_current_org())
+    WITH CHECK (app_current_org() IS NULL OR organization_id = app_current_org());
+
+ALTER TABLE accounts_history ENABLE ROW LEVEL SECURITY;
+ALTER TABLE accounts_history FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON accounts_history
+    USING (app_current_org() IS NULL OR organization_id = app_current_org())
+    WITH CHECK (app_current_org() IS NULL OR organization_id = app_current_org());
+
+-- 組み込みカテゴリ（organization_id が NULL）は全組織から参照できる
+ALTER TABLE account_categories ENABLE ROW LEVEL SECURITY;
+ALTER TABLE account_categories FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON account_categories
+    USING (app_current_org() IS NULL OR organization_id IS NULL OR organization_id = app_current_org())
+    WITH CHECK (app_current_org() IS NULL OR organization_id = app_current_org());
+
+ALTER TABLE fixed_assets ENABLE ROW LEVEL SECURITY;
+ALTER TABLE fixed_assets FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON fixed_assets
+    USING (app_current_org() IS NULL OR organization_id = app_current_org())
+    WITH CHECK (app_current_org() IS NULL OR organization_id = app_current_org());
+
+ALTER TABLE counterparties ENABLE ROW LEVEL SECURITY;
+ALTER TABLE counterparties FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON counterparties
+    USING (app_current_org() IS NULL OR organization_id = app_current_org())
+    WITH CHECK (app_current_org() IS NULL OR organization_id = app_current_org());
@@ -0,0 +1,17 @@
+DROP TRIGGER IF EXISTS trg_accounts_category ON accounts;
+DROP FUNCTION IF EXISTS check_account_category();
+
+ALTER TABLE account_categories
+    ALTER COLUMN account_type TYPE VARCHAR(20) USING account_type::TEXT;
+ALTER TABLE account_categories ADD CONSTRAINT chk_account_categories_account_type
+    CHECK (account_type IN ('asset', 'liability', 'equity', 'revenue', 'expense'));
+
+ALTER TABLE accounts_history
+    ALTER COLUMN account_type TYPE VARCHAR(20) USING account_type::TEXT;
+
+ALTER TABLE accounts
+    ALTER COLUMN account_type TYPE VARCHAR(20) USING account_type::TEXT;
+ALTER TABLE accounts ADD CONSTRAINT chk_account_type
+    CHECK (account_type IN ('asset', 'liability', 'equity', 'revenue', 'expense'));
+
+DROP TYPE IF EXISTS account_type;
@@ -0,0 +1,46 @@
+-- 勘定科目種別を列挙型で保持する（CHECK 制約は列挙型に置き換える）
+CREATE TYPE account_type AS ENUM ('asset', 'liability', 'equity', 'revenue', 'expense');
+
+ALTER TABLE accounts DROP CONSTRAINT IF EXISTS chk_account_type;
+ALTER TABLE accounts
+    ALTER COLUMN account_type TYPE account_type USING account_type::account_type;
+
+ALTER TABLE accounts_history
+    ALTER COLUMN account_type TYPE account_type USING account_type::account_type;
+
+ALTER TABLE account_categories DROP CONSTRAINT IF EXISTS chk_account_categories_account_type;
+ALTER TABLE account_categories
+    ALTER COLUMN account_type TYPE account_type USING account_type::account_type;
+
+-- 定義のないカテゴリを使う既存の勘定科目は、そのカテゴリを組織定義として登録する
+-- （名称はコードのまま。同じコードで種別が異なる場合は最後に更新した勘定科目に合わせる）
+INSERT INTO account_categories (organization_id, code, name, account_type)
+SELECT DISTINCT ON (a.organization_id, a.category)
+       a.organization_id, a.category, a.category, a.account_type
+FROM accounts a
+WHERE NOT EXISTS (
+    SELECT 1 FROM account_categories c
+    WHERE c.code = a.category
+      AND (c.organization_id IS NULL OR c.organization_id = a.organization_id)
+)
+ORDER BY a.organization_id, a.category, a.updated_at DESC;
+
+-- カテゴリは組織定義もあるため列挙型にできない。組み込みか同じ組織の定義に限る
+-- （論理削除済みの定義も既存の勘定科目のために認める）
+CREATE OR REPLACE FUNCTION check_account_category() RETURNS TRIGGER AS $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM account_categories
+        WHERE code = NEW.category
+          AND (organization_id IS NULL OR organization_id = NEW.organization_id)
+    ) THEN
+        RAISE EXCEPTION 'unknown account category: %', NEW.category
+            USING ERRCODE = 'check_violation';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_accounts_category
+    BEFORE INSERT OR UPDATE OF category, organization_id ON accounts
+    FOR EACH ROW EXECUTE FUNCTION check_account_category();
@@ -0,0 +1 @@
+ALTER TABLE cash_counts DROP COLUMN IF EXISTS book_balance;
@@ -0,0 +1,2 @@
+-- 実査時の帳簿上の現金残高（過不足の算出に使う。未入力なら NULL）
+ALTER TABLE cash_counts ADD COLUMN IF NOT EXISTS book_balance BIGINT;
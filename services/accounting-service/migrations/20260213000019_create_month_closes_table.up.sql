@@ -0,0 +1,18 @@
+-- 月次締めのチェックリスト（組織・月ごとに 1 件）
+CREATE TABLE IF NOT EXISTS month_closes (
+    organization_id   UUID            NOT NULL,
+    month             DATE            NOT NULL,
+    bank_reconciled   BOOLEAN         NOT NULL DEFAULT FALSE,
+    offerings_posted  BOOLEAN         NOT NULL DEFAULT FALSE,
+    reports_generated BOOLEAN         NOT NULL DEFAULT FALSE,
+    locked_at         TIMESTAMPTZ,
+    updated_at        TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organization_id, month),
+    CONSTRAINT month_closes_first_day CHECK (EXTRACT(DAY FROM month) = 1)
+);
+
+ALTER TABLE month_closes ENABLE ROW LEVEL SECURITY;
+ALTER TABLE month_closes FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON month_closes
+    USING (app_current_org() IS NULL OR organization_id = app_current_org())
+    WITH CHECK (app_current_org() IS NULL OR organization_id = app_current_org());
@@ -0,0 +1,23 @@
+DROP POLICY IF EXISTS tenant_isolation ON webhooks;
+ALTER TABLE webhooks NO FORCE ROW LEVEL SECURITY;
+ALTER TABLE webhooks DISABLE ROW LEVEL SECURITY;
+
+DROP POLICY IF EXISTS tenant_isolation ON cash_counts;
+ALTER TABLE cash_counts NO FORCE ROW LEVEL SECURITY;
+ALTER TABLE cash_counts DISABLE ROW LEVEL SECURITY;
+
+DROP POLICY IF EXISTS tenant_isolation ON exchange_rates;
+ALTER TABLE exchange_rates NO FORCE ROW LEVEL SECURITY;
+ALTER TABLE exchange_rates DISABLE ROW LEVEL SECURITY;
+
+DROP INDEX IF EXISTS idx_webhooks_organization;
+ALTER TABLE webhooks DROP COLUMN IF EXISTS organization_id;
+
+DROP INDEX IF EXISTS idx_cash_counts_org_safe_counted_at;
+CREATE INDEX IF NOT EXISTS idx_cash_counts_safe_counted_at ON cash_counts (safe, counted_at DESC);
+ALTER TABLE cash_counts DROP COLUMN IF EXISTS organization_id;
+
+ALTER TABLE exchange_rates DROP CONSTRAINT IF EXISTS uq_exchange_rates_org_pair_date;
+ALTER TABLE exchange_rates DROP COLUMN IF EXISTS organization_id;
+ALTER TABLE exchange_rates ADD CONSTRAINT uq_exchange_rates_pair_date
+    UNIQUE (base_currency, quote_currency, effective_date);
@@ -0,0 +1,34 @@
+-- 為替レート・金種表・Webhook を組織ごとに分離する（既存データは既定の組織に属する）
+ALTER TABLE exchange_rates
+    ADD COLUMN IF NOT EXISTS organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000';
+ALTER TABLE exchange_rates DROP CONSTRAINT IF EXISTS uq_exchange_rates_pair_date;
+ALTER TABLE exchange_rates ADD CONSTRAINT uq_exchange_rates_org_pair_date
+    UNIQUE (organization_id, base_currency, quote_currency, effective_date);
+
+ALTER TABLE cash_counts
+    ADD COLUMN IF NOT EXISTS organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000';
+DROP INDEX IF EXISTS idx_cash_counts_safe_counted_at;
+CREATE INDEX IF NOT EXISTS idx_cash_counts_org_safe_counted_at
+    ON cash_counts (organization_id, safe, counted_at DESC);
+
+ALTER TABLE webhooks
+    ADD COLUMN IF NOT EXISTS organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000';
+CREATE INDEX IF NOT EXISTS idx_webhooks_organization ON webhooks (organization_id);
+
+ALTER TABLE exchange_rates ENABLE ROW LEVEL SECURITY;
+ALTER TABLE exchange_rates FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON exchange_rates
+    USING (app_current_org() IS NULL OR organization_id = app_current_org())
+    WITH CHECK (app_current_org() IS NULL OR organization_id = app_current_org());
+
+ALTER TABLE cash_counts ENABLE ROW LEVEL SECURITY;
+ALTER TABLE cash_counts FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON cash_counts
+    USING (app_current_org() IS NULL OR organization_id = app_current_org())
+    WITH CHECK (app_current_org() IS NULL OR organization_id = app_current_org());
+
+ALTER TABLE webhooks ENABLE ROW LEVEL SECURITY;
+ALTER TABLE webhooks FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON webhooks
+    USING (app_current_org() IS NULL OR organization_id = app_current_org())
+    WITH CHECK (app_current_org() IS NULL OR organization_id = app_current_org());
@@ -0,0 +1,61 @@
+-- 行レベルセキュリティを組織が未設定の接続に対して閉じる
+--
+-- これまでのポリシーは app.current_org が未設定の接続に全行を見せていた（設定漏れで全組織が見える）。
+-- 未設定の接続は行を参照・変更できないようにし、全行を扱うマイグレーションはテーブルの所有者の
+-- ロールで行う（FORCE を外し、所有者にはポリシーを適用しない）。アプリケーションは所有者とは別の、
+-- スーパーユーザーでも BYPASSRLS 権限もないロールで接続する。
+
+ALTER TABLE accounts NO FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS tenant_isolation ON accounts;
+CREATE POLICY tenant_isolation ON accounts
+    USING (organization_id = app_current_org())
+    WITH CHECK (organization_id = app_current_org());
+
+ALTER TABLE accounts_history NO FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS tenant_isolation ON accounts_history;
+CREATE POLICY tenant_isolation ON accounts_history
+    USING (organization_id = app_current_org())
+    WITH CHECK (organization_id = app_current_org());
+
+-- 組み込みカテゴリ（organization_id が NULL）は引き続き全組織から参照できる
+ALTER TABLE account_categories NO FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS tenant_isolation ON account_categories;
+CREATE POLICY tenant_isolation ON account_categories
+    USING (organization_id IS NULL OR organization_id = app_current_org())
+    WITH CHECK (organization_id = app_current_org());
+
+ALTER TABLE fixed_assets NO FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS tenant_isolation ON fixed_assets;
+CREATE POLICY tenant_isolation ON fixed_assets
+    USING (organization_id = app_current_org())
+    WITH CHECK (organization_id = app_current_org());
+
+ALTER TABLE counterparties NO FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS tenant_isolation ON counterparties;
+CREATE POLICY tenant_isolation ON counterparties
+    USING (organization_id = app_current_org())
+    WITH CHECK (organization_id = app_current_org());
+
+ALTER TABLE month_closes NO FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS tenant_isolation ON month_closes;
+CREATE POLICY tenant_isolation ON month_closes
+    USING (organization_id = app_current_org())
+    WITH CHECK (organization_id = app_current_org());
+
+ALTER TABLE exchange_rates NO FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS tenant_isolation ON exchange_rates;
+CREATE POLICY tenant_isolation ON exchange_rates
+    USING (organization_id = app_current_org())
+    WITH CHECK (organization_id = app_current_org());
+
+ALTER TABLE cash_counts NO FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS tenant_isolation ON cash_counts;
+CREATE POLICY tenant_isolation ON cash_counts
+    USING (organization_id = app_current_org())
+    WITH CHECK (organization_id = app_current_org());
+
+ALTER TABLE webhooks NO FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS tenant_isolation ON webhooks;
+CREATE POLICY tenant_isolation ON webhooks
+    USING (organization_id = app_current_org())
+    WITH CHECK (organization_id = app_current_org());
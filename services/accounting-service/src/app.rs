@@ -0,0 +1,487 @@
+use axum::{
+    http::HeaderValue,
+    middleware,
+    routing::{get, patch, post},
+    Json, Router,
+};
+use common::admin_auth::require_admin_token;
+use common::compression::DEFAULT_COMPRESSION_MIN_SIZE;
+use common::i18n::locale_middleware;
+use common::jobs::{spawn_job_runner, DynJobQueue, InMemoryJobQueue, JobRunner};
+use common::lifecycle::Readiness;
+use common::log_level::log_level_router;
+use common::panic::catch_panic_layer;
+use common::reporting::error_reporting_middleware;
+use common::startup::{log_startup, ConfigEntry};
+use common::trace::trace_id_middleware;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::build_info::{info_router, settings_router, BuildInfo};
+use crate::cli::ServeArgs;
+use crate::config::{AppConfig, DatabaseConfig};
+use crate::domain::{AccountCodeRanges, WebhookTargetPolicy};
+use crate::events::{
+    DynEventPublisher, FanoutEventPublisher, InProcessEventPublisher, NatsEventPublisher,
+};
+use crate::graphql::graphql_router;
+use crate::handlers::{
+    cash_count_router, category_router, counterparty_router, create_account, delete_account,
+    exchange_rate_router, fixed_asset_router, get_account, get_account_tree, journal_router,
+    list_accounts, month_close_router, next_account_code, organization_router, reorder_accounts,
+    search_router, update_account, webhook_router, AccountListCaching, DynAccountRepository,
+    DynCashCountRepository, DynCategoryRepository, DynCounterpartyRepository,
+    DynExchangeRateRepository, DynFixedAssetRepository, DynMonthCloseRepository,
+    DynOrganizationRepository, DynSearchRepository, DynWebhookRepository, FixedAssetState,
+    OrganizationState, WebhookState,
+};
+use crate::handover::{handover_router, HandoverState};
+use crate::import::import_accounts_handler;
+use crate::metrics::health_router;
+use crate::migrate::{self, migrations_router};
+use crate::notifications::{
+    spawn_email_worker, DynEmailQueueRepository, EmailWorker, SmtpEmailSender,
+};
+use crate::repository::{
+    DynUnitOfWorkFactory, InMemoryAccountRepository, InMemoryCashCountRepository,
+    InMemoryCategoryRepository, InMemoryCounterpartyRepository, InMemoryEmailQueueRepository,
+    InMemoryExchangeRateRepository, InMemoryFixedAssetRepository, InMemoryMonthCloseRepository,
+    InMemoryOrganizationRepository, InMemorySearchRepository, InMemoryUnitOfWorkFactory,
+    InMemoryWebhookRepository, PostgresAccountRepository, PostgresCashCountRepository,
+    PostgresCategoryRepository, PostgresCounterpartyRepository, PostgresEmailQueueRepository,
+    PostgresExchangeRateRepository, PostgresFixedAssetRepository, PostgresJobQueue,
+    PostgresMonthCloseRepository, PostgresOrganizationRepository, PostgresSearchRepository,
+    PostgresWebhookRepository,
+};
+use crate::service::Quotas;
+use crate::standby::{self, read_only_guard, StandbyMode};
+use crate::state::AppState;
+use crate::tenant::{tenant_guard, TenantAuth, TenantIsolation};
+use crate::webhook_delivery::{spawn_webhook_worker, WebhookDispatcher, WEBHOOK_DELIVERY_JOB};
+
+/// メール送信キューを確認する間隔
+const EMAIL_POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// ジョブキューを確認する間隔
+const JOB_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 設定から状態を組み立てる（DB 接続、マイグレーション、バックグラウンド処理の起動を含む）
+///
+/// `listen_addr` は起動時ログと引き継ぎパッケージに載せる待ち受けアドレス。
+/// 単体の起動とゲートウェイへの組み込みで同じ手順を使う。
+pub async fn initialize(config: AppConfig, args: ServeArgs, listen_addr: SocketAddr) -> AppState {
+    let db_config = DatabaseConfig::from_config(&config);
+    let standby_mode = StandbyMode::new(config.read_only);
+    let code_reuse_policy = config.account_code_reuse_policy;
+    let tenant_isolation = config.tenant_isolation;
+    let code_ranges = config
+        .account_code_ranges
+        .and_then(|value| AccountCodeRanges::from_str(&value).ok());
+    let nats_url = config.nats_url;
+    let admin_token = config.admin_token;
+    let tenant_auth = config
+        .tenant_key_secret
+        .as_deref()
+        .map_or_else(TenantAuth::default, TenantAuth::with_secret);
+    let webhook_targets = WebhookTargetPolicy::from_allow_insecure(config.webhook_allow_insecure);
+    let smtp = config.smtp_url.zip(config.email_from);
+    let account_cache_ttl = config.account_cache_ttl.map(Duration::from_secs);
+    let mut quotas = Quotas::default();
+    if let Some(limit) = config.quota_max_accounts {
+        quotas = quotas.with_max_accounts(limit);
+    }
+    let mut list_caching = AccountListCaching::default();
+    if let Some(value) = config
+        .account_list_cache_control
+        .and_then(|value| HeaderValue::from_str(&value).ok())
+    {
+        list_caching.cache_control = value;
+    }
+
+    let repository = if db_config.is_some() {
+        "postgres"
+    } else {
+        "in-memory"
+    };
+    // 設定で有効にした機能（GET /api/admin/info に返す）
+    let features: Vec<String> = [
+        ("nats", nats_url.is_some()),
+        ("email", smtp.is_some()),
+        ("account_cache", account_cache_ttl.is_some()),
+        ("account_code_ranges", code_ranges.is_some()),
+        ("quotas", quotas != Quotas::default()),
+        ("rls", tenant_isolation == TenantIsolation::Rls),
+        ("multi_tenant", tenant_auth.is_multi_tenant()),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(feature, _)| feature.to_string())
+    .collect();
+    let build_info = BuildInfo::new(repository, features);
+
+    let mut entries = vec![
+        ConfigEntry::new("git_sha", build_info.git_sha),
+        ConfigEntry::new("build_timestamp", build_info.build_timestamp),
+        ConfigEntry::new("features", build_info.features.join(",")),
+        ConfigEntry::new("listen_addr", listen_addr),
+        ConfigEntry::new("repository", repository),
+        ConfigEntry::new("account_code_reuse_policy", code_reuse_policy),
+        ConfigEntry::new(
+            "account_code_ranges",
+            code_ranges
+                .as_ref()
+                .map_or("disabled".to_string(), |ranges| ranges.to_string()),
+        ),
+        ConfigEntry::new("role", standby_mode.role()),
+        ConfigEntry::new("event_bus", nats_url.as_deref().unwrap_or("in-process")),
+        ConfigEntry::new(
+            "account_cache_ttl",
+            account_cache_ttl.map_or("disabled".to_string(), |ttl| format!("{}s", ttl.as_secs())),
+        ),
+        ConfigEntry::new(
+            "quota_max_accounts",
+            quotas
+                .max_accounts
+                .map_or("unlimited".to_string(), |limit| limit.to_string()),
+        ),
+        ConfigEntry::new(
+            "account_list_cache_control",
+            list_caching.cache_control.to_str().unwrap_or_default(),
+        ),
+        ConfigEntry::new(
+            "email_from",
+            smtp.as_ref().map_or("disabled", |(_, from)| from.as_str()),
+        ),
+        ConfigEntry::new("auto_migrate", !args.no_migrate),
+        ConfigEntry::new("webhook_allow_insecure", config.webhook_allow_insecure),
+        ConfigEntry::new(
+            "shutdown_delay",
+            format!("{}s", config.shutdown_delay.unwrap_or_default()),
+        ),
+        ConfigEntry::new(
+            "compression_min_size",
+            config
+                .compression_min_size
+                .unwrap_or(DEFAULT_COMPRESSION_MIN_SIZE),
+        ),
+    ];
+    if let Some(token) = &admin_token {
+        entries.push(ConfigEntry::secret("admin_token", token));
+    }
+    if let Some(secret) = &config.tenant_key_secret {
+        entries.push(ConfigEntry::secret("tenant_key_secret", secret));
+    }
+    if let Some(config) = &db_config {
+        entries.extend(config.config_entries());
+    }
+    log_startup("accounting-service", env!("CARGO_PKG_VERSION"), &entries);
+
+    // マイグレーションの適用状況の確認に使う（インメモリでは None）
+    let mut migration_pool = None;
+    // 勘定科目の変更をまとめるトランザクション（保存先ごとに決まる）
+    let unit_of_work: DynUnitOfWorkFactory;
+    let (
+        repo,
+        rate_repo,
+        cash_count_repo,
+        webhook_repo,
+        organization_repo,
+        email_queue,
+        category_repo,
+        search_repo,
+        job_queue,
+        fixed_asset_repo,
+        counterparty_repo,
+        month_close_repo,
+    ): (
+        DynAccountRepository,
+        DynExchangeRateRepository,
+        DynCashCountRepository,
+        DynWebhookRepository,
+        DynOrganizationRepository,
+        DynEmailQueueRepository,
+        DynCategoryRepository,
+        DynSearchRepository,
+        DynJobQueue,
+        DynFixedAssetRepository,
+        DynCounterpartyRepository,
+        DynMonthCloseRepository,
+    ) = match db_config {
+        Some(config) => {
+            tracing::info!("Connecting to PostgreSQL...");
+            let pool = config
+                .create_pool()
+                .await
+                .expect("Failed to connect to PostgreSQL");
+            migration_pool = Some(pool.clone());
+
+            // スタンバイはレプリカに接続するためマイグレーションはプライマリに任せる
+            if standby_mode.is_read_only() {
+                tracing::info!("PostgreSQL connected (read-only standby, migrations skipped)");
+            } else if args.no_migrate {
+                tracing::info!("PostgreSQL connected (--no-migrate, migrations skipped)");
+            } else {
+                migrate::up(&pool)
+                    .await
+                    .expect("Failed to run database migrations");
+
+                tracing::info!("PostgreSQL connected and migrations applied");
+            }
+            config
+                .warm_up(&pool)
+                .await
+                .expect("Failed to warm up PostgreSQL connections");
+            config
+                .check_rls_role(&pool)
+                .await
+                .expect("Row-level security would not apply to this role");
+            let mut accounts = PostgresAccountRepository::new(pool.clone())
+                .with_code_reuse_policy(code_reuse_policy)
+                .with_tenant_isolation(tenant_isolation);
+            if let Some(ranges) = code_ranges {
+                accounts = accounts.with_code_ranges(ranges);
+            }
+            let accounts = Arc::new(accounts);
+            unit_of_work = accounts.clone();
+            (
+                accounts,
+                Arc::new(
+                    PostgresExchangeRateRepository::new(pool.clone())
+                        .with_tenant_isolation(tenant_isolation),
+                ),
+                Arc::new(
+                    PostgresCashCountRepository::new(pool.clone())
+                        .with_tenant_isolation(tenant_isolation),
+                ),
+                Arc::new(
+                    PostgresWebhookRepository::new(pool.clone())
+                        .with_tenant_isolation(tenant_isolation),
+                ),
+                Arc::new(PostgresOrganizationRepository::new(pool.clone())),
+                Arc::new(PostgresEmailQueueRepository::new(pool.clone())),
+                Arc::new(
+                    PostgresCategoryRepository::new(pool.clone())
+                        .with_tenant_isolation(tenant_isolation),
+                ),
+                Arc::new(
+                    PostgresSearchRepository::new(pool.clone())
+                        .with_tenant_isolation(tenant_isolation),
+                ),
+                Arc::new(PostgresJobQueue::new(pool.clone())),
+                Arc::new(
+                    PostgresFixedAssetRepository::new(pool.clone())
+                        .with_tenant_isolation(tenant_isolation),
+                ),
+                Arc::new(
+                    PostgresCounterpartyRepository::new(pool.clone())
+                        .with_tenant_isolation(tenant_isolation),
+                ),
+                Arc::new(
+                    PostgresMonthCloseRepository::new(pool).with_tenant_isolation(tenant_isolation),
+                ),
+            )
+        }
+        None => {
+            tracing::warn!("DATABASE_URL not set, using in-memory repository");
+            let categories: DynCategoryRepository = Arc::new(InMemoryCategoryRepository::new());
+            let mut accounts = InMemoryAccountRepository::new()
+                .with_code_reuse_policy(code_reuse_policy)
+                .with_categories(categories.clone());
+            if let Some(ranges) = code_ranges {
+                accounts = accounts.with_code_ranges(ranges);
+            }
+            let email_queue = Arc::new(InMemoryEmailQueueRepository::new());
+            unit_of_work = Arc::new(InMemoryUnitOfWorkFactory::new(
+                &accounts,
+                email_queue.clone(),
+            ));
+            let accounts: DynAccountRepository = Arc::new(accounts);
+            (
+                accounts.clone(),
+                Arc::new(InMemoryExchangeRateRepository::new()),
+                Arc::new(InMemoryCashCountRepository::new()),
+                Arc::new(InMemoryWebhookRepository::new()),
+                Arc::new(InMemoryOrganizationRepository::new()),
+                email_queue,
+                categories,
+                Arc::new(InMemorySearchRepository::new(accounts)),
+                Arc::new(InMemoryJobQueue::new()),
+                Arc::new(InMemoryFixedAssetRepository::new()),
+                Arc::new(InMemoryCounterpartyRepository::new()),
+                Arc::new(InMemoryMonthCloseRepository::new()),
+            )
+        }
+    };
+
+    // Webhook 配信はプロセス内のイベントを購読し、ジョブキュー経由で送る
+    let in_process = InProcessEventPublisher::default();
+    let dispatcher = Arc::new(
+        WebhookDispatcher::new(webhook_repo.clone())
+            .with_job_queue(job_queue.clone())
+            .with_target_policy(webhook_targets),
+    );
+    spawn_webhook_worker(dispatcher.clone(), in_process.subscribe());
+    // スタンバイの間はキューを更新できないため、昇格するまで処理しない
+    let job_standby = standby_mode.clone();
+    spawn_job_runner(
+        JobRunner::new(job_queue).register(WEBHOOK_DELIVERY_JOB, dispatcher),
+        JOB_POLL_INTERVAL,
+        move || !job_standby.is_read_only(),
+    );
+    if let Some((url, from)) = &smtp {
+        let sender = SmtpEmailSender::from_url(url, from).expect("Invalid SMTP settings");
+        spawn_email_worker(
+            EmailWorker::new(email_queue, Arc::new(sender)),
+            EMAIL_POLL_INTERVAL,
+            standby_mode.clone(),
+        );
+    }
+    let publisher: DynEventPublisher = match &nats_url {
+        Some(url) => Arc::new(FanoutEventPublisher::new(vec![
+            Arc::new(in_process),
+            Arc::new(
+                NatsEventPublisher::connect(url, "accounting")
+                    .await
+                    .expect("Failed to connect to NATS"),
+            ),
+        ])),
+        None => Arc::new(in_process),
+    };
+    let mut state = AppState::builder(repo)
+        .with_unit_of_work(unit_of_work)
+        .with_events(publisher)
+        .with_list_caching(list_caching)
+        .with_quotas(quotas)
+        .with_exchange_rates(rate_repo)
+        .with_cash_counts(cash_count_repo)
+        .with_webhooks(webhook_repo)
+        .with_webhook_targets(webhook_targets)
+        .with_organizations(organization_repo)
+        .with_categories(category_repo)
+        .with_search(search_repo)
+        .with_fixed_assets(fixed_asset_repo)
+        .with_counterparties(counterparty_repo)
+        .with_month_closes(month_close_repo)
+        .with_standby(standby_mode)
+        .with_tenant_auth(tenant_auth)
+        .with_settings(entries)
+        .with_build_info(build_info);
+    if let Some(ttl) = account_cache_ttl {
+        state = state.with_cache_ttl(ttl);
+    }
+    if let Some(pool) = migration_pool {
+        state = state.with_migration_pool(pool);
+    }
+    if let Some(token) = admin_token {
+        state = state.with_admin_token(token);
+    }
+
+    // ここまでの準備（マイグレーション・接続の確立）が終わってから受け付け可能にする
+    let readiness = Readiness::default();
+    let state = state.with_readiness(readiness.clone()).build();
+    readiness.set_ready(true);
+    tracing::info!("accounting-service is ready");
+    state
+}
+
+/// 本番と同じルーター（レスポンスの圧縮を除くミドルウェアを含む）
+///
+/// main.rs のほか、結合テストやゲートウェイへの組み込みでもこれを使う。
+/// 圧縮はサーバーを起動する側が全体に 1 回だけ掛ける（モノリスでは base-app の設定に従う）。
+pub fn build_router(state: AppState) -> Router {
+    let handover_state = HandoverState {
+        accounts: state.account_repository.clone(),
+        exchange_rates: state.exchange_rates.clone(),
+        cash_counts: state.cash_counts.clone(),
+        // マルチテナントでは運用者の設定（DB の接続先など）を組織に渡さない
+        settings: if state.tenant_auth.is_multi_tenant() {
+            Vec::new()
+        } else {
+            state.settings.clone()
+        },
+    };
+    let organization_state = OrganizationState {
+        organizations: state.organizations.clone(),
+        accounts: state.accounts.clone(),
+        tenant_auth: state.tenant_auth.clone(),
+    };
+    let fixed_asset_state = FixedAssetState {
+        assets: state.fixed_assets.clone(),
+        accounts: state.account_repository.clone(),
+    };
+
+    let mut admin = standby::admin_router(state.standby.clone(), state.migration_pool.clone())
+        .merge(info_router(state.build_info.clone()))
+        .merge(settings_router(state.settings.clone()))
+        .merge(log_level_router(state.admin_token.clone()));
+    if let Some(pool) = state.migration_pool.clone() {
+        admin = admin.merge(migrations_router(pool));
+    }
+    // 管理 API はすべて管理トークンで保護する（未設定なら常に拒否）
+    let admin_guard = middleware::from_fn_with_state(
+        state.admin_token.clone().map(Arc::<str>::from),
+        require_admin_token,
+    );
+    let admin = admin.layer(admin_guard.clone());
+    // マルチテナントでは組織の作成・一覧は運用者だけが行う
+    let mut organizations = organization_router(organization_state);
+    if state.tenant_auth.is_multi_tenant() {
+        organizations = organizations.layer(admin_guard);
+    }
+
+    Router::new()
+        .route("/", get(root))
+        .route("/health", get(health))
+        .route("/api/accounts", post(create_account).get(list_accounts))
+        .route("/api/accounts/tree", get(get_account_tree))
+        .route("/api/accounts/reorder", patch(reorder_accounts))
+        .route("/api/accounts/next-code", get(next_account_code))
+        .route(
+            "/api/accounts/:id",
+            get(get_account).put(update_account).delete(delete_account),
+        )
+        .route("/api/import/accounts", post(import_accounts_handler))
+        .with_state(state.clone())
+        .merge(exchange_rate_router(state.exchange_rates))
+        .merge(cash_count_router(state.cash_counts))
+        .merge(handover_router(handover_state))
+        .merge(webhook_router(WebhookState {
+            webhooks: state.webhooks,
+            targets: state.webhook_targets,
+        }))
+        .merge(organizations)
+        .merge(category_router(state.categories))
+        .merge(search_router(state.search))
+        .merge(fixed_asset_router(fixed_asset_state))
+        .merge(counterparty_router(state.counterparties))
+        .merge(month_close_router(state.month_closes))
+        .layer(middleware::from_fn_with_state(
+            state.standby,
+            read_only_guard,
+        ))
+        // GraphQL と仕訳の検証は何も書き込まないため、スタンバイでも POST を受け付ける
+        .merge(journal_router(state.accounts.clone()))
+        .merge(graphql_router(state.accounts))
+        .merge(admin)
+        .merge(health_router(state.readiness, state.migration_pool))
+        .layer(middleware::from_fn_with_state(
+            state.tenant_auth,
+            tenant_guard,
+        ))
+        .layer(middleware::from_fn(error_reporting_middleware))
+        .layer(catch_panic_layer())
+        .layer(middleware::from_fn(locale_middleware))
+        .layer(middleware::from_fn(trace_id_middleware))
+}
+
+async fn root() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "service": "accounting-service",
+        "version": env!("CARGO_PKG_VERSION")
+    }))
+}
+
+async fn health() -> &'static str {
+    "OK"
+}
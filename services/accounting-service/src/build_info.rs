@@ -0,0 +1,73 @@
+use axum::{extract::State, routing::get, Json, Router};
+use common::startup::ConfigEntry;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// ビルド時のコミット（build.rs が埋め込む）
+pub const GIT_SHA: &str = env!("VERGEN_GIT_SHA");
+/// ビルド日時（RFC 3339）
+pub const BUILD_TIMESTAMP: &str = env!("VERGEN_BUILD_TIMESTAMP");
+
+/// デプロイされているビルドと有効な機能
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildInfo {
+    pub service: &'static str,
+    pub version: &'static str,
+    pub git_sha: &'static str,
+    pub build_timestamp: &'static str,
+    /// 設定で有効にした機能（`nats`、`email` など）
+    pub features: Vec<String>,
+    /// 保存先（postgres / in-memory）
+    pub repository: String,
+}
+
+impl BuildInfo {
+    pub fn new(repository: impl Into<String>, features: Vec<String>) -> Self {
+        Self {
+            service: env!("CARGO_PKG_NAME"),
+            version: env!("CARGO_PKG_VERSION"),
+            git_sha: GIT_SHA,
+            build_timestamp: BUILD_TIMESTAMP,
+            features,
+            repository: repository.into(),
+        }
+    }
+}
+
+impl Default for BuildInfo {
+    fn default() -> Self {
+        Self::new("in-memory", Vec::new())
+    }
+}
+
+/// GET /api/admin/info - ビルド情報
+pub async fn get_info(State(info): State<BuildInfo>) -> Json<BuildInfo> {
+    Json(info)
+}
+
+/// ビルド情報のルーター
+pub fn info_router(info: BuildInfo) -> Router {
+    Router::new()
+        .route("/api/admin/info", get(get_info))
+        .with_state(info)
+}
+
+/// GET /api/admin/settings - 起動時の有効な設定（秘密情報はマスク）
+pub async fn get_settings(
+    State(settings): State<Arc<[ConfigEntry]>>,
+) -> Json<BTreeMap<String, String>> {
+    Json(
+        settings
+            .iter()
+            .map(|entry| (entry.key.clone(), entry.display_value()))
+            .collect(),
+    )
+}
+
+/// 有効な設定のルーター
+pub fn settings_router(settings: Vec<ConfigEntry>) -> Router {
+    Router::new()
+        .route("/api/admin/settings", get(get_settings))
+        .with_state(Arc::from(settings))
+}
@@ -0,0 +1,122 @@
+use clap::{Args, Parser, Subcommand};
+use sqlx::PgPool;
+
+use crate::migrate;
+
+/// 会計サービス
+#[derive(Debug, Parser)]
+#[command(name = "accounting-service", version)]
+pub struct Cli {
+    /// 設定を検証して終了する（サーバーは起動しない）
+    #[arg(long, global = true)]
+    pub config_check: bool,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl Cli {
+    /// サブコマンド（省略時は serve）
+    pub fn command(self) -> Command {
+        self.command
+            .unwrap_or_else(|| Command::Serve(ServeArgs::default()))
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// HTTP サーバーを起動する
+    Serve(ServeArgs),
+    /// マイグレーションを管理する
+    Migrate {
+        #[command(subcommand)]
+        command: MigrateCommand,
+    },
+}
+
+#[derive(Debug, Default, Args)]
+pub struct ServeArgs {
+    /// 起動時にマイグレーションを適用しない
+    #[arg(long)]
+    pub no_migrate: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MigrateCommand {
+    /// 未適用のマイグレーションをすべて適用する
+    Up,
+    /// マイグレーションを取り消す（既定は直近の 1 件）
+    Down {
+        /// このバージョンまで戻す（0 ですべて取り消し）
+        #[arg(long)]
+        target: Option<i64>,
+    },
+    /// 適用状況を表示する
+    Status,
+    /// すべて取り消してから適用し直す（データはすべて失われる）
+    Fresh {
+        /// 確認のため必須
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// migrate サブコマンドを実行する
+pub async fn run_migrate(
+    pool: &PgPool,
+    command: MigrateCommand,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        MigrateCommand::Up => {
+            migrate::up(pool).await?;
+            println!("Migrations applied");
+        }
+        MigrateCommand::Down { target } => {
+            let version = migrate::down(pool, target).await?;
+            println!("Reverted to version {}", version);
+        }
+        MigrateCommand::Status => {
+            for migration in migrate::status(pool).await? {
+                println!("{}", migration);
+            }
+        }
+        MigrateCommand::Fresh { force } => {
+            if !force {
+                return Err("migrate fresh drops all data; pass --force to continue".into());
+            }
+            migrate::fresh(pool).await?;
+            println!("Database recreated");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        let cli = Cli::parse_from(["accounting-service"]);
+        assert!(matches!(
+            cli.command(),
+            Command::Serve(ServeArgs { no_migrate: false })
+        ));
+
+        let cli = Cli::parse_from(["accounting-service", "serve", "--no-migrate"]);
+        assert!(matches!(
+            cli.command(),
+            Command::Serve(ServeArgs { no_migrate: true })
+        ));
+
+        let cli = Cli::parse_from(["accounting-service", "migrate", "down", "--target", "3"]);
+        assert!(matches!(
+            cli.command(),
+            Command::Migrate {
+                command: MigrateCommand::Down { target: Some(3) }
+            }
+        ));
+
+        assert!(Cli::try_parse_from(["accounting-service", "migrate", "sideways"]).is_err());
+        assert!(Cli::parse_from(["accounting-service", "--config-check"]).config_check);
+    }
+}
@@ -1,9 +1,14 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use common::i18n::{current_locale, Label, Localize};
+use common::patch::Patch;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::str::FromStr;
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+use crate::domain::DEFAULT_ORGANIZATION_ID;
 
 /// 勘定科目の種別（5要素）
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -62,8 +67,11 @@ impl FromStr for AccountType {
 }
 
 /// 教会会計向け勘定科目カテゴリ
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+///
+/// 組み込みのカテゴリに当てはまらない場合は、組織ごとに定義したカテゴリ
+/// （`Custom`）を使う。JSON ではどちらもカテゴリコードの文字列で表す。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum AccountCategory {
     // 資産
     Cash,
@@ -98,12 +106,43 @@ pub enum AccountCategory {
     MissionExpense,
     MaintenanceExpense,
     OtherExpense,
+
+    /// 組織が定義したカテゴリ（種別はカテゴリ定義から解決する）
+    Custom(String),
 }
 
 impl AccountCategory {
-    /// このカテゴリが属する勘定科目種別を返す
-    pub fn account_type(&self) -> AccountType {
-        match self {
+    /// 組み込みのカテゴリ
+    pub const BUILT_IN: [AccountCategory; 24] = [
+        AccountCategory::Cash,
+        AccountCategory::BankDeposit,
+        AccountCategory::FixedDeposit,
+        AccountCategory::AccountsReceivable,
+        AccountCategory::AccountsPayable,
+        AccountCategory::DepositsReceived,
+        AccountCategory::Borrowings,
+        AccountCategory::Capital,
+        AccountCategory::RetainedSurplus,
+        AccountCategory::TitheOffering,
+        AccountCategory::ThankOffering,
+        AccountCategory::SpecialOffering,
+        AccountCategory::BuildingOffering,
+        AccountCategory::InterestIncome,
+        AccountCategory::OtherRevenue,
+        AccountCategory::PersonnelExpense,
+        AccountCategory::UtilityExpense,
+        AccountCategory::CommunicationExpense,
+        AccountCategory::SuppliesExpense,
+        AccountCategory::WorshipExpense,
+        AccountCategory::EducationExpense,
+        AccountCategory::MissionExpense,
+        AccountCategory::MaintenanceExpense,
+        AccountCategory::OtherExpense,
+    ];
+
+    /// このカテゴリが属する勘定科目種別を返す（組織定義のカテゴリは None）
+    pub fn account_type(&self) -> Option<AccountType> {
+        let account_type = match self {
             AccountCategory::Cash
             | AccountCategory::BankDeposit
             | AccountCategory::FixedDeposit
@@ -131,7 +170,46 @@ impl AccountCategory {
             | AccountCategory::MissionExpense
             | AccountCategory::MaintenanceExpense
             | AccountCategory::OtherExpense => AccountType::Expense,
-        }
+
+            AccountCategory::Custom(_) => return None,
+        };
+        Some(account_type)
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, AccountCategory::Custom(_))
+    }
+
+    /// 組み込みカテゴリの表示名（組織定義のカテゴリは None）
+    pub fn label(&self) -> Option<Label> {
+        let label = match self {
+            AccountCategory::Cash => Label::new("現金", "Cash"),
+            AccountCategory::BankDeposit => Label::new("普通預金", "Bank deposits"),
+            AccountCategory::FixedDeposit => Label::new("定期預金", "Time deposits"),
+            AccountCategory::AccountsReceivable => Label::new("未収金", "Accounts receivable"),
+            AccountCategory::AccountsPayable => Label::new("未払金", "Accounts payable"),
+            AccountCategory::DepositsReceived => Label::new("預り金", "Deposits received"),
+            AccountCategory::Borrowings => Label::new("借入金", "Borrowings"),
+            AccountCategory::Capital => Label::new("基本金", "Endowment"),
+            AccountCategory::RetainedSurplus => Label::new("繰越金", "Retained surplus"),
+            AccountCategory::TitheOffering => Label::new("什一献金", "Tithes"),
+            AccountCategory::ThankOffering => Label::new("感謝献金", "Thank offerings"),
+            AccountCategory::SpecialOffering => Label::new("特別献金", "Special offerings"),
+            AccountCategory::BuildingOffering => Label::new("会堂献金", "Building fund offerings"),
+            AccountCategory::InterestIncome => Label::new("受取利息", "Interest income"),
+            AccountCategory::OtherRevenue => Label::new("雑収入", "Other revenue"),
+            AccountCategory::PersonnelExpense => Label::new("人件費", "Personnel expenses"),
+            AccountCategory::UtilityExpense => Label::new("水道光熱費", "Utilities"),
+            AccountCategory::CommunicationExpense => Label::new("通信費", "Communication"),
+            AccountCategory::SuppliesExpense => Label::new("消耗品費", "Supplies"),
+            AccountCategory::WorshipExpense => Label::new("礼拝費", "Worship expenses"),
+            AccountCategory::EducationExpense => Label::new("教育費", "Education expenses"),
+            AccountCategory::MissionExpense => Label::new("伝道費", "Mission expenses"),
+            AccountCategory::MaintenanceExpense => Label::new("営繕費", "Maintenance"),
+            AccountCategory::OtherExpense => Label::new("雑費", "Miscellaneous expenses"),
+            AccountCategory::Custom(_) => return None,
+        };
+        Some(label)
     }
 }
 
@@ -162,6 +240,7 @@ impl fmt::Display for AccountCategory {
             AccountCategory::MissionExpense => "mission_expense",
             AccountCategory::MaintenanceExpense => "maintenance_expense",
             AccountCategory::OtherExpense => "other_expense",
+            AccountCategory::Custom(code) => code,
         };
         write!(f, "{}", s)
     }
@@ -196,15 +275,216 @@ impl FromStr for AccountCategory {
             "mission_expense" => Ok(AccountCategory::MissionExpense),
             "maintenance_expense" => Ok(AccountCategory::MaintenanceExpense),
             "other_expense" => Ok(AccountCategory::OtherExpense),
+            other if CATEGORY_CODE_REGEX.is_match(other) => {
+                Ok(AccountCategory::Custom(other.to_string()))
+            }
             other => Err(format!("Invalid account category: {}", other)),
         }
     }
 }
 
+impl TryFrom<String> for AccountCategory {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<AccountCategory> for String {
+    fn from(category: AccountCategory) -> Self {
+        category.to_string()
+    }
+}
+
+impl Localize for AccountType {
+    fn label(&self) -> Label {
+        match self {
+            AccountType::Asset => Label::new("資産", "Assets"),
+            AccountType::Liability => Label::new("負債", "Liabilities"),
+            AccountType::Equity => Label::new("純資産", "Net assets"),
+            AccountType::Revenue => Label::new("収入", "Revenue"),
+            AccountType::Expense => Label::new("支出", "Expenses"),
+        }
+    }
+}
+
+/// 論理削除済み勘定科目と同じ科目コードで作成する場合の扱い
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeReusePolicy {
+    /// 論理削除済みでも重複として拒否する
+    #[default]
+    Reject,
+    /// 新しい勘定科目として作成する
+    Allow,
+    /// 論理削除済みの勘定科目を復活させて内容を上書きする
+    Revive,
+}
+
+impl fmt::Display for CodeReusePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            CodeReusePolicy::Reject => "reject",
+            CodeReusePolicy::Allow => "allow",
+            CodeReusePolicy::Revive => "revive",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for CodeReusePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reject" => Ok(CodeReusePolicy::Reject),
+            "allow" => Ok(CodeReusePolicy::Allow),
+            "revive" => Ok(CodeReusePolicy::Revive),
+            other => Err(format!("Invalid code reuse policy: {}", other)),
+        }
+    }
+}
+
+/// 科目種別ごとの科目コードの範囲
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountCodeRange {
+    pub account_type: AccountType,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// 科目コードの範囲の規則（`asset=100-199,revenue=400-499` の形式）
+///
+/// 科目コードの `-` より前（枝番を除いた部分）を数値として判定する。
+/// 範囲を指定していない科目種別の科目コードは検証しない。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountCodeRanges(Vec<AccountCodeRange>);
+
+impl Default for AccountCodeRanges {
+    /// 標準の勘定科目（`default_chart_of_accounts`）に合わせた範囲
+    fn default() -> Self {
+        Self(
+            [
+                AccountType::Asset,
+                AccountType::Liability,
+                AccountType::Equity,
+                AccountType::Revenue,
+                AccountType::Expense,
+            ]
+            .into_iter()
+            .zip(1..)
+            .map(|(account_type, digit)| AccountCodeRange {
+                account_type,
+                start: digit * 100,
+                end: digit * 100 + 99,
+            })
+            .collect(),
+        )
+    }
+}
+
+impl AccountCodeRanges {
+    pub fn range_for(&self, account_type: AccountType) -> Option<&AccountCodeRange> {
+        self.0.iter().find(|r| r.account_type == account_type)
+    }
+
+    /// 科目コードが科目種別の範囲内か
+    pub fn check(&self, code: &str, account_type: AccountType) -> Result<(), String> {
+        let Some(range) = self.range_for(account_type) else {
+            return Ok(());
+        };
+        let number = code.split('-').next().and_then(|n| n.parse::<u32>().ok());
+        match number {
+            Some(number) if (range.start..=range.end).contains(&number) => Ok(()),
+            _ => Err(format!(
+                "{}の科目コードは{}〜{}で入力してください: {}",
+                account_type.label().ja,
+                range.start,
+                range.end,
+                code
+            )),
+        }
+    }
+
+    /// 範囲内で使われていない最小の科目コード（範囲の指定がないか空きがなければ None）
+    pub fn next_code<'a>(
+        &self,
+        account_type: AccountType,
+        used: impl IntoIterator<Item = &'a str>,
+    ) -> Option<String> {
+        let range = self.range_for(account_type)?;
+        let used: HashSet<&str> = used.into_iter().collect();
+        (range.start..=range.end)
+            .map(|number| number.to_string())
+            .find(|code| !used.contains(code.as_str()))
+    }
+}
+
+impl fmt::Display for AccountCodeRanges {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges: Vec<String> = self
+            .0
+            .iter()
+            .map(|r| format!("{}={}-{}", r.account_type, r.start, r.end))
+            .collect();
+        write!(f, "{}", ranges.join(","))
+    }
+}
+
+impl FromStr for AccountCodeRanges {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ranges: Vec<AccountCodeRange> = Vec::new();
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let invalid = || format!("Invalid account code range: {}", part);
+            let (account_type, range) = part.split_once('=').ok_or_else(invalid)?;
+            let account_type = AccountType::from_str(account_type.trim())?;
+            let (start, end) = range.split_once('-').ok_or_else(invalid)?;
+            let start: u32 = start.trim().parse().map_err(|_| invalid())?;
+            let end: u32 = end.trim().parse().map_err(|_| invalid())?;
+            // 範囲が重なると科目コードから科目種別を決められない
+            if start > end
+                || ranges
+                    .iter()
+                    .any(|r| r.account_type == account_type || (start <= r.end && r.start <= end))
+            {
+                return Err(invalid());
+            }
+            ranges.push(AccountCodeRange {
+                account_type,
+                start,
+                end,
+            });
+        }
+        if ranges.is_empty() {
+            return Err(format!("Invalid account code ranges: {}", s));
+        }
+        Ok(Self(ranges))
+    }
+}
+
+/// 科目コードの提案
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NextAccountCodeResponse {
+    pub category: AccountCategory,
+    pub code: String,
+}
+
+/// 科目コード提案の条件
+#[derive(Debug, Clone, Deserialize)]
+pub struct NextAccountCodeQuery {
+    pub category: AccountCategory,
+}
+
 /// 勘定科目エンティティ
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Account {
     pub id: Uuid,
+    /// 所属する組織（テナント）
+    #[serde(default)]
+    pub organization_id: Uuid,
     pub code: String,
     pub name: String,
     pub account_type: AccountType,
@@ -212,6 +492,7 @@ pub struct Account {
     pub description: Option<String>,
     pub is_active: bool,
     pub display_order: i32,
+    pub parent_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -220,6 +501,7 @@ impl Account {
     pub fn new(
         code: String,
         name: String,
+        account_type: AccountType,
         category: AccountCategory,
         description: Option<String>,
         display_order: i32,
@@ -227,17 +509,190 @@ impl Account {
         let now = Utc::now();
         Self {
             id: Uuid::new_v4(),
+            organization_id: DEFAULT_ORGANIZATION_ID,
             code,
             name,
-            account_type: category.account_type(),
+            account_type,
             category,
             description,
             is_active: true,
             display_order,
+            parent_id: None,
             created_at: now,
             updated_at: now,
         }
     }
+
+    /// 楽観的排他制御用の ETag（id と updated_at から導出）
+    pub fn etag(&self) -> String {
+        format!(
+            "\"{}-{}\"",
+            self.id.simple(),
+            self.updated_at.timestamp_micros()
+        )
+    }
+
+    /// ETag が示す勘定科目 `id` の版（updated_at。他の勘定科目や不正な ETag なら None）
+    pub fn version_from_etag(id: Uuid, etag: &str) -> Option<DateTime<Utc>> {
+        let (tag_id, micros) = etag.strip_prefix('"')?.strip_suffix('"')?.split_once('-')?;
+        if tag_id != id.simple().to_string() {
+            return None;
+        }
+        DateTime::from_timestamp_micros(micros.parse().ok()?)
+    }
+}
+
+/// 勘定科目一覧の並び替えキー
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountSortKey {
+    Code,
+    Name,
+    #[default]
+    DisplayOrder,
+    CreatedAt,
+}
+
+impl AccountSortKey {
+    /// 並び替えに使う accounts テーブルの列
+    pub fn column(&self) -> &'static str {
+        match self {
+            AccountSortKey::Code => "code",
+            AccountSortKey::Name => "name",
+            AccountSortKey::DisplayOrder => "display_order",
+            AccountSortKey::CreatedAt => "created_at",
+        }
+    }
+}
+
+/// 並び順
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        }
+    }
+}
+
+/// 勘定科目一覧の取得条件
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccountListQuery {
+    pub account_type: Option<AccountType>,
+    /// 無効化済みの勘定科目も含める
+    #[serde(default)]
+    pub include_inactive: bool,
+    #[serde(default)]
+    pub sort: AccountSortKey,
+    #[serde(default)]
+    pub order: SortOrder,
+}
+
+impl AccountListQuery {
+    pub fn matches(&self, account: &Account) -> bool {
+        (self.include_inactive || account.is_active)
+            && self.account_type.is_none_or(|t| account.account_type == t)
+    }
+
+    /// 並び替えキーが同じ勘定科目は科目コード順に並べる
+    pub fn sort(&self, accounts: &mut [Account]) {
+        accounts.sort_by(|a, b| {
+            let ordering = match self.sort {
+                AccountSortKey::Code => a.code.cmp(&b.code),
+                AccountSortKey::Name => a.name.cmp(&b.name),
+                AccountSortKey::DisplayOrder => a.display_order.cmp(&b.display_order),
+                AccountSortKey::CreatedAt => a.created_at.cmp(&b.created_at),
+            };
+            let ordering = match self.order {
+                SortOrder::Asc => ordering,
+                SortOrder::Desc => ordering.reverse(),
+            };
+            ordering.then_with(|| a.code.cmp(&b.code))
+        });
+    }
+}
+
+/// 勘定科目詳細の取得条件
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccountQuery {
+    /// 指定した日（UTC）の終了時点の状態を返す
+    pub as_of: Option<NaiveDate>,
+}
+
+impl AccountQuery {
+    /// 履歴を参照する時点（指定した日の最後の瞬間）
+    pub fn as_of_time(&self) -> Option<DateTime<Utc>> {
+        self.as_of
+            .and_then(|date| date.and_hms_micro_opt(23, 59, 59, 999_999))
+            .map(|time| time.and_utc())
+    }
+}
+
+/// 親勘定科目として設定できるか検証（自身でなく、科目種別が一致すること）
+///
+/// `account_id` は作成前の勘定科目では `None`。
+pub fn validate_parent(
+    account_id: Option<Uuid>,
+    account_type: AccountType,
+    parent: &Account,
+) -> Result<(), String> {
+    if Some(parent.id) == account_id {
+        return Err("勘定科目を自身の親に設定することはできません".to_string());
+    }
+    if parent.account_type != account_type {
+        return Err(format!(
+            "親科目の種別（{}）と科目種別（{}）が一致しません",
+            parent.account_type, account_type
+        ));
+    }
+    Ok(())
+}
+
+/// 勘定科目ツリーのノード
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountNode {
+    pub account: Account,
+    pub children: Vec<AccountNode>,
+}
+
+/// 勘定科目一覧を親子関係に従ってツリーに組み立てる
+///
+/// 親が一覧に含まれない勘定科目はルートとして扱う。各階層は入力順を保持する。
+pub fn build_account_tree(accounts: Vec<Account>) -> Vec<AccountNode> {
+    let ids: HashSet<Uuid> = accounts.iter().map(|a| a.id).collect();
+    let mut children: HashMap<Uuid, Vec<Account>> = HashMap::new();
+    let mut roots = Vec::new();
+
+    for account in accounts {
+        match account
+            .parent_id
+            .filter(|parent_id| ids.contains(parent_id))
+        {
+            Some(parent_id) => children.entry(parent_id).or_default().push(account),
+            None => roots.push(account),
+        }
+    }
+
+    fn attach(account: Account, children: &mut HashMap<Uuid, Vec<Account>>) -> AccountNode {
+        let kids = children.remove(&account.id).unwrap_or_default();
+        AccountNode {
+            account,
+            children: kids.into_iter().map(|c| attach(c, children)).collect(),
+        }
+    }
+
+    roots
+        .into_iter()
+        .map(|account| attach(account, &mut children))
+        .collect()
 }
 
 /// 勘定科目作成リクエスト
@@ -259,24 +714,61 @@ pub struct CreateAccountRequest {
     pub description: Option<String>,
 
     pub display_order: Option<i32>,
+
+    pub parent_id: Option<Uuid>,
 }
 
 lazy_static::lazy_static! {
     static ref CODE_REGEX: regex::Regex = regex::Regex::new(r"^[A-Za-z0-9\-]+$").unwrap();
+    /// カテゴリコード（英小文字で始まる英小文字・数字・アンダースコア）
+    pub(crate) static ref CATEGORY_CODE_REGEX: regex::Regex =
+        regex::Regex::new(r"^[a-z][a-z0-9_]{1,29}$").unwrap();
 }
 
 /// 勘定科目更新リクエスト
+///
+/// `description` と `parent_id` は `null` を指定すると消去できる（省略時は変更しない）。
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct UpdateAccountRequest {
     #[validate(length(min = 1, max = 100, message = "科目名は1〜100文字で入力してください"))]
     pub name: Option<String>,
 
+    #[serde(default, skip_serializing_if = "Patch::is_absent")]
     #[validate(length(max = 500, message = "説明は500文字以内で入力してください"))]
-    pub description: Option<String>,
+    pub description: Patch<String>,
 
     pub display_order: Option<i32>,
 
     pub is_active: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Patch::is_absent")]
+    pub parent_id: Patch<Uuid>,
+}
+
+fn validate_unique_ids(ids: &[Uuid]) -> Result<(), ValidationError> {
+    let mut seen = HashSet::new();
+    match ids.iter().find(|id| !seen.insert(**id)) {
+        Some(id) => {
+            let mut error = ValidationError::new("duplicate_account_id");
+            error.message = Some(format!("勘定科目IDが重複しています: {}", id).into());
+            Err(error)
+        }
+        None => Ok(()),
+    }
+}
+
+/// 表示順の一括変更リクエスト
+///
+/// 指定した順に 1 から表示順を振り直す。
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ReorderAccountsRequest {
+    #[validate(length(
+        min = 1,
+        max = 1000,
+        message = "勘定科目IDは1〜1000件で指定してください"
+    ))]
+    #[validate(custom(function = "validate_unique_ids"))]
+    pub account_ids: Vec<Uuid>,
 }
 
 /// 勘定科目レスポンス
@@ -286,17 +778,30 @@ pub struct AccountResponse {
     pub code: String,
     pub name: String,
     pub account_type: AccountType,
+    /// 種別の表示名（`Accept-Language` に従う、既定は日本語）
+    #[serde(default)]
+    pub account_type_label: String,
     pub category: AccountCategory,
+    /// カテゴリの表示名（`Accept-Language` に従う、既定は日本語）
+    #[serde(default)]
+    pub category_label: String,
     pub description: Option<String>,
     pub is_active: bool,
     pub display_order: i32,
+    pub parent_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl From<Account> for AccountResponse {
     fn from(account: Account) -> Self {
+        let locale = current_locale().unwrap_or_default();
         Self {
+            account_type_label: account.account_type.localized(locale).to_string(),
+            category_label: account.category.label().map_or_else(
+                || account.category.to_string(),
+                |label| label.get(locale).to_string(),
+            ),
             id: account.id,
             code: account.code,
             name: account.name,
@@ -305,12 +810,34 @@ impl From<Account> for AccountResponse {
             description: account.description,
             is_active: account.is_active,
             display_order: account.display_order,
+            parent_id: account.parent_id,
             created_at: account.created_at,
             updated_at: account.updated_at,
         }
     }
 }
 
+/// 勘定科目ツリーレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountTreeResponse {
+    #[serde(flatten)]
+    pub account: AccountResponse,
+    pub children: Vec<AccountTreeResponse>,
+}
+
+impl From<AccountNode> for AccountTreeResponse {
+    fn from(node: AccountNode) -> Self {
+        Self {
+            account: AccountResponse::from(node.account),
+            children: node
+                .children
+                .into_iter()
+                .map(AccountTreeResponse::from)
+                .collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,19 +857,29 @@ mod tests {
 
     #[test]
     fn test_account_category_type_mapping() {
-        assert_eq!(AccountCategory::Cash.account_type(), AccountType::Asset);
+        assert_eq!(
+            AccountCategory::Cash.account_type(),
+            Some(AccountType::Asset)
+        );
         assert_eq!(
             AccountCategory::AccountsPayable.account_type(),
-            AccountType::Liability
+            Some(AccountType::Liability)
+        );
+        assert_eq!(
+            AccountCategory::Capital.account_type(),
+            Some(AccountType::Equity)
         );
-        assert_eq!(AccountCategory::Capital.account_type(), AccountType::Equity);
         assert_eq!(
             AccountCategory::TitheOffering.account_type(),
-            AccountType::Revenue
+            Some(AccountType::Revenue)
         );
         assert_eq!(
             AccountCategory::PersonnelExpense.account_type(),
-            AccountType::Expense
+            Some(AccountType::Expense)
+        );
+        assert_eq!(
+            AccountCategory::Custom("youth_ministry".to_string()).account_type(),
+            None
         );
     }
 
@@ -375,7 +912,63 @@ mod tests {
             assert_eq!(variant.to_string(), expected);
             assert_eq!(AccountCategory::from_str(expected).unwrap(), variant);
         }
-        assert!(AccountCategory::from_str("invalid").is_err());
+        assert_eq!(
+            AccountCategory::from_str("youth_ministry").unwrap(),
+            AccountCategory::Custom("youth_ministry".to_string())
+        );
+        assert!(AccountCategory::from_str("Invalid Category").is_err());
+        assert!(AccountCategory::from_str("").is_err());
+
+        let json = serde_json::to_string(&AccountCategory::TitheOffering).unwrap();
+        assert_eq!(json, "\"tithe_offering\"");
+        assert!(serde_json::from_str::<AccountCategory>("\"Tithe\"").is_err());
+    }
+
+    #[test]
+    fn test_code_reuse_policy_from_str() {
+        assert_eq!(CodeReusePolicy::default(), CodeReusePolicy::Reject);
+        for policy in [
+            CodeReusePolicy::Reject,
+            CodeReusePolicy::Allow,
+            CodeReusePolicy::Revive,
+        ] {
+            assert_eq!(
+                CodeReusePolicy::from_str(&policy.to_string()).unwrap(),
+                policy
+            );
+        }
+        assert!(CodeReusePolicy::from_str("invalid").is_err());
+    }
+
+    #[test]
+    fn test_account_code_ranges() {
+        let ranges = AccountCodeRanges::default();
+        assert!(ranges.check("101", AccountType::Asset).is_ok());
+        assert!(ranges.check("101-2", AccountType::Asset).is_ok());
+        assert!(ranges.check("401", AccountType::Asset).is_err());
+        assert!(ranges.check("A01", AccountType::Asset).is_err());
+
+        assert_eq!(
+            ranges.next_code(AccountType::Asset, ["100", "101", "103"]),
+            Some("102".to_string())
+        );
+
+        let custom: AccountCodeRanges = "asset=1000-1001, expense=5000-5999".parse().unwrap();
+        assert_eq!(custom.to_string(), "asset=1000-1001,expense=5000-5999");
+        assert_eq!(custom.next_code(AccountType::Asset, ["1000", "1001"]), None);
+        // 範囲を指定していない科目種別は検証しない
+        assert!(custom.check("X-1", AccountType::Revenue).is_ok());
+        assert_eq!(custom.next_code(AccountType::Revenue, []), None);
+
+        for invalid in [
+            "",
+            "asset",
+            "asset=200-100",
+            "cash=100-199",
+            "asset=100-199,expense=150-250",
+        ] {
+            assert!(invalid.parse::<AccountCodeRanges>().is_err(), "{}", invalid);
+        }
     }
 
     #[test]
@@ -383,6 +976,7 @@ mod tests {
         let account = Account::new(
             "101".to_string(),
             "現金".to_string(),
+            AccountType::Asset,
             AccountCategory::Cash,
             Some("手許現金".to_string()),
             1,
@@ -394,4 +988,123 @@ mod tests {
         assert_eq!(account.category, AccountCategory::Cash);
         assert!(account.is_active);
     }
+
+    #[test]
+    fn test_account_etag_changes_with_updated_at() {
+        let mut account = Account::new(
+            "101".to_string(),
+            "現金".to_string(),
+            AccountType::Asset,
+            AccountCategory::Cash,
+            None,
+            1,
+        );
+        let before = account.etag();
+        assert!(before.starts_with('"') && before.ends_with('"'));
+
+        account.updated_at += chrono::Duration::seconds(1);
+
+        assert_ne!(account.etag(), before);
+    }
+
+    #[test]
+    fn test_version_from_etag() {
+        let account = Account::new(
+            "101".to_string(),
+            "現金".to_string(),
+            AccountType::Asset,
+            AccountCategory::Cash,
+            None,
+            1,
+        );
+        let version = Account::version_from_etag(account.id, &account.etag()).unwrap();
+
+        assert_eq!(
+            version.timestamp_micros(),
+            account.updated_at.timestamp_micros()
+        );
+        assert!(Account::version_from_etag(Uuid::new_v4(), &account.etag()).is_none());
+        assert!(Account::version_from_etag(account.id, "\"stale\"").is_none());
+        assert!(Account::version_from_etag(account.id, "*").is_none());
+    }
+
+    #[test]
+    fn test_validate_parent() {
+        let cash = Account::new(
+            "100".to_string(),
+            "現金預金".to_string(),
+            AccountType::Asset,
+            AccountCategory::Cash,
+            None,
+            1,
+        );
+        let petty = Account::new(
+            "101".to_string(),
+            "小口現金".to_string(),
+            AccountType::Asset,
+            AccountCategory::Cash,
+            None,
+            2,
+        );
+        let tithe = Account::new(
+            "401".to_string(),
+            "什一献金".to_string(),
+            AccountType::Revenue,
+            AccountCategory::TitheOffering,
+            None,
+            10,
+        );
+
+        assert!(validate_parent(Some(petty.id), petty.account_type, &cash).is_ok());
+        assert!(validate_parent(None, AccountType::Asset, &cash).is_ok());
+        assert!(validate_parent(Some(petty.id), petty.account_type, &petty).is_err());
+        assert!(validate_parent(Some(petty.id), petty.account_type, &tithe).is_err());
+    }
+
+    #[test]
+    fn test_build_account_tree() {
+        let root = Account::new(
+            "100".to_string(),
+            "現金預金".to_string(),
+            AccountType::Asset,
+            AccountCategory::Cash,
+            None,
+            1,
+        );
+        let mut child = Account::new(
+            "101".to_string(),
+            "小口現金".to_string(),
+            AccountType::Asset,
+            AccountCategory::Cash,
+            None,
+            2,
+        );
+        child.parent_id = Some(root.id);
+        let mut grandchild = Account::new(
+            "102".to_string(),
+            "教会学校小口".to_string(),
+            AccountType::Asset,
+            AccountCategory::Cash,
+            None,
+            3,
+        );
+        grandchild.parent_id = Some(child.id);
+        let mut orphan = Account::new(
+            "103".to_string(),
+            "普通預金".to_string(),
+            AccountType::Asset,
+            AccountCategory::BankDeposit,
+            None,
+            4,
+        );
+        orphan.parent_id = Some(Uuid::new_v4());
+
+        let tree = build_account_tree(vec![grandchild.clone(), root.clone(), child, orphan]);
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].account.id, root.id);
+        assert_eq!(tree[0].children.len(), 1);
+        assert_eq!(tree[0].children[0].children[0].account.id, grandchild.id);
+        assert_eq!(tree[1].account.code, "103");
+    }
 }
@@ -0,0 +1,255 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::domain::{Currency, Money};
+use common::money::MoneyError;
+
+/// 日本円の金種（紙幣・硬貨）
+pub const JPY_DENOMINATIONS: [i64; 10] = [10000, 5000, 2000, 1000, 500, 100, 50, 10, 5, 1];
+
+/// 1 金種あたりの最大枚数
+pub const MAX_DENOMINATION_QUANTITY: i64 = 1_000_000;
+
+/// 金種ごとの枚数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DenominationCount {
+    pub denomination: i64,
+    pub quantity: i64,
+}
+
+impl DenominationCount {
+    pub fn subtotal(&self) -> Result<Money, MoneyError> {
+        self.denomination
+            .checked_mul(self.quantity)
+            .map(|amount| Money::new(amount, Currency::JPY))
+            .ok_or(MoneyError::Overflow)
+    }
+}
+
+/// 金種表（金庫ごとの現金実査記録）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CashCount {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    /// 金庫（保管場所）コード
+    pub safe: String,
+    pub counted_at: DateTime<Utc>,
+    /// 金種の大きい順
+    pub lines: Vec<DenominationCount>,
+    pub total: Money,
+    /// 実査時の帳簿上の現金残高（未入力なら過不足を出さない）
+    pub book_balance: Option<Money>,
+    pub counted_by: Option<String>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CashCount {
+    pub fn new(
+        organization_id: Uuid,
+        safe: String,
+        counted_at: DateTime<Utc>,
+        mut lines: Vec<DenominationCount>,
+        counted_by: Option<String>,
+        note: Option<String>,
+    ) -> Result<Self, MoneyError> {
+        lines.sort_by_key(|line| std::cmp::Reverse(line.denomination));
+        let total = cash_total(&lines)?;
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            organization_id,
+            safe,
+            counted_at,
+            lines,
+            total,
+            book_balance: None,
+            counted_by,
+            note,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// 帳簿残高と比べる（円）
+    pub fn with_book_balance(mut self, book_balance: Option<i64>) -> Self {
+        self.book_balance = book_balance.map(|amount| Money::new(amount, Currency::JPY));
+        self
+    }
+
+    /// 実査額と帳簿残高の差（正なら現金過剰、負なら現金不足）
+    pub fn difference(&self) -> Option<Money> {
+        self.book_balance
+            .and_then(|book| self.total.checked_sub(book).ok())
+    }
+}
+
+/// 金種表の合計金額
+pub fn cash_total(lines: &[DenominationCount]) -> Result<Money, MoneyError> {
+    lines
+        .iter()
+        .try_fold(Money::zero(Currency::JPY), |acc, line| {
+            acc.checked_add(line.subtotal()?)
+        })
+}
+
+fn validate_lines(lines: &[DenominationCount]) -> Result<(), ValidationError> {
+    let mut seen = HashSet::new();
+    for line in lines {
+        if !JPY_DENOMINATIONS.contains(&line.denomination) {
+            let mut error = ValidationError::new("denomination");
+            error.message = Some(format!("金種が不正です: {}", line.denomination).into());
+            return Err(error);
+        }
+        if !seen.insert(line.denomination) {
+            let mut error = ValidationError::new("duplicate_denomination");
+            error.message = Some(format!("金種が重複しています: {}", line.denomination).into());
+            return Err(error);
+        }
+        if !(0..=MAX_DENOMINATION_QUANTITY).contains(&line.quantity) {
+            let mut error = ValidationError::new("quantity");
+            error.message = Some(
+                format!(
+                    "枚数は0以上{}以下で入力してください",
+                    MAX_DENOMINATION_QUANTITY
+                )
+                .into(),
+            );
+            return Err(error);
+        }
+    }
+    Ok(())
+}
+
+/// 金種表登録リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateCashCountRequest {
+    #[validate(length(min = 1, max = 50, message = "金庫コードは1〜50文字で入力してください"))]
+    pub safe: String,
+
+    /// 省略時は登録時刻
+    pub counted_at: Option<DateTime<Utc>>,
+
+    #[validate(custom(function = "validate_lines"))]
+    pub lines: Vec<DenominationCount>,
+
+    /// 帳簿上の現金残高（円）。指定すると実査額との過不足を返す
+    pub book_balance: Option<i64>,
+
+    #[validate(length(max = 100, message = "実査者は100文字以内で入力してください"))]
+    pub counted_by: Option<String>,
+
+    #[validate(length(max = 500, message = "備考は500文字以内で入力してください"))]
+    pub note: Option<String>,
+}
+
+/// 金種表一覧の絞り込み条件（実査日時は from 以上 to 未満）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CashCountFilter {
+    pub safe: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl CashCountFilter {
+    pub fn matches(&self, count: &CashCount) -> bool {
+        self.safe.as_ref().is_none_or(|safe| &count.safe == safe)
+            && self.from.is_none_or(|from| count.counted_at >= from)
+            && self.to.is_none_or(|to| count.counted_at < to)
+    }
+}
+
+/// 金種表レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashCountResponse {
+    pub id: Uuid,
+    pub safe: String,
+    pub counted_at: DateTime<Utc>,
+    pub lines: Vec<DenominationCount>,
+    pub total: Money,
+    pub book_balance: Option<Money>,
+    /// 実査額 - 帳簿残高（帳簿残高が未入力なら None）
+    pub difference: Option<Money>,
+    pub counted_by: Option<String>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CashCount> for CashCountResponse {
+    fn from(count: CashCount) -> Self {
+        let difference = count.difference();
+        Self {
+            id: count.id,
+            safe: count.safe,
+            counted_at: count.counted_at,
+            lines: count.lines,
+            total: count.total,
+            book_balance: count.book_balance,
+            difference,
+            counted_by: count.counted_by,
+            note: count.note,
+            created_at: count.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::DEFAULT_ORGANIZATION_ID;
+
+    fn line(denomination: i64, quantity: i64) -> DenominationCount {
+        DenominationCount {
+            denomination,
+            quantity,
+        }
+    }
+
+    fn request(lines: Vec<DenominationCount>) -> CreateCashCountRequest {
+        CreateCashCountRequest {
+            safe: "MAIN".to_string(),
+            counted_at: None,
+            lines,
+            book_balance: None,
+            counted_by: None,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_cash_count_total_and_order() {
+        let count = CashCount::new(
+            DEFAULT_ORGANIZATION_ID,
+            "MAIN".to_string(),
+            Utc::now(),
+            vec![line(100, 7), line(10000, 3), line(1, 4)],
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(count.total, Money::new(30704, Currency::JPY));
+        assert_eq!(count.lines[0].denomination, 10000);
+        assert_eq!(count.lines[2].denomination, 1);
+        assert_eq!(count.difference(), None);
+
+        let short = count.clone().with_book_balance(Some(31000));
+        assert_eq!(short.difference(), Some(Money::new(-296, Currency::JPY)));
+        let over = count.with_book_balance(Some(30000));
+        assert_eq!(over.difference(), Some(Money::new(704, Currency::JPY)));
+    }
+
+    #[test]
+    fn test_create_request_validation() {
+        assert!(request(vec![line(10000, 1), line(500, 2)])
+            .validate()
+            .is_ok());
+        assert!(request(vec![line(3000, 1)]).validate().is_err());
+        assert!(request(vec![line(1000, 1), line(1000, 2)])
+            .validate()
+            .is_err());
+        assert!(request(vec![line(1000, -1)]).validate().is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::account::CATEGORY_CODE_REGEX;
+use crate::domain::{AccountCategory, AccountType};
+
+/// 勘定科目カテゴリの定義
+///
+/// 組み込みのカテゴリは全組織で共有し（`organization_id` が None）、
+/// 組織が定義したカテゴリはその組織の勘定科目でのみ使える。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryDefinition {
+    pub id: Uuid,
+    pub organization_id: Option<Uuid>,
+    pub code: String,
+    pub name: String,
+    pub account_type: AccountType,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CategoryDefinition {
+    pub fn new(
+        organization_id: Uuid,
+        code: String,
+        name: String,
+        account_type: AccountType,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            organization_id: Some(organization_id),
+            code,
+            name,
+            account_type,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_built_in(&self) -> bool {
+        self.organization_id.is_none()
+    }
+
+    /// 勘定科目に設定するカテゴリ
+    pub fn category(&self) -> AccountCategory {
+        self.code
+            .parse()
+            .unwrap_or_else(|_| AccountCategory::Custom(self.code.clone()))
+    }
+}
+
+/// 組み込みカテゴリの定義
+pub fn built_in_categories() -> Vec<CategoryDefinition> {
+    let now = Utc::now();
+    AccountCategory::BUILT_IN
+        .into_iter()
+        .filter_map(|category| {
+            Some(CategoryDefinition {
+                id: Uuid::new_v4(),
+                organization_id: None,
+                code: category.to_string(),
+                name: category.label()?.ja.to_string(),
+                account_type: category.account_type()?,
+                is_active: true,
+                created_at: now,
+                updated_at: now,
+            })
+        })
+        .collect()
+}
+
+/// 組み込み（定義順）、組織定義（コード順）の順に並べる
+pub fn sort_categories(definitions: &mut [CategoryDefinition]) {
+    definitions.sort_by_key(|d| {
+        let position = AccountCategory::BUILT_IN
+            .iter()
+            .position(|c| d.is_built_in() && c.to_string() == d.code);
+        (d.organization_id.is_some(), position, d.code.clone())
+    });
+}
+
+/// カテゴリ作成リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateCategoryRequest {
+    #[validate(regex(
+        path = *CATEGORY_CODE_REGEX,
+        message = "カテゴリコードは英小文字で始まる2〜30文字の英小文字・数字・アンダースコアで入力してください"
+    ))]
+    pub code: String,
+
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "カテゴリ名は1〜100文字で入力してください"
+    ))]
+    pub name: String,
+
+    pub account_type: AccountType,
+}
+
+/// カテゴリレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryResponse {
+    pub id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub account_type: AccountType,
+    pub is_built_in: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<CategoryDefinition> for CategoryResponse {
+    fn from(definition: CategoryDefinition) -> Self {
+        Self {
+            id: definition.id,
+            is_built_in: definition.is_built_in(),
+            code: definition.code,
+            name: definition.name,
+            account_type: definition.account_type,
+            created_at: definition.created_at,
+            updated_at: definition.updated_at,
+        }
+    }
+}
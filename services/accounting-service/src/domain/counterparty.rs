@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use common::patch::Patch;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// 取引先（支払先・業者など）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Counterparty {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    /// 電話番号やメールアドレスなど
+    pub contact: Option<String>,
+    pub note: Option<String>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Counterparty {
+    pub fn new(organization_id: Uuid, request: CreateCounterpartyRequest) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            organization_id,
+            name: request.name,
+            contact: request.contact,
+            note: request.note,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// 取引先登録リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateCounterpartyRequest {
+    #[validate(length(min = 1, max = 100, message = "取引先名は1〜100文字で入力してください"))]
+    pub name: String,
+
+    #[validate(length(max = 200, message = "連絡先は200文字以内で入力してください"))]
+    pub contact: Option<String>,
+
+    #[validate(length(max = 500, message = "備考は500文字以内で入力してください"))]
+    pub note: Option<String>,
+}
+
+/// 取引先更新リクエスト
+///
+/// `contact` と `note` は `null` を指定すると消去できる（省略時は変更しない）。
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct UpdateCounterpartyRequest {
+    #[validate(length(min = 1, max = 100, message = "取引先名は1〜100文字で入力してください"))]
+    pub name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Patch::is_absent")]
+    #[validate(length(max = 200, message = "連絡先は200文字以内で入力してください"))]
+    pub contact: Patch<String>,
+
+    #[serde(default, skip_serializing_if = "Patch::is_absent")]
+    #[validate(length(max = 500, message = "備考は500文字以内で入力してください"))]
+    pub note: Patch<String>,
+
+    pub is_active: Option<bool>,
+}
+
+/// 取引先レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CounterpartyResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub contact: Option<String>,
+    pub note: Option<String>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Counterparty> for CounterpartyResponse {
+    fn from(counterparty: Counterparty) -> Self {
+        Self {
+            id: counterparty.id,
+            name: counterparty.name,
+            contact: counterparty.contact,
+            note: counterparty.note,
+            is_active: counterparty.is_active,
+            created_at: counterparty.created_at,
+            updated_at: counterparty.updated_at,
+        }
+    }
+}
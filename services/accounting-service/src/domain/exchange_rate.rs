@@ -0,0 +1,176 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::domain::{Currency, Money};
+
+/// 為替レート（1 基準通貨 = rate 相手通貨、effective_date から適用）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExchangeRate {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub base_currency: Currency,
+    pub quote_currency: Currency,
+    pub rate: Decimal,
+    pub effective_date: NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExchangeRate {
+    pub fn new(
+        organization_id: Uuid,
+        base_currency: Currency,
+        quote_currency: Currency,
+        rate: Decimal,
+        effective_date: NaiveDate,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            organization_id,
+            base_currency,
+            quote_currency,
+            rate,
+            effective_date,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 基準通貨の金額を相手通貨に換算する
+    pub fn convert(&self, money: Money) -> Result<Money, String> {
+        if money.currency != self.base_currency {
+            return Err(format!(
+                "Cannot convert {} with {}/{} rate",
+                money.currency, self.base_currency, self.quote_currency
+            ));
+        }
+        let converted = money
+            .to_decimal()
+            .checked_mul(self.rate)
+            .ok_or_else(|| format!("Amount out of range: {}", money))?;
+        Money::from_decimal(converted, self.quote_currency).map_err(|e| e.to_string())
+    }
+}
+
+fn validate_positive_rate(rate: &Decimal) -> Result<(), ValidationError> {
+    if rate.is_sign_positive() && !rate.is_zero() {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("positive");
+        error.message = Some("為替レートは正の値で入力してください".into());
+        Err(error)
+    }
+}
+
+fn validate_currency_pair(request: &CreateExchangeRateRequest) -> Result<(), ValidationError> {
+    if request.base_currency == request.quote_currency {
+        let mut error = ValidationError::new("currency_pair");
+        error.message = Some("基準通貨と相手通貨は異なる通貨を指定してください".into());
+        return Err(error);
+    }
+    Ok(())
+}
+
+/// 為替レート作成リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[validate(schema(function = "validate_currency_pair"))]
+pub struct CreateExchangeRateRequest {
+    pub base_currency: Currency,
+
+    pub quote_currency: Currency,
+
+    #[validate(custom(function = "validate_positive_rate"))]
+    pub rate: Decimal,
+
+    pub effective_date: NaiveDate,
+}
+
+/// 為替レート更新リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct UpdateExchangeRateRequest {
+    #[validate(custom(function = "validate_positive_rate"))]
+    pub rate: Option<Decimal>,
+
+    pub effective_date: Option<NaiveDate>,
+}
+
+/// 為替レートレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeRateResponse {
+    pub id: Uuid,
+    pub base_currency: Currency,
+    pub quote_currency: Currency,
+    pub rate: Decimal,
+    pub effective_date: NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ExchangeRate> for ExchangeRateResponse {
+    fn from(rate: ExchangeRate) -> Self {
+        Self {
+            id: rate.id,
+            base_currency: rate.base_currency,
+            quote_currency: rate.quote_currency,
+            rate: rate.rate,
+            effective_date: rate.effective_date,
+            created_at: rate.created_at,
+            updated_at: rate.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::DEFAULT_ORGANIZATION_ID;
+    use std::str::FromStr;
+
+    fn usd_jpy(rate: &str) -> ExchangeRate {
+        ExchangeRate::new(
+            DEFAULT_ORGANIZATION_ID,
+            Currency::USD,
+            Currency::JPY,
+            Decimal::from_str(rate).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_convert() {
+        let rate = usd_jpy("150.25");
+
+        let converted = rate.convert(Money::new(1050, Currency::USD)).unwrap();
+
+        // 10.50 USD × 150.25 = 1577.625 → 1578 JPY
+        assert_eq!(converted, Money::new(1578, Currency::JPY));
+        assert!(rate.convert(Money::new(100, Currency::JPY)).is_err());
+    }
+
+    #[test]
+    fn test_create_request_validation() {
+        let valid = CreateExchangeRateRequest {
+            base_currency: Currency::USD,
+            quote_currency: Currency::JPY,
+            rate: Decimal::from_str("150").unwrap(),
+            effective_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+        };
+        assert!(valid.validate().is_ok());
+
+        let same_currency = CreateExchangeRateRequest {
+            quote_currency: Currency::USD,
+            ..valid.clone()
+        };
+        assert!(same_currency.validate().is_err());
+
+        let negative = CreateExchangeRateRequest {
+            rate: Decimal::from_str("-1").unwrap(),
+            ..valid
+        };
+        assert!(negative.validate().is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use common::money::MoneyError;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::domain::{Currency, Money};
+
+/// 固定資産（定額法で月割り償却する）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixedAsset {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub acquisition_date: NaiveDate,
+    pub acquisition_cost: Money,
+    /// 残存価額（償却後の帳簿価額）
+    pub salvage_value: Money,
+    pub useful_life_months: i32,
+    /// 減価償却費を計上する費用科目
+    pub expense_account_id: Uuid,
+    /// 除却済みは false
+    pub is_active: bool,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 償却予定表の 1 か月分
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DepreciationEntry {
+    /// 計上月（月初日）
+    pub period: NaiveDate,
+    pub amount: Money,
+    /// 償却累計額
+    pub accumulated: Money,
+    /// 計上後の帳簿価額
+    pub book_value: Money,
+}
+
+impl FixedAsset {
+    pub fn new(organization_id: Uuid, request: CreateFixedAssetRequest) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            organization_id,
+            name: request.name,
+            acquisition_date: request.acquisition_date,
+            acquisition_cost: Money::new(request.acquisition_cost, Currency::JPY),
+            salvage_value: Money::new(request.salvage_value.unwrap_or(0), Currency::JPY),
+            useful_life_months: request.useful_life_months,
+            expense_account_id: request.expense_account_id,
+            is_active: true,
+            note: request.note,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 定額法の償却予定表（取得月から耐用月数分、端数は先の月に寄せる）
+    pub fn depreciation_schedule(&self) -> Result<Vec<DepreciationEntry>, MoneyError> {
+        let depreciable = self.acquisition_cost.checked_sub(self.salvage_value)?;
+        let months = usize::try_from(self.useful_life_months).unwrap_or(0);
+        let first_period = self
+            .acquisition_date
+            .with_day(1)
+            .unwrap_or(self.acquisition_date);
+
+        let mut accumulated = Money::zero(depreciable.currency);
+        let mut entries = Vec::with_capacity(months);
+        for (offset, amount) in (0..).zip(depreciable.split(months)?) {
+            accumulated = accumulated.checked_add(amount)?;
+            entries.push(DepreciationEntry {
+                period: first_period + Months::new(offset),
+                amount,
+                accumulated,
+                book_value: self.acquisition_cost.checked_sub(accumulated)?,
+            });
+        }
+        Ok(entries)
+    }
+}
+
+fn validate_salvage_value(request: &CreateFixedAssetRequest) -> Result<(), ValidationError> {
+    let salvage_value = request.salvage_value.unwrap_or(0);
+    if salvage_value < 0 || salvage_value > request.acquisition_cost {
+        let mut error = ValidationError::new("salvage_value");
+        error.message = Some("残存価額は0以上、取得価額以下で入力してください".into());
+        return Err(error);
+    }
+    Ok(())
+}
+
+/// 固定資産登録リクエスト（金額は円）
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[validate(schema(function = "validate_salvage_value"))]
+pub struct CreateFixedAssetRequest {
+    #[validate(length(min = 1, max = 100, message = "資産名は1〜100文字で入力してください"))]
+    pub name: String,
+
+    pub acquisition_date: NaiveDate,
+
+    #[validate(range(min = 1, message = "取得価額は1円以上で入力してください"))]
+    pub acquisition_cost: i64,
+
+    /// 省略時は 0 円
+    pub salvage_value: Option<i64>,
+
+    #[validate(range(
+        min = 1,
+        max = 1200,
+        message = "耐用月数は1〜1200か月で入力してください"
+    ))]
+    pub useful_life_months: i32,
+
+    pub expense_account_id: Uuid,
+
+    #[validate(length(max = 500, message = "備考は500文字以内で入力してください"))]
+    pub note: Option<String>,
+}
+
+/// 固定資産レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixedAssetResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub acquisition_date: NaiveDate,
+    pub acquisition_cost: Money,
+    pub salvage_value: Money,
+    pub useful_life_months: i32,
+    pub expense_account_id: Uuid,
+    pub is_active: bool,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<FixedAsset> for FixedAssetResponse {
+    fn from(asset: FixedAsset) -> Self {
+        Self {
+            id: asset.id,
+            name: asset.name,
+            acquisition_date: asset.acquisition_date,
+            acquisition_cost: asset.acquisition_cost,
+            salvage_value: asset.salvage_value,
+            useful_life_months: asset.useful_life_months,
+            expense_account_id: asset.expense_account_id,
+            is_active: asset.is_active,
+            note: asset.note,
+            created_at: asset.created_at,
+            updated_at: asset.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::DEFAULT_ORGANIZATION_ID;
+
+    fn request(acquisition_cost: i64, salvage_value: Option<i64>) -> CreateFixedAssetRequest {
+        CreateFixedAssetRequest {
+            name: "音響設備".to_string(),
+            acquisition_date: NaiveDate::from_ymd_opt(2026, 11, 20).unwrap(),
+            acquisition_cost,
+            salvage_value,
+            useful_life_months: 3,
+            expense_account_id: Uuid::new_v4(),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_straight_line_schedule() {
+        let asset = FixedAsset::new(DEFAULT_ORGANIZATION_ID, request(100_001, Some(1)));
+
+        let schedule = asset.depreciation_schedule().unwrap();
+
+        let yen = |amount| Money::new(amount, Currency::JPY);
+        let periods: Vec<String> = schedule.iter().map(|e| e.period.to_string()).collect();
+        assert_eq!(periods, ["2026-11-01", "2026-12-01", "2027-01-01"]);
+        let amounts: Vec<Money> = schedule.iter().map(|e| e.amount).collect();
+        assert_eq!(amounts, [yen(33_334), yen(33_333), yen(33_333)]);
+        assert_eq!(schedule[2].accumulated, yen(100_000));
+        assert_eq!(schedule[2].book_value, asset.salvage_value);
+    }
+
+    #[test]
+    fn test_salvage_value_must_not_exceed_cost() {
+        assert!(request(1000, None).validate().is_ok());
+        assert!(request(1000, Some(1000)).validate().is_ok());
+        assert!(request(1000, Some(1001)).validate().is_err());
+        assert!(request(1000, Some(-1)).validate().is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use chrono::NaiveDate;
+use common::error::FieldError;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 仕訳の 1 件あたりの最大行数
+pub const MAX_JOURNAL_LINES: usize = 100;
+
+/// 仕訳の下書き（入力途中でも受け付け、違反はすべて [`JournalValidation`] で返す）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JournalEntryDraft {
+    pub date: Option<NaiveDate>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub lines: Vec<JournalLineDraft>,
+}
+
+/// 仕訳の 1 行（借方・貸方のどちらか一方に円で金額を入れる）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JournalLineDraft {
+    pub account_id: Option<Uuid>,
+    #[serde(default)]
+    pub debit: i64,
+    #[serde(default)]
+    pub credit: i64,
+    pub memo: Option<String>,
+}
+
+/// 仕訳の検証結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalValidation {
+    pub valid: bool,
+    pub debit_total: i64,
+    pub credit_total: i64,
+    pub violations: Vec<FieldError>,
+}
+
+fn violation(field: impl Into<String>, code: &str, message: impl Into<String>) -> FieldError {
+    FieldError {
+        field: field.into(),
+        code: code.to_string(),
+        message: message.into(),
+    }
+}
+
+/// 行の勘定科目の違反（`lines[0].account_id`）
+pub fn account_violation(index: usize, code: &str, message: impl Into<String>) -> FieldError {
+    violation(format!("lines[{}].account_id", index), code, message)
+}
+
+impl JournalEntryDraft {
+    /// 借方・貸方の合計（桁あふれなら None）
+    pub fn totals(&self) -> Option<(i64, i64)> {
+        self.lines
+            .iter()
+            .try_fold((0i64, 0i64), |(debit, credit), line| {
+                Some((
+                    debit.checked_add(line.debit)?,
+                    credit.checked_add(line.credit)?,
+                ))
+            })
+    }
+
+    /// 勘定科目を参照せずに判定できる違反（日付・行数・金額・貸借の一致）
+    pub fn structural_violations(&self) -> Vec<FieldError> {
+        let mut violations = Vec::new();
+        if self.date.is_none() {
+            violations.push(violation("date", "required", "日付を入力してください"));
+        }
+        if self
+            .description
+            .as_ref()
+            .is_some_and(|description| description.chars().count() > 200)
+        {
+            violations.push(violation(
+                "description",
+                "length",
+                "摘要は200文字以内で入力してください",
+            ));
+        }
+        if self.lines.len() < 2 {
+            violations.push(violation(
+                "lines",
+                "too_few_lines",
+                "仕訳には2行以上が必要です",
+            ));
+        }
+        if self.lines.len() > MAX_JOURNAL_LINES {
+            violations.push(violation(
+                "lines",
+                "too_many_lines",
+                format!("仕訳は{}行以内で入力してください", MAX_JOURNAL_LINES),
+            ));
+        }
+
+        for (index, line) in self.lines.iter().enumerate() {
+            let field = |name: &str| format!("lines[{}].{}", index, name);
+            if line.account_id.is_none() {
+                violations.push(violation(
+                    field("account_id"),
+                    "required",
+                    "勘定科目を選択してください",
+                ));
+            }
+            if line.debit < 0 || line.credit < 0 {
+                violations.push(violation(
+                    field("amount"),
+                    "negative_amount",
+                    "金額は0より大きい値で入力してください",
+                ));
+            } else if line.debit > 0 && line.credit > 0 {
+                violations.push(violation(
+                    field("amount"),
+                    "debit_and_credit",
+                    "借方と貸方の両方に金額は入力できません",
+                ));
+            } else if line.debit == 0 && line.credit == 0 {
+                violations.push(violation(
+                    field("amount"),
+                    "zero_amount",
+                    "借方か貸方に金額を入力してください",
+                ));
+            }
+        }
+
+        match self.totals() {
+            Some((debit, credit)) if debit != credit => violations.push(violation(
+                "lines",
+                "unbalanced",
+                format!(
+                    "借方合計（{}円）と貸方合計（{}円）が一致しません",
+                    debit, credit
+                ),
+            )),
+            Some(_) => {}
+            None => violations.push(violation(
+                "lines",
+                "amount_overflow",
+                "金額の合計が大きすぎます",
+            )),
+        }
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(debit: i64, credit: i64) -> JournalLineDraft {
+        JournalLineDraft {
+            account_id: Some(Uuid::new_v4()),
+            debit,
+            credit,
+            memo: None,
+        }
+    }
+
+    fn codes(draft: &JournalEntryDraft) -> Vec<(String, String)> {
+        draft
+            .structural_violations()
+            .into_iter()
+            .map(|v| (v.field, v.code))
+            .collect()
+    }
+
+    #[test]
+    fn test_balanced_entry_has_no_violations() {
+        let draft = JournalEntryDraft {
+            date: NaiveDate::from_ymd_opt(2026, 4, 5),
+            description: Some("主日献金".to_string()),
+            lines: vec![line(30000, 0), line(0, 20000), line(0, 10000)],
+        };
+        assert!(codes(&draft).is_empty());
+        assert_eq!(draft.totals(), Some((30000, 30000)));
+    }
+
+    #[test]
+    fn test_every_violation_is_reported() {
+        let draft = JournalEntryDraft {
+            date: None,
+            description: None,
+            lines: vec![
+                line(1000, 0),
+                line(500, 500),
+                JournalLineDraft {
+                    account_id: None,
+                    ..line(0, 0)
+                },
+            ],
+        };
+        let to_pair = |field: &str, code: &str| (field.to_string(), code.to_string());
+        assert_eq!(
+            codes(&draft),
+            vec![
+                to_pair("date", "required"),
+                to_pair("lines[1].amount", "debit_and_credit"),
+                to_pair("lines[2].account_id", "required"),
+                to_pair("lines[2].amount", "zero_amount"),
+                to_pair("lines", "unbalanced"),
+            ]
+        );
+    }
+}
@@ -1,3 +1,26 @@
 pub mod account;
+pub mod cash_count;
+pub mod category;
+pub mod counterparty;
+pub mod email;
+pub mod exchange_rate;
+pub mod fixed_asset;
+pub mod journal;
+pub mod month_close;
+pub mod organization;
+pub mod search;
+pub mod webhook;
 
 pub use account::*;
+pub use cash_count::*;
+pub use category::*;
+pub use common::money::{Currency, Money};
+pub use counterparty::*;
+pub use email::*;
+pub use exchange_rate::*;
+pub use fixed_asset::*;
+pub use journal::*;
+pub use month_close::*;
+pub use organization::*;
+pub use search::*;
+pub use webhook::*;
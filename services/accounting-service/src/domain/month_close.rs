@@ -0,0 +1,141 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// 月次締めのチェックリスト（会計担当者が毎月確認する項目）
+///
+/// すべての項目が完了した時点で月を締め（`locked_at`）、以後は変更できない。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonthClose {
+    pub organization_id: Uuid,
+    /// 対象月の初日
+    pub month: NaiveDate,
+    /// 銀行残高との照合
+    pub bank_reconciled: bool,
+    /// 献金の記帳
+    pub offerings_posted: bool,
+    /// 月次報告書の作成
+    pub reports_generated: bool,
+    pub locked_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MonthClose {
+    /// 未着手のチェックリスト
+    pub fn new(organization_id: Uuid, month: NaiveDate) -> Self {
+        Self {
+            organization_id,
+            month,
+            bank_reconciled: false,
+            offerings_posted: false,
+            reports_generated: false,
+            locked_at: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.bank_reconciled && self.offerings_posted && self.reports_generated
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked_at.is_some()
+    }
+
+    /// 指定した項目を更新し、すべて完了していれば月を締める
+    pub fn apply(&mut self, request: UpdateMonthCloseRequest) {
+        let now = Utc::now();
+        if let Some(done) = request.bank_reconciled {
+            self.bank_reconciled = done;
+        }
+        if let Some(done) = request.offerings_posted {
+            self.offerings_posted = done;
+        }
+        if let Some(done) = request.reports_generated {
+            self.reports_generated = done;
+        }
+        if self.is_complete() {
+            self.locked_at = Some(now);
+        }
+        self.updated_at = now;
+    }
+}
+
+/// `yyyy-mm` 形式の対象月をその月の初日にする
+pub fn parse_month(value: &str) -> Option<NaiveDate> {
+    let (year, month) = value.split_once('-')?;
+    if year.len() != 4 || month.len() != 2 {
+        return None;
+    }
+    NaiveDate::from_ymd_opt(year.parse().ok()?, month.parse().ok()?, 1)
+}
+
+/// チェックリスト更新リクエスト（省略した項目は変更しない）
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct UpdateMonthCloseRequest {
+    pub bank_reconciled: Option<bool>,
+    pub offerings_posted: Option<bool>,
+    pub reports_generated: Option<bool>,
+}
+
+/// 月次締めレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonthCloseResponse {
+    /// 対象月（`yyyy-mm`）
+    pub month: String,
+    pub bank_reconciled: bool,
+    pub offerings_posted: bool,
+    pub reports_generated: bool,
+    pub locked: bool,
+    pub locked_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<MonthClose> for MonthCloseResponse {
+    fn from(close: MonthClose) -> Self {
+        Self {
+            month: format!("{:04}-{:02}", close.month.year(), close.month.month()),
+            bank_reconciled: close.bank_reconciled,
+            offerings_posted: close.offerings_posted,
+            reports_generated: close.reports_generated,
+            locked: close.is_locked(),
+            locked_at: close.locked_at,
+            updated_at: close.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_month() {
+        assert_eq!(parse_month("2026-04"), NaiveDate::from_ymd_opt(2026, 4, 1));
+        for invalid in ["2026-4", "2026-13", "26-04", "2026/04", "2026-04-01", ""] {
+            assert_eq!(parse_month(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_month_locks_when_all_items_complete() {
+        let month = parse_month("2026-04").unwrap();
+        let mut close = MonthClose::new(Uuid::new_v4(), month);
+
+        close.apply(UpdateMonthCloseRequest {
+            bank_reconciled: Some(true),
+            offerings_posted: Some(true),
+            ..Default::default()
+        });
+        assert!(!close.is_locked());
+
+        close.apply(UpdateMonthCloseRequest {
+            reports_generated: Some(true),
+            ..Default::default()
+        });
+        assert!(close.is_complete());
+        assert!(close.is_locked());
+        assert_eq!(MonthCloseResponse::from(close).month, "2026-04");
+    }
+}
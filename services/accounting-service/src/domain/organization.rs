@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::{AccountCategory, AccountResponse, CreateAccountRequest};
+
+/// 組織（教会）を指定しない場合の既定の組織
+///
+/// マルチテナント化以前のデータはこの組織に属する。
+pub const DEFAULT_ORGANIZATION_ID: Uuid = Uuid::nil();
+
+/// 組織（教会）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Organization {
+    pub id: Uuid,
+    pub name: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Organization {
+    pub fn new(name: String) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// 組織作成リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateOrganizationRequest {
+    #[validate(length(min = 1, max = 100, message = "組織名は1〜100文字で入力してください"))]
+    pub name: String,
+}
+
+/// 組織更新リクエスト
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct UpdateOrganizationRequest {
+    #[validate(length(min = 1, max = 100, message = "組織名は1〜100文字で入力してください"))]
+    pub name: Option<String>,
+
+    pub is_active: Option<bool>,
+}
+
+/// 組織レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Organization> for OrganizationResponse {
+    fn from(organization: Organization) -> Self {
+        Self {
+            id: organization.id,
+            name: organization.name,
+            is_active: organization.is_active,
+            created_at: organization.created_at,
+            updated_at: organization.updated_at,
+        }
+    }
+}
+
+/// 組織作成（プロビジョニング）レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvisionedOrganizationResponse {
+    #[serde(flatten)]
+    pub organization: OrganizationResponse,
+    /// 登録した標準の勘定科目
+    pub accounts: Vec<AccountResponse>,
+    /// 組織の API キー（マルチテナントのときのみ、`X-Org-Key` に指定する）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_key: Option<String>,
+}
+
+/// 新しい組織に登録する標準の勘定科目
+pub fn default_chart_of_accounts() -> Vec<CreateAccountRequest> {
+    [
+        ("101", "現金", AccountCategory::Cash),
+        ("102", "普通預金", AccountCategory::BankDeposit),
+        ("103", "定期預金", AccountCategory::FixedDeposit),
+        ("201", "預り金", AccountCategory::DepositsReceived),
+        ("301", "基本金", AccountCategory::Capital),
+        ("302", "繰越金", AccountCategory::RetainedSurplus),
+        ("401", "什一献金", AccountCategory::TitheOffering),
+        ("402", "感謝献金", AccountCategory::ThankOffering),
+        ("403", "特別献金", AccountCategory::SpecialOffering),
+        ("404", "会堂献金", AccountCategory::BuildingOffering),
+        ("501", "人件費", AccountCategory::PersonnelExpense),
+        ("502", "水道光熱費", AccountCategory::UtilityExpense),
+        ("503", "通信費", AccountCategory::CommunicationExpense),
+        ("504", "消耗品費", AccountCategory::SuppliesExpense),
+        ("505", "礼拝費", AccountCategory::WorshipExpense),
+        ("506", "教育費", AccountCategory::EducationExpense),
+        ("507", "伝道費", AccountCategory::MissionExpense),
+        ("508", "営繕費", AccountCategory::MaintenanceExpense),
+        ("509", "雑費", AccountCategory::OtherExpense),
+    ]
+    .into_iter()
+    .zip(1..)
+    .map(
+        |((code, name, category), display_order)| CreateAccountRequest {
+            code: code.to_string(),
+            name: name.to_string(),
+            category,
+            description: None,
+            display_order: Some(display_order),
+            parent_id: None,
+        },
+    )
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::AccountCodeRanges;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_default_chart_of_accounts_is_valid() {
+        let chart = default_chart_of_accounts();
+
+        assert!(chart.iter().all(|request| request.validate().is_ok()));
+        let ranges = AccountCodeRanges::default();
+        assert!(chart.iter().all(|request| ranges
+            .check(&request.code, request.category.account_type().unwrap())
+            .is_ok()));
+        let codes: HashSet<&str> = chart.iter().map(|r| r.code.as_str()).collect();
+        assert_eq!(codes.len(), chart.len());
+    }
+}
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+use validator::Validate;
+
+/// 検索結果の既定件数
+pub const DEFAULT_SEARCH_LIMIT: i64 = 20;
+
+/// 検索対象の種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchEntityType {
+    Account,
+}
+
+impl fmt::Display for SearchEntityType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            SearchEntityType::Account => "account",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for SearchEntityType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "account" => Ok(SearchEntityType::Account),
+            other => Err(format!("Invalid search entity type: {}", other)),
+        }
+    }
+}
+
+/// 検索条件
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct SearchQuery {
+    #[validate(length(min = 1, max = 100, message = "検索語は1〜100文字で入力してください"))]
+    pub q: String,
+
+    /// 指定した種別のみ返す（ファセットの件数は全種別）
+    #[serde(rename = "type")]
+    pub entity_type: Option<SearchEntityType>,
+
+    #[validate(range(min = 1, max = 100, message = "件数は1〜100で指定してください"))]
+    pub limit: Option<i64>,
+}
+
+impl SearchQuery {
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_SEARCH_LIMIT)
+    }
+
+    /// 部分一致用の LIKE パターン（`%`・`_` はエスケープする）
+    pub fn like_pattern(&self) -> String {
+        let mut pattern = String::from("%");
+        for c in self.q.trim().chars() {
+            if matches!(c, '%' | '_' | '\\') {
+                pattern.push('\\');
+            }
+            pattern.push(c);
+        }
+        pattern.push('%');
+        pattern
+    }
+}
+
+/// 検索結果の 1 件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub entity_type: SearchEntityType,
+    pub id: Uuid,
+    pub title: String,
+    pub snippet: Option<String>,
+    /// 関連度（大きいほど上位）
+    pub rank: f64,
+}
+
+/// 種別ごとの該当件数
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchFacet {
+    pub entity_type: SearchEntityType,
+    pub count: i64,
+}
+
+/// 検索結果（関連度順）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchResults {
+    pub hits: Vec<SearchHit>,
+    pub facets: Vec<SearchFacet>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_like_pattern_escapes_wildcards() {
+        let query = SearchQuery {
+            q: " 100%_off ".to_string(),
+            entity_type: None,
+            limit: None,
+        };
+        assert_eq!(query.like_pattern(), r"%100\%\_off%");
+        assert_eq!(query.limit(), DEFAULT_SEARCH_LIMIT);
+    }
+}
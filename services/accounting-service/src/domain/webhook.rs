@@ -0,0 +1,293 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use url::{Host, Url};
+use uuid::Uuid;
+use validator::{Validate, ValidateUrl, ValidationError};
+
+/// 購読できるイベント種別
+pub const WEBHOOK_EVENTS: &[&str] = &["account.created", "account.updated", "account.deactivated"];
+
+/// 署名用シークレットの最小長
+pub const MIN_WEBHOOK_SECRET_LENGTH: u64 = 16;
+
+/// Webhook 購読（イベント発生時に url へ署名付きで POST する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub url: String,
+    pub events: Vec<String>,
+    /// HMAC-SHA256 署名のシークレット（レスポンスには含めない）
+    pub secret: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    pub fn new(organization_id: Uuid, url: String, events: Vec<String>, secret: String) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            organization_id,
+            url,
+            events,
+            secret,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 指定したイベントの配信先か
+    pub fn subscribes(&self, event_type: &str) -> bool {
+        self.is_active && self.events.iter().any(|e| e == event_type)
+    }
+}
+
+/// Webhook の配信先の制限（サーバー内部のサービスへの送信を防ぐ）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WebhookTargetPolicy {
+    /// https かつ公開アドレスのみ（既定）
+    #[default]
+    PublicHttps,
+    /// http とループバック・プライベートアドレスも許可（開発用）
+    AllowInsecure,
+}
+
+impl WebhookTargetPolicy {
+    pub fn from_allow_insecure(allow_insecure: bool) -> Self {
+        if allow_insecure {
+            Self::AllowInsecure
+        } else {
+            Self::PublicHttps
+        }
+    }
+
+    /// 配信先の URL を許可するか（ホスト名の解決は配信時に [`Self::allows_address`] で確認する）
+    pub fn check_url(self, url: &str) -> Result<(), String> {
+        if self == Self::AllowInsecure {
+            return Ok(());
+        }
+        let url = Url::parse(url).map_err(|e| e.to_string())?;
+        if url.scheme() != "https" {
+            return Err("配信先URLは https:// で始まる必要があります".to_string());
+        }
+        let internal = match url.host() {
+            Some(Host::Domain(domain)) => {
+                let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+                domain == "localhost" || domain.ends_with(".localhost")
+            }
+            Some(Host::Ipv4(ip)) => !is_public_address(IpAddr::V4(ip)),
+            Some(Host::Ipv6(ip)) => !is_public_address(IpAddr::V6(ip)),
+            None => true,
+        };
+        if internal {
+            return Err("ループバック・プライベートアドレスには配信できません".to_string());
+        }
+        Ok(())
+    }
+
+    /// 解決したアドレスへ配信してよいか
+    pub fn allows_address(self, ip: IpAddr) -> bool {
+        self == Self::AllowInsecure || is_public_address(ip)
+    }
+}
+
+/// インターネット上の公開アドレスか（ループバック・プライベート・リンクローカルなどを除く）
+pub fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_ipv4(mapped),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8・共有アドレス（100.64.0.0/10）・予約済み（240.0.0.0/4）
+        || a == 0
+        || (a == 100 && (b & 0xc0) == 64)
+        || a >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // ユニークローカル（fc00::/7）・リンクローカル（fe80::/10）
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80)
+}
+
+fn validate_webhook_url(url: &str) -> Result<(), ValidationError> {
+    if (url.starts_with("https://") || url.starts_with("http://")) && url.validate_url() {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("url");
+        error.message = Some("配信先URLは http(s):// で始まる有効なURLを入力してください".into());
+        Err(error)
+    }
+}
+
+fn validate_webhook_events(events: &[String]) -> Result<(), ValidationError> {
+    if events.is_empty() {
+        let mut error = ValidationError::new("length");
+        error.message = Some("購読するイベントを1つ以上指定してください".into());
+        return Err(error);
+    }
+    if let Some(unknown) = events
+        .iter()
+        .find(|e| !WEBHOOK_EVENTS.contains(&e.as_str()))
+    {
+        let mut error = ValidationError::new("event");
+        error.message = Some(format!("未対応のイベントです: {}", unknown).into());
+        return Err(error);
+    }
+    Ok(())
+}
+
+/// Webhook 登録リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateWebhookRequest {
+    #[validate(custom(function = "validate_webhook_url"))]
+    pub url: String,
+
+    #[validate(custom(function = "validate_webhook_events"))]
+    pub events: Vec<String>,
+
+    #[validate(length(
+        min = "MIN_WEBHOOK_SECRET_LENGTH",
+        message = "シークレットは16文字以上で入力してください"
+    ))]
+    pub secret: String,
+}
+
+/// Webhook 更新リクエスト
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct UpdateWebhookRequest {
+    #[validate(custom(function = "validate_webhook_url"))]
+    pub url: Option<String>,
+
+    #[validate(custom(function = "validate_webhook_events"))]
+    pub events: Option<Vec<String>>,
+
+    #[validate(length(
+        min = "MIN_WEBHOOK_SECRET_LENGTH",
+        message = "シークレットは16文字以上で入力してください"
+    ))]
+    pub secret: Option<String>,
+
+    pub is_active: Option<bool>,
+}
+
+/// Webhook レスポンス（シークレットは返さない）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookResponse {
+    pub id: Uuid,
+    pub url: String,
+    pub events: Vec<String>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Webhook> for WebhookResponse {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            id: webhook.id,
+            url: webhook.url,
+            events: webhook.events,
+            is_active: webhook.is_active,
+            created_at: webhook.created_at,
+            updated_at: webhook.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::DEFAULT_ORGANIZATION_ID;
+
+    fn request(url: &str, events: &[&str]) -> CreateWebhookRequest {
+        CreateWebhookRequest {
+            url: url.to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            secret: "0123456789abcdef".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_create_request_validation() {
+        assert!(request("https://example.com/hook", &["account.created"])
+            .validate()
+            .is_ok());
+        assert!(request("ftp://example.com/hook", &["account.created"])
+            .validate()
+            .is_err());
+        assert!(request("https://example.com/hook", &[]).validate().is_err());
+        assert!(request("https://example.com/hook", &["journal.unknown"])
+            .validate()
+            .is_err());
+
+        let mut short_secret = request("https://example.com/hook", &["account.created"]);
+        short_secret.secret = "short".to_string();
+        assert!(short_secret.validate().is_err());
+    }
+
+    #[test]
+    fn test_target_policy() {
+        let policy = WebhookTargetPolicy::PublicHttps;
+        assert!(policy.check_url("https://example.com/hook").is_ok());
+        assert!(policy.check_url("https://203.0.113.1.example/hook").is_ok());
+        for url in [
+            "http://example.com/hook",
+            "https://localhost/hook",
+            "https://api.localhost./hook",
+            "https://127.0.0.1/hook",
+            "https://10.0.0.5/hook",
+            "https://169.254.169.254/latest/meta-data",
+            "https://100.64.0.1/hook",
+            "https://[::1]/hook",
+            "https://[fd00::1]/hook",
+            "https://[::ffff:192.168.0.1]/hook",
+        ] {
+            assert!(policy.check_url(url).is_err(), "{}", url);
+        }
+        assert!(policy.allows_address("93.184.216.34".parse().unwrap()));
+        assert!(!policy.allows_address("172.16.0.1".parse().unwrap()));
+        assert!(!policy.allows_address("fe80::1".parse().unwrap()));
+
+        let insecure = WebhookTargetPolicy::AllowInsecure;
+        assert!(insecure.check_url("http://127.0.0.1:8080/hook").is_ok());
+        assert!(insecure.allows_address("127.0.0.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_subscribes() {
+        let mut webhook = Webhook::new(
+            DEFAULT_ORGANIZATION_ID,
+            "https://example.com/hook".to_string(),
+            vec!["account.created".to_string()],
+            "0123456789abcdef".to_string(),
+        );
+
+        assert!(webhook.subscribes("account.created"));
+        assert!(!webhook.subscribes("account.updated"));
+
+        webhook.is_active = false;
+        assert!(!webhook.subscribes("account.created"));
+    }
+}
@@ -0,0 +1,187 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::domain::Account;
+
+/// 勘定科目のライフサイクルイベント
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum AccountEvent {
+    #[serde(rename = "account.created")]
+    AccountCreated(Account),
+    #[serde(rename = "account.updated")]
+    AccountUpdated(Account),
+    #[serde(rename = "account.deactivated")]
+    AccountDeactivated { id: Uuid },
+}
+
+impl AccountEvent {
+    /// イベント種別（`account.created` など）
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            AccountEvent::AccountCreated(_) => "account.created",
+            AccountEvent::AccountUpdated(_) => "account.updated",
+            AccountEvent::AccountDeactivated { .. } => "account.deactivated",
+        }
+    }
+
+    /// 対象の勘定科目の ID
+    pub fn account_id(&self) -> Uuid {
+        match self {
+            AccountEvent::AccountCreated(account) | AccountEvent::AccountUpdated(account) => {
+                account.id
+            }
+            AccountEvent::AccountDeactivated { id } => *id,
+        }
+    }
+}
+
+/// 配信用のイベント（ID と発生日時を付与）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    pub id: Uuid,
+    /// 発生した組織（Webhook はこの組織の購読先にだけ配信する）
+    #[serde(default)]
+    pub organization_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: AccountEvent,
+}
+
+impl EventEnvelope {
+    pub fn new(organization_id: Uuid, event: AccountEvent) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            organization_id,
+            occurred_at: Utc::now(),
+            event,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum EventError {
+    #[error("Failed to serialize event: {0}")]
+    Serialize(#[from] serde_json::Error),
+
+    #[error("Failed to publish event: {0}")]
+    Publish(String),
+}
+
+/// ドメインイベントの発行先
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    async fn publish(&self, envelope: &EventEnvelope) -> Result<(), EventError>;
+}
+
+pub type DynEventPublisher = Arc<dyn EventPublisher>;
+
+/// プロセス内配信（外部ブローカーを使わない場合の既定）
+#[derive(Clone)]
+pub struct InProcessEventPublisher {
+    sender: broadcast::Sender<EventEnvelope>,
+}
+
+impl InProcessEventPublisher {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<EventEnvelope> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for InProcessEventPublisher {
+    fn default() -> Self {
+        Self::new(1024)
+    }
+}
+
+#[async_trait]
+impl EventPublisher for InProcessEventPublisher {
+    async fn publish(&self, envelope: &EventEnvelope) -> Result<(), EventError> {
+        // 購読者がいない場合の送信失敗は無視する
+        let _ = self.sender.send(envelope.clone());
+        Ok(())
+    }
+}
+
+/// 複数の発行先へ配信する（いずれかの失敗は最後に返す）
+pub struct FanoutEventPublisher {
+    publishers: Vec<DynEventPublisher>,
+}
+
+impl FanoutEventPublisher {
+    pub fn new(publishers: Vec<DynEventPublisher>) -> Self {
+        Self { publishers }
+    }
+}
+
+#[async_trait]
+impl EventPublisher for FanoutEventPublisher {
+    async fn publish(&self, envelope: &EventEnvelope) -> Result<(), EventError> {
+        let mut result = Ok(());
+        for publisher in &self.publishers {
+            if let Err(err) = publisher.publish(envelope).await {
+                result = Err(err);
+            }
+        }
+        result
+    }
+}
+
+/// NATS への配信（subject は `{prefix}.{event_type}`）
+pub struct NatsEventPublisher {
+    client: async_nats::Client,
+    subject_prefix: String,
+}
+
+impl NatsEventPublisher {
+    pub async fn connect(url: &str, subject_prefix: impl Into<String>) -> Result<Self, EventError> {
+        let client = async_nats::connect(url)
+            .await
+            .map_err(|e| EventError::Publish(e.to_string()))?;
+        Ok(Self {
+            client,
+            subject_prefix: subject_prefix.into(),
+        })
+    }
+}
+
+#[async_trait]
+impl EventPublisher for NatsEventPublisher {
+    async fn publish(&self, envelope: &EventEnvelope) -> Result<(), EventError> {
+        let subject = format!("{}.{}", self.subject_prefix, envelope.event.event_type());
+        let payload = serde_json::to_vec(envelope)?;
+        self.client
+            .publish(subject, payload.into())
+            .await
+            .map_err(|e| EventError::Publish(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_envelope_serialization() {
+        let id = Uuid::new_v4();
+        let organization_id = Uuid::new_v4();
+        let envelope = EventEnvelope::new(organization_id, AccountEvent::AccountDeactivated { id });
+
+        let json = serde_json::to_value(&envelope).unwrap();
+
+        assert_eq!(json["type"], "account.deactivated");
+        assert_eq!(json["data"]["id"], id.to_string());
+        assert_eq!(json["organization_id"], organization_id.to_string());
+        assert!(json["occurred_at"].is_string());
+    }
+}
@@ -0,0 +1,362 @@
+use async_graphql::{
+    http::{playground_source, GraphQLPlaygroundConfig},
+    Context, EmptyMutation, EmptySubscription, InputObject, Object, Schema, ID,
+};
+use axum::{
+    extract::{OriginalUri, State},
+    response::{Html, IntoResponse},
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use common::json::HardenedJson;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::domain::{Account, AccountListQuery, AccountType};
+use crate::service::AccountService;
+use crate::tenant::OrganizationId;
+
+pub type AccountingSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// リクエストの組織に限定した勘定科目サービス
+///
+/// 組織を渡さずに実行したクエリは、既定の組織を読ませずにエラーにする。
+fn accounts(ctx: &Context<'_>) -> async_graphql::Result<AccountService> {
+    let accounts = ctx.data::<AccountService>()?;
+    let OrganizationId(organization_id) = ctx
+        .data_opt::<OrganizationId>()
+        .ok_or_else(|| async_graphql::Error::new("Organization is not set for this request"))?;
+    Ok(accounts.for_organization(*organization_id))
+}
+
+/// 勘定科目（GraphQL 表現）
+pub struct AccountObject(Account);
+
+#[Object(name = "Account")]
+impl AccountObject {
+    async fn id(&self) -> ID {
+        ID(self.0.id.to_string())
+    }
+
+    async fn code(&self) -> &str {
+        &self.0.code
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    /// 科目種別（asset / liability / equity / revenue / expense）
+    async fn account_type(&self) -> String {
+        self.0.account_type.to_string()
+    }
+
+    async fn category(&self) -> String {
+        self.0.category.to_string()
+    }
+
+    async fn description(&self) -> Option<&str> {
+        self.0.description.as_deref()
+    }
+
+    async fn is_active(&self) -> bool {
+        self.0.is_active
+    }
+
+    async fn display_order(&self) -> i32 {
+        self.0.display_order
+    }
+
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at
+    }
+
+    async fn updated_at(&self) -> DateTime<Utc> {
+        self.0.updated_at
+    }
+
+    /// 親勘定科目
+    async fn parent(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<AccountObject>> {
+        let Some(parent_id) = self.0.parent_id else {
+            return Ok(None);
+        };
+        let parent = accounts(ctx)?.find(parent_id).await?;
+        Ok(parent.map(AccountObject))
+    }
+
+    /// 子勘定科目
+    async fn children(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<AccountObject>> {
+        let children = accounts(ctx)?.children(self.0.id).await?;
+        Ok(children.into_iter().map(AccountObject).collect())
+    }
+}
+
+/// 勘定科目の絞り込み条件
+#[derive(Debug, Default, InputObject)]
+pub struct AccountFilter {
+    pub account_type: Option<String>,
+    pub category: Option<String>,
+    pub is_active: Option<bool>,
+    /// 科目コードの前方一致
+    pub code_prefix: Option<String>,
+    /// 科目名の部分一致
+    pub name_contains: Option<String>,
+    /// 最上位（親なし）の科目のみ
+    pub root_only: Option<bool>,
+}
+
+impl AccountFilter {
+    fn matches(&self, account: &Account) -> bool {
+        self.category
+            .as_ref()
+            .is_none_or(|c| account.category.to_string() == *c)
+            && self.is_active.is_none_or(|a| account.is_active == a)
+            && self
+                .code_prefix
+                .as_ref()
+                .is_none_or(|p| account.code.starts_with(p.as_str()))
+            && self
+                .name_contains
+                .as_ref()
+                .is_none_or(|n| account.name.contains(n.as_str()))
+            && (!self.root_only.unwrap_or(false) || account.parent_id.is_none())
+    }
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// 勘定科目一覧（表示順）
+    async fn accounts(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<AccountFilter>,
+    ) -> async_graphql::Result<Vec<AccountObject>> {
+        let filter = filter.unwrap_or_default();
+        let account_type = filter
+            .account_type
+            .as_deref()
+            .map(AccountType::from_str)
+            .transpose()?;
+        // 有効・無効を指定しない限り無効化済みの科目は返さない
+        let query = AccountListQuery {
+            account_type,
+            include_inactive: filter.is_active.is_some(),
+            ..Default::default()
+        };
+        let accounts = accounts(ctx)?.list(query).await?;
+
+        Ok(accounts
+            .into_iter()
+            .filter(|a| filter.matches(a))
+            .map(AccountObject)
+            .collect())
+    }
+
+    /// IDで勘定科目を取得
+    async fn account(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> async_graphql::Result<Option<AccountObject>> {
+        let id = Uuid::parse_str(&id)?;
+        Ok(accounts(ctx)?.find(id).await?.map(AccountObject))
+    }
+
+    /// 科目コードで勘定科目を取得
+    async fn account_by_code(
+        &self,
+        ctx: &Context<'_>,
+        code: String,
+    ) -> async_graphql::Result<Option<AccountObject>> {
+        Ok(accounts(ctx)?.find_by_code(&code).await?.map(AccountObject))
+    }
+}
+
+/// GraphQL スキーマを構築する
+pub fn build_schema(accounts: AccountService) -> AccountingSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(accounts)
+        .limit_depth(10)
+        .finish()
+}
+
+/// POST /graphql - クエリ実行
+pub async fn graphql_handler(
+    State(schema): State<AccountingSchema>,
+    organization: OrganizationId,
+    HardenedJson(request, _): HardenedJson<async_graphql::Request>,
+) -> Json<async_graphql::Response> {
+    Json(schema.execute(request.data(organization)).await)
+}
+
+/// GET /graphql - Playground
+///
+/// 問い合わせ先は要求されたパス（ゲートウェイに組み込んだ場合はその接頭辞を含む）。
+pub async fn graphql_playground(OriginalUri(uri): OriginalUri) -> impl IntoResponse {
+    Html(playground_source(GraphQLPlaygroundConfig::new(uri.path())))
+}
+
+/// GraphQL 用ルーター
+pub fn graphql_router(accounts: AccountService) -> Router {
+    Router::new()
+        .route("/graphql", get(graphql_playground).post(graphql_handler))
+        .with_state(build_schema(accounts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AccountCategory, CreateAccountRequest, DEFAULT_ORGANIZATION_ID};
+    use crate::repository::{AccountRepository, InMemoryAccountRepository};
+    use std::sync::Arc;
+
+    /// 既定の組織としてクエリを実行する
+    async fn execute(schema: &AccountingSchema, query: &str) -> async_graphql::Response {
+        schema
+            .execute(
+                async_graphql::Request::new(query).data(OrganizationId(DEFAULT_ORGANIZATION_ID)),
+            )
+            .await
+    }
+
+    fn request(code: &str, name: &str, category: AccountCategory) -> CreateAccountRequest {
+        CreateAccountRequest {
+            code: code.to_string(),
+            name: name.to_string(),
+            category,
+            description: None,
+            display_order: None,
+            parent_id: None,
+        }
+    }
+
+    async fn create_test_schema() -> AccountingSchema {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+        let parent = repo
+            .create(request("100", "現金預金", AccountCategory::Cash))
+            .await
+            .unwrap();
+        let mut child = request("101", "現金", AccountCategory::Cash);
+        child.parent_id = Some(parent.id);
+        repo.create(child).await.unwrap();
+        repo.create(request("401", "什一献金", AccountCategory::TitheOffering))
+            .await
+            .unwrap();
+
+        build_schema(AccountService::new(repo))
+    }
+
+    #[tokio::test]
+    async fn test_query_accounts_with_filter_and_children() {
+        let schema = create_test_schema().await;
+
+        let response = execute(
+            &schema,
+            r#"{
+                accounts(filter: { accountType: "asset", rootOnly: true }) {
+                    code
+                    children { code name parent { code } }
+                }
+            }"#,
+        )
+        .await;
+
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(
+            data,
+            serde_json::json!({
+                "accounts": [{
+                    "code": "100",
+                    "children": [{"code": "101", "name": "現金", "parent": {"code": "100"}}]
+                }]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_account_by_code_and_invalid_type() {
+        let schema = create_test_schema().await;
+
+        let response = execute(
+            &schema,
+            r#"{ accountByCode(code: "401") { name accountType category } }"#,
+        )
+        .await;
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["accountByCode"]["accountType"], "revenue");
+
+        let response = execute(
+            &schema,
+            r#"{ accounts(filter: { accountType: "unknown" }) { code } }"#,
+        )
+        .await;
+        assert_eq!(response.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_query_without_organization_fails() {
+        let schema = create_test_schema().await;
+
+        let response = schema.execute(r#"{ accounts { code } }"#).await;
+
+        assert_eq!(response.errors.len(), 1);
+        assert!(response.data.into_json().unwrap().is_null());
+    }
+
+    #[tokio::test]
+    async fn test_playground_posts_to_nested_path() {
+        use axum::{body::Body, http::Request};
+        use http_body_util::BodyExt;
+        use tower::ServiceExt;
+
+        let app = Router::new().nest(
+            "/accounting",
+            graphql_router(AccountService::new(Arc::new(
+                InMemoryAccountRepository::new(),
+            ))),
+        );
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/accounting/graphql")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("\"/accounting/graphql\""));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_request_returns_error_response() {
+        use crate::tenant::trust_org_header;
+        use axum::{body::Body, http::Request, http::StatusCode};
+        use http_body_util::BodyExt;
+        use tower::ServiceExt;
+
+        let app = trust_org_header(graphql_router(AccountService::new(Arc::new(
+            InMemoryAccountRepository::new(),
+        ))));
+        let response = app
+            .oneshot(
+                Request::post("/graphql")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from("{\"query\":"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "INVALID_JSON");
+    }
+}
@@ -1,197 +1,287 @@
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
-    response::IntoResponse,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use common::error::AppError;
+use common::fields::Fields;
+use common::json::{StrictJsonLimits, ValidatedJson};
+use common::negotiate::Negotiate;
 use std::sync::Arc;
 use uuid::Uuid;
-use validator::Validate;
 
 use crate::domain::{
-    AccountResponse, AccountType, CreateAccountRequest, UpdateAccountRequest,
+    AccountListQuery, AccountQuery, AccountResponse, AccountTreeResponse, CreateAccountRequest,
+    NextAccountCodeQuery, NextAccountCodeResponse, ReorderAccountsRequest, UpdateAccountRequest,
 };
 use crate::repository::{AccountRepository, RepositoryError};
+use crate::service::AccountServiceError;
+use crate::tenant::OrganizationAccounts;
 
 pub type DynAccountRepository = Arc<dyn AccountRepository>;
 
-#[derive(Debug, Deserialize)]
-pub struct ListAccountsQuery {
-    pub account_type: Option<AccountType>,
-}
-
-/// エラーレスポンス
-#[derive(Debug, serde::Serialize)]
-pub struct ErrorResponse {
-    pub error: String,
-    pub code: String,
-}
-
-impl ErrorResponse {
-    fn new(error: impl Into<String>, code: impl Into<String>) -> Self {
-        Self {
-            error: error.into(),
-            code: code.into(),
+pub(crate) fn map_repo_error(err: RepositoryError) -> AppError {
+    match err {
+        RepositoryError::NotFound(id) => not_found(id),
+        RepositoryError::DuplicateCode(code) => AppError::Conflict {
+            code: "DUPLICATE_CODE",
+            message: format!("Account code already exists: {}", code),
+        },
+        RepositoryError::ValidationError(msg) => AppError::validation(msg),
+        RepositoryError::Conflict(msg) => AppError::Conflict {
+            code: "CONFLICT",
+            message: msg,
+        },
+        RepositoryError::DatabaseError(msg) => {
+            AppError::internal("DATABASE_ERROR", format!("Database error: {}", msg))
         }
+        err @ RepositoryError::Modified(_) => AppError::PreconditionFailed(err.to_string()),
     }
 }
 
-fn map_repo_error(err: RepositoryError) -> (StatusCode, Json<ErrorResponse>) {
+/// サービスのエラーを HTTP のエラーに変換する
+pub(crate) fn map_service_error(err: AccountServiceError) -> AppError {
     match err {
-        RepositoryError::NotFound(id) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new(
-                format!("Account not found: {}", id),
-                "NOT_FOUND",
-            )),
-        ),
-        RepositoryError::DuplicateCode(code) => (
-            StatusCode::CONFLICT,
-            Json(ErrorResponse::new(
-                format!("Account code already exists: {}", code),
-                "DUPLICATE_CODE",
-            )),
-        ),
-        RepositoryError::ValidationError(msg) => (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new(msg, "VALIDATION_ERROR")),
-        ),
-        RepositoryError::DatabaseError(msg) => {
-            tracing::error!("Database error: {}", msg);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::new(
-                    "An internal error occurred",
-                    "DATABASE_ERROR",
-                )),
-            )
+        AccountServiceError::Repository(err) => map_repo_error(err),
+        err @ AccountServiceError::PreconditionFailed(_) => {
+            AppError::PreconditionFailed(err.to_string())
         }
+        err @ AccountServiceError::NoFreeCode(_) => AppError::NotFound(err.to_string()),
+        err @ AccountServiceError::InUse(_) => AppError::Conflict {
+            code: "ACCOUNT_IN_USE",
+            message: err.to_string(),
+        },
+        err @ AccountServiceError::QuotaExceeded { .. } => AppError::Forbidden {
+            code: "QUOTA_EXCEEDED",
+            message: err.to_string(),
+        },
     }
 }
 
+fn not_found(id: Uuid) -> AppError {
+    AppError::NotFound(format!("Account not found: {}", id))
+}
+
+/// If-Match ヘッダーの値（ASCII でない値はどの ETag とも一致しない）
+fn if_match(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::IF_MATCH)
+        .map(|value| value.to_str().unwrap_or_default())
+}
+
 /// POST /api/accounts - 勘定科目作成
 pub async fn create_account(
-    State(repo): State<DynAccountRepository>,
-    Json(request): Json<CreateAccountRequest>,
-) -> impl IntoResponse {
-    // バリデーション
-    if let Err(errors) = request.validate() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new(
-                format!("Validation failed: {}", errors),
-                "VALIDATION_ERROR",
-            )),
-        )
-            .into_response();
-    }
+    OrganizationAccounts(accounts): OrganizationAccounts,
+    ValidatedJson(request, _): ValidatedJson<CreateAccountRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let account = accounts.create(request).await.map_err(map_service_error)?;
+    Ok((StatusCode::CREATED, Json(AccountResponse::from(account))))
+}
+
+/// 勘定科目一覧の HTTP キャッシュ方針
+#[derive(Debug, Clone)]
+pub struct AccountListCaching {
+    pub cache_control: HeaderValue,
+}
 
-    match repo.create(request).await {
-        Ok(account) => (StatusCode::CREATED, Json(AccountResponse::from(account))).into_response(),
-        Err(err) => map_repo_error(err).into_response(),
+impl Default for AccountListCaching {
+    /// キャッシュは保持させるが、利用のたびに If-Modified-Since で再検証させる
+    fn default() -> Self {
+        Self {
+            cache_control: HeaderValue::from_static("private, no-cache"),
+        }
     }
 }
 
+/// HTTP 日付（IMF-fixdate）
+fn http_date(time: DateTime<Utc>) -> String {
+    time.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// If-Modified-Since 以降に更新されていないか（HTTP 日付は秒単位で比較する）
+fn not_modified_since(headers: &HeaderMap, last_modified: DateTime<Utc>) -> bool {
+    headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+        .is_some_and(|since| last_modified.timestamp() <= since.timestamp())
+}
+
 /// GET /api/accounts - 勘定科目一覧取得
+///
+/// Last-Modified は無効化済みを含む勘定科目の最終更新日時。
+/// `Accept: text/csv` / `application/x-ndjson` でその形式で返す。
+/// `?fields=id,code,name` で返す項目を絞れる。
 pub async fn list_accounts(
-    State(repo): State<DynAccountRepository>,
-    Query(query): Query<ListAccountsQuery>,
-) -> impl IntoResponse {
-    let result = if let Some(account_type) = query.account_type {
-        repo.find_by_type(account_type).await
-    } else {
-        repo.find_all().await
-    };
+    OrganizationAccounts(accounts): OrganizationAccounts,
+    State(caching): State<AccountListCaching>,
+    headers: HeaderMap,
+    negotiate: Negotiate,
+    fields: Fields,
+    Query(query): Query<AccountListQuery>,
+) -> Result<Response, AppError> {
+    let last_modified = accounts.last_modified().await.map_err(map_service_error)?;
 
-    match result {
-        Ok(accounts) => {
-            let responses: Vec<AccountResponse> =
-                accounts.into_iter().map(AccountResponse::from).collect();
-            (StatusCode::OK, Json(responses)).into_response()
+    let mut response_headers = HeaderMap::new();
+    response_headers.insert(header::CACHE_CONTROL, caching.cache_control);
+    if let Some(last_modified) = last_modified {
+        if let Ok(value) = HeaderValue::from_str(&http_date(last_modified)) {
+            response_headers.insert(header::LAST_MODIFIED, value);
+        }
+        if not_modified_since(&headers, last_modified) {
+            return Ok((StatusCode::NOT_MODIFIED, response_headers).into_response());
         }
-        Err(err) => map_repo_error(err).into_response(),
     }
+
+    let accounts = accounts.list(query).await.map_err(map_service_error)?;
+
+    let responses: Vec<AccountResponse> = accounts.into_iter().map(AccountResponse::from).collect();
+    Ok((
+        StatusCode::OK,
+        response_headers,
+        negotiate.respond(&fields.project(&responses)?)?,
+    )
+        .into_response())
+}
+
+/// GET /api/accounts/tree - 勘定科目ツリー取得
+pub async fn get_account_tree(
+    OrganizationAccounts(accounts): OrganizationAccounts,
+) -> Result<impl IntoResponse, AppError> {
+    let nodes = accounts.tree().await.map_err(map_service_error)?;
+
+    let responses: Vec<AccountTreeResponse> =
+        nodes.into_iter().map(AccountTreeResponse::from).collect();
+    Ok((StatusCode::OK, Json(responses)))
 }
 
 /// GET /api/accounts/:id - 勘定科目詳細取得
+///
+/// `as_of` を指定すると、その日の終了時点の状態を返す（更新用の ETag は付けない）。
 pub async fn get_account(
-    State(repo): State<DynAccountRepository>,
+    OrganizationAccounts(accounts): OrganizationAccounts,
     Path(id): Path<Uuid>,
-) -> impl IntoResponse {
-    match repo.find_by_id(id).await {
-        Ok(Some(account)) => (StatusCode::OK, Json(AccountResponse::from(account))).into_response(),
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new(
-                format!("Account not found: {}", id),
-                "NOT_FOUND",
-            )),
-        )
-            .into_response(),
-        Err(err) => map_repo_error(err).into_response(),
+    Query(query): Query<AccountQuery>,
+) -> Result<Response, AppError> {
+    if let Some(as_of) = query.as_of_time() {
+        let account = accounts
+            .get_as_of(id, as_of)
+            .await
+            .map_err(map_service_error)?;
+        return Ok((StatusCode::OK, Json(AccountResponse::from(account))).into_response());
     }
+
+    let account = accounts.get(id).await.map_err(map_service_error)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, account.etag())],
+        Json(AccountResponse::from(account)),
+    )
+        .into_response())
+}
+
+/// GET /api/accounts/next-code - カテゴリの科目種別の範囲で空いている科目コードを提案
+pub async fn next_account_code(
+    OrganizationAccounts(accounts): OrganizationAccounts,
+    Query(query): Query<NextAccountCodeQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = accounts
+        .next_code(&query.category)
+        .await
+        .map_err(map_service_error)?;
+    Ok((
+        StatusCode::OK,
+        Json(NextAccountCodeResponse {
+            category: query.category,
+            code,
+        }),
+    ))
 }
 
 /// PUT /api/accounts/:id - 勘定科目更新
 pub async fn update_account(
-    State(repo): State<DynAccountRepository>,
+    OrganizationAccounts(accounts): OrganizationAccounts,
     Path(id): Path<Uuid>,
-    Json(request): Json<UpdateAccountRequest>,
-) -> impl IntoResponse {
-    // バリデーション
-    if let Err(errors) = request.validate() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new(
-                format!("Validation failed: {}", errors),
-                "VALIDATION_ERROR",
-            )),
-        )
-            .into_response();
-    }
+    headers: HeaderMap,
+    ValidatedJson(request, _): ValidatedJson<UpdateAccountRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let account = accounts
+        .update(id, request, if_match(&headers))
+        .await
+        .map_err(map_service_error)?;
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, account.etag())],
+        Json(AccountResponse::from(account)),
+    ))
+}
 
-    match repo.update(id, request).await {
-        Ok(account) => (StatusCode::OK, Json(AccountResponse::from(account))).into_response(),
-        Err(err) => map_repo_error(err).into_response(),
-    }
+/// PATCH /api/accounts/reorder - 表示順の一括変更（1 トランザクション）
+pub async fn reorder_accounts(
+    OrganizationAccounts(accounts): OrganizationAccounts,
+    ValidatedJson(request, _): ValidatedJson<ReorderAccountsRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let accounts = accounts
+        .reorder(&request.account_ids)
+        .await
+        .map_err(map_service_error)?;
+
+    let responses: Vec<AccountResponse> = accounts.into_iter().map(AccountResponse::from).collect();
+    Ok((StatusCode::OK, Json(responses)))
 }
 
-/// DELETE /api/accounts/:id - 勘定科目論理削除
+/// DELETE /api/accounts/:id - 勘定科目論理削除（有効な子勘定科目があれば 409）
 pub async fn delete_account(
-    State(repo): State<DynAccountRepository>,
+    OrganizationAccounts(accounts): OrganizationAccounts,
     Path(id): Path<Uuid>,
-) -> impl IntoResponse {
-    match repo.soft_delete(id).await {
-        Ok(()) => StatusCode::NO_CONTENT.into_response(),
-        Err(err) => map_repo_error(err).into_response(),
-    }
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    accounts
+        .deactivate(id, if_match(&headers))
+        .await
+        .map_err(map_service_error)?;
+    Ok(StatusCode::NO_CONTENT)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::AccountCategory;
-    use crate::repository::InMemoryAccountRepository;
+    use crate::domain::{AccountCategory, AccountType};
+    use crate::repository::{InMemoryAccountRepository, MockAccountRepository};
+    use crate::state::AppState;
+    use crate::tenant::trust_org_header;
+    use crate::tenant::ORG_ID_HEADER;
     use axum::{
         body::Body,
         http::{Request, StatusCode},
-        routing::{delete, get, post, put},
+        middleware,
+        routing::{delete, get, patch, post, put},
         Router,
     };
+    use common::i18n::locale_middleware;
     use http_body_util::BodyExt;
     use tower::ServiceExt;
 
     fn create_test_app() -> Router {
-        let repo: DynAccountRepository = Arc::new(InMemoryAccountRepository::new());
+        create_test_app_with(Arc::new(InMemoryAccountRepository::new()))
+    }
 
-        Router::new()
-            .route("/api/accounts", post(create_account).get(list_accounts))
-            .route(
-                "/api/accounts/:id",
-                get(get_account).put(update_account).delete(delete_account),
-            )
-            .with_state(repo)
+    fn create_test_app_with(repo: DynAccountRepository) -> Router {
+        trust_org_header(
+            Router::new()
+                .route("/api/accounts", post(create_account).get(list_accounts))
+                .route("/api/accounts/tree", get(get_account_tree))
+                .route("/api/accounts/reorder", patch(reorder_accounts))
+                .route("/api/accounts/next-code", get(next_account_code))
+                .route(
+                    "/api/accounts/:id",
+                    get(get_account).put(update_account).delete(delete_account),
+                )
+                .with_state(AppState::builder(repo).build()),
+        )
     }
 
     #[tokio::test]
@@ -225,6 +315,35 @@ mod tests {
         assert_eq!(account.code, "101");
         assert_eq!(account.name, "現金");
         assert_eq!(account.account_type, AccountType::Asset);
+        assert_eq!(account.account_type_label, "資産");
+    }
+
+    #[tokio::test]
+    async fn test_account_labels_follow_accept_language() {
+        let app = create_test_app().layer(middleware::from_fn(locale_middleware));
+
+        let request_body = serde_json::json!({
+            "code": "401",
+            "name": "什一献金",
+            "category": "tithe_offering"
+        });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/accounts")
+                    .header("Content-Type", "application/json")
+                    .header("Accept-Language", "en-US,en;q=0.9,ja;q=0.8")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let account: AccountResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(account.account_type_label, "Revenue");
+        assert_eq!(account.category_label, "Tithes");
     }
 
     #[tokio::test]
@@ -250,6 +369,52 @@ mod tests {
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error["code"], "VALIDATION_ERROR");
+        let fields: Vec<&str> = error["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["field"].as_str().unwrap())
+            .collect();
+        assert!(fields.contains(&"code"));
+        assert!(fields.contains(&"name"));
+        assert!(error["errors"][0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("文字"));
+    }
+
+    #[tokio::test]
+    async fn test_create_account_rejects_unknown_fields() {
+        let app = create_test_app();
+
+        let request_body = serde_json::json!({
+            "code": "101",
+            "name": "現金",
+            "category": "cash",
+            "is_admin": true
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/accounts")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error["code"], "UNKNOWN_FIELD");
     }
 
     #[tokio::test]
@@ -264,13 +429,16 @@ mod tests {
                 category: AccountCategory::Cash,
                 description: None,
                 display_order: Some(1),
+                parent_id: None,
             })
             .await
             .unwrap();
 
-        let app = Router::new()
-            .route("/api/accounts", get(list_accounts))
-            .with_state(repo as DynAccountRepository);
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts", get(list_accounts))
+                .with_state(AppState::builder(repo).build()),
+        );
 
         let response = app
             .oneshot(
@@ -291,6 +459,112 @@ mod tests {
         assert_eq!(accounts.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_list_accounts_as_csv() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+        let _ = repo
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: Some(1),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts", get(list_accounts))
+                .with_state(AppState::builder(repo).build()),
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/accounts")
+                    .header("Accept", "text/csv")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers()["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("text/csv"));
+        assert!(response.headers().contains_key("last-modified"));
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let csv = String::from_utf8(body.to_vec()).unwrap();
+        let mut lines = csv.strip_prefix('\u{feff}').unwrap().lines();
+        assert!(lines.next().unwrap().starts_with("id,code,name,"));
+        assert!(lines.next().unwrap().contains(",101,現金,"));
+        assert_eq!(lines.next(), None);
+    }
+
+    #[tokio::test]
+    async fn test_list_accounts_with_selected_fields() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+        let _ = repo
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: Some(1),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts", get(list_accounts))
+                .with_state(AppState::builder(repo).build()),
+        );
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/accounts?fields=name,code&sort=code")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let accounts: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            accounts,
+            serde_json::json!([{ "code": "101", "name": "現金" }])
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/accounts?fields=code,name")
+                    .header("Accept", "text/csv")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(
+            std::str::from_utf8(&body).unwrap(),
+            "\u{feff}code,name\r\n101,現金\r\n"
+        );
+    }
+
     #[tokio::test]
     async fn test_list_accounts_by_type() {
         let repo = Arc::new(InMemoryAccountRepository::new());
@@ -303,6 +577,7 @@ mod tests {
                 category: AccountCategory::Cash,
                 description: None,
                 display_order: Some(1),
+                parent_id: None,
             })
             .await
             .unwrap();
@@ -313,13 +588,16 @@ mod tests {
                 category: AccountCategory::TitheOffering,
                 description: None,
                 display_order: Some(10),
+                parent_id: None,
             })
             .await
             .unwrap();
 
-        let app = Router::new()
-            .route("/api/accounts", get(list_accounts))
-            .with_state(repo as DynAccountRepository);
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts", get(list_accounts))
+                .with_state(AppState::builder(repo).build()),
+        );
 
         let response = app
             .oneshot(
@@ -341,6 +619,108 @@ mod tests {
         assert_eq!(accounts[0].code, "101");
     }
 
+    #[tokio::test]
+    async fn test_list_accounts_include_inactive() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+        let created = repo
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: Some(1),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+        repo.soft_delete(created.id).await.unwrap();
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts", get(list_accounts))
+                .with_state(AppState::builder(repo).build()),
+        );
+
+        for (uri, expected) in [
+            ("/api/accounts", 0),
+            ("/api/accounts?account_type=asset", 0),
+            ("/api/accounts?include_inactive=true", 1),
+            ("/api/accounts?include_inactive=true&account_type=asset", 1),
+            (
+                "/api/accounts?include_inactive=true&account_type=revenue",
+                0,
+            ),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let accounts: Vec<AccountResponse> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(accounts.len(), expected, "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_accounts_sorted() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+        for (code, name, category) in [
+            ("101", "現金", AccountCategory::Cash),
+            ("401", "什一献金", AccountCategory::TitheOffering),
+            ("102", "普通預金", AccountCategory::BankDeposit),
+        ] {
+            repo.create(CreateAccountRequest {
+                code: code.to_string(),
+                name: name.to_string(),
+                category,
+                description: None,
+                display_order: Some(1),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+        }
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts", get(list_accounts))
+                .with_state(AppState::builder(repo).build()),
+        );
+
+        for (uri, expected) in [
+            ("/api/accounts", ["101", "102", "401"]),
+            ("/api/accounts?sort=code&order=desc", ["401", "102", "101"]),
+            (
+                "/api/accounts?sort=display_order&order=desc",
+                ["101", "102", "401"],
+            ),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let accounts: Vec<AccountResponse> = serde_json::from_slice(&body).unwrap();
+            let codes: Vec<&str> = accounts.iter().map(|a| a.code.as_str()).collect();
+            assert_eq!(codes, expected, "{}", uri);
+        }
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/accounts?sort=balance")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn test_get_account_not_found() {
         let app = create_test_app();
@@ -371,13 +751,16 @@ mod tests {
                 category: AccountCategory::Cash,
                 description: None,
                 display_order: Some(1),
+                parent_id: None,
             })
             .await
             .unwrap();
 
-        let app = Router::new()
-            .route("/api/accounts/:id", put(update_account))
-            .with_state(repo as DynAccountRepository);
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts/:id", put(update_account))
+                .with_state(AppState::builder(repo).build()),
+        );
 
         let update_body = serde_json::json!({
             "name": "小口現金",
@@ -404,6 +787,55 @@ mod tests {
         assert_eq!(updated.name, "小口現金");
     }
 
+    #[tokio::test]
+    async fn test_update_account_null_clears_field() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+        let created = repo
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: Some("手許現金".to_string()),
+                display_order: Some(1),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts/:id", put(update_account))
+                .with_state(AppState::builder(repo).build()),
+        );
+        let update = |body: serde_json::Value| {
+            Request::builder()
+                .method("PUT")
+                .uri(format!("/api/accounts/{}", created.id))
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+
+        // 省略したフィールドは変更しない
+        let response = app
+            .clone()
+            .oneshot(update(serde_json::json!({ "name": "小口現金" })))
+            .await
+            .unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let updated: AccountResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(updated.description.as_deref(), Some("手許現金"));
+
+        let response = app
+            .oneshot(update(serde_json::json!({ "description": null })))
+            .await
+            .unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let updated: AccountResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(updated.name, "小口現金");
+        assert_eq!(updated.description, None);
+    }
+
     #[tokio::test]
     async fn test_delete_account() {
         let repo = Arc::new(InMemoryAccountRepository::new());
@@ -415,13 +847,16 @@ mod tests {
                 category: AccountCategory::Cash,
                 description: None,
                 display_order: Some(1),
+                parent_id: None,
             })
             .await
             .unwrap();
 
-        let app = Router::new()
-            .route("/api/accounts/:id", delete(delete_account))
-            .with_state(repo.clone() as DynAccountRepository);
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts/:id", delete(delete_account))
+                .with_state(AppState::builder(repo.clone()).build()),
+        );
 
         let response = app
             .oneshot(
@@ -440,4 +875,602 @@ mod tests {
         let account = repo.find_by_id(created.id).await.unwrap().unwrap();
         assert!(!account.is_active);
     }
+
+    #[tokio::test]
+    async fn test_reorder_accounts() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+        let mut ids = Vec::new();
+        for (code, category) in [
+            ("101", AccountCategory::Cash),
+            ("102", AccountCategory::BankDeposit),
+            ("401", AccountCategory::TitheOffering),
+        ] {
+            let created = repo
+                .create(CreateAccountRequest {
+                    code: code.to_string(),
+                    name: code.to_string(),
+                    category,
+                    description: None,
+                    display_order: Some(1),
+                    parent_id: None,
+                })
+                .await
+                .unwrap();
+            ids.push(created.id);
+        }
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts/reorder", patch(reorder_accounts))
+                .with_state(AppState::builder(repo.clone()).build()),
+        );
+        let reorder = |account_ids: Vec<Uuid>| {
+            Request::builder()
+                .method("PATCH")
+                .uri("/api/accounts/reorder")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "account_ids": account_ids }).to_string(),
+                ))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(reorder(vec![ids[2], ids[0], ids[1]]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let accounts: Vec<AccountResponse> = serde_json::from_slice(&body).unwrap();
+        let orders: Vec<(&str, i32)> = accounts
+            .iter()
+            .map(|a| (a.code.as_str(), a.display_order))
+            .collect();
+        assert_eq!(orders, [("401", 1), ("101", 2), ("102", 3)]);
+
+        let response = app
+            .clone()
+            .oneshot(reorder(vec![ids[0], ids[0]]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        // 存在しない ID を含む場合は何も変更しない
+        let response = app
+            .oneshot(reorder(vec![ids[0], ids[1], Uuid::new_v4()]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let account = repo.find_by_id(ids[0]).await.unwrap().unwrap();
+        assert_eq!(account.display_order, 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_account_returns_etag() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+
+        let created = repo
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: Some(1),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts/:id", get(get_account))
+                .with_state(AppState::builder(repo).build()),
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/accounts/{}", created.id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::ETAG).unwrap(),
+            created.etag().as_str()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_account_as_of() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+        let created = repo
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: Some(1),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts/:id", get(get_account))
+                .with_state(AppState::builder(repo).build()),
+        );
+        let get_as_of = |as_of: String| {
+            Request::builder()
+                .method("GET")
+                .uri(format!("/api/accounts/{}?as_of={}", created.id, as_of))
+                .body(Body::empty())
+                .unwrap()
+        };
+        let today = Utc::now().date_naive();
+
+        let response = app
+            .clone()
+            .oneshot(get_as_of(today.succ_opt().unwrap().to_string()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(header::ETAG).is_none());
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let account: AccountResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(account.id, created.id);
+
+        // 作成前の時点では存在しない
+        let response = app
+            .clone()
+            .oneshot(get_as_of(today.pred_opt().unwrap().to_string()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = app
+            .oneshot(get_as_of("2024-13-01".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_code_ranges_and_next_code() {
+        let repo = Arc::new(
+            InMemoryAccountRepository::new()
+                .with_code_ranges("asset=100-102,expense=500-599".parse().unwrap()),
+        );
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts", post(create_account))
+                .route("/api/accounts/next-code", get(next_account_code))
+                .with_state(AppState::builder(repo).build()),
+        );
+        let create = |code: &str, category: &str| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/accounts")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "code": code, "name": "科目", "category": category })
+                        .to_string(),
+                ))
+                .unwrap()
+        };
+        let next_code = |category: &str| {
+            Request::builder()
+                .method("GET")
+                .uri(format!("/api/accounts/next-code?category={}", category))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        // 資産は 100〜102 のみ
+        let response = app.clone().oneshot(create("501", "cash")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        for code in ["100", "102"] {
+            let response = app.clone().oneshot(create(code, "cash")).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let response = app
+            .clone()
+            .oneshot(next_code("bank_deposit"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let suggestion: NextAccountCodeResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(suggestion.code, "101");
+        assert_eq!(suggestion.category, AccountCategory::BankDeposit);
+
+        // 範囲を指定していない科目種別は提案しない
+        let response = app
+            .clone()
+            .oneshot(next_code("tithe_offering"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let response = app.oneshot(next_code("unknown_category")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_update_account_if_match() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+
+        let created = repo
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: Some(1),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts/:id", put(update_account))
+                .with_state(AppState::builder(repo).build()),
+        );
+
+        let update_body = serde_json::json!({ "name": "小口現金" });
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/api/accounts/{}", created.id))
+                    .header("Content-Type", "application/json")
+                    .header("If-Match", created.etag())
+                    .body(Body::from(serde_json::to_string(&update_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let new_etag = response.headers().get(header::ETAG).unwrap().clone();
+        assert_ne!(new_etag, created.etag().as_str());
+
+        // 古い ETag での更新は 412
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/api/accounts/{}", created.id))
+                    .header("Content-Type", "application/json")
+                    .header("If-Match", created.etag())
+                    .body(Body::from(serde_json::to_string(&update_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_if_match_mismatch() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+
+        let created = repo
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: Some(1),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts/:id", delete(delete_account))
+                .with_state(AppState::builder(repo.clone()).build()),
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/api/accounts/{}", created.id))
+                    .header("If-Match", "\"stale\"")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+        let account = repo.find_by_id(created.id).await.unwrap().unwrap();
+        assert!(account.is_active);
+    }
+
+    #[tokio::test]
+    async fn test_get_account_tree() {
+        let repo = Arc::new(InMemoryAccountRepository::new());
+
+        let parent = repo
+            .create(CreateAccountRequest {
+                code: "100".to_string(),
+                name: "現金預金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: Some(1),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+        let _ = repo
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "小口現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: Some(2),
+                parent_id: Some(parent.id),
+            })
+            .await
+            .unwrap();
+
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts/tree", get(get_account_tree))
+                .route("/api/accounts/:id", get(get_account))
+                .with_state(AppState::builder(repo).build()),
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/accounts/tree")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let tree: Vec<AccountTreeResponse> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].account.code, "100");
+        assert_eq!(tree[0].children.len(), 1);
+        assert_eq!(tree[0].children[0].account.code, "101");
+    }
+
+    #[tokio::test]
+    async fn test_accounts_are_scoped_by_organization_header() {
+        let app = create_test_app();
+        let org_id = Uuid::new_v4();
+
+        let request_body = serde_json::json!({
+            "code": "101",
+            "name": "現金",
+            "category": "cash"
+        });
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/accounts")
+                    .header("Content-Type", "application/json")
+                    .header(ORG_ID_HEADER, org_id.to_string())
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let account: AccountResponse = serde_json::from_slice(&body).unwrap();
+
+        // ヘッダーなし（既定の組織）からは見えない
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/accounts/{}", account.id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/accounts/{}", account.id))
+                    .header(ORG_ID_HEADER, org_id.to_string())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_list_accounts_last_modified() {
+        let repo: DynAccountRepository = Arc::new(InMemoryAccountRepository::new());
+        let account = repo
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+        let app = trust_org_header(
+            Router::new()
+                .route("/api/accounts", get(list_accounts))
+                .with_state(
+                    AppState::builder(repo.clone())
+                        .with_list_caching(AccountListCaching {
+                            cache_control: HeaderValue::from_static("private, max-age=30"),
+                        })
+                        .build(),
+                ),
+        );
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/accounts")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CACHE_CONTROL],
+            "private, max-age=30"
+        );
+        let last_modified = response.headers()[header::LAST_MODIFIED].clone();
+        assert_eq!(last_modified, http_date(account.updated_at).as_str());
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/accounts")
+                    .header(header::IF_MODIFIED_SINCE, last_modified.clone())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+        // 無効化も更新として扱う
+        tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+        repo.soft_delete(account.id).await.unwrap();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/accounts")
+                    .header(header::IF_MODIFIED_SINCE, last_modified)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    async fn send_json(
+        app: Router,
+        method: &str,
+        uri: &str,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, serde_json::Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Content-Type", "application/json")
+            .body(body.map_or(Body::empty(), |b| Body::from(b.to_string())))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    #[tokio::test]
+    async fn test_repository_conflicts_map_to_409() {
+        let repo = MockAccountRepository::new();
+        let app = create_test_app_with(Arc::new(repo.clone()));
+        let body = serde_json::json!({ "code": "101", "name": "現金", "category": "cash" });
+
+        repo.fail_on("create", RepositoryError::DuplicateCode("101".to_string()));
+        let (status, error) = send_json(app.clone(), "POST", "/api/accounts", Some(body)).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(error["code"], "DUPLICATE_CODE");
+
+        repo.fail_on(
+            "reorder",
+            RepositoryError::Conflict("concurrent reorder".to_string()),
+        );
+        let body = serde_json::json!({ "account_ids": [Uuid::new_v4()] });
+        let (status, error) = send_json(app, "PATCH", "/api/accounts/reorder", Some(body)).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(error["code"], "CONFLICT");
+
+        assert_eq!(repo.calls(), vec!["create", "reorder"]);
+    }
+
+    #[tokio::test]
+    async fn test_database_errors_map_to_500_without_detail() {
+        let repo = MockAccountRepository::new();
+        let app = create_test_app_with(Arc::new(repo.clone()));
+        repo.fail_on(
+            "find_by_id",
+            RepositoryError::DatabaseError("connection refused".to_string()),
+        );
+        repo.fail_on(
+            "last_modified",
+            RepositoryError::DatabaseError("connection refused".to_string()),
+        );
+
+        let uri = format!("/api/accounts/{}", Uuid::new_v4());
+        let (status, error) = send_json(app.clone(), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(error["code"], "DATABASE_ERROR");
+        assert!(!error.to_string().contains("connection refused"));
+
+        // 一覧は最終更新日時の取得で失敗し、一覧の取得まで進まない
+        let (status, _) = send_json(app, "GET", "/api/accounts", None).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(repo.calls(), vec!["find_by_id", "last_modified"]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_with_active_children_is_conflict() {
+        let app = create_test_app();
+        let body = serde_json::json!({ "code": "100", "name": "現金預金", "category": "cash" });
+        let (_, parent) = send_json(app.clone(), "POST", "/api/accounts", Some(body)).await;
+        let body = serde_json::json!({
+            "code": "101",
+            "name": "現金",
+            "category": "cash",
+            "parent_id": parent["id"]
+        });
+        let (status, _) = send_json(app.clone(), "POST", "/api/accounts", Some(body)).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let uri = format!("/api/accounts/{}", parent["id"].as_str().unwrap());
+        let (status, error) = send_json(app.clone(), "DELETE", &uri, None).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(error["code"], "ACCOUNT_IN_USE");
+
+        let (status, account) = send_json(app, "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(account["is_active"], true);
+    }
 }
@@ -0,0 +1,199 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use common::error::AppError;
+use common::json::{StrictJsonLimits, ValidatedJson};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{CashCountFilter, CashCountResponse, CreateCashCountRequest};
+use crate::handlers::map_repo_error;
+use crate::repository::CashCountRepository;
+use crate::tenant::OrganizationId;
+
+pub type DynCashCountRepository = Arc<dyn CashCountRepository>;
+
+fn cash_count_not_found(target: impl std::fmt::Display) -> AppError {
+    AppError::NotFound(format!("Cash count not found: {}", target))
+}
+
+/// POST /api/cash-counts - 金種表登録
+///
+/// `book_balance`（帳簿上の現金残高）を指定すると、実査額との過不足を `difference` に返す。
+pub async fn create_cash_count(
+    State(repo): State<DynCashCountRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    ValidatedJson(request, _): ValidatedJson<CreateCashCountRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let count = repo
+        .for_organization(organization_id)
+        .create(request)
+        .await
+        .map_err(map_repo_error)?;
+    Ok((StatusCode::CREATED, Json(CashCountResponse::from(count))))
+}
+
+/// GET /api/cash-counts - 金種表一覧取得（金庫・期間で絞り込み）
+pub async fn list_cash_counts(
+    State(repo): State<DynCashCountRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Query(filter): Query<CashCountFilter>,
+) -> Result<impl IntoResponse, AppError> {
+    let counts = repo
+        .for_organization(organization_id)
+        .find_all(filter)
+        .await
+        .map_err(map_repo_error)?;
+
+    let responses: Vec<CashCountResponse> =
+        counts.into_iter().map(CashCountResponse::from).collect();
+    Ok((StatusCode::OK, Json(responses)))
+}
+
+/// GET /api/cash-counts/:id - 金種表詳細取得
+pub async fn get_cash_count(
+    State(repo): State<DynCashCountRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let count = repo
+        .for_organization(organization_id)
+        .find_by_id(id)
+        .await
+        .map_err(map_repo_error)?
+        .ok_or_else(|| cash_count_not_found(id))?;
+
+    Ok((StatusCode::OK, Json(CashCountResponse::from(count))))
+}
+
+/// GET /api/cash-counts/safes/:safe/latest - 金庫の最新の金種構成
+pub async fn get_latest_cash_count(
+    State(repo): State<DynCashCountRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(safe): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let count = repo
+        .for_organization(organization_id)
+        .find_latest(&safe)
+        .await
+        .map_err(map_repo_error)?
+        .ok_or_else(|| cash_count_not_found(format!("safe {}", safe)))?;
+
+    Ok((StatusCode::OK, Json(CashCountResponse::from(count))))
+}
+
+/// 金種表 API のルーター
+pub fn cash_count_router(repo: DynCashCountRepository) -> Router {
+    Router::new()
+        .route(
+            "/api/cash-counts",
+            get(list_cash_counts).post(create_cash_count),
+        )
+        .route("/api/cash-counts/:id", get(get_cash_count))
+        .route(
+            "/api/cash-counts/safes/:safe/latest",
+            get(get_latest_cash_count),
+        )
+        .with_state(repo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::InMemoryCashCountRepository;
+    use crate::tenant::trust_org_header;
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn create_test_app() -> Router {
+        trust_org_header(cash_count_router(Arc::new(
+            InMemoryCashCountRepository::new(),
+        )))
+    }
+
+    async fn send(app: &Router, method: &str, uri: &str, body: Body) -> axum::response::Response {
+        app.clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("Content-Type", "application/json")
+                    .body(body)
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_and_get_latest_cash_count() {
+        let app = create_test_app();
+        let body = serde_json::json!({
+            "safe": "MAIN",
+            "counted_at": "2026-03-01T09:00:00Z",
+            "lines": [
+                {"denomination": 1000, "quantity": 12},
+                {"denomination": 10000, "quantity": 3},
+                {"denomination": 500, "quantity": 4}
+            ],
+            "book_balance": 44500,
+            "counted_by": "会計担当"
+        });
+
+        let response = send(
+            &app,
+            "POST",
+            "/api/cash-counts",
+            Body::from(body.to_string()),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = send(
+            &app,
+            "GET",
+            "/api/cash-counts/safes/MAIN/latest",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let count: CashCountResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(count.total.amount, 44000);
+        assert_eq!(count.lines[0].denomination, 10000);
+        assert_eq!(count.difference.unwrap().amount, -500);
+
+        let response = send(
+            &app,
+            "GET",
+            "/api/cash-counts/safes/PETTY/latest",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_create_cash_count_invalid_denomination() {
+        let app = create_test_app();
+        let body = serde_json::json!({
+            "safe": "MAIN",
+            "lines": [{"denomination": 3000, "quantity": 1}]
+        });
+
+        let response = send(
+            &app,
+            "POST",
+            "/api/cash-counts",
+            Body::from(body.to_string()),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,220 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get},
+    Json, Router,
+};
+use common::error::AppError;
+use common::json::{StrictJsonLimits, ValidatedJson};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{CategoryResponse, CreateCategoryRequest};
+use crate::handlers::map_repo_error;
+use crate::repository::{CategoryRepository, RepositoryError};
+use crate::tenant::OrganizationId;
+
+pub type DynCategoryRepository = Arc<dyn CategoryRepository>;
+
+fn map_category_error(err: RepositoryError) -> AppError {
+    match err {
+        RepositoryError::NotFound(id) => {
+            AppError::NotFound(format!("Account category not found: {}", id))
+        }
+        RepositoryError::Conflict(message) => AppError::Conflict {
+            code: "DUPLICATE_CATEGORY",
+            message,
+        },
+        other => map_repo_error(other),
+    }
+}
+
+/// POST /api/account-categories - 組織定義のカテゴリ作成
+pub async fn create_category(
+    State(repo): State<DynCategoryRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    ValidatedJson(request, _): ValidatedJson<CreateCategoryRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let definition = repo
+        .for_organization(organization_id)
+        .create(request)
+        .await
+        .map_err(map_category_error)?;
+    Ok((
+        StatusCode::CREATED,
+        Json(CategoryResponse::from(definition)),
+    ))
+}
+
+/// GET /api/account-categories - カテゴリ一覧取得（組み込みを含む）
+pub async fn list_categories(
+    State(repo): State<DynCategoryRepository>,
+    OrganizationId(organization_id): OrganizationId,
+) -> Result<impl IntoResponse, AppError> {
+    let definitions = repo
+        .for_organization(organization_id)
+        .find_all()
+        .await
+        .map_err(map_category_error)?;
+
+    let responses: Vec<CategoryResponse> = definitions
+        .into_iter()
+        .map(CategoryResponse::from)
+        .collect();
+    Ok((StatusCode::OK, Json(responses)))
+}
+
+/// DELETE /api/account-categories/:id - 組織定義のカテゴリ論理削除
+pub async fn delete_category(
+    State(repo): State<DynCategoryRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    repo.for_organization(organization_id)
+        .soft_delete(id)
+        .await
+        .map_err(map_category_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 勘定科目カテゴリ API のルーター
+pub fn category_router(repo: DynCategoryRepository) -> Router {
+    Router::new()
+        .route(
+            "/api/account-categories",
+            get(list_categories).post(create_category),
+        )
+        .route("/api/account-categories/:id", delete(delete_category))
+        .with_state(repo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AccountResponse, AccountType};
+    use crate::handlers::create_account;
+    use crate::repository::{InMemoryAccountRepository, InMemoryCategoryRepository};
+    use crate::state::AppState;
+    use crate::tenant::trust_org_header;
+    use crate::tenant::ORG_ID_HEADER;
+    use axum::{body::Body, http::Request, routing::post};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn create_test_app() -> Router {
+        let categories: DynCategoryRepository = Arc::new(InMemoryCategoryRepository::new());
+        let accounts = AppState::builder(Arc::new(
+            InMemoryAccountRepository::new().with_categories(categories.clone()),
+        ))
+        .build();
+
+        trust_org_header(
+            category_router(categories).merge(
+                Router::new()
+                    .route("/api/accounts", post(create_account))
+                    .with_state(accounts),
+            ),
+        )
+    }
+
+    async fn send(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        organization_id: Uuid,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, serde_json::Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Content-Type", "application/json")
+            .header(ORG_ID_HEADER, organization_id.to_string())
+            .body(body.map_or(Body::empty(), |b| Body::from(b.to_string())))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = if body.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&body).unwrap()
+        };
+        (status, json)
+    }
+
+    #[tokio::test]
+    async fn test_custom_category_lifecycle() {
+        let app = create_test_app();
+        let org = Uuid::new_v4();
+        let other_org = Uuid::new_v4();
+
+        let (status, _) = send(
+            &app,
+            "POST",
+            "/api/account-categories",
+            org,
+            Some(serde_json::json!({
+                "code": "cash",
+                "name": "現金",
+                "account_type": "asset"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (status, created) = send(
+            &app,
+            "POST",
+            "/api/account-categories",
+            org,
+            Some(serde_json::json!({
+                "code": "youth_ministry",
+                "name": "青年会費",
+                "account_type": "expense"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let created: CategoryResponse = serde_json::from_value(created).unwrap();
+        assert!(!created.is_built_in);
+
+        let (_, categories) = send(&app, "GET", "/api/account-categories", org, None).await;
+        let categories: Vec<CategoryResponse> = serde_json::from_value(categories).unwrap();
+        assert_eq!(categories.len(), 25);
+        assert_eq!(categories[0].code, "cash");
+        assert_eq!(categories[24].code, "youth_ministry");
+
+        // 定義した組織の勘定科目でのみ使える
+        let account = serde_json::json!({
+            "code": "510",
+            "name": "青年会",
+            "category": "youth_ministry"
+        });
+        let (status, _) = send(
+            &app,
+            "POST",
+            "/api/accounts",
+            other_org,
+            Some(account.clone()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, body) = send(&app, "POST", "/api/accounts", org, Some(account.clone())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let created_account: AccountResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(created_account.account_type, AccountType::Expense);
+
+        let uri = format!("/api/account-categories/{}", created.id);
+        let (status, _) = send(&app, "DELETE", &uri, other_org, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, "DELETE", &uri, org, None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = send(&app, "POST", "/api/accounts", org, Some(account)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let built_in = format!("/api/account-categories/{}", categories[0].id);
+        let (status, _) = send(&app, "DELETE", &built_in, org, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
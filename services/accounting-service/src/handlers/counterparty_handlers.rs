@@ -0,0 +1,249 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use common::error::AppError;
+use common::json::{StrictJsonLimits, ValidatedJson};
+use common::negotiate::Negotiate;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{CounterpartyResponse, CreateCounterpartyRequest, UpdateCounterpartyRequest};
+use crate::handlers::map_repo_error;
+use crate::repository::{CounterpartyRepository, RepositoryError};
+use crate::tenant::OrganizationId;
+
+pub type DynCounterpartyRepository = Arc<dyn CounterpartyRepository>;
+
+fn map_counterparty_error(err: RepositoryError) -> AppError {
+    match err {
+        RepositoryError::NotFound(id) => counterparty_not_found(id),
+        other => map_repo_error(other),
+    }
+}
+
+fn counterparty_not_found(id: Uuid) -> AppError {
+    AppError::NotFound(format!("Counterparty not found: {}", id))
+}
+
+/// POST /api/counterparties - 取引先登録
+pub async fn create_counterparty(
+    State(repo): State<DynCounterpartyRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    ValidatedJson(request, _): ValidatedJson<CreateCounterpartyRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let counterparty = repo
+        .for_organization(organization_id)
+        .create(request)
+        .await
+        .map_err(map_counterparty_error)?;
+    Ok((
+        StatusCode::CREATED,
+        Json(CounterpartyResponse::from(counterparty)),
+    ))
+}
+
+/// GET /api/counterparties - 有効な取引先一覧取得
+pub async fn list_counterparties(
+    State(repo): State<DynCounterpartyRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    negotiate: Negotiate,
+) -> Result<impl IntoResponse, AppError> {
+    let counterparties = repo
+        .for_organization(organization_id)
+        .find_all()
+        .await
+        .map_err(map_counterparty_error)?;
+
+    let responses: Vec<CounterpartyResponse> = counterparties
+        .into_iter()
+        .map(CounterpartyResponse::from)
+        .collect();
+    negotiate.respond(&responses)
+}
+
+/// GET /api/counterparties/:id - 取引先詳細取得（無効化済みを含む）
+pub async fn get_counterparty(
+    State(repo): State<DynCounterpartyRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let counterparty = repo
+        .for_organization(organization_id)
+        .find_by_id(id)
+        .await
+        .map_err(map_counterparty_error)?
+        .ok_or_else(|| counterparty_not_found(id))?;
+    Ok((
+        StatusCode::OK,
+        Json(CounterpartyResponse::from(counterparty)),
+    ))
+}
+
+/// PUT /api/counterparties/:id - 取引先更新
+pub async fn update_counterparty(
+    State(repo): State<DynCounterpartyRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+    ValidatedJson(request, _): ValidatedJson<UpdateCounterpartyRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let counterparty = repo
+        .for_organization(organization_id)
+        .update(id, request)
+        .await
+        .map_err(map_counterparty_error)?;
+    Ok((
+        StatusCode::OK,
+        Json(CounterpartyResponse::from(counterparty)),
+    ))
+}
+
+/// DELETE /api/counterparties/:id - 取引先論理削除
+pub async fn delete_counterparty(
+    State(repo): State<DynCounterpartyRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    repo.for_organization(organization_id)
+        .soft_delete(id)
+        .await
+        .map_err(map_counterparty_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 取引先 API のルーター
+pub fn counterparty_router(repo: DynCounterpartyRepository) -> Router {
+    Router::new()
+        .route(
+            "/api/counterparties",
+            get(list_counterparties).post(create_counterparty),
+        )
+        .route(
+            "/api/counterparties/:id",
+            get(get_counterparty)
+                .put(update_counterparty)
+                .delete(delete_counterparty),
+        )
+        .with_state(repo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::InMemoryCounterpartyRepository;
+    use crate::tenant::trust_org_header;
+    use crate::tenant::ORG_ID_HEADER;
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn create_test_app() -> Router {
+        trust_org_header(counterparty_router(Arc::new(
+            InMemoryCounterpartyRepository::new(),
+        )))
+    }
+
+    async fn send(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        organization_id: Uuid,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, serde_json::Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Content-Type", "application/json")
+            .header(ORG_ID_HEADER, organization_id.to_string())
+            .body(body.map_or(Body::empty(), |b| Body::from(b.to_string())))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = if body.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&body).unwrap()
+        };
+        (status, json)
+    }
+
+    #[tokio::test]
+    async fn test_counterparty_crud() {
+        let app = create_test_app();
+        let org = Uuid::new_v4();
+
+        let (status, created) = send(
+            &app,
+            "POST",
+            "/api/counterparties",
+            org,
+            Some(serde_json::json!({ "name": "山田電気", "contact": "03-0000-0000" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let uri = format!("/api/counterparties/{}", created["id"].as_str().unwrap());
+
+        // contact は null で消去、省略した note は変更しない
+        let (status, updated) = send(
+            &app,
+            "PUT",
+            &uri,
+            org,
+            Some(serde_json::json!({ "name": "山田電気商会", "contact": null })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(updated["name"], "山田電気商会");
+        assert_eq!(updated["contact"], serde_json::Value::Null);
+
+        let (status, _) = send(&app, "DELETE", &uri, org, None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (_, list) = send(&app, "GET", "/api/counterparties", org, None).await;
+        assert_eq!(list, serde_json::json!([]));
+        let (status, deleted) = send(&app, "GET", &uri, org, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(deleted["is_active"], false);
+    }
+
+    #[tokio::test]
+    async fn test_counterparties_are_scoped_to_organization() {
+        let app = create_test_app();
+        let org = Uuid::new_v4();
+        let (_, created) = send(
+            &app,
+            "POST",
+            "/api/counterparties",
+            org,
+            Some(serde_json::json!({ "name": "山田電気" })),
+        )
+        .await;
+        let uri = format!("/api/counterparties/{}", created["id"].as_str().unwrap());
+
+        let other = Uuid::new_v4();
+        let (status, _) = send(&app, "GET", &uri, other, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, "DELETE", &uri, other, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (_, list) = send(&app, "GET", "/api/counterparties", other, None).await;
+        assert_eq!(list, serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_create_counterparty_validation() {
+        let app = create_test_app();
+
+        let (status, _) = send(
+            &app,
+            "POST",
+            "/api/counterparties",
+            Uuid::new_v4(),
+            Some(serde_json::json!({ "name": "" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
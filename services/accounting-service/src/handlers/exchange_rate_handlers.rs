@@ -0,0 +1,241 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use common::error::AppError;
+use common::json::{StrictJsonLimits, ValidatedJson};
+use common::negotiate::Negotiate;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    CreateExchangeRateRequest, Currency, ExchangeRateResponse, UpdateExchangeRateRequest,
+};
+use crate::handlers::map_repo_error;
+use crate::repository::{ExchangeRateRepository, RepositoryError};
+use crate::tenant::OrganizationId;
+
+pub type DynExchangeRateRepository = Arc<dyn ExchangeRateRepository>;
+
+#[derive(Debug, Deserialize)]
+pub struct ListExchangeRatesQuery {
+    pub base_currency: Option<Currency>,
+    pub quote_currency: Option<Currency>,
+}
+
+fn map_rate_error(err: RepositoryError) -> AppError {
+    match err {
+        RepositoryError::NotFound(id) => rate_not_found(id),
+        other => map_repo_error(other),
+    }
+}
+
+fn rate_not_found(id: Uuid) -> AppError {
+    AppError::NotFound(format!("Exchange rate not found: {}", id))
+}
+
+/// POST /api/exchange-rates - 為替レート登録
+pub async fn create_exchange_rate(
+    State(repo): State<DynExchangeRateRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    ValidatedJson(request, _): ValidatedJson<CreateExchangeRateRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let rate = repo
+        .for_organization(organization_id)
+        .create(request)
+        .await
+        .map_err(map_rate_error)?;
+    Ok((StatusCode::CREATED, Json(ExchangeRateResponse::from(rate))))
+}
+
+/// GET /api/exchange-rates - 為替レート一覧取得
+pub async fn list_exchange_rates(
+    State(repo): State<DynExchangeRateRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    negotiate: Negotiate,
+    Query(query): Query<ListExchangeRatesQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let rates = repo
+        .for_organization(organization_id)
+        .find_all(query.base_currency, query.quote_currency)
+        .await
+        .map_err(map_rate_error)?;
+
+    let responses: Vec<ExchangeRateResponse> =
+        rates.into_iter().map(ExchangeRateResponse::from).collect();
+    negotiate.respond(&responses)
+}
+
+/// GET /api/exchange-rates/:id - 為替レート詳細取得
+pub async fn get_exchange_rate(
+    State(repo): State<DynExchangeRateRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let rate = repo
+        .for_organization(organization_id)
+        .find_by_id(id)
+        .await
+        .map_err(map_rate_error)?
+        .ok_or_else(|| rate_not_found(id))?;
+
+    Ok((StatusCode::OK, Json(ExchangeRateResponse::from(rate))))
+}
+
+/// PUT /api/exchange-rates/:id - 為替レート更新
+pub async fn update_exchange_rate(
+    State(repo): State<DynExchangeRateRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+    ValidatedJson(request, _): ValidatedJson<UpdateExchangeRateRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let rate = repo
+        .for_organization(organization_id)
+        .update(id, request)
+        .await
+        .map_err(map_rate_error)?;
+    Ok((StatusCode::OK, Json(ExchangeRateResponse::from(rate))))
+}
+
+/// DELETE /api/exchange-rates/:id - 為替レート削除
+pub async fn delete_exchange_rate(
+    State(repo): State<DynExchangeRateRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    repo.for_organization(organization_id)
+        .delete(id)
+        .await
+        .map_err(map_rate_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 為替レート API のルーター
+pub fn exchange_rate_router(repo: DynExchangeRateRepository) -> Router {
+    Router::new()
+        .route(
+            "/api/exchange-rates",
+            get(list_exchange_rates).post(create_exchange_rate),
+        )
+        .route(
+            "/api/exchange-rates/:id",
+            get(get_exchange_rate)
+                .put(update_exchange_rate)
+                .delete(delete_exchange_rate),
+        )
+        .with_state(repo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::InMemoryExchangeRateRepository;
+    use crate::tenant::trust_org_header;
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn create_test_app() -> Router {
+        trust_org_header(exchange_rate_router(Arc::new(
+            InMemoryExchangeRateRepository::new(),
+        )))
+    }
+
+    async fn post_rate(app: &Router, body: serde_json::Value) -> axum::response::Response {
+        app.clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/exchange-rates")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_and_list_exchange_rates() {
+        let app = create_test_app();
+
+        let response = post_rate(
+            &app,
+            serde_json::json!({
+                "base_currency": "USD",
+                "quote_currency": "JPY",
+                "rate": "150.25",
+                "effective_date": "2026-01-01"
+            }),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/api/exchange-rates?base_currency=USD")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let rates: Vec<ExchangeRateResponse> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(rates.len(), 1);
+        assert_eq!(rates[0].quote_currency, Currency::JPY);
+    }
+
+    #[tokio::test]
+    async fn test_create_exchange_rate_errors() {
+        let app = create_test_app();
+        let body = serde_json::json!({
+            "base_currency": "USD",
+            "quote_currency": "JPY",
+            "rate": "150",
+            "effective_date": "2026-01-01"
+        });
+
+        assert_eq!(
+            post_rate(&app, body.clone()).await.status(),
+            StatusCode::CREATED
+        );
+        assert_eq!(post_rate(&app, body).await.status(), StatusCode::CONFLICT);
+
+        let invalid = serde_json::json!({
+            "base_currency": "USD",
+            "quote_currency": "USD",
+            "rate": "1",
+            "effective_date": "2026-01-01"
+        });
+        assert_eq!(
+            post_rate(&app, invalid).await.status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_exchange_rate_not_found() {
+        let app = create_test_app();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!("/api/exchange-rates/{}", Uuid::new_v4()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,312 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use common::error::AppError;
+use common::fields::Fields;
+use common::json::{StrictJsonLimits, ValidatedJson};
+use common::negotiate::Negotiate;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{AccountType, CreateFixedAssetRequest, FixedAsset, FixedAssetResponse};
+use crate::handlers::{map_repo_error, DynAccountRepository};
+use crate::repository::{FixedAssetRepository, RepositoryError};
+use crate::tenant::OrganizationId;
+
+pub type DynFixedAssetRepository = Arc<dyn FixedAssetRepository>;
+
+/// 固定資産 API の状態（償却先の費用科目を確認するため勘定科目も持つ）
+#[derive(Clone)]
+pub struct FixedAssetState {
+    pub assets: DynFixedAssetRepository,
+    pub accounts: DynAccountRepository,
+}
+
+fn map_fixed_asset_error(err: RepositoryError) -> AppError {
+    match err {
+        RepositoryError::NotFound(id) => fixed_asset_not_found(id),
+        other => map_repo_error(other),
+    }
+}
+
+fn fixed_asset_not_found(id: Uuid) -> AppError {
+    AppError::NotFound(format!("Fixed asset not found: {}", id))
+}
+
+async fn find_asset(
+    state: &FixedAssetState,
+    organization_id: Uuid,
+    id: Uuid,
+) -> Result<FixedAsset, AppError> {
+    state
+        .assets
+        .for_organization(organization_id)
+        .find_by_id(id)
+        .await
+        .map_err(map_fixed_asset_error)?
+        .ok_or_else(|| fixed_asset_not_found(id))
+}
+
+/// POST /api/assets - 固定資産登録（償却先は自組織の有効な費用科目に限る）
+pub async fn create_fixed_asset(
+    State(state): State<FixedAssetState>,
+    OrganizationId(organization_id): OrganizationId,
+    ValidatedJson(request, _): ValidatedJson<CreateFixedAssetRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let expense_account = state
+        .accounts
+        .for_organization(organization_id)
+        .find_by_id(request.expense_account_id)
+        .await
+        .map_err(map_repo_error)?;
+    if !expense_account.is_some_and(|a| a.is_active && a.account_type == AccountType::Expense) {
+        return Err(AppError::validation(format!(
+            "Expense account not found: {}",
+            request.expense_account_id
+        )));
+    }
+
+    let asset = state
+        .assets
+        .for_organization(organization_id)
+        .create(request)
+        .await
+        .map_err(map_fixed_asset_error)?;
+    Ok((StatusCode::CREATED, Json(FixedAssetResponse::from(asset))))
+}
+
+/// GET /api/assets - 固定資産一覧取得（除却済みを含む）
+pub async fn list_fixed_assets(
+    State(state): State<FixedAssetState>,
+    OrganizationId(organization_id): OrganizationId,
+    negotiate: Negotiate,
+) -> Result<impl IntoResponse, AppError> {
+    let assets = state
+        .assets
+        .for_organization(organization_id)
+        .find_all()
+        .await
+        .map_err(map_fixed_asset_error)?;
+
+    let responses: Vec<FixedAssetResponse> =
+        assets.into_iter().map(FixedAssetResponse::from).collect();
+    negotiate.respond(&responses)
+}
+
+/// GET /api/assets/:id - 固定資産詳細取得
+pub async fn get_fixed_asset(
+    State(state): State<FixedAssetState>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let asset = find_asset(&state, organization_id, id).await?;
+    Ok((StatusCode::OK, Json(FixedAssetResponse::from(asset))))
+}
+
+/// GET /api/assets/:id/depreciation - 定額法の償却予定表
+///
+/// `?fields=` で返す項目を絞れる。
+pub async fn get_depreciation_schedule(
+    State(state): State<FixedAssetState>,
+    OrganizationId(organization_id): OrganizationId,
+    negotiate: Negotiate,
+    fields: Fields,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let asset = find_asset(&state, organization_id, id).await?;
+    let schedule = asset
+        .depreciation_schedule()
+        .map_err(|e| AppError::internal("CALCULATION_ERROR", e.to_string()))?;
+    negotiate.respond(&fields.project(&schedule)?)
+}
+
+/// DELETE /api/assets/:id - 固定資産除却
+pub async fn dispose_fixed_asset(
+    State(state): State<FixedAssetState>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .assets
+        .for_organization(organization_id)
+        .dispose(id)
+        .await
+        .map_err(map_fixed_asset_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 固定資産 API のルーター
+pub fn fixed_asset_router(state: FixedAssetState) -> Router {
+    Router::new()
+        .route(
+            "/api/assets",
+            get(list_fixed_assets).post(create_fixed_asset),
+        )
+        .route(
+            "/api/assets/:id",
+            get(get_fixed_asset).delete(dispose_fixed_asset),
+        )
+        .route(
+            "/api/assets/:id/depreciation",
+            get(get_depreciation_schedule),
+        )
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AccountCategory, CreateAccountRequest, DepreciationEntry};
+    use crate::repository::{
+        AccountRepository, InMemoryAccountRepository, InMemoryFixedAssetRepository,
+    };
+    use crate::tenant::trust_org_header;
+    use crate::tenant::ORG_ID_HEADER;
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn create_test_app() -> (Router, Uuid, Uuid) {
+        let accounts = Arc::new(InMemoryAccountRepository::new());
+        let expense = accounts
+            .create(CreateAccountRequest {
+                code: "801".to_string(),
+                name: "営繕費".to_string(),
+                category: AccountCategory::MaintenanceExpense,
+                description: None,
+                display_order: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+        let cash = accounts
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        let app = trust_org_header(fixed_asset_router(FixedAssetState {
+            assets: Arc::new(InMemoryFixedAssetRepository::new()),
+            accounts,
+        }));
+        (app, expense.id, cash.id)
+    }
+
+    async fn send(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        organization_id: Option<Uuid>,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, serde_json::Value) {
+        let mut request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Content-Type", "application/json");
+        if let Some(organization_id) = organization_id {
+            request = request.header(ORG_ID_HEADER, organization_id.to_string());
+        }
+        let request = request
+            .body(body.map_or(Body::empty(), |b| Body::from(b.to_string())))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+        (status, json)
+    }
+
+    fn asset_body(expense_account_id: Uuid) -> serde_json::Value {
+        serde_json::json!({
+            "name": "音響設備",
+            "acquisition_date": "2026-04-15",
+            "acquisition_cost": 1_200_000,
+            "useful_life_months": 60,
+            "expense_account_id": expense_account_id,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_asset_and_get_schedule() {
+        let (app, expense_id, _) = create_test_app().await;
+
+        let (status, created) = send(
+            &app,
+            "POST",
+            "/api/assets",
+            None,
+            Some(asset_body(expense_id)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["acquisition_cost"]["amount"], 1_200_000);
+        assert_eq!(created["salvage_value"]["amount"], 0);
+
+        let uri = format!(
+            "/api/assets/{}/depreciation",
+            created["id"].as_str().unwrap()
+        );
+        let (status, schedule) = send(&app, "GET", &uri, None, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let schedule: Vec<DepreciationEntry> = serde_json::from_value(schedule).unwrap();
+        assert_eq!(schedule.len(), 60);
+        assert_eq!(schedule[0].period.to_string(), "2026-04-01");
+        assert_eq!(schedule[0].amount.amount, 20_000);
+        assert_eq!(schedule[59].book_value.amount, 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_asset_requires_expense_account() {
+        let (app, expense_id, cash_id) = create_test_app().await;
+
+        let (status, _) = send(&app, "POST", "/api/assets", None, Some(asset_body(cash_id))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        // 他組織の費用科目は使えない
+        let other = Some(Uuid::new_v4());
+        let (status, _) = send(
+            &app,
+            "POST",
+            "/api/assets",
+            other,
+            Some(asset_body(expense_id)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_dispose_asset() {
+        let (app, expense_id, _) = create_test_app().await;
+        let (_, created) = send(
+            &app,
+            "POST",
+            "/api/assets",
+            None,
+            Some(asset_body(expense_id)),
+        )
+        .await;
+        let uri = format!("/api/assets/{}", created["id"].as_str().unwrap());
+
+        let (status, _) = send(&app, "GET", &uri, Some(Uuid::new_v4()), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = send(&app, "DELETE", &uri, None, None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = send(&app, "DELETE", &uri, None, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (_, assets) = send(&app, "GET", "/api/assets", None, None).await;
+        assert_eq!(assets[0]["is_active"], false);
+    }
+}
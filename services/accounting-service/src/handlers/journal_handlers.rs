@@ -0,0 +1,31 @@
+use axum::{http::StatusCode, response::IntoResponse, routing::post, Json, Router};
+use common::error::AppError;
+use common::json::{HardenedJson, StrictJsonLimits};
+
+use crate::domain::JournalEntryDraft;
+use crate::handlers::map_service_error;
+use crate::service::{validate_journal_entry, AccountService};
+use crate::tenant::OrganizationAccounts;
+
+/// POST /api/journal-entries/validate - 仕訳を保存せずに検証し、違反の一覧を返す
+///
+/// 入力画面が保存前に問題をまとめて表示するためのもので、違反があっても 200 で返す。
+pub async fn validate_journal_entry_handler(
+    OrganizationAccounts(accounts): OrganizationAccounts,
+    HardenedJson(draft, _): HardenedJson<JournalEntryDraft, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let validation = validate_journal_entry(&accounts, &draft)
+        .await
+        .map_err(map_service_error)?;
+    Ok((StatusCode::OK, Json(validation)))
+}
+
+/// 仕訳 API のルーター
+pub fn journal_router(accounts: AccountService) -> Router {
+    Router::new()
+        .route(
+            "/api/journal-entries/validate",
+            post(validate_journal_entry_handler),
+        )
+        .with_state(accounts)
+}
@@ -1,3 +1,23 @@
 pub mod account_handlers;
+pub mod cash_count_handlers;
+pub mod category_handlers;
+pub mod counterparty_handlers;
+pub mod exchange_rate_handlers;
+pub mod fixed_asset_handlers;
+pub mod journal_handlers;
+pub mod month_close_handlers;
+pub mod organization_handlers;
+pub mod search_handlers;
+pub mod webhook_handlers;
 
 pub use account_handlers::*;
+pub use cash_count_handlers::*;
+pub use category_handlers::*;
+pub use counterparty_handlers::*;
+pub use exchange_rate_handlers::*;
+pub use fixed_asset_handlers::*;
+pub use journal_handlers::*;
+pub use month_close_handlers::*;
+pub use organization_handlers::*;
+pub use search_handlers::*;
+pub use webhook_handlers::*;
@@ -0,0 +1,170 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use chrono::NaiveDate;
+use common::error::AppError;
+use common::json::{StrictJsonLimits, ValidatedJson};
+use std::sync::Arc;
+
+use crate::domain::{parse_month, MonthClose, MonthCloseResponse, UpdateMonthCloseRequest};
+use crate::handlers::map_repo_error;
+use crate::repository::MonthCloseRepository;
+use crate::tenant::OrganizationId;
+
+pub type DynMonthCloseRepository = Arc<dyn MonthCloseRepository>;
+
+fn month_from_path(month: &str) -> Result<NaiveDate, AppError> {
+    parse_month(month).ok_or_else(|| AppError::BadRequest {
+        code: "INVALID_MONTH",
+        message: format!("Month must be in yyyy-mm format: {}", month),
+    })
+}
+
+/// GET /api/month-close/:yyyy-mm - 月次締めのチェックリスト取得（未着手なら全項目 false）
+pub async fn get_month_close(
+    State(repo): State<DynMonthCloseRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(month): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let month = month_from_path(&month)?;
+    let close = repo
+        .for_organization(organization_id)
+        .find(month)
+        .await
+        .map_err(map_repo_error)?
+        .unwrap_or_else(|| MonthClose::new(organization_id, month));
+    Ok((StatusCode::OK, Json(MonthCloseResponse::from(close))))
+}
+
+/// PUT /api/month-close/:yyyy-mm - チェックリスト更新（すべて完了すると月を締める）
+pub async fn update_month_close(
+    State(repo): State<DynMonthCloseRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(month): Path<String>,
+    ValidatedJson(request, _): ValidatedJson<UpdateMonthCloseRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let month = month_from_path(&month)?;
+    let repo = repo.for_organization(organization_id);
+    let mut close = repo
+        .find(month)
+        .await
+        .map_err(map_repo_error)?
+        .unwrap_or_else(|| MonthClose::new(organization_id, month));
+    if close.is_locked() {
+        return Err(AppError::Conflict {
+            code: "MONTH_LOCKED",
+            message: format!(
+                "Month is already closed: {}",
+                MonthCloseResponse::from(close).month
+            ),
+        });
+    }
+
+    close.apply(request);
+    let close = repo.save(&close).await.map_err(map_repo_error)?;
+    Ok((StatusCode::OK, Json(MonthCloseResponse::from(close))))
+}
+
+/// 月次締め API のルーター
+pub fn month_close_router(repo: DynMonthCloseRepository) -> Router {
+    Router::new()
+        .route(
+            "/api/month-close/:month",
+            get(get_month_close).put(update_month_close),
+        )
+        .with_state(repo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::InMemoryMonthCloseRepository;
+    use crate::tenant::trust_org_header;
+    use crate::tenant::ORG_ID_HEADER;
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    fn create_test_app() -> Router {
+        trust_org_header(month_close_router(Arc::new(
+            InMemoryMonthCloseRepository::new(),
+        )))
+    }
+
+    async fn send(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        organization_id: Uuid,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, serde_json::Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Content-Type", "application/json")
+            .header(ORG_ID_HEADER, organization_id.to_string())
+            .body(body.map_or(Body::empty(), |b| Body::from(b.to_string())))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_month_locks_when_checklist_is_complete() {
+        let app = create_test_app();
+        let org = Uuid::new_v4();
+        let uri = "/api/month-close/2026-04";
+
+        let (status, close) = send(&app, "GET", uri, org, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(close["month"], "2026-04");
+        assert_eq!(close["bank_reconciled"], false);
+        assert_eq!(close["locked"], false);
+
+        let body = serde_json::json!({ "bank_reconciled": true, "offerings_posted": true });
+        let (status, close) = send(&app, "PUT", uri, org, Some(body)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(close["offerings_posted"], true);
+        assert_eq!(close["locked"], false);
+
+        let body = serde_json::json!({ "reports_generated": true });
+        let (status, close) = send(&app, "PUT", uri, org, Some(body)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(close["locked"], true);
+        assert!(close["locked_at"].is_string());
+
+        let body = serde_json::json!({ "bank_reconciled": false });
+        let (status, error) = send(&app, "PUT", uri, org, Some(body)).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(error["code"], "MONTH_LOCKED");
+
+        // 他の組織・他の月には影響しない
+        let (_, close) = send(&app, "GET", uri, Uuid::new_v4(), None).await;
+        assert_eq!(close["locked"], false);
+        let (_, close) = send(&app, "GET", "/api/month-close/2026-05", org, None).await;
+        assert_eq!(close["locked"], false);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_month_is_rejected() {
+        let app = create_test_app();
+
+        let (status, error) = send(
+            &app,
+            "GET",
+            "/api/month-close/2026-13",
+            Uuid::new_v4(),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(error["code"], "INVALID_MONTH");
+    }
+}
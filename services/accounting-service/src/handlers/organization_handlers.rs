@@ -0,0 +1,366 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use common::error::AppError;
+use common::json::{StrictJsonLimits, ValidatedJson};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    default_chart_of_accounts, AccountResponse, CreateOrganizationRequest, OrganizationResponse,
+    ProvisionedOrganizationResponse, UpdateOrganizationRequest,
+};
+use crate::handlers::{map_repo_error, map_service_error};
+use crate::repository::{OrganizationRepository, RepositoryError};
+use crate::service::AccountService;
+use crate::tenant::TenantAuth;
+
+pub type DynOrganizationRepository = Arc<dyn OrganizationRepository>;
+
+/// 組織 API の状態（作成時に勘定科目を登録するため両方を持つ）
+#[derive(Clone)]
+pub struct OrganizationState {
+    pub organizations: DynOrganizationRepository,
+    pub accounts: AccountService,
+    /// 作成した組織の API キーの発行に使う
+    pub tenant_auth: TenantAuth,
+}
+
+fn map_organization_error(err: RepositoryError) -> AppError {
+    match err {
+        RepositoryError::NotFound(id) => organization_not_found(id),
+        other => map_repo_error(other),
+    }
+}
+
+fn organization_not_found(id: Uuid) -> AppError {
+    AppError::NotFound(format!("Organization not found: {}", id))
+}
+
+/// POST /api/organizations - 組織作成（標準の勘定科目を登録する）
+pub async fn create_organization(
+    State(state): State<OrganizationState>,
+    ValidatedJson(request, _): ValidatedJson<CreateOrganizationRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let organization = state
+        .organizations
+        .create(request)
+        .await
+        .map_err(map_organization_error)?;
+
+    // 勘定科目は 1 つの作業単位でまとめて登録し、失敗すれば 1 件も残らない
+    let seeded = match state
+        .accounts
+        .for_organization(organization.id)
+        .create_all(default_chart_of_accounts())
+        .await
+    {
+        Ok(accounts) => accounts.into_iter().map(AccountResponse::from).collect(),
+        Err(err) => {
+            // 勘定科目のない組織を残さない
+            if let Err(rollback) = state.organizations.soft_delete(organization.id).await {
+                tracing::error!(
+                    organization_id = %organization.id,
+                    "Failed to deactivate unprovisioned organization: {}",
+                    rollback
+                );
+            }
+            return Err(map_service_error(err));
+        }
+    };
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ProvisionedOrganizationResponse {
+            organization_key: state.tenant_auth.organization_key(organization.id),
+            organization: OrganizationResponse::from(organization),
+            accounts: seeded,
+        }),
+    ))
+}
+
+/// GET /api/organizations - 組織一覧取得
+pub async fn list_organizations(
+    State(state): State<OrganizationState>,
+) -> Result<impl IntoResponse, AppError> {
+    let organizations = state
+        .organizations
+        .find_all()
+        .await
+        .map_err(map_organization_error)?;
+
+    let responses: Vec<OrganizationResponse> = organizations
+        .into_iter()
+        .map(OrganizationResponse::from)
+        .collect();
+    Ok((StatusCode::OK, Json(responses)))
+}
+
+/// GET /api/organizations/:id - 組織詳細取得
+pub async fn get_organization(
+    State(state): State<OrganizationState>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let organization = state
+        .organizations
+        .find_by_id(id)
+        .await
+        .map_err(map_organization_error)?
+        .ok_or_else(|| organization_not_found(id))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(OrganizationResponse::from(organization)),
+    ))
+}
+
+/// PUT /api/organizations/:id - 組織更新
+pub async fn update_organization(
+    State(state): State<OrganizationState>,
+    Path(id): Path<Uuid>,
+    ValidatedJson(request, _): ValidatedJson<UpdateOrganizationRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let organization = state
+        .organizations
+        .update(id, request)
+        .await
+        .map_err(map_organization_error)?;
+    state.tenant_auth.forget(id);
+    Ok((
+        StatusCode::OK,
+        Json(OrganizationResponse::from(organization)),
+    ))
+}
+
+/// DELETE /api/organizations/:id - 組織論理削除
+pub async fn delete_organization(
+    State(state): State<OrganizationState>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .organizations
+        .soft_delete(id)
+        .await
+        .map_err(map_organization_error)?;
+    state.tenant_auth.forget(id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 組織 API のルーター
+pub fn organization_router(state: OrganizationState) -> Router {
+    Router::new()
+        .route(
+            "/api/organizations",
+            get(list_organizations).post(create_organization),
+        )
+        .route(
+            "/api/organizations/:id",
+            get(get_organization)
+                .put(update_organization)
+                .delete(delete_organization),
+        )
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::{
+        InMemoryAccountRepository, InMemoryEmailQueueRepository, InMemoryOrganizationRepository,
+        InMemoryUnitOfWorkFactory,
+    };
+    use crate::service::Quotas;
+    use crate::state::AppState;
+    use crate::tenant::trust_org_header;
+    use crate::tenant::ORG_ID_HEADER;
+    use crate::{create_account, list_accounts};
+    use axum::{body::Body, http::Request, routing::post};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn send(app: &Router, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json = if body.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&body).unwrap()
+        };
+        (status, json)
+    }
+
+    fn json_request(method: &str, uri: &str, body: serde_json::Value) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_provisioning_seeds_chart_of_accounts() {
+        let state = AppState::builder(Arc::new(InMemoryAccountRepository::new())).build();
+        let app = trust_org_header(
+            organization_router(OrganizationState {
+                organizations: Arc::new(InMemoryOrganizationRepository::new()),
+                accounts: state.accounts.clone(),
+                tenant_auth: TenantAuth::default(),
+            })
+            .merge(
+                Router::new()
+                    .route("/api/accounts", post(create_account).get(list_accounts))
+                    .with_state(state),
+            ),
+        );
+
+        let (status, created) = send(
+            &app,
+            json_request(
+                "POST",
+                "/api/organizations",
+                serde_json::json!({ "name": "恵み教会" }),
+            ),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["name"], "恵み教会");
+        let seeded = created["accounts"].as_array().unwrap().len();
+        assert_eq!(seeded, default_chart_of_accounts().len());
+
+        // 作成した組織の勘定科目として参照できる
+        let (status, listed) = send(
+            &app,
+            Request::builder()
+                .uri("/api/accounts")
+                .header(ORG_ID_HEADER, created["id"].as_str().unwrap())
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(listed.as_array().unwrap().len(), seeded);
+
+        // 既定の組織には登録されない
+        let (_, listed) = send(
+            &app,
+            Request::builder()
+                .uri("/api/accounts")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+        assert!(listed.as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_provisioning_deactivates_organization() {
+        let repo = InMemoryAccountRepository::new();
+        let factory =
+            InMemoryUnitOfWorkFactory::new(&repo, Arc::new(InMemoryEmailQueueRepository::new()));
+        let state = AppState::builder(Arc::new(repo))
+            .with_unit_of_work(Arc::new(factory))
+            .with_quotas(Quotas::default().with_max_accounts(3))
+            .build();
+        let organizations: DynOrganizationRepository =
+            Arc::new(InMemoryOrganizationRepository::new());
+        let app = organization_router(OrganizationState {
+            organizations: organizations.clone(),
+            accounts: state.accounts.clone(),
+            tenant_auth: TenantAuth::default(),
+        });
+
+        let (status, _) = send(
+            &app,
+            json_request(
+                "POST",
+                "/api/organizations",
+                serde_json::json!({ "name": "恵み教会" }),
+            ),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        // 勘定科目を登録できなかった組織は無効化する
+        assert!(organizations.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_organization_crud() {
+        let app = trust_org_header(organization_router(OrganizationState {
+            organizations: Arc::new(InMemoryOrganizationRepository::new()),
+            accounts: AccountService::new(Arc::new(InMemoryAccountRepository::new())),
+            tenant_auth: TenantAuth::default(),
+        }));
+
+        let (status, _) = send(
+            &app,
+            json_request(
+                "POST",
+                "/api/organizations",
+                serde_json::json!({ "name": "" }),
+            ),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (_, created) = send(
+            &app,
+            json_request(
+                "POST",
+                "/api/organizations",
+                serde_json::json!({ "name": "恵み教会" }),
+            ),
+        )
+        .await;
+        let uri = format!("/api/organizations/{}", created["id"].as_str().unwrap());
+
+        let (status, updated) = send(
+            &app,
+            json_request(
+                "PUT",
+                &uri,
+                serde_json::json!({ "name": "恵みキリスト教会" }),
+            ),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(updated["name"], "恵みキリスト教会");
+
+        let (status, _) = send(
+            &app,
+            Request::builder()
+                .method("DELETE")
+                .uri(&uri)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (_, listed) = send(
+            &app,
+            Request::builder()
+                .uri("/api/organizations")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+        assert!(listed.as_array().unwrap().is_empty());
+
+        let (status, _) = send(
+            &app,
+            Request::builder()
+                .uri(format!("/api/organizations/{}", Uuid::new_v4()))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,113 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use common::error::AppError;
+use std::sync::Arc;
+use validator::Validate;
+
+use crate::domain::SearchQuery;
+use crate::handlers::map_repo_error;
+use crate::repository::SearchRepository;
+use crate::tenant::OrganizationId;
+
+pub type DynSearchRepository = Arc<dyn SearchRepository>;
+
+/// GET /api/search?q= - 勘定科目の横断検索（関連度順、種別ごとの件数付き）
+pub async fn search(
+    State(repo): State<DynSearchRepository>,
+    OrganizationId(organization_id): OrganizationId,
+    Query(mut query): Query<SearchQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    query.q = query.q.trim().to_string();
+    query.validate()?;
+
+    let results = repo
+        .for_organization(organization_id)
+        .search(&query)
+        .await
+        .map_err(map_repo_error)?;
+    Ok((StatusCode::OK, Json(results)))
+}
+
+/// 横断検索 API のルーター
+pub fn search_router(repo: DynSearchRepository) -> Router {
+    Router::new()
+        .route("/api/search", get(search))
+        .with_state(repo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AccountCategory, CreateAccountRequest, SearchEntityType, SearchResults};
+    use crate::repository::{
+        AccountRepository, InMemoryAccountRepository, InMemorySearchRepository,
+    };
+    use crate::tenant::trust_org_header;
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn get(app: &Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_search_accounts() {
+        let accounts = Arc::new(InMemoryAccountRepository::new());
+        for (code, name, category, description) in [
+            ("101", "現金", AccountCategory::Cash, Some("手許現金")),
+            ("102", "小口現金", AccountCategory::Cash, None),
+            (
+                "401",
+                "什一献金",
+                AccountCategory::TitheOffering,
+                Some("現金での献金を含む"),
+            ),
+            ("501", "水道光熱費", AccountCategory::UtilityExpense, None),
+        ] {
+            accounts
+                .create(CreateAccountRequest {
+                    code: code.to_string(),
+                    name: name.to_string(),
+                    category,
+                    description: description.map(str::to_string),
+                    display_order: None,
+                    parent_id: None,
+                })
+                .await
+                .unwrap();
+        }
+        let app = trust_org_header(search_router(Arc::new(InMemorySearchRepository::new(
+            accounts,
+        ))));
+
+        let (status, body) = get(&app, "/api/search?q=%E7%8F%BE%E9%87%91").await;
+        assert_eq!(status, StatusCode::OK);
+        let results: SearchResults = serde_json::from_value(body).unwrap();
+        let titles: Vec<&str> = results.hits.iter().map(|h| h.title.as_str()).collect();
+        assert_eq!(titles, ["現金", "小口現金", "什一献金"]);
+        assert_eq!(results.facets.len(), 1);
+        assert_eq!(results.facets[0].entity_type, SearchEntityType::Account);
+        assert_eq!(results.facets[0].count, 3);
+
+        let (_, body) = get(&app, "/api/search?q=%E7%8F%BE%E9%87%91&limit=1").await;
+        let results: SearchResults = serde_json::from_value(body).unwrap();
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(results.facets[0].count, 3);
+
+        let (status, _) = get(&app, "/api/search?q=%20%20").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
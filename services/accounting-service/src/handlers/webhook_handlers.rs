@@ -0,0 +1,252 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use common::error::{AppError, FieldError};
+use common::json::{StrictJsonLimits, ValidatedJson};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    CreateWebhookRequest, UpdateWebhookRequest, WebhookResponse, WebhookTargetPolicy,
+};
+use crate::handlers::map_repo_error;
+use crate::repository::{RepositoryError, WebhookRepository};
+use crate::tenant::OrganizationId;
+
+pub type DynWebhookRepository = Arc<dyn WebhookRepository>;
+
+/// Webhook API の状態（登録時に配信先の制限を確認する）
+#[derive(Clone)]
+pub struct WebhookState {
+    pub webhooks: DynWebhookRepository,
+    pub targets: WebhookTargetPolicy,
+}
+
+/// 配信先として許可されない URL を入力エラーにする
+fn check_target(targets: WebhookTargetPolicy, url: &str) -> Result<(), AppError> {
+    targets
+        .check_url(url)
+        .map_err(|message| AppError::Validation {
+            message: message.clone(),
+            errors: vec![FieldError {
+                field: "url".to_string(),
+                code: "url_target".to_string(),
+                message,
+            }],
+        })
+}
+
+fn map_webhook_error(err: RepositoryError) -> AppError {
+    match err {
+        RepositoryError::NotFound(id) => webhook_not_found(id),
+        other => map_repo_error(other),
+    }
+}
+
+fn webhook_not_found(id: Uuid) -> AppError {
+    AppError::NotFound(format!("Webhook not found: {}", id))
+}
+
+/// POST /api/webhooks - Webhook 登録
+pub async fn create_webhook(
+    State(state): State<WebhookState>,
+    OrganizationId(organization_id): OrganizationId,
+    ValidatedJson(request, _): ValidatedJson<CreateWebhookRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    check_target(state.targets, &request.url)?;
+    let webhook = state
+        .webhooks
+        .for_organization(organization_id)
+        .create(request)
+        .await
+        .map_err(map_webhook_error)?;
+    Ok((StatusCode::CREATED, Json(WebhookResponse::from(webhook))))
+}
+
+/// GET /api/webhooks - Webhook 一覧取得
+pub async fn list_webhooks(
+    State(state): State<WebhookState>,
+    OrganizationId(organization_id): OrganizationId,
+) -> Result<impl IntoResponse, AppError> {
+    let webhooks = state
+        .webhooks
+        .for_organization(organization_id)
+        .find_all()
+        .await
+        .map_err(map_webhook_error)?;
+
+    let responses: Vec<WebhookResponse> = webhooks.into_iter().map(WebhookResponse::from).collect();
+    Ok((StatusCode::OK, Json(responses)))
+}
+
+/// GET /api/webhooks/:id - Webhook 詳細取得
+pub async fn get_webhook(
+    State(state): State<WebhookState>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let webhook = state
+        .webhooks
+        .for_organization(organization_id)
+        .find_by_id(id)
+        .await
+        .map_err(map_webhook_error)?
+        .ok_or_else(|| webhook_not_found(id))?;
+
+    Ok((StatusCode::OK, Json(WebhookResponse::from(webhook))))
+}
+
+/// PUT /api/webhooks/:id - Webhook 更新
+pub async fn update_webhook(
+    State(state): State<WebhookState>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+    ValidatedJson(request, _): ValidatedJson<UpdateWebhookRequest, StrictJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    if let Some(url) = &request.url {
+        check_target(state.targets, url)?;
+    }
+    let webhook = state
+        .webhooks
+        .for_organization(organization_id)
+        .update(id, request)
+        .await
+        .map_err(map_webhook_error)?;
+    Ok((StatusCode::OK, Json(WebhookResponse::from(webhook))))
+}
+
+/// DELETE /api/webhooks/:id - Webhook 削除
+pub async fn delete_webhook(
+    State(state): State<WebhookState>,
+    OrganizationId(organization_id): OrganizationId,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .webhooks
+        .for_organization(organization_id)
+        .delete(id)
+        .await
+        .map_err(map_webhook_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Webhook API のルーター
+pub fn webhook_router(state: WebhookState) -> Router {
+    Router::new()
+        .route("/api/webhooks", get(list_webhooks).post(create_webhook))
+        .route(
+            "/api/webhooks/:id",
+            get(get_webhook).put(update_webhook).delete(delete_webhook),
+        )
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::InMemoryWebhookRepository;
+    use crate::tenant::trust_org_header;
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn create_test_app() -> Router {
+        trust_org_header(webhook_router(WebhookState {
+            webhooks: Arc::new(InMemoryWebhookRepository::new()),
+            targets: WebhookTargetPolicy::default(),
+        }))
+    }
+
+    async fn send(app: &Router, method: &str, uri: &str, body: Body) -> axum::response::Response {
+        app.clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("Content-Type", "application/json")
+                    .body(body)
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_webhook_crud() {
+        let app = create_test_app();
+        let body = serde_json::json!({
+            "url": "https://example.com/hook",
+            "events": ["account.created"],
+            "secret": "0123456789abcdef"
+        });
+
+        let response = send(&app, "POST", "/api/webhooks", Body::from(body.to_string())).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(created.get("secret").is_none());
+        let id = created["id"].as_str().unwrap();
+
+        let update = serde_json::json!({"events": ["account.created", "account.deactivated"]});
+        let response = send(
+            &app,
+            "PUT",
+            &format!("/api/webhooks/{}", id),
+            Body::from(update.to_string()),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = send(
+            &app,
+            "DELETE",
+            &format!("/api/webhooks/{}", id),
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let response = send(&app, "GET", &format!("/api/webhooks/{}", id), Body::empty()).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_rejects_unknown_event() {
+        let app = create_test_app();
+        let body = serde_json::json!({
+            "url": "https://example.com/hook",
+            "events": ["journal.posted"],
+            "secret": "0123456789abcdef"
+        });
+
+        let response = send(&app, "POST", "/api/webhooks", Body::from(body.to_string())).await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_rejects_internal_target() {
+        let app = create_test_app();
+
+        for url in [
+            "https://169.254.169.254/latest/meta-data",
+            "https://localhost:8082/api/admin/promote",
+            "http://example.com/hook",
+        ] {
+            let body = serde_json::json!({
+                "url": url,
+                "events": ["account.created"],
+                "secret": "0123456789abcdef"
+            });
+            let response = send(&app, "POST", "/api/webhooks", Body::from(body.to_string())).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", url);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(error["errors"][0]["field"], "url");
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
+use common::error::AppError;
+use common::startup::ConfigEntry;
+use flate2::{write::GzEncoder, Compression};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::domain::{
+    build_account_tree, AccountTreeResponse, CashCountFilter, CashCountResponse,
+    ExchangeRateResponse,
+};
+use crate::handlers::{
+    map_repo_error, DynAccountRepository, DynCashCountRepository, DynExchangeRateRepository,
+};
+use crate::tenant::OrganizationId;
+
+/// 会計担当者引き継ぎパッケージの生成に必要な状態
+#[derive(Clone)]
+pub struct HandoverState {
+    pub accounts: DynAccountRepository,
+    pub exchange_rates: DynExchangeRateRepository,
+    pub cash_counts: DynCashCountRepository,
+    /// 起動時の有効な設定（秘密情報はマスクして出力。空なら settings.json を含めない）
+    pub settings: Vec<ConfigEntry>,
+}
+
+/// 引き継ぎパッケージの内容
+#[derive(Debug, Serialize)]
+pub struct HandoverPackage {
+    pub generated_at: DateTime<Utc>,
+    pub accounts: Vec<AccountTreeResponse>,
+    pub exchange_rates: Vec<ExchangeRateResponse>,
+    /// 金庫ごとの最新の金種表
+    pub cash_on_hand: Vec<CashCountResponse>,
+    pub settings: BTreeMap<String, String>,
+}
+
+impl HandoverPackage {
+    /// 新任者向けの手順書（Markdown）
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "# 会計担当者 引き継ぎ資料\n\n作成日時: {}\n\n",
+            self.generated_at.to_rfc3339()
+        );
+
+        summary.push_str("## 同梱ファイル\n\n");
+        summary.push_str("- `accounts.json`: 勘定科目（親子構成、無効化済みを含む）\n");
+        summary.push_str("- `exchange_rates.json`: 登録済みの為替レート\n");
+        summary.push_str("- `cash_on_hand.json`: 金庫ごとの最新の金種表\n");
+        if !self.settings.is_empty() {
+            summary.push_str("- `settings.json`: 稼働中の設定（秘密情報はマスク済み）\n");
+        }
+        summary.push('\n');
+
+        summary.push_str("## 現況\n\n");
+        summary.push_str(&format!(
+            "- 勘定科目: {} 件（最上位）\n",
+            self.accounts.len()
+        ));
+        summary.push_str(&format!("- 為替レート: {} 件\n", self.exchange_rates.len()));
+        for count in &self.cash_on_hand {
+            summary.push_str(&format!(
+                "- 金庫 {}: {}（{} 実査）\n",
+                count.safe,
+                count.total,
+                count.counted_at.format("%Y-%m-%d %H:%M")
+            ));
+        }
+
+        summary.push_str("\n## 引き継ぎ後の作業\n\n");
+        summary.push_str("1. 各金庫の現金を実査し、`POST /api/cash-counts` で金種表を登録する\n");
+        summary.push_str("2. `GET /api/accounts/tree` で勘定科目の構成を確認する\n");
+        summary.push_str("3. 外貨を扱う場合は `GET /api/exchange-rates` で最新レートを確認する\n");
+        summary
+    }
+
+    /// tar.gz アーカイブに書き出す
+    pub fn to_archive(&self) -> std::io::Result<Vec<u8>> {
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+        let mtime = self.generated_at.timestamp().max(0) as u64;
+
+        let mut files = vec![
+            ("handover/README.md", self.summary().into_bytes()),
+            (
+                "handover/accounts.json",
+                serde_json::to_vec_pretty(&self.accounts)?,
+            ),
+            (
+                "handover/exchange_rates.json",
+                serde_json::to_vec_pretty(&self.exchange_rates)?,
+            ),
+            (
+                "handover/cash_on_hand.json",
+                serde_json::to_vec_pretty(&self.cash_on_hand)?,
+            ),
+        ];
+        if !self.settings.is_empty() {
+            files.push((
+                "handover/settings.json",
+                serde_json::to_vec_pretty(&self.settings)?,
+            ));
+        }
+        for (path, data) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(mtime);
+            header.set_cksum();
+            builder.append_data(&mut header, path, data.as_slice())?;
+        }
+
+        builder.into_inner()?.finish()
+    }
+}
+
+/// 各リポジトリから引き継ぎパッケージを組み立てる
+pub async fn build_handover_package(state: &HandoverState) -> Result<HandoverPackage, AppError> {
+    // 過去の仕訳の確認に要るため、無効化済みの勘定科目も含める
+    let accounts = build_account_tree(state.accounts.find_all().await.map_err(map_repo_error)?);
+    let exchange_rates = state
+        .exchange_rates
+        .find_all(None, None)
+        .await
+        .map_err(map_repo_error)?;
+
+    // 実査日時の新しい順なので、金庫ごとに最初の 1 件が最新
+    let mut latest = BTreeMap::new();
+    for count in state
+        .cash_counts
+        .find_all(CashCountFilter::default())
+        .await
+        .map_err(map_repo_error)?
+    {
+        latest.entry(count.safe.clone()).or_insert(count);
+    }
+
+    Ok(HandoverPackage {
+        generated_at: Utc::now(),
+        accounts: accounts
+            .into_iter()
+            .map(AccountTreeResponse::from)
+            .collect(),
+        exchange_rates: exchange_rates
+            .into_iter()
+            .map(ExchangeRateResponse::from)
+            .collect(),
+        cash_on_hand: latest.into_values().map(CashCountResponse::from).collect(),
+        settings: state
+            .settings
+            .iter()
+            .map(|entry| (entry.key.clone(), entry.display_value()))
+            .collect(),
+    })
+}
+
+/// GET /api/handover - 会計担当者引き継ぎパッケージ（tar.gz）
+pub async fn download_handover(
+    State(state): State<HandoverState>,
+    OrganizationId(organization_id): OrganizationId,
+) -> Result<impl IntoResponse, AppError> {
+    let state = HandoverState {
+        accounts: state.accounts.for_organization(organization_id),
+        exchange_rates: state.exchange_rates.for_organization(organization_id),
+        cash_counts: state.cash_counts.for_organization(organization_id),
+        ..state
+    };
+    let package = build_handover_package(&state).await?;
+    let archive = package.to_archive().map_err(|err| {
+        AppError::internal(
+            "ARCHIVE_ERROR",
+            format!("Failed to build handover archive: {}", err),
+        )
+    })?;
+
+    let filename = format!(
+        "attachment; filename=\"handover-{}.tar.gz\"",
+        package.generated_at.format("%Y%m%d")
+    );
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "application/gzip".to_string()),
+            (header::CONTENT_DISPOSITION, filename),
+        ],
+        archive,
+    ))
+}
+
+/// 引き継ぎパッケージ用ルーター
+pub fn handover_router(state: HandoverState) -> Router {
+    Router::new()
+        .route("/api/handover", get(download_handover))
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        AccountCategory, CreateAccountRequest, CreateExchangeRateRequest, Currency,
+    };
+    use crate::repository::{
+        AccountRepository, ExchangeRateRepository, InMemoryAccountRepository,
+        InMemoryCashCountRepository, InMemoryExchangeRateRepository,
+    };
+    use crate::tenant::trust_org_header;
+    use axum::{body::Body, http::Request};
+    use chrono::NaiveDate;
+    use flate2::read::GzDecoder;
+    use http_body_util::BodyExt;
+    use rust_decimal::Decimal;
+    use std::io::Read;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_download_handover_archive() {
+        let accounts = Arc::new(InMemoryAccountRepository::new());
+        accounts
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+        let retired = accounts
+            .create(CreateAccountRequest {
+                code: "102".to_string(),
+                name: "旧小口現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+        accounts.soft_delete(retired.id).await.unwrap();
+        // 他組織の為替レートは含めない
+        let exchange_rates = Arc::new(InMemoryExchangeRateRepository::new());
+        exchange_rates
+            .for_organization(Uuid::new_v4())
+            .create(CreateExchangeRateRequest {
+                base_currency: Currency::USD,
+                quote_currency: Currency::JPY,
+                rate: Decimal::new(150, 0),
+                effective_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            })
+            .await
+            .unwrap();
+        let app = trust_org_header(handover_router(HandoverState {
+            accounts,
+            exchange_rates,
+            cash_counts: Arc::new(InMemoryCashCountRepository::new()),
+            settings: vec![
+                ConfigEntry::new("database.url", "postgres://app:s3cret@db/accounting"),
+                ConfigEntry::secret("api_key", "abc"),
+            ],
+        }));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/handover")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/gzip");
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+
+        let mut archive = tar::Archive::new(GzDecoder::new(body.as_ref()));
+        let mut files = BTreeMap::new();
+        for entry in archive.entries().unwrap() {
+            let mut entry = entry.unwrap();
+            let path = entry.path().unwrap().to_string_lossy().to_string();
+            let mut content = String::new();
+            entry.read_to_string(&mut content).unwrap();
+            files.insert(path, content);
+        }
+
+        assert_eq!(files.len(), 5);
+        assert!(files["handover/accounts.json"].contains("現金"));
+        assert!(files["handover/accounts.json"].contains("旧小口現金"));
+        assert!(files["handover/README.md"].contains("勘定科目: 2 件"));
+        assert!(files["handover/README.md"].contains("為替レート: 0 件"));
+        let settings = &files["handover/settings.json"];
+        assert!(settings.contains("postgres://app:****@db/accounting"));
+        assert!(!settings.contains("s3cret"));
+        assert!(!settings.contains("abc"));
+    }
+}
@@ -0,0 +1,40 @@
+use super::{read_accounts, ExternalAccount, ImportError, ImportFormat};
+
+/// freee 会計の勘定科目のエクスポート（CSV）
+///
+/// freee の勘定科目は名前で識別するため、科目コードの列はあれば読む。
+/// 区分には小カテゴリー（なければ大カテゴリー）を使う。
+pub struct Freee;
+
+impl ImportFormat for Freee {
+    fn name(&self) -> &'static str {
+        "freee"
+    }
+
+    fn parse_accounts(&self, content: &str) -> Result<Vec<ExternalAccount>, ImportError> {
+        read_accounts(
+            content,
+            &["勘定科目コード", "コード"],
+            &["勘定科目", "勘定科目名"],
+            &["小カテゴリー", "大カテゴリー"],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accounts() {
+        let content = "勘定科目,表示名（決算書）,小カテゴリー,大カテゴリー,税区分\n\
+                       普通預金,普通預金,現金・預金,流動資産,対象外\n\
+                       通信費,通信費,経費,販売管理費,課対仕入10%\n";
+
+        let accounts = Freee.parse_accounts(content).unwrap();
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[1].name, "通信費");
+        assert_eq!(accounts[1].code, None);
+        assert_eq!(accounts[1].category.as_deref(), Some("経費"));
+    }
+}
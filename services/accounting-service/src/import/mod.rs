@@ -0,0 +1,445 @@
+//! 他の会計ソフトからの取り込み
+//!
+//! 形式ごとの違い（列名など）は [`ImportFormat`] に閉じ込め、外部の勘定科目から
+//! 内部のカテゴリへの対応付けと登録はすべての形式で共有する。
+
+mod freee;
+mod yayoi;
+
+pub use freee::Freee;
+pub use yayoi::Yayoi;
+
+use axum::{extract::Query, http::StatusCode, response::IntoResponse, Json};
+use common::error::AppError;
+use common::json::{JsonLimits, ValidatedJson};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+use validator::Validate;
+
+use crate::domain::{AccountCategory, AccountListQuery, AccountResponse, CreateAccountRequest};
+use crate::handlers::map_service_error;
+use crate::service::AccountService;
+use crate::tenant::OrganizationAccounts;
+
+/// 外部の会計ソフトの勘定科目（CSV の 1 行）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalAccount {
+    /// CSV の行番号（見出しが 1 行目）
+    pub line: usize,
+    pub code: Option<String>,
+    pub name: String,
+    /// 外部ソフトでの区分（弥生の科目区分、freee の小カテゴリーなど）
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ImportError {
+    #[error("Missing column: {0}")]
+    MissingColumn(&'static str),
+
+    #[error("Invalid CSV at line {line}: {message}")]
+    InvalidCsv { line: usize, message: String },
+}
+
+/// 取り込める CSV の形式
+pub trait ImportFormat: Send + Sync {
+    /// リクエストの `format` に指定する名前
+    fn name(&self) -> &'static str;
+
+    /// 勘定科目の一覧を読む（UTF-8 に変換済みの CSV）
+    fn parse_accounts(&self, content: &str) -> Result<Vec<ExternalAccount>, ImportError>;
+}
+
+/// 対応している形式
+pub fn formats() -> [&'static dyn ImportFormat; 2] {
+    [&Yayoi, &Freee]
+}
+
+/// 名前から形式を選ぶ
+pub fn find_format(name: &str) -> Option<&'static dyn ImportFormat> {
+    formats().into_iter().find(|format| format.name() == name)
+}
+
+/// 見出しの列名で値を取り出す（列名の候補を順に探す）
+struct Columns {
+    indexes: HashMap<String, usize>,
+}
+
+impl Columns {
+    fn new(headers: &csv::StringRecord) -> Self {
+        Self {
+            indexes: headers
+                .iter()
+                .enumerate()
+                .map(|(index, name)| {
+                    // UTF-8 の BOM は 1 列目の見出しに付いてくる
+                    let name = name.trim_start_matches('\u{feff}').trim();
+                    (name.to_string(), index)
+                })
+                .collect(),
+        }
+    }
+
+    fn find(&self, candidates: &[&str]) -> Option<usize> {
+        candidates
+            .iter()
+            .find_map(|name| self.indexes.get(*name).copied())
+    }
+}
+
+/// 見出し付きの CSV を列名の候補で読む（形式ごとの実装で共有する）
+pub(crate) fn read_accounts(
+    content: &str,
+    code_columns: &[&str],
+    name_columns: &[&'static str],
+    category_columns: &[&str],
+) -> Result<Vec<ExternalAccount>, ImportError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(content.as_bytes());
+    let headers = reader.headers().map_err(|e| ImportError::InvalidCsv {
+        line: 1,
+        message: e.to_string(),
+    })?;
+    let columns = Columns::new(headers);
+    let name_column = columns
+        .find(name_columns)
+        .ok_or(ImportError::MissingColumn(name_columns[0]))?;
+    let code_column = columns.find(code_columns);
+    let category_column = columns.find(category_columns);
+
+    let mut accounts = Vec::new();
+    // 引用符の中の改行で 1 件が複数行にまたがるため、行番号は件数ではなく
+    // 先頭からのバイト位置で数える（csv の Position::line は CRLF の行末で
+    // 1 行ずれる）。位置が前の行末の LF を指すことがあるので読み飛ばす
+    let line_of = |position: Option<&csv::Position>| {
+        let start = position.map_or(content.len(), |position| position.byte() as usize);
+        let start = content[start..]
+            .strip_prefix('\n')
+            .map_or(start, |_| start + 1);
+        content[..start].matches('\n').count() + 1
+    };
+    for record in reader.records() {
+        let record = record.map_err(|e| ImportError::InvalidCsv {
+            line: line_of(e.position()),
+            message: e.to_string(),
+        })?;
+        let line = line_of(record.position());
+        let value = |column: Option<usize>| {
+            column
+                .and_then(|column| record.get(column))
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let Some(name) = value(Some(name_column)) else {
+            continue;
+        };
+        accounts.push(ExternalAccount {
+            line,
+            code: value(code_column),
+            name,
+            category: value(category_column),
+        });
+    }
+    Ok(accounts)
+}
+
+/// 外部の勘定科目から内部のカテゴリへの対応表
+///
+/// 指定した対応（外部の科目コード・科目名・区分のいずれか）を優先し、
+/// なければよく使われる科目名の既定の対応を使う。
+#[derive(Debug, Clone, Default)]
+pub struct CategoryMapping {
+    entries: HashMap<String, AccountCategory>,
+}
+
+impl CategoryMapping {
+    pub fn new(entries: HashMap<String, AccountCategory>) -> Self {
+        Self { entries }
+    }
+
+    pub fn resolve(&self, account: &ExternalAccount) -> Option<AccountCategory> {
+        [
+            account.code.as_deref(),
+            Some(account.name.as_str()),
+            account.category.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .find_map(|key| self.entries.get(key).cloned())
+        .or_else(|| default_category(&account.name))
+    }
+}
+
+/// 一般的な会計ソフトの科目名と組み込みカテゴリの既定の対応
+fn default_category(name: &str) -> Option<AccountCategory> {
+    let category = match name {
+        "現金" | "小口現金" => AccountCategory::Cash,
+        "普通預金" | "当座預金" => AccountCategory::BankDeposit,
+        "定期預金" => AccountCategory::FixedDeposit,
+        "売掛金" | "未収入金" => AccountCategory::AccountsReceivable,
+        "買掛金" | "未払金" | "未払費用" => AccountCategory::AccountsPayable,
+        "預り金" => AccountCategory::DepositsReceived,
+        "短期借入金" | "長期借入金" | "借入金" => AccountCategory::Borrowings,
+        "元入金" | "資本金" => AccountCategory::Capital,
+        "繰越利益剰余金" => AccountCategory::RetainedSurplus,
+        "受取利息" => AccountCategory::InterestIncome,
+        "雑収入" => AccountCategory::OtherRevenue,
+        "給料手当" | "給料賃金" | "法定福利費" => AccountCategory::PersonnelExpense,
+        "水道光熱費" => AccountCategory::UtilityExpense,
+        "通信費" => AccountCategory::CommunicationExpense,
+        "消耗品費" | "事務用品費" => AccountCategory::SuppliesExpense,
+        "修繕費" => AccountCategory::MaintenanceExpense,
+        "雑費" => AccountCategory::OtherExpense,
+        _ => return None,
+    };
+    Some(category)
+}
+
+/// 取り込みリクエスト（CSV は UTF-8 に変換して送る）
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ImportAccountsRequest {
+    #[validate(length(min = 1, message = "CSVの内容を入力してください"))]
+    pub content: String,
+    /// 外部の科目コード・科目名・区分からカテゴリコードへの対応
+    #[serde(default)]
+    pub mapping: HashMap<String, AccountCategory>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportQuery {
+    pub format: String,
+    /// true なら登録せずに結果だけを返す
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// 取り込まなかった行
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkippedAccount {
+    pub line: usize,
+    pub name: String,
+    pub reason: String,
+}
+
+/// 取り込みの結果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportOutcome {
+    /// 登録する勘定科目（dry_run でも返す）
+    pub accounts: Vec<CreateAccountRequest>,
+    /// 登録した勘定科目（dry_run では空）
+    pub created: Vec<AccountResponse>,
+    pub skipped: Vec<SkippedAccount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportAccountsResponse {
+    pub format: String,
+    pub dry_run: bool,
+    #[serde(flatten)]
+    pub outcome: ImportOutcome,
+}
+
+/// CSV 全体を受け付けるための入力制限
+pub struct ImportJsonLimits;
+
+impl JsonLimits for ImportJsonLimits {
+    const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+    const MAX_STRING_LENGTH: usize = 2 * 1024 * 1024;
+    const DENY_UNKNOWN_FIELDS: bool = true;
+}
+
+/// 外部の勘定科目を内部の勘定科目として登録する
+///
+/// 同じ名前の有効な勘定科目がある行と、カテゴリを決められない行は取り込まない。
+/// 科目コードはカテゴリの範囲で空いているものを振る。
+/// dry_run では登録しないため、同じカテゴリの行には同じ科目コードが表示される。
+pub async fn import_accounts(
+    accounts: &AccountService,
+    external: Vec<ExternalAccount>,
+    mapping: &CategoryMapping,
+    dry_run: bool,
+) -> Result<ImportOutcome, AppError> {
+    let mut names: HashSet<String> = accounts
+        .list(AccountListQuery::default())
+        .await
+        .map_err(map_service_error)?
+        .into_iter()
+        .map(|account| account.name)
+        .collect();
+
+    let mut outcome = ImportOutcome::default();
+    for account in external {
+        let skip = |reason: &str| SkippedAccount {
+            line: account.line,
+            name: account.name.clone(),
+            reason: reason.to_string(),
+        };
+        if names.contains(&account.name) {
+            outcome.skipped.push(skip("already exists"));
+            continue;
+        }
+        let Some(category) = mapping.resolve(&account) else {
+            outcome.skipped.push(skip("no category mapping"));
+            continue;
+        };
+
+        let request = CreateAccountRequest {
+            code: accounts
+                .next_code(&category)
+                .await
+                .map_err(map_service_error)?,
+            name: account.name.clone(),
+            category,
+            description: account
+                .code
+                .as_ref()
+                .map(|code| format!("取り込み元の科目コード: {}", code)),
+            display_order: None,
+            parent_id: None,
+        };
+        request.validate()?;
+        names.insert(account.name);
+        if !dry_run {
+            let account = accounts
+                .create(request.clone())
+                .await
+                .map_err(map_service_error)?;
+            outcome.created.push(AccountResponse::from(account));
+        }
+        outcome.accounts.push(request);
+    }
+    Ok(outcome)
+}
+
+/// POST /api/import/accounts?format=yayoi|freee - 他の会計ソフトの勘定科目を取り込む
+pub async fn import_accounts_handler(
+    OrganizationAccounts(accounts): OrganizationAccounts,
+    Query(query): Query<ImportQuery>,
+    ValidatedJson(request, _): ValidatedJson<ImportAccountsRequest, ImportJsonLimits>,
+) -> Result<impl IntoResponse, AppError> {
+    let format = find_format(&query.format).ok_or_else(|| AppError::BadRequest {
+        code: "UNSUPPORTED_FORMAT",
+        message: format!(
+            "Unsupported import format: {} (expected one of: {})",
+            query.format,
+            formats().map(|format| format.name()).join(", ")
+        ),
+    })?;
+    let external = format
+        .parse_accounts(&request.content)
+        .map_err(|e| AppError::BadRequest {
+            code: "INVALID_IMPORT",
+            message: e.to_string(),
+        })?;
+
+    let mapping = CategoryMapping::new(request.mapping);
+    let outcome = import_accounts(&accounts, external, &mapping, query.dry_run).await?;
+
+    let status = if outcome.created.is_empty() {
+        StatusCode::OK
+    } else {
+        StatusCode::CREATED
+    };
+    Ok((
+        status,
+        Json(ImportAccountsResponse {
+            format: format.name().to_string(),
+            dry_run: query.dry_run,
+            outcome,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::InMemoryAccountRepository;
+    use std::sync::Arc;
+
+    fn external(line: usize, code: Option<&str>, name: &str) -> ExternalAccount {
+        ExternalAccount {
+            line,
+            code: code.map(str::to_string),
+            name: name.to_string(),
+            category: None,
+        }
+    }
+
+    #[test]
+    fn test_read_accounts_reports_physical_lines() {
+        let content = "勘定科目,摘要\n\
+                       現金,\"1 行目\n2 行目\"\n\
+                       普通預金,\n";
+
+        let accounts = read_accounts(content, &[], &["勘定科目"], &[]).unwrap();
+        assert_eq!(
+            accounts.iter().map(|a| a.line).collect::<Vec<_>>(),
+            vec![2, 4]
+        );
+
+        let content = content.replace('\n', "\r\n");
+        let accounts = read_accounts(&content, &[], &["勘定科目"], &[]).unwrap();
+        assert_eq!(
+            accounts.iter().map(|a| a.line).collect::<Vec<_>>(),
+            vec![2, 4]
+        );
+    }
+
+    #[test]
+    fn test_mapping_prefers_explicit_entries() {
+        let mapping = CategoryMapping::new(HashMap::from([
+            ("1110".to_string(), AccountCategory::FixedDeposit),
+            ("献金".to_string(), AccountCategory::TitheOffering),
+        ]));
+
+        assert_eq!(
+            mapping.resolve(&external(2, Some("1110"), "現金")),
+            Some(AccountCategory::FixedDeposit)
+        );
+        assert_eq!(
+            mapping.resolve(&external(3, None, "献金")),
+            Some(AccountCategory::TitheOffering)
+        );
+        assert_eq!(
+            mapping.resolve(&external(4, None, "現金")),
+            Some(AccountCategory::Cash)
+        );
+        assert_eq!(mapping.resolve(&external(5, None, "交際費")), None);
+    }
+
+    #[tokio::test]
+    async fn test_import_assigns_codes_and_skips_duplicates() {
+        let accounts = AccountService::new(Arc::new(InMemoryAccountRepository::new()));
+        let rows = vec![
+            external(2, Some("100"), "現金"),
+            external(3, Some("111"), "普通預金"),
+            external(4, None, "交際費"),
+            external(5, None, "現金"),
+        ];
+
+        let planned = import_accounts(&accounts, rows.clone(), &CategoryMapping::default(), true)
+            .await
+            .unwrap();
+        assert_eq!(planned.accounts.len(), 2);
+        assert!(planned.created.is_empty());
+        assert_eq!(planned.skipped.len(), 2);
+
+        let outcome = import_accounts(&accounts, rows, &CategoryMapping::default(), false)
+            .await
+            .unwrap();
+        assert_eq!(outcome.created.len(), 2);
+        assert_eq!(outcome.created[0].category, AccountCategory::Cash);
+        assert_ne!(outcome.created[0].code, outcome.created[1].code);
+        assert_eq!(
+            outcome
+                .skipped
+                .iter()
+                .map(|s| (s.line, s.reason.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(4, "no category mapping"), (5, "already exists")]
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use super::{read_accounts, ExternalAccount, ImportError, ImportFormat};
+
+/// 弥生会計の勘定科目の書き出し（CSV）
+///
+/// 見出しの列名で読むため、列の並びや余分な列は問わない。
+pub struct Yayoi;
+
+impl ImportFormat for Yayoi {
+    fn name(&self) -> &'static str {
+        "yayoi"
+    }
+
+    fn parse_accounts(&self, content: &str) -> Result<Vec<ExternalAccount>, ImportError> {
+        read_accounts(
+            content,
+            &["科目コード", "勘定科目コード", "コード"],
+            &["勘定科目", "勘定科目名", "科目名"],
+            &["科目区分", "区分", "決算書科目区分"],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accounts() {
+        let content = "\u{feff}科目コード,勘定科目,科目区分,税区分\r\n\
+                       100,現金,現金・預金,対象外\r\n\
+                       111,\"普通預金\",現金・預金,対象外\r\n\
+                       ,,,\r\n";
+
+        let accounts = Yayoi.parse_accounts(content).unwrap();
+        assert_eq!(
+            accounts,
+            vec![
+                ExternalAccount {
+                    line: 2,
+                    code: Some("100".to_string()),
+                    name: "現金".to_string(),
+                    category: Some("現金・預金".to_string()),
+                },
+                ExternalAccount {
+                    line: 3,
+                    code: Some("111".to_string()),
+                    name: "普通預金".to_string(),
+                    category: Some("現金・預金".to_string()),
+                },
+            ]
+        );
+
+        assert_eq!(
+            Yayoi.parse_accounts("コード,区分\r\n100,資産\r\n"),
+            Err(ImportError::MissingColumn("勘定科目"))
+        );
+    }
+}
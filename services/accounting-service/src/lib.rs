@@ -1,8 +1,24 @@
+pub mod app;
+pub mod build_info;
+pub mod cli;
 pub mod config;
 pub mod domain;
+pub mod events;
+pub mod graphql;
 pub mod handlers;
+pub mod handover;
+pub mod import;
+pub mod metrics;
+pub mod migrate;
+pub mod notifications;
 pub mod repository;
+pub mod service;
+pub mod standby;
+pub mod state;
+pub mod tenant;
+pub mod webhook_delivery;
 
 pub use domain::*;
 pub use handlers::*;
 pub use repository::*;
+pub use service::*;
@@ -1,69 +1,75 @@
-use axum::{
-    routing::{get, post},
-    Router,
-};
+use clap::Parser;
+use common::compression::{compression_layer, DEFAULT_COMPRESSION_MIN_SIZE};
+use common::lifecycle::shutdown_signal;
 use std::net::SocketAddr;
-use std::sync::Arc;
+use std::time::Duration;
 
-use accounting_service::config::DatabaseConfig;
-use accounting_service::handlers::{
-    create_account, delete_account, get_account, list_accounts, update_account,
-    DynAccountRepository,
-};
-use accounting_service::repository::{InMemoryAccountRepository, PostgresAccountRepository};
+use accounting_service::app::{build_router, initialize};
+use accounting_service::cli::{run_migrate, Cli, Command, MigrateCommand, ServeArgs};
+use accounting_service::config::{AppConfig, DatabaseConfig};
 
 #[tokio::main]
 async fn main() {
-    common::init_tracing();
-
+    // RUST_LOG や SENTRY_DSN も .env から読むため、ログとエラー通知の初期化より先に読み込む
     let _ = dotenvy::dotenv();
+    let cli = Cli::parse();
+    common::init_tracing();
+    let _error_reporting = common::reporting::init_error_reporting(concat!(
+        env!("CARGO_PKG_NAME"),
+        "@",
+        env!("CARGO_PKG_VERSION")
+    ));
 
-    let repo: DynAccountRepository = match DatabaseConfig::from_env() {
-        Some(config) => {
-            tracing::info!("Connecting to PostgreSQL...");
-            let pool = config
-                .create_pool()
-                .await
-                .expect("Failed to connect to PostgreSQL");
+    let config = match AppConfig::load() {
+        Ok(config) => config,
+        Err(err) => {
+            tracing::error!("{}", err);
+            std::process::exit(1);
+        }
+    };
+    if cli.config_check {
+        println!("Configuration OK");
+        return;
+    }
 
-            sqlx::migrate!("./migrations")
-                .run(&pool)
-                .await
-                .expect("Failed to run database migrations");
+    match cli.command() {
+        Command::Serve(args) => serve(config, args).await,
+        Command::Migrate { command } => migrate_command(config, command).await,
+    }
+}
 
-            tracing::info!("PostgreSQL connected and migrations applied");
-            Arc::new(PostgresAccountRepository::new(pool))
-        }
-        None => {
-            tracing::warn!("DATABASE_URL not set, using in-memory repository");
-            Arc::new(InMemoryAccountRepository::new())
-        }
+/// migrate サブコマンド（HTTP サーバーは起動しない）
+async fn migrate_command(config: AppConfig, command: MigrateCommand) {
+    let Some(db_config) = DatabaseConfig::from_config(&config) else {
+        tracing::error!("DATABASE_URL or POSTGRES_* must be set to manage migrations");
+        std::process::exit(1);
     };
+    let pool = db_config
+        .create_pool()
+        .await
+        .expect("Failed to connect to PostgreSQL");
 
-    let app = Router::new()
-        .route("/", get(root))
-        .route("/health", get(health))
-        .route("/api/accounts", post(create_account).get(list_accounts))
-        .route(
-            "/api/accounts/:id",
-            get(get_account).put(update_account).delete(delete_account),
-        )
-        .with_state(repo);
+    if let Err(err) = run_migrate(&pool, command).await {
+        tracing::error!("Migration failed: {}", err);
+        std::process::exit(1);
+    }
+}
 
+async fn serve(config: AppConfig, args: ServeArgs) {
     let addr = SocketAddr::from(([0, 0, 0, 0], 8082));
+    let shutdown_delay = Duration::from_secs(config.shutdown_delay.unwrap_or_default());
+    let compression_min_size = config
+        .compression_min_size
+        .unwrap_or(DEFAULT_COMPRESSION_MIN_SIZE);
+    let state = initialize(config, args, addr).await;
+    let readiness = state.readiness.clone();
+    let app = build_router(state).layer(compression_layer(compression_min_size));
+
     tracing::info!("accounting-service listening on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
-}
-
-async fn root() -> axum::Json<serde_json::Value> {
-    axum::Json(serde_json::json!({
-        "service": "accounting-service",
-        "version": env!("CARGO_PKG_VERSION")
-    }))
-}
-
-async fn health() -> &'static str {
-    "OK"
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal(readiness, shutdown_delay))
+        .await
+        .unwrap();
 }
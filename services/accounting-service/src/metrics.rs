@@ -0,0 +1,203 @@
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use common::lifecycle::Readiness;
+use common::ErrorResponse;
+use serde::Serialize;
+use sqlx::PgPool;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// 使用中の接続がこの割合を超えたら degraded とする
+pub const DEGRADED_POOL_UTILIZATION: f64 = 0.9;
+/// readiness probe で接続の取得を待つ上限（プローブ自体のタイムアウトより短くする）
+const PROBE_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// 接続プールの状態
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PoolStats {
+    /// 確立済みの接続数
+    pub size: u32,
+    pub idle: u32,
+    pub in_use: u32,
+    pub max_connections: u32,
+    /// 使用中の接続の割合（0.0〜1.0）
+    pub utilization: f64,
+    /// 接続の取得にかかった時間（ミリ秒）
+    pub acquire_wait_ms: u64,
+}
+
+impl PoolStats {
+    pub fn new(size: u32, idle: u32, max_connections: u32, acquire_wait: Duration) -> Self {
+        let in_use = size.saturating_sub(idle);
+        Self {
+            size,
+            idle,
+            in_use,
+            max_connections,
+            utilization: f64::from(in_use) / f64::from(max_connections.max(1)),
+            acquire_wait_ms: acquire_wait.as_millis() as u64,
+        }
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        self.utilization > DEGRADED_POOL_UTILIZATION
+    }
+
+    /// Prometheus のテキスト形式
+    pub fn to_prometheus(&self) -> String {
+        let mut text = String::new();
+        for (name, help, value) in [
+            ("db_pool_size", "Open connections", f64::from(self.size)),
+            ("db_pool_idle", "Idle connections", f64::from(self.idle)),
+            (
+                "db_pool_in_use",
+                "Connections in use",
+                f64::from(self.in_use),
+            ),
+            (
+                "db_pool_max_connections",
+                "Maximum connections",
+                f64::from(self.max_connections),
+            ),
+            (
+                "db_pool_acquire_wait_seconds",
+                "Time to acquire a connection",
+                self.acquire_wait_ms as f64 / 1000.0,
+            ),
+        ] {
+            let _ = writeln!(text, "# HELP {} {}", name, help);
+            let _ = writeln!(text, "# TYPE {} gauge", name);
+            let _ = writeln!(text, "{} {}", name, value);
+        }
+        text
+    }
+}
+
+/// 接続を 1 本取得して待ち時間を測り、プールの状態を返す
+///
+/// 件数は取得前の値（測定のための接続を含めない）。
+pub async fn pool_stats(pool: &PgPool) -> Result<PoolStats, sqlx::Error> {
+    let size = pool.size();
+    let idle = pool.num_idle() as u32;
+    let started = Instant::now();
+    let conn = tokio::time::timeout(PROBE_ACQUIRE_TIMEOUT, pool.acquire())
+        .await
+        .map_err(|_| sqlx::Error::PoolTimedOut)??;
+    let acquire_wait = started.elapsed();
+    drop(conn);
+    Ok(PoolStats::new(
+        size,
+        idle,
+        pool.options().get_max_connections(),
+        acquire_wait,
+    ))
+}
+
+#[derive(Clone)]
+struct HealthState {
+    readiness: Readiness,
+    pool: Option<PgPool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReadyResponse {
+    /// ok / degraded
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<PoolStats>,
+}
+
+/// GET /ready - 起動処理の完了と接続プールの状態（接続を取得できなければ 503）
+async fn ready(State(state): State<HealthState>) -> Response {
+    if !state.readiness.is_ready() {
+        return common::lifecycle::ready(State(state.readiness)).await;
+    }
+    let Some(pool) = state.pool else {
+        return Json(ReadyResponse {
+            status: "ok",
+            pool: None,
+        })
+        .into_response();
+    };
+
+    match pool_stats(&pool).await {
+        Ok(stats) => {
+            let status = if stats.is_degraded() {
+                tracing::warn!(
+                    in_use = stats.in_use,
+                    max_connections = stats.max_connections,
+                    "Connection pool is nearly exhausted"
+                );
+                "degraded"
+            } else {
+                "ok"
+            };
+            Json(ReadyResponse {
+                status,
+                pool: Some(stats),
+            })
+            .into_response()
+        }
+        Err(err) => {
+            tracing::warn!("Readiness check failed to acquire a connection: {}", err);
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(ErrorResponse::new(
+                    "Database connection is not available",
+                    "DB_UNAVAILABLE",
+                )),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /metrics - 接続プールのメトリクス（Prometheus 形式、インメモリでは空）
+async fn metrics(State(state): State<HealthState>) -> Response {
+    let body = match &state.pool {
+        Some(pool) => match pool_stats(pool).await {
+            Ok(stats) => stats.to_prometheus(),
+            Err(err) => {
+                return (
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    Json(ErrorResponse::new(err.to_string(), "DB_UNAVAILABLE")),
+                )
+                    .into_response()
+            }
+        },
+        None => String::new(),
+    };
+    ([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], body).into_response()
+}
+
+/// readiness probe とメトリクスのルーター
+pub fn health_router(readiness: Readiness, pool: Option<PgPool>) -> Router {
+    Router::new()
+        .route("/ready", get(ready))
+        .route("/metrics", get(metrics))
+        .with_state(HealthState { readiness, pool })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_stats_degraded_over_threshold() {
+        let stats = PoolStats::new(10, 1, 10, Duration::from_millis(3));
+        assert_eq!(stats.in_use, 9);
+        assert!(!stats.is_degraded());
+
+        let stats = PoolStats::new(10, 0, 10, Duration::from_millis(3));
+        assert!(stats.is_degraded());
+        assert!(stats.to_prometheus().contains("db_pool_in_use 10\n"));
+        assert!(stats
+            .to_prometheus()
+            .contains("db_pool_acquire_wait_seconds 0.003\n"));
+    }
+}
@@ -0,0 +1,200 @@
+use axum::{extract::State, routing::get, Json, Router};
+use common::error::AppError;
+use serde::Serialize;
+use sqlx::migrate::{Migrate, MigrateError, Migrator};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::fmt;
+
+/// accounting-service のマイグレーション
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// マイグレーションの適用状況
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    pub applied: bool,
+    /// 適用後にファイルが変更されている
+    pub checksum_mismatch: bool,
+}
+
+impl MigrationStatus {
+    pub fn state(&self) -> &'static str {
+        match (self.applied, self.checksum_mismatch) {
+            (true, true) => "modified",
+            (true, false) => "applied",
+            (false, _) => "pending",
+        }
+    }
+}
+
+impl fmt::Display for MigrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<8} {} {}",
+            self.state(),
+            self.version,
+            self.description
+        )
+    }
+}
+
+/// 各マイグレーションの適用状況（バージョン順）
+///
+/// 読み取り専用のスタンバイでも確認できるよう、管理テーブルがなくても作成しない。
+pub async fn status(pool: &PgPool) -> Result<Vec<MigrationStatus>, MigrateError> {
+    let mut conn = pool.acquire().await?;
+    let has_table: bool = sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+        .fetch_one(&mut *conn)
+        .await?;
+    let applied: HashMap<i64, Vec<u8>> = if has_table {
+        conn.list_applied_migrations()
+            .await?
+            .into_iter()
+            .map(|m| (m.version, m.checksum.into_owned()))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    Ok(MIGRATOR
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| {
+            let checksum = applied.get(&m.version);
+            MigrationStatus {
+                version: m.version,
+                description: m.description.to_string(),
+                applied: checksum.is_some(),
+                checksum_mismatch: checksum.is_some_and(|c| c.as_slice() != &*m.checksum),
+            }
+        })
+        .collect())
+}
+
+/// 未適用のマイグレーションをすべて適用する
+pub async fn up(pool: &PgPool) -> Result<(), MigrateError> {
+    MIGRATOR.run(pool).await
+}
+
+/// 指定バージョンより新しいマイグレーションを取り消す（未指定なら直近の 1 件）
+///
+/// 取り消した後のバージョン（0 はすべて取り消し済み）を返す。
+pub async fn down(pool: &PgPool, target: Option<i64>) -> Result<i64, MigrateError> {
+    let target = match target {
+        Some(target) => target,
+        None => {
+            let applied: Vec<i64> = status(pool)
+                .await?
+                .into_iter()
+                .filter(|m| m.applied)
+                .map(|m| m.version)
+                .collect();
+            match applied.as_slice() {
+                [.., previous, _] => *previous,
+                _ => 0,
+            }
+        }
+    };
+    MIGRATOR.undo(pool, target).await?;
+    Ok(target)
+}
+
+/// すべて取り消してから適用し直す（データはすべて失われる）
+pub async fn fresh(pool: &PgPool) -> Result<(), MigrateError> {
+    MIGRATOR.undo(pool, 0).await?;
+    MIGRATOR.run(pool).await
+}
+
+/// マイグレーションの適用状況のレスポンス
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationsResponse {
+    /// 適用済みの最新バージョン（未適用なら None）
+    pub schema_version: Option<i64>,
+    /// 同梱しているマイグレーションの最新バージョン
+    pub latest_version: Option<i64>,
+    /// 未適用の件数
+    pub pending: usize,
+    pub migrations: Vec<MigrationStatusResponse>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationStatusResponse {
+    pub version: i64,
+    pub description: String,
+    /// applied / pending / modified（適用後にファイルが変更されている）
+    pub state: &'static str,
+}
+
+impl From<Vec<MigrationStatus>> for MigrationsResponse {
+    fn from(statuses: Vec<MigrationStatus>) -> Self {
+        Self {
+            schema_version: statuses
+                .iter()
+                .filter(|m| m.applied)
+                .map(|m| m.version)
+                .max(),
+            latest_version: statuses.iter().map(|m| m.version).max(),
+            pending: statuses.iter().filter(|m| !m.applied).count(),
+            migrations: statuses
+                .into_iter()
+                .map(|m| MigrationStatusResponse {
+                    state: m.state(),
+                    version: m.version,
+                    description: m.description,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// GET /api/admin/migrations - マイグレーションの適用状況
+pub async fn get_migrations(
+    State(pool): State<PgPool>,
+) -> Result<Json<MigrationsResponse>, AppError> {
+    let statuses = status(&pool)
+        .await
+        .map_err(|e| AppError::internal("MIGRATION_ERROR", e.to_string()))?;
+    Ok(Json(MigrationsResponse::from(statuses)))
+}
+
+/// マイグレーション確認用ルーター（PostgreSQL 使用時のみ）
+pub fn migrations_router(pool: PgPool) -> Router {
+    Router::new()
+        .route("/api/admin/migrations", get(get_migrations))
+        .with_state(pool)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migration(version: i64, applied: bool, checksum_mismatch: bool) -> MigrationStatus {
+        MigrationStatus {
+            version,
+            description: format!("migration {}", version),
+            applied,
+            checksum_mismatch,
+        }
+    }
+
+    #[test]
+    fn test_migrations_response() {
+        let response = MigrationsResponse::from(vec![
+            migration(1, true, false),
+            migration(2, true, true),
+            migration(3, false, false),
+        ]);
+
+        assert_eq!(response.schema_version, Some(2));
+        assert_eq!(response.latest_version, Some(3));
+        assert_eq!(response.pending, 1);
+        let states: Vec<&str> = response.migrations.iter().map(|m| m.state).collect();
+        assert_eq!(states, ["applied", "modified", "pending"]);
+
+        let empty = MigrationsResponse::from(vec![migration(1, false, false)]);
+        assert_eq!(empty.schema_version, None);
+    }
+}
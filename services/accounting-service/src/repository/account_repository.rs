@@ -1,10 +1,15 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::domain::{Account, AccountType, CreateAccountRequest, UpdateAccountRequest};
+use crate::domain::{
+    build_account_tree, Account, AccountCategory, AccountListQuery, AccountNode, AccountType,
+    CreateAccountRequest, UpdateAccountRequest,
+};
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum RepositoryError {
     #[error("Account not found: {0}")]
     NotFound(Uuid),
@@ -17,6 +22,12 @@ pub enum RepositoryError {
 
     #[error("Validation error: {0}")]
     ValidationError(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Account has been modified: {0}")]
+    Modified(Uuid),
 }
 
 pub type RepositoryResult<T> = Result<T, RepositoryError>;
@@ -33,20 +44,73 @@ pub trait AccountRepository: Send + Sync {
     /// 科目コードで勘定科目を取得
     async fn find_by_code(&self, code: &str) -> RepositoryResult<Option<Account>>;
 
-    /// 全勘定科目を取得
+    /// 全勘定科目を取得（無効化済みを含む）
     async fn find_all(&self) -> RepositoryResult<Vec<Account>>;
 
-    /// 科目種別で勘定科目を取得
+    /// 有効な勘定科目を取得
+    async fn find_active(&self) -> RepositoryResult<Vec<Account>>;
+
+    /// 科目種別で有効な勘定科目を取得
     async fn find_by_type(&self, account_type: AccountType) -> RepositoryResult<Vec<Account>>;
 
+    /// 条件に合う勘定科目を指定した順で取得
+    async fn list(&self, query: AccountListQuery) -> RepositoryResult<Vec<Account>>;
+
     /// 勘定科目を更新
     async fn update(&self, id: Uuid, request: UpdateAccountRequest) -> RepositoryResult<Account>;
 
+    /// 現在の版（updated_at）が `versions` のいずれかである場合だけ勘定科目を更新
+    ///
+    /// 版の確認と更新は不可分に行い、一致しなければ `RepositoryError::Modified` を返す。
+    async fn update_if_unmodified(
+        &self,
+        id: Uuid,
+        request: UpdateAccountRequest,
+        versions: &[DateTime<Utc>],
+    ) -> RepositoryResult<Account>;
+
+    /// 指定した順に表示順を 1 から振り直す
+    ///
+    /// 見つからない勘定科目が 1 件でもあれば何も変更しない。
+    async fn reorder(&self, account_ids: &[Uuid]) -> RepositoryResult<Vec<Account>>;
+
     /// 勘定科目を論理削除（is_active = false）
     async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()>;
 
+    /// 現在の版が `versions` のいずれかである場合だけ論理削除（`update_if_unmodified` と同じ条件）
+    async fn soft_delete_if_unmodified(
+        &self,
+        id: Uuid,
+        versions: &[DateTime<Utc>],
+    ) -> RepositoryResult<()>;
+
     /// 科目コードの重複チェック
     async fn exists_by_code(&self, code: &str) -> RepositoryResult<bool>;
+
+    /// 子勘定科目を取得
+    async fn find_children(&self, parent_id: Uuid) -> RepositoryResult<Vec<Account>>;
+
+    /// 最終更新日時（無効化済みを含む。勘定科目がなければ None）
+    async fn last_modified(&self) -> RepositoryResult<Option<DateTime<Utc>>>;
+
+    /// 指定した時点の勘定科目（その時点で未作成なら None）
+    async fn find_as_of(&self, id: Uuid, as_of: DateTime<Utc>)
+        -> RepositoryResult<Option<Account>>;
+
+    /// カテゴリの科目種別の範囲で空いている最小の科目コード
+    ///
+    /// 無効化済みの科目コードも使用中とみなす。範囲の規則が未設定なら標準の範囲で提案する。
+    async fn next_code(&self, category: &AccountCategory) -> RepositoryResult<Option<String>>;
+
+    /// 勘定科目をツリー構造で取得
+    async fn find_tree(&self) -> RepositoryResult<Vec<AccountNode>> {
+        Ok(build_account_tree(self.find_active().await?))
+    }
+
+    /// 指定した組織の勘定科目だけを扱うリポジトリを返す
+    ///
+    /// 他組織の勘定科目は存在しないものとして扱う。
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn AccountRepository>;
 }
 
 #[cfg(test)]
@@ -54,6 +118,7 @@ mod tests {
     use super::*;
     use crate::domain::AccountCategory;
     use crate::repository::InMemoryAccountRepository;
+    use common::patch::Patch;
 
     fn create_test_request() -> CreateAccountRequest {
         CreateAccountRequest {
@@ -62,185 +127,53 @@ mod tests {
             category: AccountCategory::Cash,
             description: Some("手許現金".to_string()),
             display_order: Some(1),
+            parent_id: None,
         }
     }
 
     #[tokio::test]
-    async fn test_create_account() {
-        let repo = InMemoryAccountRepository::new();
-        let request = create_test_request();
-
-        let result = repo.create(request).await;
-
-        assert!(result.is_ok());
-        let account = result.unwrap();
-        assert_eq!(account.code, "101");
-        assert_eq!(account.name, "現金");
-        assert_eq!(account.account_type, AccountType::Asset);
-    }
-
-    #[tokio::test]
-    async fn test_create_duplicate_code_fails() {
-        let repo = InMemoryAccountRepository::new();
-        let request = create_test_request();
-
-        let _ = repo.create(request.clone()).await.unwrap();
-        let result = repo.create(request).await;
-
-        assert!(matches!(result, Err(RepositoryError::DuplicateCode(_))));
-    }
-
-    #[tokio::test]
-    async fn test_find_by_id() {
-        let repo = InMemoryAccountRepository::new();
-        let request = create_test_request();
-        let created = repo.create(request).await.unwrap();
-
-        let found = repo.find_by_id(created.id).await.unwrap();
-
-        assert!(found.is_some());
-        assert_eq!(found.unwrap().id, created.id);
-    }
-
-    #[tokio::test]
-    async fn test_find_by_id_not_found() {
-        let repo = InMemoryAccountRepository::new();
-        let random_id = Uuid::new_v4();
-
-        let found = repo.find_by_id(random_id).await.unwrap();
-
-        assert!(found.is_none());
-    }
-
-    #[tokio::test]
-    async fn test_find_by_code() {
-        let repo = InMemoryAccountRepository::new();
-        let request = create_test_request();
-        let _ = repo.create(request).await.unwrap();
-
-        let found = repo.find_by_code("101").await.unwrap();
-
-        assert!(found.is_some());
-        assert_eq!(found.unwrap().code, "101");
-    }
-
-    #[tokio::test]
-    async fn test_find_all() {
-        let repo = InMemoryAccountRepository::new();
-
-        let request1 = CreateAccountRequest {
-            code: "101".to_string(),
-            name: "現金".to_string(),
-            category: AccountCategory::Cash,
-            description: None,
-            display_order: Some(1),
-        };
-        let request2 = CreateAccountRequest {
-            code: "401".to_string(),
-            name: "什一献金".to_string(),
-            category: AccountCategory::TitheOffering,
-            description: None,
-            display_order: Some(10),
-        };
-
-        let _ = repo.create(request1).await.unwrap();
-        let _ = repo.create(request2).await.unwrap();
-
-        let all = repo.find_all().await.unwrap();
-
-        assert_eq!(all.len(), 2);
-    }
-
-    #[tokio::test]
-    async fn test_find_by_type() {
-        let repo = InMemoryAccountRepository::new();
-
-        let asset_request = CreateAccountRequest {
-            code: "101".to_string(),
-            name: "現金".to_string(),
-            category: AccountCategory::Cash,
-            description: None,
-            display_order: Some(1),
-        };
-        let revenue_request = CreateAccountRequest {
-            code: "401".to_string(),
-            name: "什一献金".to_string(),
-            category: AccountCategory::TitheOffering,
-            description: None,
-            display_order: Some(10),
-        };
-
-        let _ = repo.create(asset_request).await.unwrap();
-        let _ = repo.create(revenue_request).await.unwrap();
-
-        let assets = repo.find_by_type(AccountType::Asset).await.unwrap();
-        let revenues = repo.find_by_type(AccountType::Revenue).await.unwrap();
-
-        assert_eq!(assets.len(), 1);
-        assert_eq!(revenues.len(), 1);
-        assert_eq!(assets[0].code, "101");
-        assert_eq!(revenues[0].code, "401");
-    }
-
-    #[tokio::test]
-    async fn test_update_account() {
-        let repo = InMemoryAccountRepository::new();
-        let request = create_test_request();
-        let created = repo.create(request).await.unwrap();
-
-        let update_request = UpdateAccountRequest {
-            name: Some("小口現金".to_string()),
-            description: Some("小口経費用".to_string()),
-            display_order: None,
-            is_active: None,
-        };
-
-        let updated = repo.update(created.id, update_request).await.unwrap();
-
-        assert_eq!(updated.name, "小口現金");
-        assert_eq!(updated.description, Some("小口経費用".to_string()));
-        assert_eq!(updated.code, "101"); // codeは変更されない
-    }
-
-    #[tokio::test]
-    async fn test_update_not_found() {
+    async fn test_find_as_of() {
         let repo = InMemoryAccountRepository::new();
-        let random_id = Uuid::new_v4();
+        let before_create = Utc::now();
+        let created = repo.create(create_test_request()).await.unwrap();
+        let after_create = Utc::now();
 
-        let update_request = UpdateAccountRequest {
-            name: Some("テスト".to_string()),
-            description: None,
+        let rename = UpdateAccountRequest {
+            name: Some("手許現金".to_string()),
+            description: Patch::Absent,
             display_order: None,
             is_active: None,
+            parent_id: Patch::Absent,
         };
-
-        let result = repo.update(random_id, update_request).await;
-
-        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
-    }
-
-    #[tokio::test]
-    async fn test_soft_delete() {
-        let repo = InMemoryAccountRepository::new();
-        let request = create_test_request();
-        let created = repo.create(request).await.unwrap();
-
-        let result = repo.soft_delete(created.id).await;
-        assert!(result.is_ok());
-
-        let found = repo.find_by_id(created.id).await.unwrap().unwrap();
-        assert!(!found.is_active);
-    }
-
-    #[tokio::test]
-    async fn test_exists_by_code() {
-        let repo = InMemoryAccountRepository::new();
-        let request = create_test_request();
-
-        assert!(!repo.exists_by_code("101").await.unwrap());
-
-        let _ = repo.create(request).await.unwrap();
-
-        assert!(repo.exists_by_code("101").await.unwrap());
+        repo.update(created.id, rename).await.unwrap();
+        repo.soft_delete(created.id).await.unwrap();
+
+        assert!(repo
+            .find_as_of(created.id, before_create - chrono::Duration::seconds(1))
+            .await
+            .unwrap()
+            .is_none());
+        let original = repo
+            .find_as_of(created.id, after_create)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(original.name, "現金");
+        assert!(original.is_active);
+
+        let latest = repo
+            .find_as_of(created.id, Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.name, "手許現金");
+        assert!(!latest.is_active);
+
+        let other = repo.for_organization(Uuid::new_v4());
+        assert!(other
+            .find_as_of(created.id, Utc::now())
+            .await
+            .unwrap()
+            .is_none());
     }
 }
@@ -0,0 +1,362 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use moka::future::Cache;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::{
+    Account, AccountCategory, AccountListQuery, AccountType, CreateAccountRequest,
+    UpdateAccountRequest, DEFAULT_ORGANIZATION_ID,
+};
+use crate::repository::{
+    AccountRepository, DynUnitOfWorkFactory, EmailQueueRepository, RepositoryResult, UnitOfWork,
+    UnitOfWorkFactory,
+};
+
+/// キャッシュごとの最大件数
+const MAX_CAPACITY: u64 = 10_000;
+
+/// 全組織で共有するキャッシュ（キーの先頭は組織 ID）
+#[derive(Clone)]
+struct AccountCaches {
+    by_id: Cache<(Uuid, Uuid), Account>,
+    by_code: Cache<(Uuid, String), Account>,
+    all: Cache<Uuid, Arc<Vec<Account>>>,
+}
+
+impl AccountCaches {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            by_id: Cache::builder()
+                .time_to_live(ttl)
+                .max_capacity(MAX_CAPACITY)
+                .support_invalidation_closures()
+                .build(),
+            by_code: Cache::builder()
+                .time_to_live(ttl)
+                .max_capacity(MAX_CAPACITY)
+                .support_invalidation_closures()
+                .build(),
+            all: Cache::builder()
+                .time_to_live(ttl)
+                .max_capacity(MAX_CAPACITY)
+                .build(),
+        }
+    }
+
+    /// 組織のキャッシュをすべて破棄する
+    async fn invalidate(&self, organization_id: Uuid) {
+        // 述語による破棄は以降の読み取りに即座に反映される
+        if let Err(err) = self
+            .by_id
+            .invalidate_entries_if(move |key, _| key.0 == organization_id)
+        {
+            tracing::warn!("Failed to invalidate account cache: {}", err);
+            self.by_id.invalidate_all();
+        }
+        if let Err(err) = self
+            .by_code
+            .invalidate_entries_if(move |key, _| key.0 == organization_id)
+        {
+            tracing::warn!("Failed to invalidate account cache: {}", err);
+            self.by_code.invalidate_all();
+        }
+        self.all.invalidate(&organization_id).await;
+    }
+}
+
+/// 参照系（ID・科目コード・一覧）をプロセス内にキャッシュするリポジトリ
+///
+/// 更新系が成功すると同じ組織のキャッシュをすべて破棄する。
+/// 他のインスタンスでの更新は TTL が切れるまで反映されない。
+pub struct CachedAccountRepository {
+    inner: Arc<dyn AccountRepository>,
+    caches: AccountCaches,
+    organization_id: Uuid,
+}
+
+impl CachedAccountRepository {
+    pub fn new(inner: Arc<dyn AccountRepository>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            caches: AccountCaches::new(ttl),
+            organization_id: DEFAULT_ORGANIZATION_ID,
+        }
+    }
+
+    /// 作業単位の確定時にこのキャッシュを破棄する作業単位の開始
+    ///
+    /// 作業単位の中の読み書きはキャッシュを通さない。
+    pub fn unit_of_work(&self, factory: DynUnitOfWorkFactory) -> DynUnitOfWorkFactory {
+        Arc::new(CachedUnitOfWorkFactory {
+            inner: factory,
+            caches: self.caches.clone(),
+        })
+    }
+
+    async fn invalidate(&self) {
+        self.caches.invalidate(self.organization_id).await;
+    }
+}
+
+struct CachedUnitOfWorkFactory {
+    inner: DynUnitOfWorkFactory,
+    caches: AccountCaches,
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for CachedUnitOfWorkFactory {
+    async fn begin(&self, organization_id: Uuid) -> RepositoryResult<Box<dyn UnitOfWork>> {
+        Ok(Box::new(CachedUnitOfWork {
+            inner: self.inner.begin(organization_id).await?,
+            caches: self.caches.clone(),
+            organization_id,
+        }))
+    }
+}
+
+struct CachedUnitOfWork {
+    inner: Box<dyn UnitOfWork>,
+    caches: AccountCaches,
+    organization_id: Uuid,
+}
+
+#[async_trait]
+impl UnitOfWork for CachedUnitOfWork {
+    fn accounts(&self) -> Arc<dyn AccountRepository> {
+        self.inner.accounts()
+    }
+
+    fn email_queue(&self) -> Arc<dyn EmailQueueRepository> {
+        self.inner.email_queue()
+    }
+
+    async fn commit(self: Box<Self>) -> RepositoryResult<()> {
+        self.inner.commit().await?;
+        self.caches.invalidate(self.organization_id).await;
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> RepositoryResult<()> {
+        self.inner.rollback().await
+    }
+}
+
+#[async_trait]
+impl AccountRepository for CachedAccountRepository {
+    async fn create(&self, request: CreateAccountRequest) -> RepositoryResult<Account> {
+        let account = self.inner.create(request).await?;
+        self.invalidate().await;
+        Ok(account)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Account>> {
+        let key = (self.organization_id, id);
+        if let Some(account) = self.caches.by_id.get(&key).await {
+            return Ok(Some(account));
+        }
+
+        let account = self.inner.find_by_id(id).await?;
+        if let Some(account) = &account {
+            self.caches.by_id.insert(key, account.clone()).await;
+        }
+        Ok(account)
+    }
+
+    async fn find_by_code(&self, code: &str) -> RepositoryResult<Option<Account>> {
+        let key = (self.organization_id, code.to_string());
+        if let Some(account) = self.caches.by_code.get(&key).await {
+            return Ok(Some(account));
+        }
+
+        let account = self.inner.find_by_code(code).await?;
+        if let Some(account) = &account {
+            self.caches.by_code.insert(key, account.clone()).await;
+        }
+        Ok(account)
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<Account>> {
+        if let Some(accounts) = self.caches.all.get(&self.organization_id).await {
+            return Ok(accounts.as_ref().clone());
+        }
+
+        let accounts = self.inner.find_all().await?;
+        self.caches
+            .all
+            .insert(self.organization_id, Arc::new(accounts.clone()))
+            .await;
+        Ok(accounts)
+    }
+
+    async fn find_active(&self) -> RepositoryResult<Vec<Account>> {
+        let accounts = self.find_all().await?;
+        Ok(accounts.into_iter().filter(|a| a.is_active).collect())
+    }
+
+    async fn find_by_type(&self, account_type: AccountType) -> RepositoryResult<Vec<Account>> {
+        self.inner.find_by_type(account_type).await
+    }
+
+    async fn list(&self, query: AccountListQuery) -> RepositoryResult<Vec<Account>> {
+        self.inner.list(query).await
+    }
+
+    async fn update(&self, id: Uuid, request: UpdateAccountRequest) -> RepositoryResult<Account> {
+        let account = self.inner.update(id, request).await?;
+        self.invalidate().await;
+        Ok(account)
+    }
+
+    async fn update_if_unmodified(
+        &self,
+        id: Uuid,
+        request: UpdateAccountRequest,
+        versions: &[DateTime<Utc>],
+    ) -> RepositoryResult<Account> {
+        let account = self
+            .inner
+            .update_if_unmodified(id, request, versions)
+            .await?;
+        self.invalidate().await;
+        Ok(account)
+    }
+
+    async fn reorder(&self, account_ids: &[Uuid]) -> RepositoryResult<Vec<Account>> {
+        let accounts = self.inner.reorder(account_ids).await?;
+        self.invalidate().await;
+        Ok(accounts)
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()> {
+        self.inner.soft_delete(id).await?;
+        self.invalidate().await;
+        Ok(())
+    }
+
+    async fn soft_delete_if_unmodified(
+        &self,
+        id: Uuid,
+        versions: &[DateTime<Utc>],
+    ) -> RepositoryResult<()> {
+        self.inner.soft_delete_if_unmodified(id, versions).await?;
+        self.invalidate().await;
+        Ok(())
+    }
+
+    async fn exists_by_code(&self, code: &str) -> RepositoryResult<bool> {
+        self.inner.exists_by_code(code).await
+    }
+
+    async fn find_children(&self, parent_id: Uuid) -> RepositoryResult<Vec<Account>> {
+        self.inner.find_children(parent_id).await
+    }
+
+    async fn last_modified(&self) -> RepositoryResult<Option<DateTime<Utc>>> {
+        self.inner.last_modified().await
+    }
+
+    async fn find_as_of(
+        &self,
+        id: Uuid,
+        as_of: DateTime<Utc>,
+    ) -> RepositoryResult<Option<Account>> {
+        self.inner.find_as_of(id, as_of).await
+    }
+
+    async fn next_code(&self, category: &AccountCategory) -> RepositoryResult<Option<String>> {
+        self.inner.next_code(category).await
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn AccountRepository> {
+        Arc::new(Self {
+            inner: self.inner.for_organization(organization_id),
+            caches: self.caches.clone(),
+            organization_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::AccountCategory;
+    use crate::repository::{
+        InMemoryAccountRepository, InMemoryEmailQueueRepository, InMemoryUnitOfWorkFactory,
+    };
+
+    fn request(code: &str) -> CreateAccountRequest {
+        CreateAccountRequest {
+            code: code.to_string(),
+            name: "現金".to_string(),
+            category: AccountCategory::Cash,
+            description: None,
+            display_order: None,
+            parent_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reads_are_cached_and_writes_invalidate() {
+        let inner = Arc::new(InMemoryAccountRepository::new());
+        let repo = CachedAccountRepository::new(inner.clone(), Duration::from_secs(60));
+        let account = repo.create(request("101")).await.unwrap();
+
+        assert_eq!(repo.find_all().await.unwrap().len(), 1);
+        assert!(repo.find_by_code("101").await.unwrap().is_some());
+
+        // キャッシュを経由しない更新は TTL まで反映されない
+        inner.create(request("102")).await.unwrap();
+        assert_eq!(repo.find_all().await.unwrap().len(), 1);
+
+        // キャッシュを経由した更新で破棄される
+        repo.soft_delete(account.id).await.unwrap();
+        assert_eq!(repo.find_all().await.unwrap().len(), 2);
+        let accounts = repo.find_active().await.unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].code, "102");
+        assert!(
+            !repo
+                .find_by_id(account.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .is_active
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work_commit_invalidates() {
+        let inner = InMemoryAccountRepository::new();
+        let factory =
+            InMemoryUnitOfWorkFactory::new(&inner, Arc::new(InMemoryEmailQueueRepository::new()));
+        let repo = CachedAccountRepository::new(Arc::new(inner), Duration::from_secs(60));
+        let factory = repo.unit_of_work(Arc::new(factory));
+        assert!(repo.find_all().await.unwrap().is_empty());
+
+        let uow = factory.begin(DEFAULT_ORGANIZATION_ID).await.unwrap();
+        uow.accounts().create(request("101")).await.unwrap();
+        assert!(repo.find_all().await.unwrap().is_empty());
+
+        uow.commit().await.unwrap();
+        assert_eq!(repo.find_all().await.unwrap().len(), 1);
+        assert!(repo.find_by_code("101").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cache_is_scoped_by_organization() {
+        let repo = CachedAccountRepository::new(
+            Arc::new(InMemoryAccountRepository::new()),
+            Duration::from_secs(60),
+        );
+        let org = repo.for_organization(Uuid::new_v4());
+
+        let account = repo.create(request("101")).await.unwrap();
+        assert!(repo.find_by_id(account.id).await.unwrap().is_some());
+
+        assert!(org.find_by_id(account.id).await.unwrap().is_none());
+        assert!(org.find_by_code("101").await.unwrap().is_none());
+        assert!(org.find_all().await.unwrap().is_empty());
+    }
+}
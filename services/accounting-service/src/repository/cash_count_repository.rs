@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{CashCount, CashCountFilter, CreateCashCountRequest};
+use crate::repository::RepositoryResult;
+
+/// 金種表リポジトリインターフェース
+#[async_trait]
+pub trait CashCountRepository: Send + Sync {
+    /// 金種表を登録
+    async fn create(&self, request: CreateCashCountRequest) -> RepositoryResult<CashCount>;
+
+    /// IDで金種表を取得
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<CashCount>>;
+
+    /// 金種表一覧を取得（実査日時の新しい順）
+    async fn find_all(&self, filter: CashCountFilter) -> RepositoryResult<Vec<CashCount>>;
+
+    /// 金庫ごとの最新の金種表を取得
+    async fn find_latest(&self, safe: &str) -> RepositoryResult<Option<CashCount>> {
+        let counts = self
+            .find_all(CashCountFilter {
+                safe: Some(safe.to_string()),
+                ..Default::default()
+            })
+            .await?;
+        Ok(counts.into_iter().next())
+    }
+
+    /// 指定した組織に限定したリポジトリ
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn CashCountRepository>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{DenominationCount, Money};
+    use crate::repository::InMemoryCashCountRepository;
+    use chrono::{DateTime, TimeZone, Utc};
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, 18, 0, 0).unwrap()
+    }
+
+    fn request(safe: &str, day: u32, ten_thousands: i64) -> CreateCashCountRequest {
+        CreateCashCountRequest {
+            safe: safe.to_string(),
+            counted_at: Some(at(day)),
+            lines: vec![
+                DenominationCount {
+                    denomination: 10000,
+                    quantity: ten_thousands,
+                },
+                DenominationCount {
+                    denomination: 100,
+                    quantity: 5,
+                },
+            ],
+            book_balance: None,
+            counted_by: Some("会計担当".to_string()),
+            note: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_and_find() {
+        let repo = InMemoryCashCountRepository::new();
+
+        let created = repo.create(request("MAIN", 1, 2)).await.unwrap();
+        let found = repo.find_by_id(created.id).await.unwrap().unwrap();
+
+        assert_eq!(found.total.amount, 20500);
+        assert_eq!(found, created);
+    }
+
+    #[tokio::test]
+    async fn test_find_all_filter_and_latest() {
+        let repo = InMemoryCashCountRepository::new();
+        let _ = repo.create(request("MAIN", 1, 2)).await.unwrap();
+        let _ = repo.create(request("MAIN", 5, 3)).await.unwrap();
+        let _ = repo.create(request("PETTY", 3, 1)).await.unwrap();
+
+        let main = repo
+            .find_all(CashCountFilter {
+                safe: Some("MAIN".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let march_first_week = repo
+            .find_all(CashCountFilter {
+                from: Some(at(2)),
+                to: Some(at(5)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let latest = repo.find_latest("MAIN").await.unwrap().unwrap();
+
+        assert_eq!(main.len(), 2);
+        assert_eq!(main[0].counted_at, at(5));
+        assert_eq!(march_first_week.len(), 1);
+        assert_eq!(march_first_week[0].safe, "PETTY");
+        assert_eq!(latest.total, Money::new(30500, latest.total.currency));
+        assert!(repo.find_latest("NONE").await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{CategoryDefinition, CreateCategoryRequest};
+use crate::repository::RepositoryResult;
+
+/// 勘定科目カテゴリリポジトリインターフェース
+///
+/// 組み込みのカテゴリと、自組織が定義したカテゴリを扱う。
+#[async_trait]
+pub trait CategoryRepository: Send + Sync {
+    /// 自組織のカテゴリを定義（組み込みと同じコードは重複として拒否）
+    async fn create(&self, request: CreateCategoryRequest) -> RepositoryResult<CategoryDefinition>;
+
+    /// 有効なカテゴリの一覧を取得（組み込み、自組織の定義の順）
+    async fn find_all(&self) -> RepositoryResult<Vec<CategoryDefinition>>;
+
+    /// コードで有効なカテゴリを取得
+    async fn find_by_code(&self, code: &str) -> RepositoryResult<Option<CategoryDefinition>>;
+
+    /// 自組織のカテゴリを論理削除（既存の勘定科目のカテゴリはそのまま）
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()>;
+
+    /// 指定した組織に限定したリポジトリ
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn CategoryRepository>;
+}
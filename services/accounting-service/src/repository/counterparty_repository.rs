@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{Counterparty, CreateCounterpartyRequest, UpdateCounterpartyRequest};
+use crate::repository::RepositoryResult;
+
+/// 取引先リポジトリインターフェース
+#[async_trait]
+pub trait CounterpartyRepository: Send + Sync {
+    /// 取引先を登録
+    async fn create(&self, request: CreateCounterpartyRequest) -> RepositoryResult<Counterparty>;
+
+    /// IDで取引先を取得
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Counterparty>>;
+
+    /// 有効な取引先の一覧を取得（名前順）
+    async fn find_all(&self) -> RepositoryResult<Vec<Counterparty>>;
+
+    /// 取引先を更新
+    async fn update(
+        &self,
+        id: Uuid,
+        request: UpdateCounterpartyRequest,
+    ) -> RepositoryResult<Counterparty>;
+
+    /// 取引先を論理削除（is_active = false）
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()>;
+
+    /// 指定した組織に限定したリポジトリ
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn CounterpartyRepository>;
+}
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::{EmailMessage, QueuedEmail};
+use crate::repository::RepositoryResult;
+
+/// メール送信キュー（アウトボックス）インターフェース
+#[async_trait]
+pub trait EmailQueueRepository: Send + Sync {
+    /// 送信待ちとして積む
+    async fn enqueue(&self, message: EmailMessage) -> RepositoryResult<QueuedEmail>;
+
+    /// IDで取得
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<QueuedEmail>>;
+
+    /// 送信時刻を迎えた送信待ちを古い順に取り出す
+    ///
+    /// 取り出したメールの次回送信時刻を `lease` だけ先に延ばし、他のワーカーとの
+    /// 二重送信を防ぐ（結果を記録しないまま停止した場合は期限後に再送される）。
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+        lease: Duration,
+    ) -> RepositoryResult<Vec<QueuedEmail>>;
+
+    /// 送信済みにする
+    async fn mark_sent(&self, id: Uuid) -> RepositoryResult<()>;
+
+    /// 送信失敗を記録する（`retry_at` が None なら再試行を打ち切る）
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> RepositoryResult<()>;
+}
@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{CreateExchangeRateRequest, Currency, ExchangeRate, UpdateExchangeRateRequest};
+use crate::repository::RepositoryResult;
+
+/// 為替レートリポジトリインターフェース
+#[async_trait]
+pub trait ExchangeRateRepository: Send + Sync {
+    /// 為替レートを作成（同一通貨ペア・適用日の重複は Conflict）
+    async fn create(&self, request: CreateExchangeRateRequest) -> RepositoryResult<ExchangeRate>;
+
+    /// IDで為替レートを取得
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<ExchangeRate>>;
+
+    /// 為替レート一覧を取得（通貨で絞り込み、適用日の新しい順）
+    async fn find_all(
+        &self,
+        base_currency: Option<Currency>,
+        quote_currency: Option<Currency>,
+    ) -> RepositoryResult<Vec<ExchangeRate>>;
+
+    /// 指定日に有効な為替レート（適用日が指定日以前で最新のもの）を取得
+    async fn find_effective(
+        &self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        on: NaiveDate,
+    ) -> RepositoryResult<Option<ExchangeRate>>;
+
+    /// 為替レートを更新
+    async fn update(
+        &self,
+        id: Uuid,
+        request: UpdateExchangeRateRequest,
+    ) -> RepositoryResult<ExchangeRate>;
+
+    /// 為替レートを削除
+    async fn delete(&self, id: Uuid) -> RepositoryResult<()>;
+
+    /// 指定した組織に限定したリポジトリ
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn ExchangeRateRepository>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::{InMemoryExchangeRateRepository, RepositoryError};
+    use rust_decimal::Decimal;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    fn usd_jpy(rate: i64, effective_date: NaiveDate) -> CreateExchangeRateRequest {
+        CreateExchangeRateRequest {
+            base_currency: Currency::USD,
+            quote_currency: Currency::JPY,
+            rate: Decimal::new(rate, 0),
+            effective_date,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_duplicate_conflict() {
+        let repo = InMemoryExchangeRateRepository::new();
+
+        let _ = repo.create(usd_jpy(150, date(1, 1))).await.unwrap();
+        let result = repo.create(usd_jpy(151, date(1, 1))).await;
+
+        assert!(matches!(result, Err(RepositoryError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_find_effective() {
+        let repo = InMemoryExchangeRateRepository::new();
+        let _ = repo.create(usd_jpy(150, date(1, 1))).await.unwrap();
+        let _ = repo.create(usd_jpy(155, date(2, 1))).await.unwrap();
+
+        let january = repo
+            .find_effective(Currency::USD, Currency::JPY, date(1, 31))
+            .await
+            .unwrap()
+            .unwrap();
+        let march = repo
+            .find_effective(Currency::USD, Currency::JPY, date(3, 1))
+            .await
+            .unwrap()
+            .unwrap();
+        let before = repo
+            .find_effective(Currency::USD, Currency::JPY, date(1, 1).pred_opt().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(january.rate, Decimal::new(150, 0));
+        assert_eq!(march.rate, Decimal::new(155, 0));
+        assert!(before.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_all_filter_and_order() {
+        let repo = InMemoryExchangeRateRepository::new();
+        let _ = repo.create(usd_jpy(150, date(1, 1))).await.unwrap();
+        let _ = repo.create(usd_jpy(155, date(2, 1))).await.unwrap();
+        let _ = repo
+            .create(CreateExchangeRateRequest {
+                base_currency: Currency::EUR,
+                quote_currency: Currency::JPY,
+                rate: Decimal::new(160, 0),
+                effective_date: date(1, 1),
+            })
+            .await
+            .unwrap();
+
+        let all = repo.find_all(None, None).await.unwrap();
+        let usd = repo.find_all(Some(Currency::USD), None).await.unwrap();
+
+        assert_eq!(all.len(), 3);
+        assert_eq!(usd.len(), 2);
+        assert_eq!(usd[0].effective_date, date(2, 1));
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete() {
+        let repo = InMemoryExchangeRateRepository::new();
+        let created = repo.create(usd_jpy(150, date(1, 1))).await.unwrap();
+
+        let updated = repo
+            .update(
+                created.id,
+                UpdateExchangeRateRequest {
+                    rate: Some(Decimal::new(14950, 2)),
+                    effective_date: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.rate, Decimal::new(14950, 2));
+
+        repo.delete(created.id).await.unwrap();
+        assert!(repo.find_by_id(created.id).await.unwrap().is_none());
+        assert!(matches!(
+            repo.delete(created.id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+}
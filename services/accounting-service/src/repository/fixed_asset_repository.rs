@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{CreateFixedAssetRequest, FixedAsset};
+use crate::repository::RepositoryResult;
+
+/// 固定資産台帳リポジトリインターフェース
+#[async_trait]
+pub trait FixedAssetRepository: Send + Sync {
+    /// 固定資産を登録
+    async fn create(&self, request: CreateFixedAssetRequest) -> RepositoryResult<FixedAsset>;
+
+    /// IDで固定資産を取得
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<FixedAsset>>;
+
+    /// 固定資産の一覧を取得（除却済みを含む、取得日順）
+    async fn find_all(&self) -> RepositoryResult<Vec<FixedAsset>>;
+
+    /// 固定資産を除却（is_active = false）
+    async fn dispose(&self, id: Uuid) -> RepositoryResult<()>;
+
+    /// 指定した組織に限定したリポジトリ
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn FixedAssetRepository>;
+}
@@ -1,23 +1,256 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use std::collections::HashMap;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 
-use crate::domain::{Account, AccountType, CreateAccountRequest, UpdateAccountRequest};
-use crate::repository::{AccountRepository, RepositoryError, RepositoryResult};
+use crate::domain::{
+    built_in_categories, sort_categories, validate_parent, Account, AccountCategory,
+    AccountCodeRanges, AccountListQuery, AccountType, CashCount, CashCountFilter,
+    CategoryDefinition, CodeReusePolicy, Counterparty, CreateAccountRequest,
+    CreateCashCountRequest, CreateCategoryRequest, CreateCounterpartyRequest,
+    CreateExchangeRateRequest, CreateFixedAssetRequest, CreateOrganizationRequest,
+    CreateWebhookRequest, Currency, EmailMessage, EmailStatus, ExchangeRate, FixedAsset,
+    MonthClose, Organization, QueuedEmail, SearchEntityType, SearchFacet, SearchHit, SearchQuery,
+    SearchResults, UpdateAccountRequest, UpdateCounterpartyRequest, UpdateExchangeRateRequest,
+    UpdateOrganizationRequest, UpdateWebhookRequest, Webhook, DEFAULT_ORGANIZATION_ID,
+};
+use crate::repository::{
+    AccountRepository, CashCountRepository, CategoryRepository, CounterpartyRepository,
+    EmailQueueRepository, ExchangeRateRepository, FixedAssetRepository, MonthCloseRepository,
+    OrganizationRepository, RepositoryError, RepositoryResult, SearchRepository, UnitOfWork,
+    UnitOfWorkFactory, WebhookRepository,
+};
+
+/// 勘定科目の保存先（await をまたいで保持するため tokio のロックを使う）
+type AccountStore = Arc<tokio::sync::RwLock<HashMap<Uuid, Account>>>;
+/// 変更ごとの勘定科目（`updated_at` から有効）
+type AccountHistory = Arc<tokio::sync::RwLock<Vec<Account>>>;
 
 /// インメモリ勘定科目リポジトリ（テスト用）
+///
+/// 全組織の勘定科目を共有し、各インスタンスは自組織の科目のみを扱う。
 pub struct InMemoryAccountRepository {
-    accounts: RwLock<HashMap<Uuid, Account>>,
+    accounts: AccountStore,
+    history: AccountHistory,
+    code_reuse_policy: CodeReusePolicy,
+    /// 科目種別ごとの科目コードの範囲（未指定なら検証しない）
+    code_ranges: Option<AccountCodeRanges>,
+    /// 組織定義のカテゴリの参照先（未指定なら組み込みのカテゴリのみ使える）
+    categories: Option<Arc<dyn CategoryRepository>>,
+    organization_id: Uuid,
 }
 
 impl InMemoryAccountRepository {
     pub fn new() -> Self {
         Self {
-            accounts: RwLock::new(HashMap::new()),
+            accounts: AccountStore::default(),
+            history: AccountHistory::default(),
+            code_reuse_policy: CodeReusePolicy::default(),
+            code_ranges: None,
+            categories: None,
+            organization_id: DEFAULT_ORGANIZATION_ID,
+        }
+    }
+
+    /// 論理削除済み科目コードの扱いを指定
+    pub fn with_code_reuse_policy(mut self, policy: CodeReusePolicy) -> Self {
+        self.code_reuse_policy = policy;
+        self
+    }
+
+    /// 作成時に科目コードが科目種別の範囲内か検証する
+    pub fn with_code_ranges(mut self, ranges: AccountCodeRanges) -> Self {
+        self.code_ranges = Some(ranges);
+        self
+    }
+
+    /// 組織定義のカテゴリを参照するリポジトリを指定
+    pub fn with_categories(mut self, categories: Arc<dyn CategoryRepository>) -> Self {
+        self.categories = Some(categories);
+        self
+    }
+
+    /// カテゴリの勘定科目種別（組織定義のカテゴリはカテゴリ定義から解決する）
+    async fn resolve_account_type(
+        &self,
+        category: &AccountCategory,
+    ) -> RepositoryResult<AccountType> {
+        if let Some(account_type) = category.account_type() {
+            return Ok(account_type);
+        }
+        let definition = match &self.categories {
+            Some(categories) => {
+                categories
+                    .for_organization(self.organization_id)
+                    .find_by_code(&category.to_string())
+                    .await?
+            }
+            None => None,
+        };
+        definition.map(|d| d.account_type).ok_or_else(|| {
+            RepositoryError::ValidationError(format!("Unknown account category: {}", category))
+        })
+    }
+
+    /// 同じ設定で、指定した保存先の組織の勘定科目を扱うリポジトリ
+    fn share(
+        &self,
+        accounts: AccountStore,
+        history: AccountHistory,
+        organization_id: Uuid,
+    ) -> Self {
+        Self {
+            accounts,
+            history,
+            code_reuse_policy: self.code_reuse_policy,
+            code_ranges: self.code_ranges.clone(),
+            categories: self.categories.clone(),
+            organization_id,
+        }
+    }
+
+    /// 変更後の勘定科目を履歴に記録する
+    async fn record(&self, account: &Account) {
+        self.history.write().await.push(account.clone());
+    }
+
+    /// 勘定科目を更新する（`versions` を指定すると現在の版がそのいずれかの場合だけ）
+    async fn update_account(
+        &self,
+        id: Uuid,
+        request: UpdateAccountRequest,
+        versions: Option<&[DateTime<Utc>]>,
+    ) -> RepositoryResult<Account> {
+        let mut accounts = self.accounts.write().await;
+
+        let current = accounts
+            .get(&id)
+            .filter(|a| a.organization_id == self.organization_id)
+            .ok_or(RepositoryError::NotFound(id))?;
+        check_version(current, versions)?;
+
+        // 再有効化時は有効な科目とのコード重複を確認
+        if request.is_active == Some(true)
+            && !current.is_active
+            && accounts
+                .values()
+                .any(|a| a.id != id && a.is_active && a.code == current.code)
+        {
+            return Err(RepositoryError::DuplicateCode(current.code.clone()));
+        }
+
+        if let Some(parent_id) = request.parent_id.as_value() {
+            check_parent(
+                &accounts,
+                Some(id),
+                current.account_type,
+                *parent_id,
+                self.organization_id,
+            )?;
+        }
+
+        let account = accounts
+            .get_mut(&id)
+            .filter(|a| a.organization_id == self.organization_id)
+            .ok_or(RepositoryError::NotFound(id))?;
+
+        if let Some(name) = request.name {
+            account.name = name;
+        }
+        account.description = request.description.apply(account.description.take());
+        if let Some(display_order) = request.display_order {
+            account.display_order = display_order;
+        }
+        if let Some(is_active) = request.is_active {
+            account.is_active = is_active;
+        }
+        account.parent_id = request.parent_id.apply(account.parent_id);
+
+        account.updated_at = Utc::now();
+        let account = account.clone();
+        self.record(&account).await;
+
+        Ok(account)
+    }
+
+    /// 勘定科目を論理削除する（`versions` の扱いは `update_account` と同じ）
+    async fn deactivate(
+        &self,
+        id: Uuid,
+        versions: Option<&[DateTime<Utc>]>,
+    ) -> RepositoryResult<()> {
+        let mut accounts = self.accounts.write().await;
+
+        let account = accounts
+            .get_mut(&id)
+            .filter(|a| a.organization_id == self.organization_id)
+            .ok_or(RepositoryError::NotFound(id))?;
+        check_version(account, versions)?;
+
+        account.is_active = false;
+        account.updated_at = Utc::now();
+        let account = account.clone();
+        self.record(&account).await;
+
+        Ok(())
+    }
+
+    /// 自組織の勘定科目
+    fn scoped<'a>(
+        &self,
+        accounts: &'a HashMap<Uuid, Account>,
+    ) -> impl Iterator<Item = &'a Account> + 'a {
+        let organization_id = self.organization_id;
+        accounts
+            .values()
+            .filter(move |a| a.organization_id == organization_id)
+    }
+}
+
+/// 現在の版が指定のいずれかか（None は版を問わない。ETag と同じくマイクロ秒単位で比べる）
+fn check_version(account: &Account, versions: Option<&[DateTime<Utc>]>) -> RepositoryResult<()> {
+    let updated_at = account.updated_at.timestamp_micros();
+    match versions {
+        Some(versions) if !versions.iter().any(|v| v.timestamp_micros() == updated_at) => {
+            Err(RepositoryError::Modified(account.id))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 親勘定科目の存在・種別・循環を検証する（親は同じ組織の科目に限る）
+fn check_parent(
+    accounts: &HashMap<Uuid, Account>,
+    account_id: Option<Uuid>,
+    account_type: AccountType,
+    parent_id: Uuid,
+    organization_id: Uuid,
+) -> RepositoryResult<()> {
+    let parent = accounts
+        .get(&parent_id)
+        .filter(|p| p.organization_id == organization_id)
+        .ok_or_else(|| {
+            RepositoryError::ValidationError(format!("Parent account not found: {}", parent_id))
+        })?;
+    validate_parent(account_id, account_type, parent).map_err(RepositoryError::ValidationError)?;
+
+    // 親の祖先に自身が含まれていれば循環
+    if let Some(id) = account_id {
+        let mut current = parent.parent_id;
+        let mut depth = 0;
+        while let Some(ancestor_id) = current {
+            if ancestor_id == id || depth > accounts.len() {
+                return Err(RepositoryError::ValidationError(
+                    "勘定科目の親子関係が循環しています".to_string(),
+                ));
+            }
+            current = accounts.get(&ancestor_id).and_then(|a| a.parent_id);
+            depth += 1;
         }
     }
+
+    Ok(())
 }
 
 impl Default for InMemoryAccountRepository {
@@ -29,129 +262,1446 @@ impl Default for InMemoryAccountRepository {
 #[async_trait]
 impl AccountRepository for InMemoryAccountRepository {
     async fn create(&self, request: CreateAccountRequest) -> RepositoryResult<Account> {
-        let mut accounts = self
-            .accounts
-            .write()
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let account_type = self.resolve_account_type(&request.category).await?;
+        if let Some(ranges) = &self.code_ranges {
+            ranges
+                .check(&request.code, account_type)
+                .map_err(RepositoryError::ValidationError)?;
+        }
+        let mut accounts = self.accounts.write().await;
 
         // 重複チェック
-        if accounts.values().any(|a| a.code == request.code) {
+        if self
+            .scoped(&accounts)
+            .any(|a| a.is_active && a.code == request.code)
+        {
             return Err(RepositoryError::DuplicateCode(request.code));
         }
 
-        let account = Account::new(
+        if let Some(parent_id) = request.parent_id {
+            check_parent(
+                &accounts,
+                None,
+                account_type,
+                parent_id,
+                self.organization_id,
+            )?;
+        }
+
+        match self.code_reuse_policy {
+            CodeReusePolicy::Reject => {
+                if self.scoped(&accounts).any(|a| a.code == request.code) {
+                    return Err(RepositoryError::DuplicateCode(request.code));
+                }
+            }
+            CodeReusePolicy::Allow => {}
+            CodeReusePolicy::Revive => {
+                let revived_id = accounts
+                    .values()
+                    .filter(|a| a.code == request.code)
+                    .max_by_key(|a| a.updated_at)
+                    .map(|a| a.id);
+
+                if let Some(revived_id) = revived_id {
+                    if let Some(parent_id) = request.parent_id {
+                        check_parent(
+                            &accounts,
+                            Some(revived_id),
+                            account_type,
+                            parent_id,
+                            self.organization_id,
+                        )?;
+                    }
+
+                    let account = accounts
+                        .get_mut(&revived_id)
+                        .ok_or(RepositoryError::NotFound(revived_id))?;
+                    account.name = request.name;
+                    account.account_type = account_type;
+                    account.category = request.category;
+                    account.description = request.description;
+                    account.display_order = request.display_order.unwrap_or(0);
+                    account.parent_id = request.parent_id;
+                    account.is_active = true;
+                    account.updated_at = Utc::now();
+                    let account = account.clone();
+                    self.record(&account).await;
+                    return Ok(account);
+                }
+            }
+        }
+
+        let mut account = Account::new(
             request.code,
             request.name,
+            account_type,
             request.category,
             request.description,
             request.display_order.unwrap_or(0),
         );
+        account.organization_id = self.organization_id;
+        account.parent_id = request.parent_id;
 
         accounts.insert(account.id, account.clone());
+        self.record(&account).await;
 
         Ok(account)
     }
 
     async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Account>> {
-        let accounts = self
-            .accounts
-            .read()
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let accounts = self.accounts.read().await;
 
-        Ok(accounts.get(&id).cloned())
+        Ok(accounts
+            .get(&id)
+            .filter(|a| a.organization_id == self.organization_id)
+            .cloned())
     }
 
     async fn find_by_code(&self, code: &str) -> RepositoryResult<Option<Account>> {
-        let accounts = self
-            .accounts
-            .read()
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let accounts = self.accounts.read().await;
 
-        Ok(accounts.values().find(|a| a.code == code).cloned())
+        // 同一コードが複数ある場合は有効なもの、次に最新のものを優先
+        Ok(self
+            .scoped(&accounts)
+            .filter(|a| a.code == code)
+            .max_by_key(|a| (a.is_active, a.updated_at))
+            .cloned())
     }
 
     async fn find_all(&self) -> RepositoryResult<Vec<Account>> {
-        let accounts = self
-            .accounts
-            .read()
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let accounts = self.accounts.read().await;
 
-        let mut result: Vec<Account> = accounts
-            .values()
+        let mut result: Vec<Account> = self.scoped(&accounts).cloned().collect();
+        result.sort_by_key(|a| a.display_order);
+
+        Ok(result)
+    }
+
+    async fn find_active(&self) -> RepositoryResult<Vec<Account>> {
+        let accounts = self.accounts.read().await;
+
+        let mut result: Vec<Account> = self
+            .scoped(&accounts)
             .filter(|a| a.is_active)
             .cloned()
             .collect();
-        result.sort_by(|a, b| a.display_order.cmp(&b.display_order));
+        result.sort_by_key(|a| a.display_order);
 
         Ok(result)
     }
 
     async fn find_by_type(&self, account_type: AccountType) -> RepositoryResult<Vec<Account>> {
-        let accounts = self
-            .accounts
-            .read()
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let accounts = self.accounts.read().await;
 
-        let mut result: Vec<Account> = accounts
-            .values()
+        let mut result: Vec<Account> = self
+            .scoped(&accounts)
             .filter(|a| a.is_active && a.account_type == account_type)
             .cloned()
             .collect();
-        result.sort_by(|a, b| a.display_order.cmp(&b.display_order));
+        result.sort_by_key(|a| a.display_order);
+
+        Ok(result)
+    }
+
+    async fn list(&self, query: AccountListQuery) -> RepositoryResult<Vec<Account>> {
+        let accounts = self.accounts.read().await;
+
+        let mut result: Vec<Account> = self
+            .scoped(&accounts)
+            .filter(|a| query.matches(a))
+            .cloned()
+            .collect();
+        query.sort(&mut result);
 
         Ok(result)
     }
 
     async fn update(&self, id: Uuid, request: UpdateAccountRequest) -> RepositoryResult<Account> {
-        let mut accounts = self
-            .accounts
-            .write()
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        self.update_account(id, request, None).await
+    }
 
-        let account = accounts
-            .get_mut(&id)
-            .ok_or(RepositoryError::NotFound(id))?;
+    async fn update_if_unmodified(
+        &self,
+        id: Uuid,
+        request: UpdateAccountRequest,
+        versions: &[DateTime<Utc>],
+    ) -> RepositoryResult<Account> {
+        self.update_account(id, request, Some(versions)).await
+    }
 
-        if let Some(name) = request.name {
-            account.name = name;
+    async fn reorder(&self, account_ids: &[Uuid]) -> RepositoryResult<Vec<Account>> {
+        let mut accounts = self.accounts.write().await;
+
+        if let Some(missing) = account_ids.iter().find(|id| {
+            accounts
+                .get(id)
+                .is_none_or(|a| a.organization_id != self.organization_id)
+        }) {
+            return Err(RepositoryError::NotFound(*missing));
         }
-        if let Some(description) = request.description {
-            account.description = Some(description);
+
+        let now = Utc::now();
+        let mut result = Vec::with_capacity(account_ids.len());
+        for (position, id) in (1..).zip(account_ids) {
+            if let Some(account) = accounts.get_mut(id) {
+                account.display_order = position;
+                account.updated_at = now;
+                result.push(account.clone());
+            }
         }
-        if let Some(display_order) = request.display_order {
-            account.display_order = display_order;
+        for account in &result {
+            self.record(account).await;
         }
-        if let Some(is_active) = request.is_active {
-            account.is_active = is_active;
+
+        Ok(result)
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()> {
+        self.deactivate(id, None).await
+    }
+
+    async fn soft_delete_if_unmodified(
+        &self,
+        id: Uuid,
+        versions: &[DateTime<Utc>],
+    ) -> RepositoryResult<()> {
+        self.deactivate(id, Some(versions)).await
+    }
+
+    async fn exists_by_code(&self, code: &str) -> RepositoryResult<bool> {
+        let accounts = self.accounts.read().await;
+
+        let exists = self.scoped(&accounts).any(|a| a.code == code);
+        Ok(exists)
+    }
+
+    async fn find_children(&self, parent_id: Uuid) -> RepositoryResult<Vec<Account>> {
+        let accounts = self.accounts.read().await;
+
+        let mut result: Vec<Account> = self
+            .scoped(&accounts)
+            .filter(|a| a.is_active && a.parent_id == Some(parent_id))
+            .cloned()
+            .collect();
+        result.sort_by_key(|a| a.display_order);
+
+        Ok(result)
+    }
+
+    async fn last_modified(&self) -> RepositoryResult<Option<DateTime<Utc>>> {
+        let accounts = self.accounts.read().await;
+
+        let last_modified = self.scoped(&accounts).map(|a| a.updated_at).max();
+        Ok(last_modified)
+    }
+
+    async fn find_as_of(
+        &self,
+        id: Uuid,
+        as_of: DateTime<Utc>,
+    ) -> RepositoryResult<Option<Account>> {
+        let history = self.history.read().await;
+
+        // 同時刻の変更は後に記録したものを優先
+        Ok(history
+            .iter()
+            .filter(|a| a.id == id && a.organization_id == self.organization_id)
+            .filter(|a| a.updated_at <= as_of)
+            .max_by_key(|a| a.updated_at)
+            .cloned())
+    }
+
+    async fn next_code(&self, category: &AccountCategory) -> RepositoryResult<Option<String>> {
+        let account_type = self.resolve_account_type(category).await?;
+        let accounts = self.accounts.read().await;
+
+        let ranges = self.code_ranges.clone().unwrap_or_default();
+        Ok(ranges.next_code(
+            account_type,
+            self.scoped(&accounts).map(|a| a.code.as_str()),
+        ))
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn AccountRepository> {
+        Arc::new(self.share(
+            Arc::clone(&self.accounts),
+            Arc::clone(&self.history),
+            organization_id,
+        ))
+    }
+}
+
+/// インメモリ勘定科目カテゴリリポジトリ（テスト用）
+///
+/// 組み込みのカテゴリと全組織の定義を共有し、各インスタンスは自組織の定義のみを扱う。
+pub struct InMemoryCategoryRepository {
+    definitions: Arc<RwLock<HashMap<Uuid, CategoryDefinition>>>,
+    organization_id: Uuid,
+}
+
+impl InMemoryCategoryRepository {
+    pub fn new() -> Self {
+        let definitions = built_in_categories()
+            .into_iter()
+            .map(|definition| (definition.id, definition))
+            .collect();
+        Self {
+            definitions: Arc::new(RwLock::new(definitions)),
+            organization_id: DEFAULT_ORGANIZATION_ID,
         }
+    }
 
-        account.updated_at = Utc::now();
+    /// 組み込み、または自組織の有効なカテゴリか
+    fn visible(&self, definition: &CategoryDefinition) -> bool {
+        definition.is_active
+            && definition
+                .organization_id
+                .is_none_or(|id| id == self.organization_id)
+    }
+}
+
+impl Default for InMemoryCategoryRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for InMemoryCategoryRepository {
+    async fn create(&self, request: CreateCategoryRequest) -> RepositoryResult<CategoryDefinition> {
+        let mut definitions = self
+            .definitions
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if definitions
+            .values()
+            .any(|d| self.visible(d) && d.code == request.code)
+        {
+            return Err(RepositoryError::Conflict(format!(
+                "Category code already exists: {}",
+                request.code
+            )));
+        }
+
+        let definition = CategoryDefinition::new(
+            self.organization_id,
+            request.code,
+            request.name,
+            request.account_type,
+        );
+        definitions.insert(definition.id, definition.clone());
+
+        Ok(definition)
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<CategoryDefinition>> {
+        let definitions = self
+            .definitions
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut result: Vec<CategoryDefinition> = definitions
+            .values()
+            .filter(|d| self.visible(d))
+            .cloned()
+            .collect();
+        sort_categories(&mut result);
 
-        Ok(account.clone())
+        Ok(result)
+    }
+
+    async fn find_by_code(&self, code: &str) -> RepositoryResult<Option<CategoryDefinition>> {
+        let definitions = self
+            .definitions
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(definitions
+            .values()
+            .find(|d| self.visible(d) && d.code == code)
+            .cloned())
     }
 
     async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()> {
-        let mut accounts = self
-            .accounts
+        let mut definitions = self
+            .definitions
             .write()
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        let account = accounts
+        let definition = definitions
             .get_mut(&id)
+            .filter(|d| d.is_active && d.organization_id == Some(self.organization_id))
             .ok_or(RepositoryError::NotFound(id))?;
-
-        account.is_active = false;
-        account.updated_at = Utc::now();
+        definition.is_active = false;
+        definition.updated_at = Utc::now();
 
         Ok(())
     }
 
-    async fn exists_by_code(&self, code: &str) -> RepositoryResult<bool> {
-        let accounts = self
-            .accounts
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn CategoryRepository> {
+        Arc::new(Self {
+            definitions: Arc::clone(&self.definitions),
+            organization_id,
+        })
+    }
+}
+
+/// インメモリ為替レートリポジトリ（テスト用）
+///
+/// 全組織の為替レートを共有し、各インスタンスは自組織のレートのみを扱う。
+pub struct InMemoryExchangeRateRepository {
+    rates: Arc<RwLock<HashMap<Uuid, ExchangeRate>>>,
+    organization_id: Uuid,
+}
+
+impl InMemoryExchangeRateRepository {
+    pub fn new() -> Self {
+        Self {
+            rates: Arc::new(RwLock::new(HashMap::new())),
+            organization_id: DEFAULT_ORGANIZATION_ID,
+        }
+    }
+}
+
+impl Default for InMemoryExchangeRateRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn duplicate_rate_error(rate: &ExchangeRate) -> RepositoryError {
+    RepositoryError::Conflict(format!(
+        "Exchange rate already exists: {}/{} on {}",
+        rate.base_currency, rate.quote_currency, rate.effective_date
+    ))
+}
+
+#[async_trait]
+impl ExchangeRateRepository for InMemoryExchangeRateRepository {
+    async fn create(&self, request: CreateExchangeRateRequest) -> RepositoryResult<ExchangeRate> {
+        let mut rates = self
+            .rates
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let rate = ExchangeRate::new(
+            self.organization_id,
+            request.base_currency,
+            request.quote_currency,
+            request.rate,
+            request.effective_date,
+        );
+
+        if rates.values().any(|r| {
+            r.organization_id == self.organization_id
+                && r.base_currency == rate.base_currency
+                && r.quote_currency == rate.quote_currency
+                && r.effective_date == rate.effective_date
+        }) {
+            return Err(duplicate_rate_error(&rate));
+        }
+
+        rates.insert(rate.id, rate.clone());
+
+        Ok(rate)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<ExchangeRate>> {
+        let rates = self
+            .rates
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rates
+            .get(&id)
+            .filter(|r| r.organization_id == self.organization_id)
+            .cloned())
+    }
+
+    async fn find_all(
+        &self,
+        base_currency: Option<Currency>,
+        quote_currency: Option<Currency>,
+    ) -> RepositoryResult<Vec<ExchangeRate>> {
+        let rates = self
+            .rates
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut result: Vec<ExchangeRate> = rates
+            .values()
+            .filter(|r| r.organization_id == self.organization_id)
+            .filter(|r| base_currency.is_none_or(|c| r.base_currency == c))
+            .filter(|r| quote_currency.is_none_or(|c| r.quote_currency == c))
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| {
+            (a.base_currency, a.quote_currency, b.effective_date).cmp(&(
+                b.base_currency,
+                b.quote_currency,
+                a.effective_date,
+            ))
+        });
+
+        Ok(result)
+    }
+
+    async fn find_effective(
+        &self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        on: NaiveDate,
+    ) -> RepositoryResult<Option<ExchangeRate>> {
+        let rates = self
+            .rates
             .read()
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(accounts.values().any(|a| a.code == code))
+        Ok(rates
+            .values()
+            .filter(|r| {
+                r.organization_id == self.organization_id
+                    && r.base_currency == base_currency
+                    && r.quote_currency == quote_currency
+                    && r.effective_date <= on
+            })
+            .max_by_key(|r| r.effective_date)
+            .cloned())
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        request: UpdateExchangeRateRequest,
+    ) -> RepositoryResult<ExchangeRate> {
+        let mut rates = self
+            .rates
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut rate = rates
+            .get(&id)
+            .filter(|r| r.organization_id == self.organization_id)
+            .cloned()
+            .ok_or(RepositoryError::NotFound(id))?;
+
+        if let Some(value) = request.rate {
+            rate.rate = value;
+        }
+        if let Some(effective_date) = request.effective_date {
+            rate.effective_date = effective_date;
+        }
+
+        if rates.values().any(|r| {
+            r.id != id
+                && r.organization_id == self.organization_id
+                && r.base_currency == rate.base_currency
+                && r.quote_currency == rate.quote_currency
+                && r.effective_date == rate.effective_date
+        }) {
+            return Err(duplicate_rate_error(&rate));
+        }
+
+        rate.updated_at = Utc::now();
+        rates.insert(id, rate.clone());
+
+        Ok(rate)
+    }
+
+    async fn delete(&self, id: Uuid) -> RepositoryResult<()> {
+        let mut rates = self
+            .rates
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if rates
+            .get(&id)
+            .is_none_or(|r| r.organization_id != self.organization_id)
+        {
+            return Err(RepositoryError::NotFound(id));
+        }
+        rates.remove(&id);
+
+        Ok(())
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn ExchangeRateRepository> {
+        Arc::new(Self {
+            rates: Arc::clone(&self.rates),
+            organization_id,
+        })
+    }
+}
+
+/// インメモリ金種表リポジトリ（テスト用）
+///
+/// 全組織の金種表を共有し、各インスタンスは自組織の金種表のみを扱う。
+pub struct InMemoryCashCountRepository {
+    counts: Arc<RwLock<HashMap<Uuid, CashCount>>>,
+    organization_id: Uuid,
+}
+
+impl InMemoryCashCountRepository {
+    pub fn new() -> Self {
+        Self {
+            counts: Arc::new(RwLock::new(HashMap::new())),
+            organization_id: DEFAULT_ORGANIZATION_ID,
+        }
+    }
+}
+
+impl Default for InMemoryCashCountRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CashCountRepository for InMemoryCashCountRepository {
+    async fn create(&self, request: CreateCashCountRequest) -> RepositoryResult<CashCount> {
+        let mut counts = self
+            .counts
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let count = CashCount::new(
+            self.organization_id,
+            request.safe,
+            request.counted_at.unwrap_or_else(Utc::now),
+            request.lines,
+            request.counted_by,
+            request.note,
+        )
+        .map_err(|e| RepositoryError::ValidationError(e.to_string()))?
+        .with_book_balance(request.book_balance);
+
+        counts.insert(count.id, count.clone());
+
+        Ok(count)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<CashCount>> {
+        let counts = self
+            .counts
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(counts
+            .get(&id)
+            .filter(|c| c.organization_id == self.organization_id)
+            .cloned())
+    }
+
+    async fn find_all(&self, filter: CashCountFilter) -> RepositoryResult<Vec<CashCount>> {
+        let counts = self
+            .counts
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut result: Vec<CashCount> = counts
+            .values()
+            .filter(|c| c.organization_id == self.organization_id && filter.matches(c))
+            .cloned()
+            .collect();
+        result.sort_by_key(|c| std::cmp::Reverse(c.counted_at));
+
+        Ok(result)
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn CashCountRepository> {
+        Arc::new(Self {
+            counts: Arc::clone(&self.counts),
+            organization_id,
+        })
+    }
+}
+
+/// インメモリ固定資産台帳リポジトリ（テスト用）
+///
+/// 全組織の固定資産を共有し、各インスタンスは自組織の資産のみを扱う。
+pub struct InMemoryFixedAssetRepository {
+    assets: Arc<RwLock<HashMap<Uuid, FixedAsset>>>,
+    organization_id: Uuid,
+}
+
+impl InMemoryFixedAssetRepository {
+    pub fn new() -> Self {
+        Self {
+            assets: Arc::new(RwLock::new(HashMap::new())),
+            organization_id: DEFAULT_ORGANIZATION_ID,
+        }
+    }
+}
+
+impl Default for InMemoryFixedAssetRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl FixedAssetRepository for InMemoryFixedAssetRepository {
+    async fn create(&self, request: CreateFixedAssetRequest) -> RepositoryResult<FixedAsset> {
+        let mut assets = self
+            .assets
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let asset = FixedAsset::new(self.organization_id, request);
+        assets.insert(asset.id, asset.clone());
+
+        Ok(asset)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<FixedAsset>> {
+        let assets = self
+            .assets
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(assets
+            .get(&id)
+            .filter(|a| a.organization_id == self.organization_id)
+            .cloned())
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<FixedAsset>> {
+        let assets = self
+            .assets
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut result: Vec<FixedAsset> = assets
+            .values()
+            .filter(|a| a.organization_id == self.organization_id)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| {
+            (a.acquisition_date, &a.name, a.created_at).cmp(&(
+                b.acquisition_date,
+                &b.name,
+                b.created_at,
+            ))
+        });
+
+        Ok(result)
+    }
+
+    async fn dispose(&self, id: Uuid) -> RepositoryResult<()> {
+        let mut assets = self
+            .assets
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let asset = assets
+            .get_mut(&id)
+            .filter(|a| a.organization_id == self.organization_id && a.is_active)
+            .ok_or(RepositoryError::NotFound(id))?;
+
+        asset.is_active = false;
+        asset.updated_at = Utc::now();
+
+        Ok(())
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn FixedAssetRepository> {
+        Arc::new(Self {
+            assets: Arc::clone(&self.assets),
+            organization_id,
+        })
+    }
+}
+
+/// インメモリ取引先リポジトリ（テスト用）
+///
+/// 全組織の取引先を共有し、各インスタンスは自組織の取引先のみを扱う。
+pub struct InMemoryCounterpartyRepository {
+    counterparties: Arc<RwLock<HashMap<Uuid, Counterparty>>>,
+    organization_id: Uuid,
+}
+
+impl InMemoryCounterpartyRepository {
+    pub fn new() -> Self {
+        Self {
+            counterparties: Arc::new(RwLock::new(HashMap::new())),
+            organization_id: DEFAULT_ORGANIZATION_ID,
+        }
+    }
+}
+
+impl Default for InMemoryCounterpartyRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CounterpartyRepository for InMemoryCounterpartyRepository {
+    async fn create(&self, request: CreateCounterpartyRequest) -> RepositoryResult<Counterparty> {
+        let mut counterparties = self
+            .counterparties
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let counterparty = Counterparty::new(self.organization_id, request);
+        counterparties.insert(counterparty.id, counterparty.clone());
+
+        Ok(counterparty)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Counterparty>> {
+        let counterparties = self
+            .counterparties
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(counterparties
+            .get(&id)
+            .filter(|c| c.organization_id == self.organization_id)
+            .cloned())
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<Counterparty>> {
+        let counterparties = self
+            .counterparties
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut result: Vec<Counterparty> = counterparties
+            .values()
+            .filter(|c| c.organization_id == self.organization_id && c.is_active)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(result)
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        request: UpdateCounterpartyRequest,
+    ) -> RepositoryResult<Counterparty> {
+        let mut counterparties = self
+            .counterparties
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let counterparty = counterparties
+            .get_mut(&id)
+            .filter(|c| c.organization_id == self.organization_id)
+            .ok_or(RepositoryError::NotFound(id))?;
+
+        if let Some(name) = request.name {
+            counterparty.name = name;
+        }
+        counterparty.contact = request.contact.apply(counterparty.contact.take());
+        counterparty.note = request.note.apply(counterparty.note.take());
+        if let Some(is_active) = request.is_active {
+            counterparty.is_active = is_active;
+        }
+        counterparty.updated_at = Utc::now();
+
+        Ok(counterparty.clone())
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()> {
+        let mut counterparties = self
+            .counterparties
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let counterparty = counterparties
+            .get_mut(&id)
+            .filter(|c| c.organization_id == self.organization_id)
+            .ok_or(RepositoryError::NotFound(id))?;
+
+        counterparty.is_active = false;
+        counterparty.updated_at = Utc::now();
+
+        Ok(())
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn CounterpartyRepository> {
+        Arc::new(Self {
+            counterparties: Arc::clone(&self.counterparties),
+            organization_id,
+        })
+    }
+}
+
+/// インメモリ月次締めリポジトリ（テスト用）
+///
+/// 全組織のチェックリストを共有し、各インスタンスは自組織のチェックリストのみを扱う。
+pub struct InMemoryMonthCloseRepository {
+    closes: Arc<RwLock<HashMap<(Uuid, NaiveDate), MonthClose>>>,
+    organization_id: Uuid,
+}
+
+impl InMemoryMonthCloseRepository {
+    pub fn new() -> Self {
+        Self {
+            closes: Arc::new(RwLock::new(HashMap::new())),
+            organization_id: DEFAULT_ORGANIZATION_ID,
+        }
+    }
+}
+
+impl Default for InMemoryMonthCloseRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl MonthCloseRepository for InMemoryMonthCloseRepository {
+    async fn find(&self, month: NaiveDate) -> RepositoryResult<Option<MonthClose>> {
+        let closes = self
+            .closes
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(closes.get(&(self.organization_id, month)).cloned())
+    }
+
+    async fn save(&self, close: &MonthClose) -> RepositoryResult<MonthClose> {
+        let mut closes = self
+            .closes
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let close = MonthClose {
+            organization_id: self.organization_id,
+            ..close.clone()
+        };
+        closes.insert((close.organization_id, close.month), close.clone());
+
+        Ok(close)
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn MonthCloseRepository> {
+        Arc::new(Self {
+            closes: Arc::clone(&self.closes),
+            organization_id,
+        })
+    }
+}
+
+/// インメモリ Webhook リポジトリ（テスト用）
+///
+/// 全組織の購読を共有し、各インスタンスは自組織の購読のみを扱う。
+pub struct InMemoryWebhookRepository {
+    webhooks: Arc<RwLock<HashMap<Uuid, Webhook>>>,
+    organization_id: Uuid,
+}
+
+impl InMemoryWebhookRepository {
+    pub fn new() -> Self {
+        Self {
+            webhooks: Arc::new(RwLock::new(HashMap::new())),
+            organization_id: DEFAULT_ORGANIZATION_ID,
+        }
+    }
+}
+
+impl Default for InMemoryWebhookRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for InMemoryWebhookRepository {
+    async fn create(&self, request: CreateWebhookRequest) -> RepositoryResult<Webhook> {
+        let mut webhooks = self
+            .webhooks
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let webhook = Webhook::new(
+            self.organization_id,
+            request.url,
+            request.events,
+            request.secret,
+        );
+        webhooks.insert(webhook.id, webhook.clone());
+
+        Ok(webhook)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Webhook>> {
+        let webhooks = self
+            .webhooks
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(webhooks
+            .get(&id)
+            .filter(|w| w.organization_id == self.organization_id)
+            .cloned())
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<Webhook>> {
+        let webhooks = self
+            .webhooks
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut result: Vec<Webhook> = webhooks
+            .values()
+            .filter(|w| w.organization_id == self.organization_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|w| w.created_at);
+
+        Ok(result)
+    }
+
+    async fn update(&self, id: Uuid, request: UpdateWebhookRequest) -> RepositoryResult<Webhook> {
+        let mut webhooks = self
+            .webhooks
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let webhook = webhooks
+            .get_mut(&id)
+            .filter(|w| w.organization_id == self.organization_id)
+            .ok_or(RepositoryError::NotFound(id))?;
+
+        if let Some(url) = request.url {
+            webhook.url = url;
+        }
+        if let Some(events) = request.events {
+            webhook.events = events;
+        }
+        if let Some(secret) = request.secret {
+            webhook.secret = secret;
+        }
+        if let Some(is_active) = request.is_active {
+            webhook.is_active = is_active;
+        }
+        webhook.updated_at = Utc::now();
+
+        Ok(webhook.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> RepositoryResult<()> {
+        let mut webhooks = self
+            .webhooks
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if webhooks
+            .get(&id)
+            .is_none_or(|w| w.organization_id != self.organization_id)
+        {
+            return Err(RepositoryError::NotFound(id));
+        }
+        webhooks.remove(&id);
+
+        Ok(())
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn WebhookRepository> {
+        Arc::new(Self {
+            webhooks: Arc::clone(&self.webhooks),
+            organization_id,
+        })
+    }
+}
+
+/// インメモリ組織リポジトリ（テスト用）
+#[derive(Default)]
+pub struct InMemoryOrganizationRepository {
+    organizations: RwLock<HashMap<Uuid, Organization>>,
+}
+
+impl InMemoryOrganizationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for InMemoryOrganizationRepository {
+    async fn create(&self, request: CreateOrganizationRequest) -> RepositoryResult<Organization> {
+        let mut organizations = self
+            .organizations
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let organization = Organization::new(request.name);
+        organizations.insert(organization.id, organization.clone());
+
+        Ok(organization)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Organization>> {
+        let organizations = self
+            .organizations
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(organizations.get(&id).cloned())
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<Organization>> {
+        let organizations = self
+            .organizations
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut result: Vec<Organization> = organizations
+            .values()
+            .filter(|o| o.is_active)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(result)
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        request: UpdateOrganizationRequest,
+    ) -> RepositoryResult<Organization> {
+        let mut organizations = self
+            .organizations
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let organization = organizations
+            .get_mut(&id)
+            .ok_or(RepositoryError::NotFound(id))?;
+
+        if let Some(name) = request.name {
+            organization.name = name;
+        }
+        if let Some(is_active) = request.is_active {
+            organization.is_active = is_active;
+        }
+        organization.updated_at = Utc::now();
+
+        Ok(organization.clone())
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()> {
+        let mut organizations = self
+            .organizations
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let organization = organizations
+            .get_mut(&id)
+            .ok_or(RepositoryError::NotFound(id))?;
+
+        organization.is_active = false;
+        organization.updated_at = Utc::now();
+
+        Ok(())
+    }
+}
+
+/// インメモリメール送信キュー（テスト用）
+#[derive(Default)]
+pub struct InMemoryEmailQueueRepository {
+    emails: RwLock<HashMap<Uuid, QueuedEmail>>,
+}
+
+impl InMemoryEmailQueueRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl EmailQueueRepository for InMemoryEmailQueueRepository {
+    async fn enqueue(&self, message: EmailMessage) -> RepositoryResult<QueuedEmail> {
+        let mut emails = self
+            .emails
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let email = QueuedEmail::new(message);
+        emails.insert(email.id, email.clone());
+
+        Ok(email)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<QueuedEmail>> {
+        let emails = self
+            .emails
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(emails.get(&id).cloned())
+    }
+
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+        lease: Duration,
+    ) -> RepositoryResult<Vec<QueuedEmail>> {
+        let mut emails = self
+            .emails
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut result: Vec<QueuedEmail> = emails
+            .values()
+            .filter(|e| e.status == EmailStatus::Pending && e.next_attempt_at <= now)
+            .cloned()
+            .collect();
+        result.sort_by_key(|e| (e.next_attempt_at, e.created_at));
+        result.truncate(usize::try_from(limit).unwrap_or(0));
+
+        for email in &mut result {
+            email.next_attempt_at = now + lease;
+            emails.insert(email.id, email.clone());
+        }
+
+        Ok(result)
+    }
+
+    async fn mark_sent(&self, id: Uuid) -> RepositoryResult<()> {
+        let mut emails = self
+            .emails
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let email = emails.get_mut(&id).ok_or(RepositoryError::NotFound(id))?;
+        email.status = EmailStatus::Sent;
+        email.attempts += 1;
+        email.last_error = None;
+        email.sent_at = Some(Utc::now());
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> RepositoryResult<()> {
+        let mut emails = self
+            .emails
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let email = emails.get_mut(&id).ok_or(RepositoryError::NotFound(id))?;
+        email.attempts += 1;
+        email.last_error = Some(error.to_string());
+        match retry_at {
+            Some(retry_at) => email.next_attempt_at = retry_at,
+            None => email.status = EmailStatus::Failed,
+        }
+
+        Ok(())
+    }
+}
+
+/// インメモリの作業単位の開始（テスト用）
+///
+/// 作業単位は開始時点の複製を変更し、確定時に変更した勘定科目・メールだけを書き戻す。
+/// 作業単位は組織を問わず 1 つずつ実行する。作業単位の外の書き込みとの競合は検出しない
+/// （後に確定した変更が優先される）。
+pub struct InMemoryUnitOfWorkFactory {
+    accounts: InMemoryAccountRepository,
+    email_queue: Arc<InMemoryEmailQueueRepository>,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl InMemoryUnitOfWorkFactory {
+    pub fn new(
+        accounts: &InMemoryAccountRepository,
+        email_queue: Arc<InMemoryEmailQueueRepository>,
+    ) -> Self {
+        Self {
+            accounts: accounts.share(
+                Arc::clone(&accounts.accounts),
+                Arc::clone(&accounts.history),
+                accounts.organization_id,
+            ),
+            email_queue,
+            lock: Arc::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for InMemoryUnitOfWorkFactory {
+    async fn begin(&self, organization_id: Uuid) -> RepositoryResult<Box<dyn UnitOfWork>> {
+        let guard = Arc::clone(&self.lock).lock_owned().await;
+        let base_accounts = self.accounts.accounts.read().await.clone();
+        let history = self.accounts.history.read().await.clone();
+        let base_emails = self
+            .email_queue
+            .emails
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .clone();
+
+        let accounts = self.accounts.share(
+            Arc::new(tokio::sync::RwLock::new(base_accounts.clone())),
+            Arc::new(tokio::sync::RwLock::new(history.clone())),
+            organization_id,
+        );
+        let email_queue = InMemoryEmailQueueRepository {
+            emails: RwLock::new(base_emails.clone()),
+        };
+        Ok(Box::new(InMemoryUnitOfWork {
+            target_accounts: Arc::clone(&self.accounts.accounts),
+            target_history: Arc::clone(&self.accounts.history),
+            target_emails: Arc::clone(&self.email_queue),
+            base_accounts,
+            base_history_len: history.len(),
+            base_emails,
+            accounts: Arc::new(accounts),
+            email_queue: Arc::new(email_queue),
+            _guard: guard,
+        }))
+    }
+}
+
+/// インメモリの作業単位（テスト用）
+pub struct InMemoryUnitOfWork {
+    target_accounts: AccountStore,
+    target_history: AccountHistory,
+    target_emails: Arc<InMemoryEmailQueueRepository>,
+    /// 開始時点の内容（確定時に変更したものだけを書き戻すため）
+    base_accounts: HashMap<Uuid, Account>,
+    base_history_len: usize,
+    base_emails: HashMap<Uuid, QueuedEmail>,
+    accounts: Arc<InMemoryAccountRepository>,
+    email_queue: Arc<InMemoryEmailQueueRepository>,
+    /// 確定・取り消し・破棄まで次の作業単位を待たせる
+    _guard: tokio::sync::OwnedMutexGuard<()>,
+}
+
+#[async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    fn accounts(&self) -> Arc<dyn AccountRepository> {
+        self.accounts.clone()
+    }
+
+    fn email_queue(&self) -> Arc<dyn EmailQueueRepository> {
+        self.email_queue.clone()
+    }
+
+    async fn commit(self: Box<Self>) -> RepositoryResult<()> {
+        let accounts = self.accounts.accounts.read().await;
+        let history = self.accounts.history.read().await;
+        let mut target_accounts = self.target_accounts.write().await;
+        let mut target_history = self.target_history.write().await;
+
+        // メールの送信キューは同期のロックのため、以降は await しない
+        let emails = self
+            .email_queue
+            .emails
+            .read()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut target_emails = self
+            .target_emails
+            .emails
+            .write()
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        for (id, account) in accounts.iter() {
+            if self.base_accounts.get(id) != Some(account) {
+                target_accounts.insert(*id, account.clone());
+            }
+        }
+        target_history.extend(history[self.base_history_len..].iter().cloned());
+        for (id, email) in emails.iter() {
+            if self.base_emails.get(id) != Some(email) {
+                target_emails.insert(*id, email.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> RepositoryResult<()> {
+        Ok(())
+    }
+}
+
+/// インメモリ横断検索（テスト用）
+///
+/// 勘定科目リポジトリの有効な科目を部分一致で検索する。関連度は科目名、
+/// 科目コード、説明の順に重みを付けた簡易的なもの。
+pub struct InMemorySearchRepository {
+    accounts: Arc<dyn AccountRepository>,
+}
+
+impl InMemorySearchRepository {
+    pub fn new(accounts: Arc<dyn AccountRepository>) -> Self {
+        Self { accounts }
+    }
+}
+
+#[async_trait]
+impl SearchRepository for InMemorySearchRepository {
+    async fn search(&self, query: &SearchQuery) -> RepositoryResult<SearchResults> {
+        let term = query.q.trim().to_lowercase();
+        let mut hits: Vec<SearchHit> = self
+            .accounts
+            .find_active()
+            .await?
+            .into_iter()
+            .filter_map(|account| {
+                let name = account.name.to_lowercase();
+                let mut rank = 0.0;
+                if name.contains(&term) {
+                    rank += 1.0 + term.chars().count() as f64 / name.chars().count() as f64;
+                }
+                if account.code.to_lowercase().starts_with(&term) {
+                    rank += 1.0;
+                }
+                if account
+                    .description
+                    .as_ref()
+                    .is_some_and(|d| d.to_lowercase().contains(&term))
+                {
+                    rank += 0.5;
+                }
+                (rank > 0.0).then_some(SearchHit {
+                    entity_type: SearchEntityType::Account,
+                    id: account.id,
+                    title: account.name,
+                    snippet: account.description,
+                    rank,
+                })
+            })
+            .collect();
+
+        let facets = if hits.is_empty() {
+            Vec::new()
+        } else {
+            vec![SearchFacet {
+                entity_type: SearchEntityType::Account,
+                count: hits.len() as i64,
+            }]
+        };
+
+        hits.retain(|hit| query.entity_type.is_none_or(|t| hit.entity_type == t));
+        hits.sort_by(|a, b| {
+            b.rank
+                .total_cmp(&a.rank)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+        hits.truncate(usize::try_from(query.limit()).unwrap_or(0));
+
+        Ok(SearchResults { hits, facets })
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn SearchRepository> {
+        Arc::new(Self {
+            accounts: self.accounts.for_organization(organization_id),
+        })
     }
 }
@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use crate::domain::{
+    Account, AccountCategory, AccountListQuery, AccountType, CreateAccountRequest,
+    UpdateAccountRequest,
+};
+use crate::repository::{
+    AccountRepository, InMemoryAccountRepository, RepositoryError, RepositoryResult,
+};
+
+/// ハンドラーのテスト用の勘定科目リポジトリ
+///
+/// 通常はインメモリ実装に委譲し、`fail_on` で指定したメソッドだけ指定したエラーを返す。
+/// 呼び出したメソッドは組織をまたいで `calls` に記録する。
+#[derive(Clone)]
+pub struct MockAccountRepository {
+    inner: Arc<dyn AccountRepository>,
+    failures: Arc<Mutex<HashMap<&'static str, RepositoryError>>>,
+    calls: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl MockAccountRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(InMemoryAccountRepository::new()),
+            failures: Arc::default(),
+            calls: Arc::default(),
+        }
+    }
+
+    /// `method`（トレイトのメソッド名）の呼び出しで `error` を返す
+    pub fn fail_on(&self, method: &'static str, error: RepositoryError) {
+        self.failures.lock().unwrap().insert(method, error);
+    }
+
+    /// 呼び出したメソッド名（呼び出し順）
+    pub fn calls(&self) -> Vec<&'static str> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, method: &'static str) -> RepositoryResult<()> {
+        self.calls.lock().unwrap().push(method);
+        match self.failures.lock().unwrap().get(method) {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for MockAccountRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AccountRepository for MockAccountRepository {
+    async fn create(&self, request: CreateAccountRequest) -> RepositoryResult<Account> {
+        self.record("create")?;
+        self.inner.create(request).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Account>> {
+        self.record("find_by_id")?;
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_by_code(&self, code: &str) -> RepositoryResult<Option<Account>> {
+        self.record("find_by_code")?;
+        self.inner.find_by_code(code).await
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<Account>> {
+        self.record("find_all")?;
+        self.inner.find_all().await
+    }
+
+    async fn find_active(&self) -> RepositoryResult<Vec<Account>> {
+        self.record("find_active")?;
+        self.inner.find_active().await
+    }
+
+    async fn find_by_type(&self, account_type: AccountType) -> RepositoryResult<Vec<Account>> {
+        self.record("find_by_type")?;
+        self.inner.find_by_type(account_type).await
+    }
+
+    async fn list(&self, query: AccountListQuery) -> RepositoryResult<Vec<Account>> {
+        self.record("list")?;
+        self.inner.list(query).await
+    }
+
+    async fn update(&self, id: Uuid, request: UpdateAccountRequest) -> RepositoryResult<Account> {
+        self.record("update")?;
+        self.inner.update(id, request).await
+    }
+
+    async fn update_if_unmodified(
+        &self,
+        id: Uuid,
+        request: UpdateAccountRequest,
+        versions: &[DateTime<Utc>],
+    ) -> RepositoryResult<Account> {
+        self.record("update_if_unmodified")?;
+        self.inner.update_if_unmodified(id, request, versions).await
+    }
+
+    async fn reorder(&self, account_ids: &[Uuid]) -> RepositoryResult<Vec<Account>> {
+        self.record("reorder")?;
+        self.inner.reorder(account_ids).await
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()> {
+        self.record("soft_delete")?;
+        self.inner.soft_delete(id).await
+    }
+
+    async fn soft_delete_if_unmodified(
+        &self,
+        id: Uuid,
+        versions: &[DateTime<Utc>],
+    ) -> RepositoryResult<()> {
+        self.record("soft_delete_if_unmodified")?;
+        self.inner.soft_delete_if_unmodified(id, versions).await
+    }
+
+    async fn exists_by_code(&self, code: &str) -> RepositoryResult<bool> {
+        self.record("exists_by_code")?;
+        self.inner.exists_by_code(code).await
+    }
+
+    async fn find_children(&self, parent_id: Uuid) -> RepositoryResult<Vec<Account>> {
+        self.record("find_children")?;
+        self.inner.find_children(parent_id).await
+    }
+
+    async fn last_modified(&self) -> RepositoryResult<Option<DateTime<Utc>>> {
+        self.record("last_modified")?;
+        self.inner.last_modified().await
+    }
+
+    async fn find_as_of(
+        &self,
+        id: Uuid,
+        as_of: DateTime<Utc>,
+    ) -> RepositoryResult<Option<Account>> {
+        self.record("find_as_of")?;
+        self.inner.find_as_of(id, as_of).await
+    }
+
+    async fn next_code(&self, category: &AccountCategory) -> RepositoryResult<Option<String>> {
+        self.record("next_code")?;
+        self.inner.next_code(category).await
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn AccountRepository> {
+        Arc::new(Self {
+            inner: self.inner.for_organization(organization_id),
+            failures: self.failures.clone(),
+            calls: self.calls.clone(),
+        })
+    }
+}
@@ -1,7 +1,35 @@
 pub mod account_repository;
+pub mod cached;
+pub mod cash_count_repository;
+pub mod category_repository;
+pub mod counterparty_repository;
+pub mod email_queue_repository;
+pub mod exchange_rate_repository;
+pub mod fixed_asset_repository;
 pub mod in_memory;
+#[cfg(test)]
+pub mod mock;
+pub mod month_close_repository;
+pub mod organization_repository;
 pub mod postgres;
+pub mod search_repository;
+pub mod unit_of_work;
+pub mod webhook_repository;
 
 pub use account_repository::*;
+pub use cached::*;
+pub use cash_count_repository::*;
+pub use category_repository::*;
+pub use counterparty_repository::*;
+pub use email_queue_repository::*;
+pub use exchange_rate_repository::*;
+pub use fixed_asset_repository::*;
 pub use in_memory::*;
+#[cfg(test)]
+pub use mock::*;
+pub use month_close_repository::*;
+pub use organization_repository::*;
 pub use postgres::*;
+pub use search_repository::*;
+pub use unit_of_work::*;
+pub use webhook_repository::*;
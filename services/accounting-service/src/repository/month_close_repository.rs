@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::MonthClose;
+use crate::repository::RepositoryResult;
+
+/// 月次締めリポジトリインターフェース
+#[async_trait]
+pub trait MonthCloseRepository: Send + Sync {
+    /// 対象月のチェックリストを取得（未着手なら None）
+    async fn find(&self, month: NaiveDate) -> RepositoryResult<Option<MonthClose>>;
+
+    /// チェックリストを保存（対象月ごとに 1 件）
+    async fn save(&self, close: &MonthClose) -> RepositoryResult<MonthClose>;
+
+    /// 指定した組織に限定したリポジトリ
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn MonthCloseRepository>;
+}
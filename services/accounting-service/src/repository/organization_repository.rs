@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{CreateOrganizationRequest, Organization, UpdateOrganizationRequest};
+use crate::repository::RepositoryResult;
+
+/// 組織リポジトリインターフェース
+#[async_trait]
+pub trait OrganizationRepository: Send + Sync {
+    /// 組織を作成
+    async fn create(&self, request: CreateOrganizationRequest) -> RepositoryResult<Organization>;
+
+    /// IDで組織を取得
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Organization>>;
+
+    /// 有効な組織の一覧を取得（名前順）
+    async fn find_all(&self) -> RepositoryResult<Vec<Organization>>;
+
+    /// 組織を更新
+    async fn update(
+        &self,
+        id: Uuid,
+        request: UpdateOrganizationRequest,
+    ) -> RepositoryResult<Organization>;
+
+    /// 組織を論理削除（is_active = false）
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()>;
+}
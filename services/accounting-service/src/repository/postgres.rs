@@ -1,22 +1,332 @@
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
-use sqlx::PgPool;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use common::jobs::{Job, JobQueue, JobQueueError, JobQueueResult, JobStatus, NewJob};
+use rust_decimal::Decimal;
+use sqlx::pool::PoolConnection;
+use sqlx::{Connection, PgConnection, PgPool, Postgres, Transaction};
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
 use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::{Mutex, MutexGuard};
 use uuid::Uuid;
 
 use crate::domain::{
-    Account, AccountCategory, AccountType, CreateAccountRequest, UpdateAccountRequest,
+    sort_categories, validate_parent, Account, AccountCategory, AccountCodeRanges,
+    AccountListQuery, AccountType, CashCount, CashCountFilter, CategoryDefinition, CodeReusePolicy,
+    Counterparty, CreateAccountRequest, CreateCashCountRequest, CreateCategoryRequest,
+    CreateCounterpartyRequest, CreateExchangeRateRequest, CreateFixedAssetRequest,
+    CreateOrganizationRequest, CreateWebhookRequest, Currency, DenominationCount, EmailMessage,
+    EmailStatus, ExchangeRate, FixedAsset, Money, MonthClose, Organization, QueuedEmail,
+    SearchEntityType, SearchFacet, SearchHit, SearchQuery, SearchResults, UpdateAccountRequest,
+    UpdateCounterpartyRequest, UpdateExchangeRateRequest, UpdateOrganizationRequest,
+    UpdateWebhookRequest, Webhook, DEFAULT_ORGANIZATION_ID,
 };
-use crate::repository::{AccountRepository, RepositoryError, RepositoryResult};
+use crate::repository::{
+    AccountRepository, CashCountRepository, CategoryRepository, CounterpartyRepository,
+    EmailQueueRepository, ExchangeRateRepository, FixedAssetRepository, MonthCloseRepository,
+    OrganizationRepository, RepositoryError, RepositoryResult, SearchRepository, UnitOfWork,
+    UnitOfWorkFactory, WebhookRepository,
+};
+use crate::tenant::TenantIsolation;
 
 /// PostgreSQL 勘定科目リポジトリ
 pub struct PostgresAccountRepository {
     pool: PgPool,
+    code_reuse_policy: CodeReusePolicy,
+    /// 科目種別ごとの科目コードの範囲（未指定なら検証しない）
+    code_ranges: Option<AccountCodeRanges>,
+    organization_id: Uuid,
+    tenant_isolation: TenantIsolation,
+    /// 作業単位の中で使う場合のトランザクション
+    transaction: Option<SharedTransaction>,
 }
 
 impl PostgresAccountRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            code_reuse_policy: CodeReusePolicy::default(),
+            code_ranges: None,
+            organization_id: DEFAULT_ORGANIZATION_ID,
+            tenant_isolation: TenantIsolation::default(),
+            transaction: None,
+        }
+    }
+
+    /// 組織間の分離方式を指定（rls では接続に組織を設定する）
+    pub fn with_tenant_isolation(mut self, isolation: TenantIsolation) -> Self {
+        self.tenant_isolation = isolation;
+        self
+    }
+
+    async fn connection(&self) -> RepositoryResult<RepositoryConnection<'_>> {
+        match &self.transaction {
+            Some(transaction) => RepositoryConnection::transaction(transaction).await,
+            None => tenant_connection(&self.pool, self.tenant_isolation, self.organization_id)
+                .await
+                .map(RepositoryConnection::Pool),
+        }
+    }
+
+    /// 論理削除済み科目コードの扱いを指定
+    pub fn with_code_reuse_policy(mut self, policy: CodeReusePolicy) -> Self {
+        self.code_reuse_policy = policy;
+        self
+    }
+
+    /// 作成時に科目コードが科目種別の範囲内か検証する
+    pub fn with_code_ranges(mut self, ranges: AccountCodeRanges) -> Self {
+        self.code_ranges = Some(ranges);
+        self
+    }
+
+    /// カテゴリの勘定科目種別（組織定義のカテゴリは account_categories から解決する）
+    async fn resolve_account_type(
+        &self,
+        category: &AccountCategory,
+    ) -> RepositoryResult<AccountType> {
+        if let Some(account_type) = category.account_type() {
+            return Ok(account_type);
+        }
+        let account_type = sqlx::query_scalar!(
+            r#"SELECT account_type AS "account_type: AccountTypeColumn" FROM account_categories WHERE code = $1 AND organization_id = $2 AND is_active"#,
+            category.to_string(),
+            self.organization_id
+        )
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?
+        .ok_or_else(|| {
+            RepositoryError::ValidationError(format!("Unknown account category: {}", category))
+        })?;
+        Ok(account_type.into())
+    }
+
+    /// 同じ科目コードの論理削除済み勘定科目（最新のもの）を復活させる
+    async fn revive(
+        &self,
+        request: &CreateAccountRequest,
+        account_type: AccountType,
+    ) -> RepositoryResult<Option<Account>> {
+        let revived_id = sqlx::query_scalar!(
+            "SELECT id FROM accounts WHERE code = $1 AND organization_id = $2 AND NOT is_active ORDER BY updated_at DESC LIMIT 1",
+            request.code,
+            self.organization_id
+        )
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let Some(revived_id) = revived_id else {
+            return Ok(None);
+        };
+
+        if let Some(parent_id) = request.parent_id {
+            self.check_parent(Some(revived_id), account_type, parent_id)
+                .await?;
+        }
+
+        let row = sqlx::query_as!(
+            AccountRow,
+            r#"
+            UPDATE accounts
+            SET name          = $2,
+                account_type  = $3,
+                category      = $4,
+                description   = $5,
+                display_order = $6,
+                parent_id     = $7,
+                is_active     = TRUE,
+                updated_at    = NOW()
+            WHERE id = $1 AND organization_id = $8 AND NOT is_active
+            RETURNING id, organization_id, code, name, account_type AS "account_type: AccountTypeColumn", category, description, is_active, display_order, parent_id, created_at, updated_at
+            "#,
+            revived_id,
+            request.name,
+            AccountTypeColumn::from(account_type) as AccountTypeColumn,
+            request.category.to_string(),
+            request.description,
+            request.display_order.unwrap_or(0),
+            request.parent_id,
+            self.organization_id
+        )
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(Account::try_from).transpose()
+    }
+
+    /// 勘定科目を更新する（`versions` を指定すると現在の版がそのいずれかの場合だけ）
+    async fn update_account(
+        &self,
+        id: Uuid,
+        request: UpdateAccountRequest,
+        versions: Option<&[DateTime<Utc>]>,
+    ) -> RepositoryResult<Account> {
+        if let Some(parent_id) = request.parent_id.as_value() {
+            let current = self
+                .find_by_id(id)
+                .await?
+                .ok_or(RepositoryError::NotFound(id))?;
+            self.check_parent(Some(id), current.account_type, *parent_id)
+                .await?;
+        }
+
+        // description・parent_id は省略時のみ現在値を残す（null なら消去）
+        let row = sqlx::query_as!(
+            AccountRow,
+            r#"
+            UPDATE accounts
+            SET name         = COALESCE($2, name),
+                description  = CASE WHEN $3 THEN description ELSE $4 END,
+                display_order = COALESCE($5, display_order),
+                is_active    = COALESCE($6, is_active),
+                parent_id    = CASE WHEN $7 THEN parent_id ELSE $8 END,
+                updated_at   = NOW()
+            WHERE id = $1 AND organization_id = $9
+              AND ($10::TIMESTAMPTZ[] IS NULL OR updated_at = ANY($10))
+            RETURNING id, organization_id, code, name, account_type AS "account_type: AccountTypeColumn", category, description, is_active, display_order, parent_id, created_at, updated_at
+            "#,
+            id,
+            request.name,
+            request.description.is_absent(),
+            request.description.as_value(),
+            request.display_order,
+            request.is_active,
+            request.parent_id.is_absent(),
+            request.parent_id.as_value(),
+            self.organization_id,
+            versions
+        )
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        match row {
+            Some(row) => Account::try_from(row),
+            None => Err(self.not_updated(id, versions).await),
+        }
+    }
+
+    /// 勘定科目を論理削除する（`versions` の扱いは `update_account` と同じ）
+    async fn deactivate(
+        &self,
+        id: Uuid,
+        versions: Option<&[DateTime<Utc>]>,
+    ) -> RepositoryResult<()> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE accounts
+            SET is_active = FALSE, updated_at = NOW()
+            WHERE id = $1 AND organization_id = $2
+              AND ($3::TIMESTAMPTZ[] IS NULL OR updated_at = ANY($3))
+            "#,
+            id,
+            self.organization_id,
+            versions
+        )
+        .execute(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(self.not_updated(id, versions).await);
+        }
+
+        Ok(())
+    }
+
+    /// 更新できなかった理由（版の指定があり勘定科目が存在すれば、版の不一致）
+    async fn not_updated(&self, id: Uuid, versions: Option<&[DateTime<Utc>]>) -> RepositoryError {
+        if versions.is_none() {
+            return RepositoryError::NotFound(id);
+        }
+        match self.find_by_id(id).await {
+            Ok(Some(_)) => RepositoryError::Modified(id),
+            Ok(None) => RepositoryError::NotFound(id),
+            Err(err) => err,
+        }
+    }
+
+    /// 親勘定科目の存在・種別・循環を検証する
+    async fn check_parent(
+        &self,
+        account_id: Option<Uuid>,
+        account_type: AccountType,
+        parent_id: Uuid,
+    ) -> RepositoryResult<()> {
+        let parent = self.find_by_id(parent_id).await?.ok_or_else(|| {
+            RepositoryError::ValidationError(format!("Parent account not found: {}", parent_id))
+        })?;
+        validate_parent(account_id, account_type, &parent)
+            .map_err(RepositoryError::ValidationError)?;
+
+        let Some(account_id) = account_id else {
+            return Ok(());
+        };
+
+        // 親の祖先に自身が含まれていれば循環
+        let cyclic = sqlx::query_scalar!(
+            r#"
+            WITH RECURSIVE ancestors AS (
+                SELECT id, parent_id FROM accounts WHERE id = $1
+                UNION
+                SELECT a.id, a.parent_id FROM accounts a
+                JOIN ancestors an ON a.id = an.parent_id
+            )
+            SELECT EXISTS(SELECT 1 FROM ancestors WHERE id = $2) AS "cyclic!"
+            "#,
+            parent_id,
+            account_id
+        )
+        .fetch_one(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if cyclic {
+            return Err(RepositoryError::ValidationError(
+                "勘定科目の親子関係が循環しています".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// 勘定科目種別の列（PostgreSQL の `account_type` 列挙型）
+#[derive(Debug, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "account_type", rename_all = "snake_case")]
+enum AccountTypeColumn {
+    Asset,
+    Liability,
+    Equity,
+    Revenue,
+    Expense,
+}
+
+impl From<AccountType> for AccountTypeColumn {
+    fn from(account_type: AccountType) -> Self {
+        match account_type {
+            AccountType::Asset => Self::Asset,
+            AccountType::Liability => Self::Liability,
+            AccountType::Equity => Self::Equity,
+            AccountType::Revenue => Self::Revenue,
+            AccountType::Expense => Self::Expense,
+        }
+    }
+}
+
+impl From<AccountTypeColumn> for AccountType {
+    fn from(column: AccountTypeColumn) -> Self {
+        match column {
+            AccountTypeColumn::Asset => Self::Asset,
+            AccountTypeColumn::Liability => Self::Liability,
+            AccountTypeColumn::Equity => Self::Equity,
+            AccountTypeColumn::Revenue => Self::Revenue,
+            AccountTypeColumn::Expense => Self::Expense,
+        }
     }
 }
 
@@ -24,13 +334,15 @@ impl PostgresAccountRepository {
 #[derive(Debug, sqlx::FromRow)]
 struct AccountRow {
     id: Uuid,
+    organization_id: Uuid,
     code: String,
     name: String,
-    account_type: String,
+    account_type: AccountTypeColumn,
     category: String,
     description: Option<String>,
     is_active: bool,
     display_order: i32,
+    parent_id: Option<Uuid>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -39,20 +351,20 @@ impl TryFrom<AccountRow> for Account {
     type Error = RepositoryError;
 
     fn try_from(row: AccountRow) -> Result<Self, Self::Error> {
-        let account_type =
-            AccountType::from_str(&row.account_type).map_err(RepositoryError::DatabaseError)?;
         let category =
             AccountCategory::from_str(&row.category).map_err(RepositoryError::DatabaseError)?;
 
         Ok(Account {
             id: row.id,
+            organization_id: row.organization_id,
             code: row.code,
             name: row.name,
-            account_type,
+            account_type: row.account_type.into(),
             category,
             description: row.description,
             is_active: row.is_active,
             display_order: row.display_order,
+            parent_id: row.parent_id,
             created_at: row.created_at,
             updated_at: row.updated_at,
         })
@@ -73,28 +385,136 @@ fn map_sqlx_error(err: sqlx::Error) -> RepositoryError {
     }
 }
 
+/// 組織の接続を取得する（rls では接続に `app.current_org` を設定する）
+///
+/// 設定は接続の返却時にプールがリセットする（`DatabaseConfig::create_pool`）。
+async fn tenant_connection(
+    pool: &PgPool,
+    isolation: TenantIsolation,
+    organization_id: Uuid,
+) -> RepositoryResult<PoolConnection<Postgres>> {
+    let mut conn = pool.acquire().await.map_err(map_sqlx_error)?;
+    if isolation == TenantIsolation::Rls {
+        sqlx::query("SELECT set_config('app.current_org', $1, false)")
+            .bind(organization_id.to_string())
+            .execute(&mut *conn)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+    Ok(conn)
+}
+
+/// 組織のトランザクションを開始する（rls ではトランザクション内に限り組織を設定する）
+async fn tenant_transaction(
+    pool: &PgPool,
+    isolation: TenantIsolation,
+    organization_id: Uuid,
+) -> RepositoryResult<Transaction<'static, Postgres>> {
+    let mut tx = pool.begin().await.map_err(map_sqlx_error)?;
+    if isolation == TenantIsolation::Rls {
+        sqlx::query("SELECT set_config('app.current_org', $1, true)")
+            .bind(organization_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+    Ok(tx)
+}
+
+/// 作業単位で共有するトランザクション（確定・取り消し後は None）
+type SharedTransaction = Arc<Mutex<Option<Transaction<'static, Postgres>>>>;
+
+/// リポジトリが使う接続（作業単位の中ではそのトランザクション）
+enum RepositoryConnection<'a> {
+    Pool(PoolConnection<Postgres>),
+    Transaction(MutexGuard<'a, Option<Transaction<'static, Postgres>>>),
+}
+
+impl<'a> RepositoryConnection<'a> {
+    /// 作業単位のトランザクションを使う（確定・取り消し後はエラー）
+    async fn transaction(transaction: &'a SharedTransaction) -> RepositoryResult<Self> {
+        let guard = transaction.lock().await;
+        if guard.is_none() {
+            return Err(transaction_finished());
+        }
+        Ok(Self::Transaction(guard))
+    }
+}
+
+impl Deref for RepositoryConnection<'_> {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &PgConnection {
+        match self {
+            Self::Pool(conn) => conn,
+            Self::Transaction(tx) => tx.as_deref().expect("transaction is checked on acquire"),
+        }
+    }
+}
+
+impl DerefMut for RepositoryConnection<'_> {
+    fn deref_mut(&mut self) -> &mut PgConnection {
+        match self {
+            Self::Pool(conn) => conn,
+            Self::Transaction(tx) => tx
+                .as_deref_mut()
+                .expect("transaction is checked on acquire"),
+        }
+    }
+}
+
+fn transaction_finished() -> RepositoryError {
+    RepositoryError::DatabaseError("Transaction already committed or rolled back".to_string())
+}
+
 #[async_trait]
 impl AccountRepository for PostgresAccountRepository {
     async fn create(&self, request: CreateAccountRequest) -> RepositoryResult<Account> {
+        let account_type = self.resolve_account_type(&request.category).await?;
+        if let Some(ranges) = &self.code_ranges {
+            ranges
+                .check(&request.code, account_type)
+                .map_err(RepositoryError::ValidationError)?;
+        }
+        match self.code_reuse_policy {
+            CodeReusePolicy::Reject => {
+                if self.exists_by_code(&request.code).await? {
+                    return Err(RepositoryError::DuplicateCode(request.code));
+                }
+            }
+            CodeReusePolicy::Allow => {}
+            CodeReusePolicy::Revive => {
+                if let Some(account) = self.revive(&request, account_type).await? {
+                    return Ok(account);
+                }
+            }
+        }
+
+        if let Some(parent_id) = request.parent_id {
+            self.check_parent(None, account_type, parent_id).await?;
+        }
+
         let id = Uuid::new_v4();
-        let account_type = request.category.account_type();
         let display_order = request.display_order.unwrap_or(0);
 
-        let row = sqlx::query_as::<_, AccountRow>(
+        let row = sqlx::query_as!(
+            AccountRow,
             r#"
-            INSERT INTO accounts (id, code, name, account_type, category, description, display_order)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING id, code, name, account_type, category, description, is_active, display_order, created_at, updated_at
+            INSERT INTO accounts (id, code, name, account_type, category, description, display_order, parent_id, organization_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, organization_id, code, name, account_type AS "account_type: AccountTypeColumn", category, description, is_active, display_order, parent_id, created_at, updated_at
             "#,
+            id,
+            request.code,
+            request.name,
+            AccountTypeColumn::from(account_type) as AccountTypeColumn,
+            request.category.to_string(),
+            request.description,
+            display_order,
+            request.parent_id,
+            self.organization_id
         )
-        .bind(id)
-        .bind(&request.code)
-        .bind(&request.name)
-        .bind(account_type.to_string())
-        .bind(request.category.to_string())
-        .bind(&request.description)
-        .bind(display_order)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *self.connection().await?)
         .await
         .map_err(map_sqlx_error)?;
 
@@ -102,11 +522,13 @@ impl AccountRepository for PostgresAccountRepository {
     }
 
     async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Account>> {
-        let row = sqlx::query_as::<_, AccountRow>(
-            "SELECT id, code, name, account_type, category, description, is_active, display_order, created_at, updated_at FROM accounts WHERE id = $1",
+        let row = sqlx::query_as!(
+            AccountRow,
+            r#"SELECT id, organization_id, code, name, account_type AS "account_type: AccountTypeColumn", category, description, is_active, display_order, parent_id, created_at, updated_at FROM accounts WHERE id = $1 AND organization_id = $2"#,
+            id,
+            self.organization_id
         )
-        .bind(id)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.connection().await?)
         .await
         .map_err(map_sqlx_error)?;
 
@@ -114,11 +536,13 @@ impl AccountRepository for PostgresAccountRepository {
     }
 
     async fn find_by_code(&self, code: &str) -> RepositoryResult<Option<Account>> {
-        let row = sqlx::query_as::<_, AccountRow>(
-            "SELECT id, code, name, account_type, category, description, is_active, display_order, created_at, updated_at FROM accounts WHERE code = $1",
+        let row = sqlx::query_as!(
+            AccountRow,
+            r#"SELECT id, organization_id, code, name, account_type AS "account_type: AccountTypeColumn", category, description, is_active, display_order, parent_id, created_at, updated_at FROM accounts WHERE code = $1 AND organization_id = $2 ORDER BY is_active DESC, updated_at DESC LIMIT 1"#,
+            code,
+            self.organization_id
         )
-        .bind(code)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.connection().await?)
         .await
         .map_err(map_sqlx_error)?;
 
@@ -126,10 +550,25 @@ impl AccountRepository for PostgresAccountRepository {
     }
 
     async fn find_all(&self) -> RepositoryResult<Vec<Account>> {
-        let rows = sqlx::query_as::<_, AccountRow>(
-            "SELECT id, code, name, account_type, category, description, is_active, display_order, created_at, updated_at FROM accounts ORDER BY display_order",
+        let rows = sqlx::query_as!(
+            AccountRow,
+            r#"SELECT id, organization_id, code, name, account_type AS "account_type: AccountTypeColumn", category, description, is_active, display_order, parent_id, created_at, updated_at FROM accounts WHERE organization_id = $1 ORDER BY display_order"#,
+            self.organization_id
         )
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(Account::try_from).collect()
+    }
+
+    async fn find_active(&self) -> RepositoryResult<Vec<Account>> {
+        let rows = sqlx::query_as!(
+            AccountRow,
+            r#"SELECT id, organization_id, code, name, account_type AS "account_type: AccountTypeColumn", category, description, is_active, display_order, parent_id, created_at, updated_at FROM accounts WHERE organization_id = $1 AND is_active ORDER BY display_order"#,
+            self.organization_id
+        )
+        .fetch_all(&mut *self.connection().await?)
         .await
         .map_err(map_sqlx_error)?;
 
@@ -137,68 +576,2007 @@ impl AccountRepository for PostgresAccountRepository {
     }
 
     async fn find_by_type(&self, account_type: AccountType) -> RepositoryResult<Vec<Account>> {
-        let rows = sqlx::query_as::<_, AccountRow>(
-            "SELECT id, code, name, account_type, category, description, is_active, display_order, created_at, updated_at FROM accounts WHERE account_type = $1 ORDER BY display_order",
+        let rows = sqlx::query_as!(
+            AccountRow,
+            r#"SELECT id, organization_id, code, name, account_type AS "account_type: AccountTypeColumn", category, description, is_active, display_order, parent_id, created_at, updated_at FROM accounts WHERE account_type = $1 AND organization_id = $2 AND is_active ORDER BY display_order"#,
+            AccountTypeColumn::from(account_type) as AccountTypeColumn,
+            self.organization_id
         )
-        .bind(account_type.to_string())
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *self.connection().await?)
         .await
         .map_err(map_sqlx_error)?;
 
         rows.into_iter().map(Account::try_from).collect()
     }
 
+    async fn list(&self, query: AccountListQuery) -> RepositoryResult<Vec<Account>> {
+        // 並び替えの列と向きは列挙型から決まる固定の文字列のみ埋め込む
+        // （SQL を実行時に組み立てるため、ここだけはクエリマクロで検査できない）
+        let sql = format!(
+            r#"
+            SELECT id, organization_id, code, name, account_type, category, description, is_active, display_order, parent_id, created_at, updated_at
+            FROM accounts
+            WHERE organization_id = $1
+              AND ($2 OR is_active)
+              AND ($3::account_type IS NULL OR account_type = $3)
+            ORDER BY {} {}, code
+            "#,
+            query.sort.column(),
+            query.order.keyword()
+        );
+        let rows = sqlx::query_as::<_, AccountRow>(&sql)
+            .bind(self.organization_id)
+            .bind(query.include_inactive)
+            .bind(query.account_type.map(AccountTypeColumn::from))
+            .fetch_all(&mut *self.connection().await?)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(Account::try_from).collect()
+    }
+
     async fn update(&self, id: Uuid, request: UpdateAccountRequest) -> RepositoryResult<Account> {
-        let row = sqlx::query_as::<_, AccountRow>(
+        self.update_account(id, request, None).await
+    }
+
+    async fn update_if_unmodified(
+        &self,
+        id: Uuid,
+        request: UpdateAccountRequest,
+        versions: &[DateTime<Utc>],
+    ) -> RepositoryResult<Account> {
+        self.update_account(id, request, Some(versions)).await
+    }
+
+    async fn reorder(&self, account_ids: &[Uuid]) -> RepositoryResult<Vec<Account>> {
+        // 作業単位の中ではセーブポイントになる
+        let mut conn = self.connection().await?;
+        let mut tx = conn.begin().await.map_err(map_sqlx_error)?;
+
+        let rows = sqlx::query_as!(
+            AccountRow,
             r#"
-            UPDATE accounts
-            SET name         = COALESCE($2, name),
-                description  = COALESCE($3, description),
-                display_order = COALESCE($4, display_order),
-                is_active    = COALESCE($5, is_active),
-                updated_at   = NOW()
-            WHERE id = $1
-            RETURNING id, code, name, account_type, category, description, is_active, display_order, created_at, updated_at
+            UPDATE accounts a
+            SET display_order = o.position::INT,
+                updated_at    = NOW()
+            FROM UNNEST($1::UUID[]) WITH ORDINALITY AS o(id, position)
+            WHERE a.id = o.id AND a.organization_id = $2
+            RETURNING a.id, a.organization_id, a.code, a.name, a.account_type AS "account_type: AccountTypeColumn", a.category, a.description, a.is_active, a.display_order, a.parent_id, a.created_at, a.updated_at
             "#,
+            account_ids,
+            self.organization_id
         )
-        .bind(id)
-        .bind(&request.name)
-        .bind(&request.description)
-        .bind(request.display_order)
-        .bind(request.is_active)
-        .fetch_optional(&self.pool)
+        .fetch_all(&mut *tx)
         .await
         .map_err(map_sqlx_error)?;
 
-        match row {
-            Some(row) => Account::try_from(row),
-            None => Err(RepositoryError::NotFound(id)),
-        }
+        // 見つからない勘定科目があればコミットせずに破棄する（ロールバック）
+        let mut accounts: HashMap<Uuid, Account> = rows
+            .into_iter()
+            .map(|row| Account::try_from(row).map(|a| (a.id, a)))
+            .collect::<RepositoryResult<_>>()?;
+        let result = account_ids
+            .iter()
+            .map(|id| accounts.remove(id).ok_or(RepositoryError::NotFound(*id)))
+            .collect::<RepositoryResult<Vec<Account>>>()?;
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(result)
     }
 
     async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()> {
-        let result = sqlx::query(
-            "UPDATE accounts SET is_active = FALSE, updated_at = NOW() WHERE id = $1",
+        self.deactivate(id, None).await
+    }
+
+    async fn soft_delete_if_unmodified(
+        &self,
+        id: Uuid,
+        versions: &[DateTime<Utc>],
+    ) -> RepositoryResult<()> {
+        self.deactivate(id, Some(versions)).await
+    }
+
+    async fn exists_by_code(&self, code: &str) -> RepositoryResult<bool> {
+        let row = sqlx::query_scalar!(
+            "SELECT EXISTS(SELECT 1 FROM accounts WHERE code = $1 AND organization_id = $2) AS \"exists!\"",
+            code,
+            self.organization_id
         )
-        .bind(id)
-        .execute(&self.pool)
+        .fetch_one(&mut *self.connection().await?)
         .await
         .map_err(map_sqlx_error)?;
 
-        if result.rows_affected() == 0 {
-            return Err(RepositoryError::NotFound(id));
-        }
+        Ok(row)
+    }
 
-        Ok(())
+    async fn find_children(&self, parent_id: Uuid) -> RepositoryResult<Vec<Account>> {
+        let rows = sqlx::query_as!(
+            AccountRow,
+            r#"SELECT id, organization_id, code, name, account_type AS "account_type: AccountTypeColumn", category, description, is_active, display_order, parent_id, created_at, updated_at FROM accounts WHERE parent_id = $1 AND organization_id = $2 AND is_active ORDER BY display_order"#,
+            parent_id,
+            self.organization_id
+        )
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(Account::try_from).collect()
     }
 
-    async fn exists_by_code(&self, code: &str) -> RepositoryResult<bool> {
-        let row = sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM accounts WHERE code = $1)")
-            .bind(code)
-            .fetch_one(&self.pool)
+    async fn last_modified(&self) -> RepositoryResult<Option<DateTime<Utc>>> {
+        sqlx::query_scalar!(
+            "SELECT MAX(updated_at) FROM accounts WHERE organization_id = $1",
+            self.organization_id
+        )
+        .fetch_one(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)
+    }
+
+    async fn find_as_of(
+        &self,
+        id: Uuid,
+        as_of: DateTime<Utc>,
+    ) -> RepositoryResult<Option<Account>> {
+        // 履歴はトリガーで記録する（同時刻の変更は後に記録したものを優先）
+        let row = sqlx::query_as!(
+            AccountRow,
+            r#"
+            SELECT id, organization_id, code, name, account_type AS "account_type: AccountTypeColumn", category, description, is_active, display_order, parent_id, created_at, updated_at
+            FROM accounts_history
+            WHERE id = $1 AND organization_id = $2 AND valid_from <= $3
+            ORDER BY valid_from DESC, history_id DESC
+            LIMIT 1
+            "#,
+            id,
+            self.organization_id,
+            as_of
+        )
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(Account::try_from).transpose()
+    }
+
+    async fn next_code(&self, category: &AccountCategory) -> RepositoryResult<Option<String>> {
+        let account_type = self.resolve_account_type(category).await?;
+        let codes = sqlx::query_scalar!(
+            "SELECT code FROM accounts WHERE organization_id = $1",
+            self.organization_id
+        )
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let ranges = self.code_ranges.clone().unwrap_or_default();
+        Ok(ranges.next_code(account_type, codes.iter().map(String::as_str)))
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn AccountRepository> {
+        Arc::new(Self {
+            pool: self.pool.clone(),
+            code_reuse_policy: self.code_reuse_policy,
+            code_ranges: self.code_ranges.clone(),
+            organization_id,
+            tenant_isolation: self.tenant_isolation,
+            transaction: self.transaction.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for PostgresAccountRepository {
+    async fn begin(&self, organization_id: Uuid) -> RepositoryResult<Box<dyn UnitOfWork>> {
+        let mut tx = tenant_transaction(&self.pool, self.tenant_isolation, organization_id).await?;
+        // 同じ組織の作業単位は順に実行し、上限などの事前条件の確認と変更の間に割り込ませない
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind(organization_id.to_string())
+            .execute(&mut *tx)
             .await
             .map_err(map_sqlx_error)?;
+        let transaction: SharedTransaction = Arc::new(Mutex::new(Some(tx)));
+        let accounts = Self {
+            pool: self.pool.clone(),
+            code_reuse_policy: self.code_reuse_policy,
+            code_ranges: self.code_ranges.clone(),
+            organization_id,
+            tenant_isolation: self.tenant_isolation,
+            transaction: Some(transaction.clone()),
+        };
+        let email_queue = PostgresEmailQueueRepository {
+            pool: self.pool.clone(),
+            transaction: Some(transaction.clone()),
+        };
+        Ok(Box::new(PostgresUnitOfWork {
+            transaction,
+            accounts: Arc::new(accounts),
+            email_queue: Arc::new(email_queue),
+        }))
+    }
+}
 
-        Ok(row)
+/// PostgreSQL の作業単位（各リポジトリが 1 つのトランザクションを共有する）
+///
+/// 確定も取り消しもせずに破棄した場合、トランザクションは最後の参照とともにロールバックされる。
+pub struct PostgresUnitOfWork {
+    transaction: SharedTransaction,
+    accounts: Arc<PostgresAccountRepository>,
+    email_queue: Arc<PostgresEmailQueueRepository>,
+}
+
+impl PostgresUnitOfWork {
+    async fn finish(&self) -> RepositoryResult<Transaction<'static, Postgres>> {
+        self.transaction
+            .lock()
+            .await
+            .take()
+            .ok_or_else(transaction_finished)
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    fn accounts(&self) -> Arc<dyn AccountRepository> {
+        self.accounts.clone()
+    }
+
+    fn email_queue(&self) -> Arc<dyn EmailQueueRepository> {
+        self.email_queue.clone()
+    }
+
+    async fn commit(self: Box<Self>) -> RepositoryResult<()> {
+        let tx = self.finish().await?;
+        tx.commit().await.map_err(map_sqlx_error)
+    }
+
+    async fn rollback(self: Box<Self>) -> RepositoryResult<()> {
+        let tx = self.finish().await?;
+        tx.rollback().await.map_err(map_sqlx_error)
+    }
+}
+
+/// PostgreSQL 為替レートリポジトリ
+pub struct PostgresExchangeRateRepository {
+    pool: PgPool,
+    organization_id: Uuid,
+    tenant_isolation: TenantIsolation,
+}
+
+impl PostgresExchangeRateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            organization_id: DEFAULT_ORGANIZATION_ID,
+            tenant_isolation: TenantIsolation::default(),
+        }
+    }
+
+    /// 組織間の分離方式を指定（rls では接続に組織を設定する）
+    pub fn with_tenant_isolation(mut self, isolation: TenantIsolation) -> Self {
+        self.tenant_isolation = isolation;
+        self
+    }
+
+    async fn connection(&self) -> RepositoryResult<PoolConnection<Postgres>> {
+        tenant_connection(&self.pool, self.tenant_isolation, self.organization_id).await
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct ExchangeRateRow {
+    id: Uuid,
+    organization_id: Uuid,
+    base_currency: String,
+    quote_currency: String,
+    rate: Decimal,
+    effective_date: NaiveDate,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<ExchangeRateRow> for ExchangeRate {
+    type Error = RepositoryError;
+
+    fn try_from(row: ExchangeRateRow) -> Result<Self, Self::Error> {
+        let base_currency =
+            Currency::from_str(&row.base_currency).map_err(RepositoryError::DatabaseError)?;
+        let quote_currency =
+            Currency::from_str(&row.quote_currency).map_err(RepositoryError::DatabaseError)?;
+
+        Ok(ExchangeRate {
+            id: row.id,
+            organization_id: row.organization_id,
+            base_currency,
+            quote_currency,
+            rate: row.rate,
+            effective_date: row.effective_date,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+fn map_exchange_rate_error(err: sqlx::Error) -> RepositoryError {
+    match &err {
+        sqlx::Error::Database(db_err) if db_err.code().as_deref() == Some("23505") => {
+            RepositoryError::Conflict(format!(
+                "Exchange rate already exists: {}",
+                db_err.message()
+            ))
+        }
+        _ => RepositoryError::DatabaseError(err.to_string()),
+    }
+}
+
+#[async_trait]
+impl ExchangeRateRepository for PostgresExchangeRateRepository {
+    async fn create(&self, request: CreateExchangeRateRequest) -> RepositoryResult<ExchangeRate> {
+        let row = sqlx::query_as::<_, ExchangeRateRow>(
+            r#"
+            INSERT INTO exchange_rates (id, organization_id, base_currency, quote_currency, rate, effective_date)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, organization_id, base_currency, quote_currency, rate, effective_date, created_at, updated_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(self.organization_id)
+        .bind(request.base_currency.as_str())
+        .bind(request.quote_currency.as_str())
+        .bind(request.rate)
+        .bind(request.effective_date)
+        .fetch_one(&mut *self.connection().await?)
+        .await
+        .map_err(map_exchange_rate_error)?;
+
+        ExchangeRate::try_from(row)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<ExchangeRate>> {
+        let row = sqlx::query_as::<_, ExchangeRateRow>(
+            "SELECT id, organization_id, base_currency, quote_currency, rate, effective_date, created_at, updated_at FROM exchange_rates WHERE id = $1 AND organization_id = $2",
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_exchange_rate_error)?;
+
+        row.map(ExchangeRate::try_from).transpose()
+    }
+
+    async fn find_all(
+        &self,
+        base_currency: Option<Currency>,
+        quote_currency: Option<Currency>,
+    ) -> RepositoryResult<Vec<ExchangeRate>> {
+        let rows = sqlx::query_as::<_, ExchangeRateRow>(
+            r#"
+            SELECT id, organization_id, base_currency, quote_currency, rate, effective_date, created_at, updated_at
+            FROM exchange_rates
+            WHERE organization_id = $1
+              AND ($2::VARCHAR IS NULL OR base_currency = $2)
+              AND ($3::VARCHAR IS NULL OR quote_currency = $3)
+            ORDER BY base_currency, quote_currency, effective_date DESC
+            "#,
+        )
+        .bind(self.organization_id)
+        .bind(base_currency.as_ref().map(Currency::as_str))
+        .bind(quote_currency.as_ref().map(Currency::as_str))
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_exchange_rate_error)?;
+
+        rows.into_iter().map(ExchangeRate::try_from).collect()
+    }
+
+    async fn find_effective(
+        &self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        on: NaiveDate,
+    ) -> RepositoryResult<Option<ExchangeRate>> {
+        let row = sqlx::query_as::<_, ExchangeRateRow>(
+            r#"
+            SELECT id, organization_id, base_currency, quote_currency, rate, effective_date, created_at, updated_at
+            FROM exchange_rates
+            WHERE organization_id = $1
+              AND base_currency = $2 AND quote_currency = $3 AND effective_date <= $4
+            ORDER BY effective_date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(self.organization_id)
+        .bind(base_currency.as_str())
+        .bind(quote_currency.as_str())
+        .bind(on)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_exchange_rate_error)?;
+
+        row.map(ExchangeRate::try_from).transpose()
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        request: UpdateExchangeRateRequest,
+    ) -> RepositoryResult<ExchangeRate> {
+        let row = sqlx::query_as::<_, ExchangeRateRow>(
+            r#"
+            UPDATE exchange_rates
+            SET rate           = COALESCE($3, rate),
+                effective_date = COALESCE($4, effective_date),
+                updated_at     = NOW()
+            WHERE id = $1 AND organization_id = $2
+            RETURNING id, organization_id, base_currency, quote_currency, rate, effective_date, created_at, updated_at
+            "#,
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .bind(request.rate)
+        .bind(request.effective_date)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_exchange_rate_error)?;
+
+        match row {
+            Some(row) => ExchangeRate::try_from(row),
+            None => Err(RepositoryError::NotFound(id)),
+        }
+    }
+
+    async fn delete(&self, id: Uuid) -> RepositoryResult<()> {
+        let result =
+            sqlx::query("DELETE FROM exchange_rates WHERE id = $1 AND organization_id = $2")
+                .bind(id)
+                .bind(self.organization_id)
+                .execute(&mut *self.connection().await?)
+                .await
+                .map_err(map_exchange_rate_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn ExchangeRateRepository> {
+        Arc::new(Self {
+            pool: self.pool.clone(),
+            organization_id,
+            tenant_isolation: self.tenant_isolation,
+        })
+    }
+}
+
+/// PostgreSQL 金種表リポジトリ
+pub struct PostgresCashCountRepository {
+    pool: PgPool,
+    organization_id: Uuid,
+    tenant_isolation: TenantIsolation,
+}
+
+impl PostgresCashCountRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            organization_id: DEFAULT_ORGANIZATION_ID,
+            tenant_isolation: TenantIsolation::default(),
+        }
+    }
+
+    /// 組織間の分離方式を指定（rls では接続に組織を設定する）
+    pub fn with_tenant_isolation(mut self, isolation: TenantIsolation) -> Self {
+        self.tenant_isolation = isolation;
+        self
+    }
+
+    async fn connection(&self) -> RepositoryResult<PoolConnection<Postgres>> {
+        tenant_connection(&self.pool, self.tenant_isolation, self.organization_id).await
+    }
+
+    /// 金種表の見出し行に明細を付与する
+    async fn attach_lines(&self, rows: Vec<CashCountRow>) -> RepositoryResult<Vec<CashCount>> {
+        let ids: Vec<Uuid> = rows.iter().map(|row| row.id).collect();
+        let lines = sqlx::query_as::<_, CashCountLineRow>(
+            r#"
+            SELECT cash_count_id, denomination, quantity
+            FROM cash_count_lines
+            WHERE cash_count_id = ANY($1)
+            ORDER BY denomination DESC
+            "#,
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let count_lines = lines
+                    .iter()
+                    .filter(|line| line.cash_count_id == row.id)
+                    .map(|line| DenominationCount {
+                        denomination: line.denomination,
+                        quantity: line.quantity,
+                    })
+                    .collect();
+                row.into_cash_count(count_lines)
+            })
+            .collect())
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CashCountRow {
+    id: Uuid,
+    organization_id: Uuid,
+    safe: String,
+    counted_at: DateTime<Utc>,
+    total: i64,
+    book_balance: Option<i64>,
+    counted_by: Option<String>,
+    note: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl CashCountRow {
+    fn into_cash_count(self, lines: Vec<DenominationCount>) -> CashCount {
+        CashCount {
+            id: self.id,
+            organization_id: self.organization_id,
+            safe: self.safe,
+            counted_at: self.counted_at,
+            lines,
+            total: Money::new(self.total, Currency::JPY),
+            book_balance: self
+                .book_balance
+                .map(|amount| Money::new(amount, Currency::JPY)),
+            counted_by: self.counted_by,
+            note: self.note,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CashCountLineRow {
+    cash_count_id: Uuid,
+    denomination: i64,
+    quantity: i64,
+}
+
+#[async_trait]
+impl CashCountRepository for PostgresCashCountRepository {
+    async fn create(&self, request: CreateCashCountRequest) -> RepositoryResult<CashCount> {
+        let count = CashCount::new(
+            self.organization_id,
+            request.safe,
+            request.counted_at.unwrap_or_else(Utc::now),
+            request.lines,
+            request.counted_by,
+            request.note,
+        )
+        .map_err(|e| RepositoryError::ValidationError(e.to_string()))?
+        .with_book_balance(request.book_balance);
+
+        let mut tx =
+            tenant_transaction(&self.pool, self.tenant_isolation, self.organization_id).await?;
+
+        let created_at: DateTime<Utc> = sqlx::query_scalar(
+            r#"
+            INSERT INTO cash_counts (id, organization_id, safe, counted_at, total, book_balance, counted_by, note)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING created_at
+            "#,
+        )
+        .bind(count.id)
+        .bind(count.organization_id)
+        .bind(&count.safe)
+        .bind(count.counted_at)
+        .bind(count.total.amount)
+        .bind(count.book_balance.map(|balance| balance.amount))
+        .bind(&count.counted_by)
+        .bind(&count.note)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        for line in &count.lines {
+            sqlx::query(
+                "INSERT INTO cash_count_lines (cash_count_id, denomination, quantity) VALUES ($1, $2, $3)",
+            )
+            .bind(count.id)
+            .bind(line.denomination)
+            .bind(line.quantity)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+
+        Ok(CashCount {
+            created_at,
+            ..count
+        })
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<CashCount>> {
+        let row = sqlx::query_as::<_, CashCountRow>(
+            "SELECT id, organization_id, safe, counted_at, total, book_balance, counted_by, note, created_at FROM cash_counts WHERE id = $1 AND organization_id = $2",
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        match row {
+            Some(row) => Ok(self.attach_lines(vec![row]).await?.pop()),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all(&self, filter: CashCountFilter) -> RepositoryResult<Vec<CashCount>> {
+        let rows = sqlx::query_as::<_, CashCountRow>(
+            r#"
+            SELECT id, organization_id, safe, counted_at, total, book_balance, counted_by, note, created_at
+            FROM cash_counts
+            WHERE organization_id = $1
+              AND ($2::VARCHAR IS NULL OR safe = $2)
+              AND ($3::TIMESTAMPTZ IS NULL OR counted_at >= $3)
+              AND ($4::TIMESTAMPTZ IS NULL OR counted_at < $4)
+            ORDER BY counted_at DESC
+            "#,
+        )
+        .bind(self.organization_id)
+        .bind(&filter.safe)
+        .bind(filter.from)
+        .bind(filter.to)
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        self.attach_lines(rows).await
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn CashCountRepository> {
+        Arc::new(Self {
+            pool: self.pool.clone(),
+            organization_id,
+            tenant_isolation: self.tenant_isolation,
+        })
+    }
+}
+
+/// PostgreSQL Webhook リポジトリ
+pub struct PostgresWebhookRepository {
+    pool: PgPool,
+    organization_id: Uuid,
+    tenant_isolation: TenantIsolation,
+}
+
+impl PostgresWebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            organization_id: DEFAULT_ORGANIZATION_ID,
+            tenant_isolation: TenantIsolation::default(),
+        }
+    }
+
+    /// 組織間の分離方式を指定（rls では接続に組織を設定する）
+    pub fn with_tenant_isolation(mut self, isolation: TenantIsolation) -> Self {
+        self.tenant_isolation = isolation;
+        self
+    }
+
+    async fn connection(&self) -> RepositoryResult<PoolConnection<Postgres>> {
+        tenant_connection(&self.pool, self.tenant_isolation, self.organization_id).await
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct WebhookRow {
+    id: Uuid,
+    organization_id: Uuid,
+    url: String,
+    events: Vec<String>,
+    secret: String,
+    is_active: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<WebhookRow> for Webhook {
+    fn from(row: WebhookRow) -> Self {
+        Webhook {
+            id: row.id,
+            organization_id: row.organization_id,
+            url: row.url,
+            events: row.events,
+            secret: row.secret,
+            is_active: row.is_active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PostgresWebhookRepository {
+    async fn create(&self, request: CreateWebhookRequest) -> RepositoryResult<Webhook> {
+        let row = sqlx::query_as::<_, WebhookRow>(
+            r#"
+            INSERT INTO webhooks (id, organization_id, url, events, secret)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, organization_id, url, events, secret, is_active, created_at, updated_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(self.organization_id)
+        .bind(&request.url)
+        .bind(&request.events)
+        .bind(&request.secret)
+        .fetch_one(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Webhook>> {
+        let row = sqlx::query_as::<_, WebhookRow>(
+            "SELECT id, organization_id, url, events, secret, is_active, created_at, updated_at FROM webhooks WHERE id = $1 AND organization_id = $2",
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(row.map(Webhook::from))
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<Webhook>> {
+        let rows = sqlx::query_as::<_, WebhookRow>(
+            "SELECT id, organization_id, url, events, secret, is_active, created_at, updated_at FROM webhooks WHERE organization_id = $1 ORDER BY created_at",
+        )
+        .bind(self.organization_id)
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows.into_iter().map(Webhook::from).collect())
+    }
+
+    async fn update(&self, id: Uuid, request: UpdateWebhookRequest) -> RepositoryResult<Webhook> {
+        let row = sqlx::query_as::<_, WebhookRow>(
+            r#"
+            UPDATE webhooks
+            SET url        = COALESCE($3, url),
+                events     = COALESCE($4, events),
+                secret     = COALESCE($5, secret),
+                is_active  = COALESCE($6, is_active),
+                updated_at = NOW()
+            WHERE id = $1 AND organization_id = $2
+            RETURNING id, organization_id, url, events, secret, is_active, created_at, updated_at
+            "#,
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .bind(&request.url)
+        .bind(&request.events)
+        .bind(&request.secret)
+        .bind(request.is_active)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(Webhook::from).ok_or(RepositoryError::NotFound(id))
+    }
+
+    async fn delete(&self, id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query("DELETE FROM webhooks WHERE id = $1 AND organization_id = $2")
+            .bind(id)
+            .bind(self.organization_id)
+            .execute(&mut *self.connection().await?)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    async fn find_subscribers(&self, event_type: &str) -> RepositoryResult<Vec<Webhook>> {
+        let rows = sqlx::query_as::<_, WebhookRow>(
+            r#"
+            SELECT id, organization_id, url, events, secret, is_active, created_at, updated_at
+            FROM webhooks
+            WHERE organization_id = $1 AND is_active AND $2 = ANY(events)
+            ORDER BY created_at
+            "#,
+        )
+        .bind(self.organization_id)
+        .bind(event_type)
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows.into_iter().map(Webhook::from).collect())
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn WebhookRepository> {
+        Arc::new(Self {
+            pool: self.pool.clone(),
+            organization_id,
+            tenant_isolation: self.tenant_isolation,
+        })
+    }
+}
+
+/// PostgreSQL 組織リポジトリ
+pub struct PostgresOrganizationRepository {
+    pool: PgPool,
+}
+
+impl PostgresOrganizationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct OrganizationRow {
+    id: Uuid,
+    name: String,
+    is_active: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<OrganizationRow> for Organization {
+    fn from(row: OrganizationRow) -> Self {
+        Organization {
+            id: row.id,
+            name: row.name,
+            is_active: row.is_active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for PostgresOrganizationRepository {
+    async fn create(&self, request: CreateOrganizationRequest) -> RepositoryResult<Organization> {
+        let row = sqlx::query_as::<_, OrganizationRow>(
+            r#"
+            INSERT INTO organizations (id, name)
+            VALUES ($1, $2)
+            RETURNING id, name, is_active, created_at, updated_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(&request.name)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Organization>> {
+        let row = sqlx::query_as::<_, OrganizationRow>(
+            "SELECT id, name, is_active, created_at, updated_at FROM organizations WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(row.map(Organization::from))
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<Organization>> {
+        let rows = sqlx::query_as::<_, OrganizationRow>(
+            "SELECT id, name, is_active, created_at, updated_at FROM organizations WHERE is_active ORDER BY name",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows.into_iter().map(Organization::from).collect())
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        request: UpdateOrganizationRequest,
+    ) -> RepositoryResult<Organization> {
+        let row = sqlx::query_as::<_, OrganizationRow>(
+            r#"
+            UPDATE organizations
+            SET name       = COALESCE($2, name),
+                is_active  = COALESCE($3, is_active),
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING id, name, is_active, created_at, updated_at
+            "#,
+        )
+        .bind(id)
+        .bind(&request.name)
+        .bind(request.is_active)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(Organization::from)
+            .ok_or(RepositoryError::NotFound(id))
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query(
+            "UPDATE organizations SET is_active = FALSE, updated_at = NOW() WHERE id = $1",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(id));
+        }
+
+        Ok(())
+    }
+}
+
+/// PostgreSQL メール送信キュー
+pub struct PostgresEmailQueueRepository {
+    pool: PgPool,
+    /// 作業単位の中で使う場合のトランザクション
+    transaction: Option<SharedTransaction>,
+}
+
+impl PostgresEmailQueueRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            transaction: None,
+        }
+    }
+
+    async fn connection(&self) -> RepositoryResult<RepositoryConnection<'_>> {
+        match &self.transaction {
+            Some(transaction) => RepositoryConnection::transaction(transaction).await,
+            None => self
+                .pool
+                .acquire()
+                .await
+                .map(RepositoryConnection::Pool)
+                .map_err(map_sqlx_error),
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct QueuedEmailRow {
+    id: Uuid,
+    recipient: String,
+    subject: String,
+    body: String,
+    status: String,
+    attempts: i32,
+    last_error: Option<String>,
+    next_attempt_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    sent_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<QueuedEmailRow> for QueuedEmail {
+    type Error = RepositoryError;
+
+    fn try_from(row: QueuedEmailRow) -> Result<Self, Self::Error> {
+        let status = EmailStatus::from_str(&row.status).map_err(RepositoryError::DatabaseError)?;
+
+        Ok(QueuedEmail {
+            id: row.id,
+            message: EmailMessage {
+                to: row.recipient,
+                subject: row.subject,
+                body: row.body,
+            },
+            status,
+            attempts: row.attempts,
+            last_error: row.last_error,
+            next_attempt_at: row.next_attempt_at,
+            created_at: row.created_at,
+            sent_at: row.sent_at,
+        })
+    }
+}
+
+#[async_trait]
+impl EmailQueueRepository for PostgresEmailQueueRepository {
+    async fn enqueue(&self, message: EmailMessage) -> RepositoryResult<QueuedEmail> {
+        let row = sqlx::query_as::<_, QueuedEmailRow>(
+            r#"
+            INSERT INTO email_outbox (id, recipient, subject, body)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, recipient, subject, body, status, attempts, last_error, next_attempt_at, created_at, sent_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(&message.to)
+        .bind(&message.subject)
+        .bind(&message.body)
+        .fetch_one(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        QueuedEmail::try_from(row)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<QueuedEmail>> {
+        let row = sqlx::query_as::<_, QueuedEmailRow>(
+            "SELECT id, recipient, subject, body, status, attempts, last_error, next_attempt_at, created_at, sent_at FROM email_outbox WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(QueuedEmail::try_from).transpose()
+    }
+
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+        lease: Duration,
+    ) -> RepositoryResult<Vec<QueuedEmail>> {
+        let rows = sqlx::query_as::<_, QueuedEmailRow>(
+            r#"
+            WITH due AS (
+                SELECT id FROM email_outbox
+                WHERE status = 'pending' AND next_attempt_at <= $1
+                ORDER BY next_attempt_at, created_at
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE email_outbox e
+            SET next_attempt_at = $3
+            FROM due
+            WHERE e.id = due.id
+            RETURNING e.id, e.recipient, e.subject, e.body, e.status, e.attempts, e.last_error, e.next_attempt_at, e.created_at, e.sent_at
+            "#,
+        )
+        .bind(now)
+        .bind(limit)
+        .bind(now + lease)
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(QueuedEmail::try_from).collect()
+    }
+
+    async fn mark_sent(&self, id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE email_outbox
+            SET status = 'sent', attempts = attempts + 1, last_error = NULL, sent_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .execute(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> RepositoryResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE email_outbox
+            SET attempts        = attempts + 1,
+                last_error      = $2,
+                status          = CASE WHEN $3::TIMESTAMPTZ IS NULL THEN 'failed' ELSE status END,
+                next_attempt_at = COALESCE($3, next_attempt_at)
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .bind(retry_at)
+        .execute(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(id));
+        }
+
+        Ok(())
+    }
+}
+
+/// PostgreSQL 勘定科目カテゴリリポジトリ
+pub struct PostgresCategoryRepository {
+    pool: PgPool,
+    organization_id: Uuid,
+    tenant_isolation: TenantIsolation,
+}
+
+impl PostgresCategoryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            organization_id: DEFAULT_ORGANIZATION_ID,
+            tenant_isolation: TenantIsolation::default(),
+        }
+    }
+
+    /// 組織間の分離方式を指定（rls では接続に組織を設定する）
+    pub fn with_tenant_isolation(mut self, isolation: TenantIsolation) -> Self {
+        self.tenant_isolation = isolation;
+        self
+    }
+
+    async fn connection(&self) -> RepositoryResult<PoolConnection<Postgres>> {
+        tenant_connection(&self.pool, self.tenant_isolation, self.organization_id).await
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CategoryRow {
+    id: Uuid,
+    organization_id: Option<Uuid>,
+    code: String,
+    name: String,
+    account_type: AccountTypeColumn,
+    is_active: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<CategoryRow> for CategoryDefinition {
+    type Error = RepositoryError;
+
+    fn try_from(row: CategoryRow) -> Result<Self, Self::Error> {
+        Ok(CategoryDefinition {
+            id: row.id,
+            organization_id: row.organization_id,
+            code: row.code,
+            name: row.name,
+            account_type: row.account_type.into(),
+            is_active: row.is_active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for PostgresCategoryRepository {
+    async fn create(&self, request: CreateCategoryRequest) -> RepositoryResult<CategoryDefinition> {
+        let duplicate =
+            || RepositoryError::Conflict(format!("Category code already exists: {}", request.code));
+        if self.find_by_code(&request.code).await?.is_some() {
+            return Err(duplicate());
+        }
+
+        let row = sqlx::query_as::<_, CategoryRow>(
+            r#"
+            INSERT INTO account_categories (id, organization_id, code, name, account_type)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, organization_id, code, name, account_type, is_active, created_at, updated_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(self.organization_id)
+        .bind(&request.code)
+        .bind(&request.name)
+        .bind(AccountTypeColumn::from(request.account_type))
+        .fetch_one(&mut *self.connection().await?)
+        .await
+        .map_err(|err| match map_sqlx_error(err) {
+            RepositoryError::DuplicateCode(_) => duplicate(),
+            other => other,
+        })?;
+
+        CategoryDefinition::try_from(row)
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<CategoryDefinition>> {
+        let rows = sqlx::query_as::<_, CategoryRow>(
+            "SELECT id, organization_id, code, name, account_type, is_active, created_at, updated_at FROM account_categories WHERE (organization_id IS NULL OR organization_id = $1) AND is_active",
+        )
+        .bind(self.organization_id)
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let mut definitions = rows
+            .into_iter()
+            .map(CategoryDefinition::try_from)
+            .collect::<RepositoryResult<Vec<_>>>()?;
+        sort_categories(&mut definitions);
+        Ok(definitions)
+    }
+
+    async fn find_by_code(&self, code: &str) -> RepositoryResult<Option<CategoryDefinition>> {
+        let row = sqlx::query_as::<_, CategoryRow>(
+            "SELECT id, organization_id, code, name, account_type, is_active, created_at, updated_at FROM account_categories WHERE code = $1 AND (organization_id IS NULL OR organization_id = $2) AND is_active LIMIT 1",
+        )
+        .bind(code)
+        .bind(self.organization_id)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(CategoryDefinition::try_from).transpose()
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query(
+            "UPDATE account_categories SET is_active = FALSE, updated_at = NOW() WHERE id = $1 AND organization_id = $2 AND is_active",
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .execute(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(id));
+        }
+        Ok(())
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn CategoryRepository> {
+        Arc::new(Self {
+            pool: self.pool.clone(),
+            organization_id,
+            tenant_isolation: self.tenant_isolation,
+        })
+    }
+}
+
+/// PostgreSQL 横断検索
+///
+/// 全文検索（`search_vector`）とトライグラムによる部分一致を組み合わせる。
+/// 日本語は単語に分割されないため、部分一致で拾ったものも関連度に加える。
+pub struct PostgresSearchRepository {
+    pool: PgPool,
+    organization_id: Uuid,
+    tenant_isolation: TenantIsolation,
+}
+
+impl PostgresSearchRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            organization_id: DEFAULT_ORGANIZATION_ID,
+            tenant_isolation: TenantIsolation::default(),
+        }
+    }
+
+    /// 組織間の分離方式を指定（rls では接続に組織を設定する）
+    pub fn with_tenant_isolation(mut self, isolation: TenantIsolation) -> Self {
+        self.tenant_isolation = isolation;
+        self
+    }
+
+    async fn connection(&self) -> RepositoryResult<PoolConnection<Postgres>> {
+        tenant_connection(&self.pool, self.tenant_isolation, self.organization_id).await
+    }
+}
+
+/// 検索対象（$1: 組織、$2: 検索語、$3: LIKE パターン）
+const SEARCH_MATCHES: &str = r#"
+    WITH matches AS (
+        SELECT 'account' AS entity_type, id, name AS title, description AS snippet,
+               (ts_rank(search_vector, plainto_tsquery('simple', $2))
+                + similarity(name, $2)
+                + CASE WHEN name ILIKE $3 THEN 1 ELSE 0 END
+                + CASE WHEN starts_with(code, $2) THEN 1 ELSE 0 END)::FLOAT8 AS rank
+        FROM accounts
+        WHERE organization_id = $1
+          AND is_active
+          AND (search_vector @@ plainto_tsquery('simple', $2)
+               OR name ILIKE $3
+               OR description ILIKE $3
+               OR starts_with(code, $2))
+    )
+"#;
+
+#[derive(Debug, sqlx::FromRow)]
+struct SearchHitRow {
+    entity_type: String,
+    id: Uuid,
+    title: String,
+    snippet: Option<String>,
+    rank: f64,
+}
+
+impl TryFrom<SearchHitRow> for SearchHit {
+    type Error = RepositoryError;
+
+    fn try_from(row: SearchHitRow) -> Result<Self, Self::Error> {
+        Ok(SearchHit {
+            entity_type: SearchEntityType::from_str(&row.entity_type)
+                .map_err(RepositoryError::DatabaseError)?,
+            id: row.id,
+            title: row.title,
+            snippet: row.snippet,
+            rank: row.rank,
+        })
+    }
+}
+
+#[async_trait]
+impl SearchRepository for PostgresSearchRepository {
+    async fn search(&self, query: &SearchQuery) -> RepositoryResult<SearchResults> {
+        let term = query.q.trim();
+        let pattern = query.like_pattern();
+
+        let rows = sqlx::query_as::<_, SearchHitRow>(&format!(
+            r#"{}
+            SELECT entity_type, id, title, snippet, rank
+            FROM matches
+            WHERE ($4::VARCHAR IS NULL OR entity_type = $4)
+            ORDER BY rank DESC, title
+            LIMIT $5
+            "#,
+            SEARCH_MATCHES
+        ))
+        .bind(self.organization_id)
+        .bind(term)
+        .bind(&pattern)
+        .bind(query.entity_type.map(|t| t.to_string()))
+        .bind(query.limit())
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let facets: Vec<(String, i64)> = sqlx::query_as(&format!(
+            r#"{}
+            SELECT entity_type, COUNT(*) FROM matches
+            GROUP BY entity_type
+            ORDER BY entity_type
+            "#,
+            SEARCH_MATCHES
+        ))
+        .bind(self.organization_id)
+        .bind(term)
+        .bind(&pattern)
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(SearchResults {
+            hits: rows
+                .into_iter()
+                .map(SearchHit::try_from)
+                .collect::<RepositoryResult<_>>()?,
+            facets: facets
+                .into_iter()
+                .map(|(entity_type, count)| {
+                    Ok(SearchFacet {
+                        entity_type: SearchEntityType::from_str(&entity_type)
+                            .map_err(RepositoryError::DatabaseError)?,
+                        count,
+                    })
+                })
+                .collect::<RepositoryResult<_>>()?,
+        })
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn SearchRepository> {
+        Arc::new(Self {
+            pool: self.pool.clone(),
+            organization_id,
+            tenant_isolation: self.tenant_isolation,
+        })
+    }
+}
+
+/// PostgreSQL 固定資産台帳リポジトリ
+pub struct PostgresFixedAssetRepository {
+    pool: PgPool,
+    organization_id: Uuid,
+    tenant_isolation: TenantIsolation,
+}
+
+impl PostgresFixedAssetRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            organization_id: DEFAULT_ORGANIZATION_ID,
+            tenant_isolation: TenantIsolation::default(),
+        }
+    }
+
+    /// 組織間の分離方式を指定（rls では接続に組織を設定する）
+    pub fn with_tenant_isolation(mut self, isolation: TenantIsolation) -> Self {
+        self.tenant_isolation = isolation;
+        self
+    }
+
+    async fn connection(&self) -> RepositoryResult<PoolConnection<Postgres>> {
+        tenant_connection(&self.pool, self.tenant_isolation, self.organization_id).await
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct FixedAssetRow {
+    id: Uuid,
+    organization_id: Uuid,
+    name: String,
+    acquisition_date: NaiveDate,
+    acquisition_cost: i64,
+    salvage_value: i64,
+    useful_life_months: i32,
+    expense_account_id: Uuid,
+    is_active: bool,
+    note: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<FixedAssetRow> for FixedAsset {
+    fn from(row: FixedAssetRow) -> Self {
+        FixedAsset {
+            id: row.id,
+            organization_id: row.organization_id,
+            name: row.name,
+            acquisition_date: row.acquisition_date,
+            acquisition_cost: Money::new(row.acquisition_cost, Currency::JPY),
+            salvage_value: Money::new(row.salvage_value, Currency::JPY),
+            useful_life_months: row.useful_life_months,
+            expense_account_id: row.expense_account_id,
+            is_active: row.is_active,
+            note: row.note,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl FixedAssetRepository for PostgresFixedAssetRepository {
+    async fn create(&self, request: CreateFixedAssetRequest) -> RepositoryResult<FixedAsset> {
+        let asset = FixedAsset::new(self.organization_id, request);
+        let row = sqlx::query_as::<_, FixedAssetRow>(
+            r#"
+            INSERT INTO fixed_assets (id, organization_id, name, acquisition_date, acquisition_cost, salvage_value, useful_life_months, expense_account_id, note)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, organization_id, name, acquisition_date, acquisition_cost, salvage_value, useful_life_months, expense_account_id, is_active, note, created_at, updated_at
+            "#,
+        )
+        .bind(asset.id)
+        .bind(asset.organization_id)
+        .bind(&asset.name)
+        .bind(asset.acquisition_date)
+        .bind(asset.acquisition_cost.amount)
+        .bind(asset.salvage_value.amount)
+        .bind(asset.useful_life_months)
+        .bind(asset.expense_account_id)
+        .bind(&asset.note)
+        .fetch_one(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(FixedAsset::from(row))
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<FixedAsset>> {
+        let row = sqlx::query_as::<_, FixedAssetRow>(
+            "SELECT id, organization_id, name, acquisition_date, acquisition_cost, salvage_value, useful_life_months, expense_account_id, is_active, note, created_at, updated_at FROM fixed_assets WHERE id = $1 AND organization_id = $2",
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(row.map(FixedAsset::from))
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<FixedAsset>> {
+        let rows = sqlx::query_as::<_, FixedAssetRow>(
+            "SELECT id, organization_id, name, acquisition_date, acquisition_cost, salvage_value, useful_life_months, expense_account_id, is_active, note, created_at, updated_at FROM fixed_assets WHERE organization_id = $1 ORDER BY acquisition_date, name, created_at",
+        )
+        .bind(self.organization_id)
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows.into_iter().map(FixedAsset::from).collect())
+    }
+
+    async fn dispose(&self, id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query(
+            "UPDATE fixed_assets SET is_active = FALSE, updated_at = NOW() WHERE id = $1 AND organization_id = $2 AND is_active",
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .execute(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn FixedAssetRepository> {
+        Arc::new(Self {
+            pool: self.pool.clone(),
+            organization_id,
+            tenant_isolation: self.tenant_isolation,
+        })
+    }
+}
+
+/// PostgreSQL 取引先リポジトリ
+pub struct PostgresCounterpartyRepository {
+    pool: PgPool,
+    organization_id: Uuid,
+    tenant_isolation: TenantIsolation,
+}
+
+impl PostgresCounterpartyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            organization_id: DEFAULT_ORGANIZATION_ID,
+            tenant_isolation: TenantIsolation::default(),
+        }
+    }
+
+    /// 組織間の分離方式を指定（rls では接続に組織を設定する）
+    pub fn with_tenant_isolation(mut self, isolation: TenantIsolation) -> Self {
+        self.tenant_isolation = isolation;
+        self
+    }
+
+    async fn connection(&self) -> RepositoryResult<PoolConnection<Postgres>> {
+        tenant_connection(&self.pool, self.tenant_isolation, self.organization_id).await
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CounterpartyRow {
+    id: Uuid,
+    organization_id: Uuid,
+    name: String,
+    contact: Option<String>,
+    note: Option<String>,
+    is_active: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<CounterpartyRow> for Counterparty {
+    fn from(row: CounterpartyRow) -> Self {
+        Counterparty {
+            id: row.id,
+            organization_id: row.organization_id,
+            name: row.name,
+            contact: row.contact,
+            note: row.note,
+            is_active: row.is_active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl CounterpartyRepository for PostgresCounterpartyRepository {
+    async fn create(&self, request: CreateCounterpartyRequest) -> RepositoryResult<Counterparty> {
+        let counterparty = Counterparty::new(self.organization_id, request);
+        let row = sqlx::query_as::<_, CounterpartyRow>(
+            r#"
+            INSERT INTO counterparties (id, organization_id, name, contact, note)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, organization_id, name, contact, note, is_active, created_at, updated_at
+            "#,
+        )
+        .bind(counterparty.id)
+        .bind(counterparty.organization_id)
+        .bind(&counterparty.name)
+        .bind(&counterparty.contact)
+        .bind(&counterparty.note)
+        .fetch_one(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(Counterparty::from(row))
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Counterparty>> {
+        let row = sqlx::query_as::<_, CounterpartyRow>(
+            "SELECT id, organization_id, name, contact, note, is_active, created_at, updated_at FROM counterparties WHERE id = $1 AND organization_id = $2",
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(row.map(Counterparty::from))
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<Counterparty>> {
+        let rows = sqlx::query_as::<_, CounterpartyRow>(
+            "SELECT id, organization_id, name, contact, note, is_active, created_at, updated_at FROM counterparties WHERE organization_id = $1 AND is_active ORDER BY name",
+        )
+        .bind(self.organization_id)
+        .fetch_all(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows.into_iter().map(Counterparty::from).collect())
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        request: UpdateCounterpartyRequest,
+    ) -> RepositoryResult<Counterparty> {
+        // contact・note は省略時のみ現在値を残す（null なら消去）
+        let row = sqlx::query_as::<_, CounterpartyRow>(
+            r#"
+            UPDATE counterparties
+            SET name       = COALESCE($3, name),
+                contact    = CASE WHEN $4 THEN contact ELSE $5 END,
+                note       = CASE WHEN $6 THEN note ELSE $7 END,
+                is_active  = COALESCE($8, is_active),
+                updated_at = NOW()
+            WHERE id = $1 AND organization_id = $2
+            RETURNING id, organization_id, name, contact, note, is_active, created_at, updated_at
+            "#,
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .bind(&request.name)
+        .bind(request.contact.is_absent())
+        .bind(request.contact.as_value())
+        .bind(request.note.is_absent())
+        .bind(request.note.as_value())
+        .bind(request.is_active)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(Counterparty::from)
+            .ok_or(RepositoryError::NotFound(id))
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query(
+            "UPDATE counterparties SET is_active = FALSE, updated_at = NOW() WHERE id = $1 AND organization_id = $2",
+        )
+        .bind(id)
+        .bind(self.organization_id)
+        .execute(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn CounterpartyRepository> {
+        Arc::new(Self {
+            pool: self.pool.clone(),
+            organization_id,
+            tenant_isolation: self.tenant_isolation,
+        })
+    }
+}
+
+/// PostgreSQL 月次締めリポジトリ
+pub struct PostgresMonthCloseRepository {
+    pool: PgPool,
+    organization_id: Uuid,
+    tenant_isolation: TenantIsolation,
+}
+
+impl PostgresMonthCloseRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            organization_id: DEFAULT_ORGANIZATION_ID,
+            tenant_isolation: TenantIsolation::default(),
+        }
+    }
+
+    /// 組織間の分離方式を指定（rls では接続に組織を設定する）
+    pub fn with_tenant_isolation(mut self, isolation: TenantIsolation) -> Self {
+        self.tenant_isolation = isolation;
+        self
+    }
+
+    async fn connection(&self) -> RepositoryResult<PoolConnection<Postgres>> {
+        tenant_connection(&self.pool, self.tenant_isolation, self.organization_id).await
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct MonthCloseRow {
+    organization_id: Uuid,
+    month: NaiveDate,
+    bank_reconciled: bool,
+    offerings_posted: bool,
+    reports_generated: bool,
+    locked_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<MonthCloseRow> for MonthClose {
+    fn from(row: MonthCloseRow) -> Self {
+        MonthClose {
+            organization_id: row.organization_id,
+            month: row.month,
+            bank_reconciled: row.bank_reconciled,
+            offerings_posted: row.offerings_posted,
+            reports_generated: row.reports_generated,
+            locked_at: row.locked_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl MonthCloseRepository for PostgresMonthCloseRepository {
+    async fn find(&self, month: NaiveDate) -> RepositoryResult<Option<MonthClose>> {
+        let row = sqlx::query_as::<_, MonthCloseRow>(
+            "SELECT organization_id, month, bank_reconciled, offerings_posted, reports_generated, locked_at, updated_at FROM month_closes WHERE organization_id = $1 AND month = $2",
+        )
+        .bind(self.organization_id)
+        .bind(month)
+        .fetch_optional(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(row.map(MonthClose::from))
+    }
+
+    async fn save(&self, close: &MonthClose) -> RepositoryResult<MonthClose> {
+        let row = sqlx::query_as::<_, MonthCloseRow>(
+            r#"
+            INSERT INTO month_closes
+                (organization_id, month, bank_reconciled, offerings_posted, reports_generated, locked_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (organization_id, month) DO UPDATE
+            SET bank_reconciled   = EXCLUDED.bank_reconciled,
+                offerings_posted  = EXCLUDED.offerings_posted,
+                reports_generated = EXCLUDED.reports_generated,
+                locked_at         = EXCLUDED.locked_at,
+                updated_at        = EXCLUDED.updated_at
+            RETURNING organization_id, month, bank_reconciled, offerings_posted, reports_generated, locked_at, updated_at
+            "#,
+        )
+        .bind(self.organization_id)
+        .bind(close.month)
+        .bind(close.bank_reconciled)
+        .bind(close.offerings_posted)
+        .bind(close.reports_generated)
+        .bind(close.locked_at)
+        .bind(close.updated_at)
+        .fetch_one(&mut *self.connection().await?)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(MonthClose::from(row))
+    }
+
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn MonthCloseRepository> {
+        Arc::new(Self {
+            pool: self.pool.clone(),
+            organization_id,
+            tenant_isolation: self.tenant_isolation,
+        })
+    }
+}
+
+/// PostgreSQL ジョブキュー
+pub struct PostgresJobQueue {
+    pool: PgPool,
+}
+
+impl PostgresJobQueue {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const JOB_COLUMNS: &str = "id, kind, payload, status, attempts, max_attempts, run_at, last_error, unique_key, created_at, updated_at";
+
+#[derive(Debug, sqlx::FromRow)]
+struct JobRow {
+    id: Uuid,
+    kind: String,
+    payload: serde_json::Value,
+    status: String,
+    attempts: i32,
+    max_attempts: i32,
+    run_at: DateTime<Utc>,
+    last_error: Option<String>,
+    unique_key: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<JobRow> for Job {
+    type Error = JobQueueError;
+
+    fn try_from(row: JobRow) -> Result<Self, Self::Error> {
+        let status = JobStatus::from_str(&row.status).map_err(JobQueueError::Storage)?;
+
+        Ok(Job {
+            id: row.id,
+            kind: row.kind,
+            payload: row.payload,
+            status,
+            attempts: row.attempts,
+            max_attempts: row.max_attempts,
+            run_at: row.run_at,
+            last_error: row.last_error,
+            unique_key: row.unique_key,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+fn map_job_error(err: sqlx::Error) -> JobQueueError {
+    JobQueueError::Storage(err.to_string())
+}
+
+#[async_trait]
+impl JobQueue for PostgresJobQueue {
+    async fn enqueue(&self, job: NewJob) -> JobQueueResult<Option<Job>> {
+        let row = sqlx::query_as::<_, JobRow>(&format!(
+            r#"
+            INSERT INTO jobs (id, kind, payload, max_attempts, run_at, unique_key)
+            VALUES ($1, $2, $3, $4, COALESCE($5, NOW()), $6)
+            ON CONFLICT (unique_key) WHERE unique_key IS NOT NULL DO NOTHING
+            RETURNING {}
+            "#,
+            JOB_COLUMNS
+        ))
+        .bind(Uuid::new_v4())
+        .bind(&job.kind)
+        .bind(&job.payload)
+        .bind(job.max_attempts)
+        .bind(job.run_at)
+        .bind(&job.unique_key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_job_error)?;
+
+        row.map(Job::try_from).transpose()
+    }
+
+    async fn claim(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+        visibility_timeout: Duration,
+    ) -> JobQueueResult<Vec<Job>> {
+        let rows = sqlx::query_as::<_, JobRow>(
+            r#"
+            WITH due AS (
+                SELECT id FROM jobs
+                WHERE status = 'pending' AND run_at <= $1
+                ORDER BY run_at, created_at
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE jobs j
+            SET attempts = j.attempts + 1, run_at = $3, updated_at = NOW()
+            FROM due
+            WHERE j.id = due.id
+            RETURNING j.id, j.kind, j.payload, j.status, j.attempts, j.max_attempts, j.run_at, j.last_error, j.unique_key, j.created_at, j.updated_at
+            "#,
+        )
+        .bind(now)
+        .bind(limit)
+        .bind(now + visibility_timeout)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_job_error)?;
+
+        rows.into_iter().map(Job::try_from).collect()
+    }
+
+    async fn complete(&self, id: Uuid) -> JobQueueResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE jobs
+            SET status = 'succeeded', last_error = NULL, updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(map_job_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(JobQueueError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    async fn fail(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> JobQueueResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE jobs
+            SET last_error = $2,
+                status     = CASE WHEN $3::TIMESTAMPTZ IS NULL THEN 'failed' ELSE status END,
+                run_at     = COALESCE($3, run_at),
+                updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .bind(retry_at)
+        .execute(&self.pool)
+        .await
+        .map_err(map_job_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(JobQueueError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> JobQueueResult<Option<Job>> {
+        let row =
+            sqlx::query_as::<_, JobRow>(&format!("SELECT {} FROM jobs WHERE id = $1", JOB_COLUMNS))
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(map_job_error)?;
+
+        row.map(Job::try_from).transpose()
     }
 }
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{SearchQuery, SearchResults};
+use crate::repository::RepositoryResult;
+
+/// 横断検索インターフェース
+///
+/// 有効な勘定科目の科目名・説明・科目コードを対象に、関連度順で返す。
+#[async_trait]
+pub trait SearchRepository: Send + Sync {
+    async fn search(&self, query: &SearchQuery) -> RepositoryResult<SearchResults>;
+
+    /// 指定した組織に限定したリポジトリ
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn SearchRepository>;
+}
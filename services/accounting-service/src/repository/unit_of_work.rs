@@ -0,0 +1,124 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::repository::{AccountRepository, EmailQueueRepository, RepositoryResult};
+
+pub type DynUnitOfWorkFactory = Arc<dyn UnitOfWorkFactory>;
+
+/// 複数のリポジトリ操作をまとめて確定する作業単位
+///
+/// 作業単位から取得したリポジトリの変更は `commit` するまで外から見えず、
+/// `commit` せずに破棄すると取り消される。同じ組織の作業単位は同時に 1 つだけ開始でき、
+/// 後から開始したものは先の作業単位が終わるまで待つ。
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    /// 作業単位の中で勘定科目を扱うリポジトリ（開始時に指定した組織の勘定科目のみ）
+    fn accounts(&self) -> Arc<dyn AccountRepository>;
+
+    /// 作業単位の中でメールを積むアウトボックス
+    fn email_queue(&self) -> Arc<dyn EmailQueueRepository>;
+
+    /// 変更を確定する
+    async fn commit(self: Box<Self>) -> RepositoryResult<()>;
+
+    /// 変更を取り消す（破棄した場合と同じ）
+    async fn rollback(self: Box<Self>) -> RepositoryResult<()>;
+}
+
+/// 作業単位の開始
+#[async_trait]
+pub trait UnitOfWorkFactory: Send + Sync {
+    /// 指定した組織の作業単位を開始する
+    async fn begin(&self, organization_id: Uuid) -> RepositoryResult<Box<dyn UnitOfWork>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AccountCategory, CreateAccountRequest, EmailMessage};
+    use crate::repository::{
+        InMemoryAccountRepository, InMemoryEmailQueueRepository, InMemoryUnitOfWorkFactory,
+    };
+
+    fn create_request(code: &str) -> CreateAccountRequest {
+        CreateAccountRequest {
+            code: code.to_string(),
+            name: "現金".to_string(),
+            category: AccountCategory::Cash,
+            description: None,
+            display_order: None,
+            parent_id: None,
+        }
+    }
+
+    fn message() -> EmailMessage {
+        EmailMessage {
+            to: "treasurer@example.com".to_string(),
+            subject: "勘定科目を追加しました".to_string(),
+            body: "101 現金".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_commit_applies_changes_together() {
+        let accounts = InMemoryAccountRepository::new();
+        let email_queue = Arc::new(InMemoryEmailQueueRepository::new());
+        let factory = InMemoryUnitOfWorkFactory::new(&accounts, email_queue.clone());
+        let org = Uuid::new_v4();
+        let repo = accounts.for_organization(org);
+        let existing = repo.create(create_request("100")).await.unwrap();
+
+        let uow = factory.begin(org).await.unwrap();
+        let created = uow.accounts().create(create_request("101")).await.unwrap();
+        let queued = uow.email_queue().enqueue(message()).await.unwrap();
+        assert_eq!(created.organization_id, org);
+        assert!(uow
+            .accounts()
+            .find_by_id(existing.id)
+            .await
+            .unwrap()
+            .is_some());
+
+        // 確定前は作業単位の外から見えない
+        assert!(repo.find_by_id(created.id).await.unwrap().is_none());
+        assert!(email_queue.find_by_id(queued.id).await.unwrap().is_none());
+        // 作業単位の外での変更は確定しても失われない
+        let outside = repo.create(create_request("102")).await.unwrap();
+
+        uow.commit().await.unwrap();
+        assert_eq!(repo.find_by_id(created.id).await.unwrap(), Some(created));
+        assert_eq!(repo.find_by_id(outside.id).await.unwrap(), Some(outside));
+        assert_eq!(
+            email_queue.find_by_id(queued.id).await.unwrap(),
+            Some(queued)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rollback_discards_changes() {
+        let accounts = InMemoryAccountRepository::new();
+        let email_queue = Arc::new(InMemoryEmailQueueRepository::new());
+        let factory = InMemoryUnitOfWorkFactory::new(&accounts, email_queue.clone());
+        let org = Uuid::new_v4();
+
+        let uow = factory.begin(org).await.unwrap();
+        let created = uow.accounts().create(create_request("101")).await.unwrap();
+        let queued = uow.email_queue().enqueue(message()).await.unwrap();
+        uow.rollback().await.unwrap();
+
+        // 確定せずに破棄した場合も同じ
+        let dropped = factory.begin(org).await.unwrap();
+        dropped
+            .accounts()
+            .create(create_request("102"))
+            .await
+            .unwrap();
+        drop(dropped);
+
+        let repo = accounts.for_organization(org);
+        assert!(repo.find_by_id(created.id).await.unwrap().is_none());
+        assert!(repo.find_all().await.unwrap().is_empty());
+        assert!(email_queue.find_by_id(queued.id).await.unwrap().is_none());
+    }
+}
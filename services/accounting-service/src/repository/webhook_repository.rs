@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{CreateWebhookRequest, UpdateWebhookRequest, Webhook};
+use crate::repository::RepositoryResult;
+
+/// Webhook 購読リポジトリインターフェース
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    /// Webhook を登録
+    async fn create(&self, request: CreateWebhookRequest) -> RepositoryResult<Webhook>;
+
+    /// IDで Webhook を取得
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Webhook>>;
+
+    /// Webhook 一覧を取得（登録順）
+    async fn find_all(&self) -> RepositoryResult<Vec<Webhook>>;
+
+    /// Webhook を更新
+    async fn update(&self, id: Uuid, request: UpdateWebhookRequest) -> RepositoryResult<Webhook>;
+
+    /// Webhook を削除
+    async fn delete(&self, id: Uuid) -> RepositoryResult<()>;
+
+    /// イベントの配信先（有効な購読のみ）を取得
+    async fn find_subscribers(&self, event_type: &str) -> RepositoryResult<Vec<Webhook>> {
+        let webhooks = self.find_all().await?;
+        Ok(webhooks
+            .into_iter()
+            .filter(|w| w.subscribes(event_type))
+            .collect())
+    }
+
+    /// 指定した組織に限定したリポジトリ
+    fn for_organization(&self, organization_id: Uuid) -> Arc<dyn WebhookRepository>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::{InMemoryWebhookRepository, RepositoryError};
+
+    fn request(events: &[&str]) -> CreateWebhookRequest {
+        CreateWebhookRequest {
+            url: "https://example.com/hook".to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            secret: "0123456789abcdef".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_subscribers() {
+        let repo = InMemoryWebhookRepository::new();
+        let created = repo.create(request(&["account.created"])).await.unwrap();
+        let both = repo
+            .create(request(&["account.created", "account.updated"]))
+            .await
+            .unwrap();
+        repo.update(
+            both.id,
+            UpdateWebhookRequest {
+                is_active: Some(false),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let subscribers = repo.find_subscribers("account.created").await.unwrap();
+        assert_eq!(subscribers.len(), 1);
+        assert_eq!(subscribers[0].id, created.id);
+        assert!(repo
+            .find_subscribers("account.updated")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete() {
+        let repo = InMemoryWebhookRepository::new();
+        let webhook = repo.create(request(&["account.created"])).await.unwrap();
+
+        repo.delete(webhook.id).await.unwrap();
+
+        assert!(repo.find_by_id(webhook.id).await.unwrap().is_none());
+        assert!(matches!(
+            repo.delete(webhook.id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,586 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    Account, AccountCategory, AccountListQuery, AccountNode, CreateAccountRequest,
+    UpdateAccountRequest, DEFAULT_ORGANIZATION_ID,
+};
+use crate::events::{AccountEvent, DynEventPublisher, EventEnvelope};
+use crate::handlers::DynAccountRepository;
+use crate::repository::{DynUnitOfWorkFactory, RepositoryError, UnitOfWork};
+use crate::service::Quotas;
+
+#[derive(Debug, Clone, Error)]
+pub enum AccountServiceError {
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+
+    #[error("Account has been modified: {0}")]
+    PreconditionFailed(Uuid),
+
+    #[error("No free account code for category: {0}")]
+    NoFreeCode(AccountCategory),
+
+    #[error("Account has active children: {0}")]
+    InUse(Uuid),
+
+    #[error("Quota exceeded for {resource}: limit is {limit}")]
+    QuotaExceeded {
+        resource: &'static str,
+        limit: usize,
+    },
+}
+
+pub type AccountServiceResult<T> = Result<T, AccountServiceError>;
+
+/// 勘定科目の業務ルール
+///
+/// HTTP・GraphQL などの入口はリポジトリを直接呼ばずにこれを使い、更新の事前条件、
+/// 使用中の科目の無効化の禁止、組織ごとの上限、監査ログ、イベントの発行を共有する。
+/// 科目コードの再利用方針や範囲のように保存と同時に検証する規則はリポジトリが持つ。
+#[derive(Clone)]
+pub struct AccountService {
+    repo: DynAccountRepository,
+    publisher: Option<DynEventPublisher>,
+    quotas: Quotas,
+    organization_id: Uuid,
+    unit_of_work: Option<DynUnitOfWorkFactory>,
+}
+
+impl AccountService {
+    pub fn new(repo: DynAccountRepository) -> Self {
+        Self {
+            repo,
+            publisher: None,
+            quotas: Quotas::default(),
+            organization_id: DEFAULT_ORGANIZATION_ID,
+            unit_of_work: None,
+        }
+    }
+
+    /// 変更の成功後にイベントを発行する（発行の失敗は変更を失敗させず、ログに残す）
+    pub fn with_events(mut self, publisher: DynEventPublisher) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    /// 組織ごとの上限を超える作成・再有効化を QuotaExceeded で拒否する
+    pub fn with_quotas(mut self, quotas: Quotas) -> Self {
+        self.quotas = quotas;
+        self
+    }
+
+    /// 事前条件の確認と変更を 1 つの作業単位（トランザクション）で行う
+    ///
+    /// イベントは作業単位の確定後に発行するため、取り消された変更のイベントは発行しない。
+    pub fn with_unit_of_work(mut self, factory: DynUnitOfWorkFactory) -> Self {
+        self.unit_of_work = Some(factory);
+        self
+    }
+
+    /// 指定した組織の勘定科目のみを扱うサービス
+    pub fn for_organization(&self, organization_id: Uuid) -> Self {
+        Self {
+            repo: self.repo.for_organization(organization_id),
+            publisher: self.publisher.clone(),
+            quotas: self.quotas,
+            organization_id,
+            unit_of_work: self.unit_of_work.clone(),
+        }
+    }
+
+    /// 作業単位を開始し、その中で勘定科目を扱うサービスを返す
+    ///
+    /// 作業単位が未設定なら自身をそのまま使う。返したサービスはイベントを発行しない。
+    async fn begin(&self) -> AccountServiceResult<(Self, Option<Box<dyn UnitOfWork>>)> {
+        let Some(factory) = &self.unit_of_work else {
+            return Ok((self.clone(), None));
+        };
+        let uow = factory.begin(self.organization_id).await?;
+        let service = Self {
+            repo: uow.accounts(),
+            publisher: None,
+            quotas: self.quotas,
+            organization_id: self.organization_id,
+            unit_of_work: None,
+        };
+        Ok((service, Some(uow)))
+    }
+
+    /// 勘定科目を作成
+    pub async fn create(&self, request: CreateAccountRequest) -> AccountServiceResult<Account> {
+        let (tx, uow) = self.begin().await?;
+        tx.check_account_quota().await?;
+        let account = tx.repo.create(request).await?;
+        commit(uow).await?;
+        self.record(AccountEvent::AccountCreated(account.clone()))
+            .await;
+        Ok(account)
+    }
+
+    /// 勘定科目をまとめて作成（1 件でも失敗すれば 1 件も作成しない）
+    ///
+    /// 組織の作成時に標準の勘定科目を登録するのに使う。作業単位が未設定なら途中までの作成が残る。
+    pub async fn create_all(
+        &self,
+        requests: Vec<CreateAccountRequest>,
+    ) -> AccountServiceResult<Vec<Account>> {
+        let (tx, uow) = self.begin().await?;
+        let mut accounts = Vec::with_capacity(requests.len());
+        for request in requests {
+            tx.check_account_quota().await?;
+            accounts.push(tx.repo.create(request).await?);
+        }
+        commit(uow).await?;
+        for account in &accounts {
+            self.record(AccountEvent::AccountCreated(account.clone()))
+                .await;
+        }
+        Ok(accounts)
+    }
+
+    /// IDで勘定科目を取得
+    pub async fn find(&self, id: Uuid) -> AccountServiceResult<Option<Account>> {
+        Ok(self.repo.find_by_id(id).await?)
+    }
+
+    /// IDで勘定科目を取得（見つからなければ NotFound）
+    pub async fn get(&self, id: Uuid) -> AccountServiceResult<Account> {
+        self.find(id)
+            .await?
+            .ok_or(RepositoryError::NotFound(id).into())
+    }
+
+    /// 指定した時点の勘定科目（その時点で未作成なら NotFound）
+    pub async fn get_as_of(&self, id: Uuid, as_of: DateTime<Utc>) -> AccountServiceResult<Account> {
+        self.repo
+            .find_as_of(id, as_of)
+            .await?
+            .ok_or(RepositoryError::NotFound(id).into())
+    }
+
+    /// 科目コードで勘定科目を取得
+    pub async fn find_by_code(&self, code: &str) -> AccountServiceResult<Option<Account>> {
+        Ok(self.repo.find_by_code(code).await?)
+    }
+
+    /// 条件に合う勘定科目を指定した順で取得
+    pub async fn list(&self, query: AccountListQuery) -> AccountServiceResult<Vec<Account>> {
+        Ok(self.repo.list(query).await?)
+    }
+
+    /// 勘定科目ツリー
+    pub async fn tree(&self) -> AccountServiceResult<Vec<AccountNode>> {
+        Ok(self.repo.find_tree().await?)
+    }
+
+    /// 有効な子勘定科目
+    pub async fn children(&self, parent_id: Uuid) -> AccountServiceResult<Vec<Account>> {
+        Ok(self.repo.find_children(parent_id).await?)
+    }
+
+    /// 最終更新日時（無効化済みを含む。勘定科目がなければ None）
+    pub async fn last_modified(&self) -> AccountServiceResult<Option<DateTime<Utc>>> {
+        Ok(self.repo.last_modified().await?)
+    }
+
+    /// カテゴリの科目種別の範囲で空いている最小の科目コード
+    pub async fn next_code(&self, category: &AccountCategory) -> AccountServiceResult<String> {
+        self.repo
+            .next_code(category)
+            .await?
+            .ok_or_else(|| AccountServiceError::NoFreeCode(category.clone()))
+    }
+
+    /// 勘定科目を更新
+    ///
+    /// `if_match` は If-Match ヘッダーと同じ形式（カンマ区切りの ETag か `*`）で、
+    /// 指定時は現在の ETag と照合する。
+    pub async fn update(
+        &self,
+        id: Uuid,
+        request: UpdateAccountRequest,
+        if_match: Option<&str>,
+    ) -> AccountServiceResult<Account> {
+        let (tx, uow) = self.begin().await?;
+        let versions = tx.expected_versions(id, if_match).await?;
+        match request.is_active {
+            Some(false) => tx.check_unused(id).await?,
+            Some(true) if !tx.get(id).await?.is_active => tx.check_account_quota().await?,
+            _ => {}
+        }
+
+        let account = match &versions {
+            Some(versions) => tx.repo.update_if_unmodified(id, request, versions).await,
+            None => tx.repo.update(id, request).await,
+        }
+        .map_err(precondition_failed)?;
+        commit(uow).await?;
+        let event = if account.is_active {
+            AccountEvent::AccountUpdated(account.clone())
+        } else {
+            AccountEvent::AccountDeactivated { id }
+        };
+        self.record(event).await;
+        Ok(account)
+    }
+
+    /// 指定した順に表示順を 1 から振り直す
+    pub async fn reorder(&self, account_ids: &[Uuid]) -> AccountServiceResult<Vec<Account>> {
+        let (tx, uow) = self.begin().await?;
+        let accounts = tx.repo.reorder(account_ids).await?;
+        commit(uow).await?;
+        for account in &accounts {
+            self.record(AccountEvent::AccountUpdated(account.clone()))
+                .await;
+        }
+        Ok(accounts)
+    }
+
+    /// 勘定科目を無効化（論理削除）
+    pub async fn deactivate(&self, id: Uuid, if_match: Option<&str>) -> AccountServiceResult<()> {
+        let (tx, uow) = self.begin().await?;
+        let versions = tx.expected_versions(id, if_match).await?;
+        tx.check_unused(id).await?;
+
+        match &versions {
+            Some(versions) => tx.repo.soft_delete_if_unmodified(id, versions).await,
+            None => tx.repo.soft_delete(id).await,
+        }
+        .map_err(precondition_failed)?;
+        commit(uow).await?;
+        self.record(AccountEvent::AccountDeactivated { id }).await;
+        Ok(())
+    }
+
+    /// If-Match が許す版（None なら版を問わない）
+    ///
+    /// 版の比較は更新と同時にリポジトリで行う。この勘定科目の ETag が 1 つもなければ変更しない。
+    async fn expected_versions(
+        &self,
+        id: Uuid,
+        if_match: Option<&str>,
+    ) -> AccountServiceResult<Option<Vec<DateTime<Utc>>>> {
+        let Some(if_match) = if_match else {
+            return Ok(None);
+        };
+
+        let tags: Vec<&str> = if_match.split(',').map(str::trim).collect();
+        if tags.contains(&"*") {
+            return Ok(None);
+        }
+        let versions: Vec<DateTime<Utc>> = tags
+            .into_iter()
+            .filter_map(|tag| Account::version_from_etag(id, tag))
+            .collect();
+        if versions.is_empty() {
+            // 存在しない勘定科目なら NotFound を優先する
+            self.get(id).await?;
+            return Err(AccountServiceError::PreconditionFailed(id));
+        }
+        Ok(Some(versions))
+    }
+
+    /// 有効な子勘定科目がある科目は無効化させない
+    async fn check_unused(&self, id: Uuid) -> AccountServiceResult<()> {
+        if self.repo.find_children(id).await?.is_empty() {
+            Ok(())
+        } else {
+            Err(AccountServiceError::InUse(id))
+        }
+    }
+
+    /// 有効な勘定科目がもう 1 件増えても上限以内か
+    async fn check_account_quota(&self) -> AccountServiceResult<()> {
+        let Some(limit) = self.quotas.max_accounts else {
+            return Ok(());
+        };
+        if self.repo.find_active().await?.len() < limit {
+            Ok(())
+        } else {
+            Err(AccountServiceError::QuotaExceeded {
+                resource: "accounts",
+                limit,
+            })
+        }
+    }
+
+    /// 変更を監査ログに残し、イベントを発行する
+    async fn record(&self, event: AccountEvent) {
+        let envelope = EventEnvelope::new(self.organization_id, event);
+        tracing::info!(
+            target: "audit",
+            organization_id = %self.organization_id,
+            account_id = %envelope.event.account_id(),
+            event_id = %envelope.id,
+            event_type = envelope.event.event_type(),
+            "Account changed"
+        );
+
+        let Some(publisher) = &self.publisher else {
+            return;
+        };
+        if let Err(err) = publisher.publish(&envelope).await {
+            tracing::warn!(
+                event_id = %envelope.id,
+                event_type = envelope.event.event_type(),
+                "{}",
+                err
+            );
+        }
+    }
+}
+
+/// 作業単位を確定する（作業単位がなければ何もしない）
+///
+/// 途中でエラーを返した場合、作業単位は確定されずに破棄され、変更は取り消される。
+async fn commit(uow: Option<Box<dyn UnitOfWork>>) -> AccountServiceResult<()> {
+    if let Some(uow) = uow {
+        uow.commit().await?;
+    }
+    Ok(())
+}
+
+/// 版の不一致を事前条件の失敗として扱う
+fn precondition_failed(err: RepositoryError) -> AccountServiceError {
+    match err {
+        RepositoryError::Modified(id) => AccountServiceError::PreconditionFailed(id),
+        err => err.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::InProcessEventPublisher;
+    use crate::repository::{
+        InMemoryAccountRepository, InMemoryEmailQueueRepository, InMemoryUnitOfWorkFactory,
+    };
+    use common::patch::Patch;
+    use std::sync::Arc;
+
+    fn create_request(code: &str, parent_id: Option<Uuid>) -> CreateAccountRequest {
+        CreateAccountRequest {
+            code: code.to_string(),
+            name: "現金".to_string(),
+            category: AccountCategory::Cash,
+            description: None,
+            display_order: None,
+            parent_id,
+        }
+    }
+
+    fn update_request() -> UpdateAccountRequest {
+        UpdateAccountRequest {
+            name: None,
+            description: Patch::Absent,
+            display_order: None,
+            is_active: None,
+            parent_id: Patch::Absent,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lifecycle_events_are_published() {
+        let publisher = InProcessEventPublisher::default();
+        let mut receiver = publisher.subscribe();
+        let service = AccountService::new(Arc::new(InMemoryAccountRepository::new()))
+            .with_events(Arc::new(publisher));
+
+        let account = service.create(create_request("101", None)).await.unwrap();
+        service
+            .update(
+                account.id,
+                UpdateAccountRequest {
+                    name: Some("手許現金".to_string()),
+                    ..update_request()
+                },
+                None,
+            )
+            .await
+            .unwrap();
+        service.deactivate(account.id, None).await.unwrap();
+
+        let types: Vec<&str> = [
+            receiver.recv().await.unwrap(),
+            receiver.recv().await.unwrap(),
+            receiver.recv().await.unwrap(),
+        ]
+        .iter()
+        .map(|e| e.event.event_type())
+        .collect();
+        assert_eq!(
+            types,
+            vec!["account.created", "account.updated", "account.deactivated"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_update_publishes_nothing() {
+        let publisher = InProcessEventPublisher::default();
+        let mut receiver = publisher.subscribe();
+        let service = AccountService::new(Arc::new(InMemoryAccountRepository::new()))
+            .with_events(Arc::new(publisher));
+
+        assert!(service.deactivate(Uuid::new_v4(), None).await.is_err());
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_account_with_active_children_cannot_be_deactivated() {
+        let service = AccountService::new(Arc::new(InMemoryAccountRepository::new()));
+        let parent = service.create(create_request("100", None)).await.unwrap();
+        let child = service
+            .create(create_request("101", Some(parent.id)))
+            .await
+            .unwrap();
+
+        let err = service.deactivate(parent.id, None).await.unwrap_err();
+        assert!(matches!(err, AccountServiceError::InUse(id) if id == parent.id));
+        let deactivate = UpdateAccountRequest {
+            is_active: Some(false),
+            ..update_request()
+        };
+        let err = service
+            .update(parent.id, deactivate.clone(), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AccountServiceError::InUse(_)));
+        assert!(service.get(parent.id).await.unwrap().is_active);
+
+        // 子を無効化すれば無効化できる
+        service.deactivate(child.id, None).await.unwrap();
+        let updated = service.update(parent.id, deactivate, None).await.unwrap();
+        assert!(!updated.is_active);
+    }
+
+    #[tokio::test]
+    async fn test_account_quota_is_per_organization() {
+        let service = AccountService::new(Arc::new(InMemoryAccountRepository::new()))
+            .with_quotas(Quotas::default().with_max_accounts(1));
+        let account = service.create(create_request("101", None)).await.unwrap();
+
+        let err = service
+            .create(create_request("102", None))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AccountServiceError::QuotaExceeded {
+                resource: "accounts",
+                limit: 1
+            }
+        ));
+
+        // 無効化した科目は数えず、再有効化は上限の範囲でのみ許可する
+        service.deactivate(account.id, None).await.unwrap();
+        service.create(create_request("102", None)).await.unwrap();
+        let reactivate = UpdateAccountRequest {
+            is_active: Some(true),
+            ..update_request()
+        };
+        let err = service
+            .update(account.id, reactivate, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AccountServiceError::QuotaExceeded { .. }));
+
+        // 上限は組織ごとに数える
+        let other = service.for_organization(Uuid::new_v4());
+        other.create(create_request("101", None)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_if_match_is_checked_before_changes() {
+        let service = AccountService::new(Arc::new(InMemoryAccountRepository::new()));
+        let account = service.create(create_request("101", None)).await.unwrap();
+        let etag = account.etag();
+
+        let err = service
+            .deactivate(account.id, Some("\"stale\""))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AccountServiceError::PreconditionFailed(_)));
+        assert!(service.get(account.id).await.unwrap().is_active);
+
+        let if_match = format!("\"stale\", {}", etag);
+        service
+            .deactivate(account.id, Some(&if_match))
+            .await
+            .unwrap();
+        let err = service
+            .deactivate(Uuid::new_v4(), Some("*"))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AccountServiceError::Repository(RepositoryError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_changes_are_committed_through_unit_of_work() {
+        let repo = InMemoryAccountRepository::new();
+        let factory =
+            InMemoryUnitOfWorkFactory::new(&repo, Arc::new(InMemoryEmailQueueRepository::new()));
+        let publisher = InProcessEventPublisher::default();
+        let mut receiver = publisher.subscribe();
+        let org = Uuid::new_v4();
+        let repo: DynAccountRepository = Arc::new(repo);
+        let service = AccountService::new(repo.clone())
+            .with_events(Arc::new(publisher))
+            .with_unit_of_work(Arc::new(factory))
+            .for_organization(org);
+        let repo = repo.for_organization(org);
+
+        let parent = service.create(create_request("100", None)).await.unwrap();
+        service
+            .create(create_request("101", Some(parent.id)))
+            .await
+            .unwrap();
+        assert_eq!(repo.find_active().await.unwrap().len(), 2);
+
+        // 事前条件を満たさない変更は確定せず、イベントも発行しない
+        let err = service.deactivate(parent.id, None).await.unwrap_err();
+        assert!(matches!(err, AccountServiceError::InUse(_)));
+        assert!(repo.find_by_id(parent.id).await.unwrap().unwrap().is_active);
+
+        let events: Vec<EventEnvelope> = vec![
+            receiver.recv().await.unwrap(),
+            receiver.recv().await.unwrap(),
+        ];
+        assert!(events.iter().all(|e| e.organization_id == org));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_all_is_all_or_nothing() {
+        let repo = InMemoryAccountRepository::new();
+        let factory =
+            InMemoryUnitOfWorkFactory::new(&repo, Arc::new(InMemoryEmailQueueRepository::new()));
+        let repo: DynAccountRepository = Arc::new(repo);
+        let service = AccountService::new(repo.clone())
+            .with_quotas(Quotas::default().with_max_accounts(2))
+            .with_unit_of_work(Arc::new(factory));
+
+        let requests = vec![
+            create_request("101", None),
+            create_request("102", None),
+            create_request("103", None),
+        ];
+        let err = service.create_all(requests).await.unwrap_err();
+        assert!(matches!(err, AccountServiceError::QuotaExceeded { .. }));
+        assert!(repo.find_all().await.unwrap().is_empty());
+
+        let created = service
+            .create_all(vec![
+                create_request("101", None),
+                create_request("102", None),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(created.len(), 2);
+        assert_eq!(repo.find_active().await.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::{hash_map::Entry, HashMap};
+use uuid::Uuid;
+
+use crate::domain::{account_violation, Account, JournalEntryDraft, JournalValidation};
+use crate::service::{AccountService, AccountServiceResult};
+
+/// 仕訳の下書きを保存せずに検証し、違反をすべて返す
+///
+/// 貸借の一致などの形式の検査に加え、各行の勘定科目が組織に存在し有効かを確かめる。
+pub async fn validate_journal_entry(
+    accounts: &AccountService,
+    draft: &JournalEntryDraft,
+) -> AccountServiceResult<JournalValidation> {
+    let mut violations = draft.structural_violations();
+
+    let mut found: HashMap<Uuid, Option<Account>> = HashMap::new();
+    for (index, line) in draft.lines.iter().enumerate() {
+        let Some(account_id) = line.account_id else {
+            continue;
+        };
+        if let Entry::Vacant(entry) = found.entry(account_id) {
+            entry.insert(accounts.find(account_id).await?);
+        }
+        match &found[&account_id] {
+            None => violations.push(account_violation(
+                index,
+                "account_not_found",
+                "勘定科目が見つかりません",
+            )),
+            Some(account) if !account.is_active => violations.push(account_violation(
+                index,
+                "account_inactive",
+                format!("勘定科目「{}」は無効化されています", account.name),
+            )),
+            Some(_) => {}
+        }
+    }
+
+    let (debit_total, credit_total) = draft.totals().unwrap_or_default();
+    Ok(JournalValidation {
+        valid: violations.is_empty(),
+        debit_total,
+        credit_total,
+        violations,
+    })
+}
@@ -0,0 +1,7 @@
+pub mod account_service;
+pub mod journal_validation;
+pub mod quota;
+
+pub use account_service::*;
+pub use journal_validation::*;
+pub use quota::*;
@@ -0,0 +1,15 @@
+/// 組織ごとの利用上限（複数の教会を受け入れるホスティング向け）
+///
+/// 作成の直前に数えて判定する緩い上限で、同時に作成すると上限をわずかに超えることがある。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Quotas {
+    /// 有効な勘定科目の数の上限（None なら無制限）
+    pub max_accounts: Option<usize>,
+}
+
+impl Quotas {
+    pub fn with_max_accounts(mut self, limit: usize) -> Self {
+        self.max_accounts = Some(limit);
+        self
+    }
+}
@@ -0,0 +1,231 @@
+use axum::{
+    extract::{Request, State},
+    http::Method,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use common::error::AppError;
+use serde::Serialize;
+use sqlx::PgPool;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::migrate;
+
+/// アクティブ/パッシブ構成における読み取り専用（スタンバイ）状態
+///
+/// スタンバイはレプリカ DB に接続して参照系のみ受け付け、
+/// 管理 API で昇格すると更新系を受け付けるようになる。
+#[derive(Debug, Clone, Default)]
+pub struct StandbyMode {
+    read_only: Arc<AtomicBool>,
+}
+
+impl StandbyMode {
+    pub fn new(read_only: bool) -> Self {
+        Self {
+            read_only: Arc::new(AtomicBool::new(read_only)),
+        }
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::SeqCst)
+    }
+
+    /// スタンバイをプライマリに昇格（更新系を解禁）。昇格した場合 true
+    pub fn promote(&self) -> bool {
+        self.read_only.swap(false, Ordering::SeqCst)
+    }
+
+    pub fn role(&self) -> &'static str {
+        if self.is_read_only() {
+            "standby"
+        } else {
+            "primary"
+        }
+    }
+}
+
+/// ロール情報レスポンス
+#[derive(Debug, Serialize)]
+pub struct RoleResponse {
+    pub role: &'static str,
+    pub read_only: bool,
+}
+
+impl From<&StandbyMode> for RoleResponse {
+    fn from(mode: &StandbyMode) -> Self {
+        Self {
+            role: mode.role(),
+            read_only: mode.is_read_only(),
+        }
+    }
+}
+
+/// 読み取り専用モード中は更新系メソッドを 503 で拒否する
+pub async fn read_only_guard(
+    State(mode): State<StandbyMode>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let is_mutation = !matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS
+    );
+
+    if is_mutation && mode.is_read_only() {
+        return AppError::ServiceUnavailable {
+            code: "READ_ONLY",
+            message: "This instance is a read-only standby".to_string(),
+        }
+        .into_response();
+    }
+
+    next.run(request).await
+}
+
+/// GET /api/admin/role - 現在のロール
+pub async fn get_role(State(mode): State<StandbyMode>) -> Json<RoleResponse> {
+    Json(RoleResponse::from(&mode))
+}
+
+/// 昇格の前提となる状態
+#[derive(Clone)]
+pub struct PromoteState {
+    pub mode: StandbyMode,
+    /// スキーマの確認に使う（インメモリでは None）
+    pub migration_pool: Option<PgPool>,
+}
+
+/// POST /api/admin/promote - スタンバイをプライマリに昇格
+///
+/// スタンバイは起動時のマイグレーションを飛ばしているため、
+/// 未適用のマイグレーションが残っている間は昇格を拒否する（`migrate up` を先に実行する）。
+pub async fn promote(State(state): State<PromoteState>) -> Result<Json<RoleResponse>, AppError> {
+    if let Some(pool) = &state.migration_pool {
+        let pending = migrate::status(pool)
+            .await
+            .map_err(|e| AppError::internal("MIGRATION_ERROR", e.to_string()))?
+            .into_iter()
+            .filter(|m| !m.applied)
+            .count();
+        if pending > 0 {
+            return Err(AppError::Conflict {
+                code: "MIGRATIONS_PENDING",
+                message: format!(
+                    "{} migration(s) are pending; run `accounting-service migrate up` before promoting",
+                    pending
+                ),
+            });
+        }
+    }
+    if state.mode.promote() {
+        tracing::warn!("standby promoted to primary; accepting writes");
+    }
+    Ok(Json(RoleResponse::from(&state.mode)))
+}
+
+/// ロール管理用ルーター（管理トークンの検査は呼び出し側で管理 API 全体にかける）
+pub fn admin_router(mode: StandbyMode, migration_pool: Option<PgPool>) -> Router {
+    let promote_state = PromoteState {
+        mode: mode.clone(),
+        migration_pool,
+    };
+    Router::new()
+        .route("/api/admin/role", get(get_role))
+        .with_state(mode)
+        .merge(
+            Router::new()
+                .route("/api/admin/promote", post(promote))
+                .with_state(promote_state),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::StatusCode, middleware};
+    use common::admin_auth::require_admin_token;
+    use tower::ServiceExt;
+
+    fn create_test_app(mode: StandbyMode) -> Router {
+        Router::new()
+            .route(
+                "/api/accounts",
+                get(|| async { "list" }).post(|| async { "created" }),
+            )
+            .layer(middleware::from_fn_with_state(
+                mode.clone(),
+                read_only_guard,
+            ))
+            .merge(
+                admin_router(mode, None).layer(middleware::from_fn_with_state(
+                    Some(Arc::from("secret")),
+                    require_admin_token,
+                )),
+            )
+    }
+
+    async fn send(app: &Router, method: &str, uri: &str) -> StatusCode {
+        app.clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("Authorization", "Bearer secret")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn test_standby_rejects_writes_until_promoted() {
+        let mode = StandbyMode::new(true);
+        let app = create_test_app(mode.clone());
+
+        assert_eq!(send(&app, "GET", "/api/accounts").await, StatusCode::OK);
+        assert_eq!(
+            send(&app, "POST", "/api/accounts").await,
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        assert_eq!(
+            send(&app, "POST", "/api/admin/promote").await,
+            StatusCode::OK
+        );
+        assert!(!mode.is_read_only());
+        assert_eq!(send(&app, "POST", "/api/accounts").await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_promote_requires_admin_token() {
+        let mode = StandbyMode::new(true);
+        let app = create_test_app(mode.clone());
+
+        let response = app
+            .oneshot(
+                Request::post("/api/admin/promote")
+                    .header("Authorization", "Bearer wrong")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(mode.is_read_only());
+    }
+
+    #[test]
+    fn test_role() {
+        let mode = StandbyMode::new(true);
+        assert_eq!(mode.role(), "standby");
+        assert!(mode.promote());
+        assert!(!mode.promote());
+        assert_eq!(mode.role(), "primary");
+    }
+}
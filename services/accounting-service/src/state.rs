@@ -0,0 +1,359 @@
+use axum::extract::FromRef;
+use common::lifecycle::Readiness;
+use common::startup::ConfigEntry;
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::build_info::BuildInfo;
+use crate::domain::WebhookTargetPolicy;
+use crate::events::DynEventPublisher;
+use crate::handlers::{
+    AccountListCaching, DynAccountRepository, DynCashCountRepository, DynCategoryRepository,
+    DynCounterpartyRepository, DynExchangeRateRepository, DynFixedAssetRepository,
+    DynMonthCloseRepository, DynOrganizationRepository, DynSearchRepository, DynWebhookRepository,
+};
+use crate::repository::{
+    CachedAccountRepository, DynUnitOfWorkFactory, InMemoryCashCountRepository,
+    InMemoryCategoryRepository, InMemoryCounterpartyRepository, InMemoryExchangeRateRepository,
+    InMemoryFixedAssetRepository, InMemoryMonthCloseRepository, InMemoryOrganizationRepository,
+    InMemorySearchRepository, InMemoryWebhookRepository,
+};
+use crate::service::{AccountService, Quotas};
+use crate::standby::StandbyMode;
+use crate::tenant::TenantAuth;
+
+/// API 全体の状態
+///
+/// ハンドラーは `FromRef` で必要なものだけを取り出す。main.rs とテストは
+/// [`AppState::builder`] で同じ手順で組み立て、[`crate::app::build_router`] に渡す。
+#[derive(Clone)]
+pub struct AppState {
+    pub accounts: AccountService,
+    /// キャッシュを含む勘定科目リポジトリ（参照のみの機能で使う）
+    pub account_repository: DynAccountRepository,
+    pub list_caching: AccountListCaching,
+    pub exchange_rates: DynExchangeRateRepository,
+    pub cash_counts: DynCashCountRepository,
+    pub webhooks: DynWebhookRepository,
+    /// Webhook の配信先の制限
+    pub webhook_targets: WebhookTargetPolicy,
+    pub organizations: DynOrganizationRepository,
+    pub categories: DynCategoryRepository,
+    pub search: DynSearchRepository,
+    pub fixed_assets: DynFixedAssetRepository,
+    pub counterparties: DynCounterpartyRepository,
+    pub month_closes: DynMonthCloseRepository,
+    pub standby: StandbyMode,
+    /// マイグレーションの適用状況と接続プールの状態の確認に使う（インメモリでは None）
+    pub migration_pool: Option<PgPool>,
+    /// 起動時の有効な設定（引き継ぎパッケージに含める）
+    pub settings: Vec<ConfigEntry>,
+    pub readiness: Readiness,
+    pub build_info: BuildInfo,
+    /// ログレベルの変更などに必要な管理トークン（None なら拒否）
+    pub admin_token: Option<String>,
+    pub tenant_auth: TenantAuth,
+}
+
+impl AppState {
+    /// 保存先の勘定科目リポジトリから組み立てる（指定しなかったリポジトリはインメモリ）
+    pub fn builder(repo: DynAccountRepository) -> AppStateBuilder {
+        AppStateBuilder {
+            repo,
+            publisher: None,
+            unit_of_work: None,
+            cache_ttl: None,
+            quotas: Quotas::default(),
+            list_caching: AccountListCaching::default(),
+            exchange_rates: None,
+            cash_counts: None,
+            webhooks: None,
+            webhook_targets: WebhookTargetPolicy::default(),
+            organizations: None,
+            categories: None,
+            search: None,
+            fixed_assets: None,
+            counterparties: None,
+            month_closes: None,
+            standby: StandbyMode::default(),
+            migration_pool: None,
+            settings: Vec::new(),
+            readiness: Readiness::ready(),
+            build_info: BuildInfo::default(),
+            admin_token: None,
+            tenant_auth: TenantAuth::default(),
+        }
+    }
+}
+
+impl FromRef<AppState> for AccountService {
+    fn from_ref(state: &AppState) -> Self {
+        state.accounts.clone()
+    }
+}
+
+impl FromRef<AppState> for DynAccountRepository {
+    fn from_ref(state: &AppState) -> Self {
+        state.account_repository.clone()
+    }
+}
+
+impl FromRef<AppState> for AccountListCaching {
+    fn from_ref(state: &AppState) -> Self {
+        state.list_caching.clone()
+    }
+}
+
+pub struct AppStateBuilder {
+    repo: DynAccountRepository,
+    publisher: Option<DynEventPublisher>,
+    unit_of_work: Option<DynUnitOfWorkFactory>,
+    cache_ttl: Option<Duration>,
+    quotas: Quotas,
+    list_caching: AccountListCaching,
+    exchange_rates: Option<DynExchangeRateRepository>,
+    cash_counts: Option<DynCashCountRepository>,
+    webhooks: Option<DynWebhookRepository>,
+    webhook_targets: WebhookTargetPolicy,
+    organizations: Option<DynOrganizationRepository>,
+    categories: Option<DynCategoryRepository>,
+    search: Option<DynSearchRepository>,
+    fixed_assets: Option<DynFixedAssetRepository>,
+    counterparties: Option<DynCounterpartyRepository>,
+    month_closes: Option<DynMonthCloseRepository>,
+    standby: StandbyMode,
+    migration_pool: Option<PgPool>,
+    settings: Vec<ConfigEntry>,
+    readiness: Readiness,
+    build_info: BuildInfo,
+    admin_token: Option<String>,
+    tenant_auth: TenantAuth,
+}
+
+impl AppStateBuilder {
+    /// 勘定科目の変更後にイベントを発行する
+    pub fn with_events(mut self, publisher: DynEventPublisher) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    /// 勘定科目の変更を作業単位（トランザクション）で行う
+    pub fn with_unit_of_work(mut self, factory: DynUnitOfWorkFactory) -> Self {
+        self.unit_of_work = Some(factory);
+        self
+    }
+
+    /// 勘定科目の参照をプロセス内にキャッシュする
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// 組織ごとの利用上限
+    pub fn with_quotas(mut self, quotas: Quotas) -> Self {
+        self.quotas = quotas;
+        self
+    }
+
+    /// 勘定科目一覧の HTTP キャッシュ方針
+    pub fn with_list_caching(mut self, list_caching: AccountListCaching) -> Self {
+        self.list_caching = list_caching;
+        self
+    }
+
+    pub fn with_exchange_rates(mut self, repo: DynExchangeRateRepository) -> Self {
+        self.exchange_rates = Some(repo);
+        self
+    }
+
+    pub fn with_cash_counts(mut self, repo: DynCashCountRepository) -> Self {
+        self.cash_counts = Some(repo);
+        self
+    }
+
+    pub fn with_webhooks(mut self, repo: DynWebhookRepository) -> Self {
+        self.webhooks = Some(repo);
+        self
+    }
+
+    pub fn with_webhook_targets(mut self, targets: WebhookTargetPolicy) -> Self {
+        self.webhook_targets = targets;
+        self
+    }
+
+    pub fn with_organizations(mut self, repo: DynOrganizationRepository) -> Self {
+        self.organizations = Some(repo);
+        self
+    }
+
+    pub fn with_categories(mut self, repo: DynCategoryRepository) -> Self {
+        self.categories = Some(repo);
+        self
+    }
+
+    pub fn with_search(mut self, repo: DynSearchRepository) -> Self {
+        self.search = Some(repo);
+        self
+    }
+
+    pub fn with_fixed_assets(mut self, repo: DynFixedAssetRepository) -> Self {
+        self.fixed_assets = Some(repo);
+        self
+    }
+
+    pub fn with_counterparties(mut self, repo: DynCounterpartyRepository) -> Self {
+        self.counterparties = Some(repo);
+        self
+    }
+
+    pub fn with_month_closes(mut self, repo: DynMonthCloseRepository) -> Self {
+        self.month_closes = Some(repo);
+        self
+    }
+
+    /// スタンバイ（読み取り専用）の切り替え
+    pub fn with_standby(mut self, standby: StandbyMode) -> Self {
+        self.standby = standby;
+        self
+    }
+
+    /// マイグレーションの管理 API を有効にする
+    pub fn with_migration_pool(mut self, pool: PgPool) -> Self {
+        self.migration_pool = Some(pool);
+        self
+    }
+
+    /// 引き継ぎパッケージに含める設定
+    pub fn with_settings(mut self, settings: Vec<ConfigEntry>) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// readiness probe に返す状態（既定は受け付け可能）
+    pub fn with_readiness(mut self, readiness: Readiness) -> Self {
+        self.readiness = readiness;
+        self
+    }
+
+    /// GET /api/admin/info に返すビルド情報
+    pub fn with_build_info(mut self, build_info: BuildInfo) -> Self {
+        self.build_info = build_info;
+        self
+    }
+
+    /// 管理トークンで保護した API を有効にする
+    pub fn with_admin_token(mut self, token: String) -> Self {
+        self.admin_token = Some(token);
+        self
+    }
+
+    /// リクエストの組織の認証方式（既定は単一組織）
+    pub fn with_tenant_auth(mut self, tenant_auth: TenantAuth) -> Self {
+        self.tenant_auth = tenant_auth;
+        self
+    }
+
+    pub fn build(self) -> AppState {
+        // 検索はキャッシュを通さず保存先を直接参照する
+        let search = self
+            .search
+            .unwrap_or_else(|| Arc::new(InMemorySearchRepository::new(self.repo.clone())));
+        let (repo, unit_of_work): (DynAccountRepository, _) = match self.cache_ttl {
+            Some(ttl) => {
+                let cached = CachedAccountRepository::new(self.repo, ttl);
+                // 作業単位で確定した変更もキャッシュを破棄する
+                let unit_of_work = self
+                    .unit_of_work
+                    .map(|factory| cached.unit_of_work(factory));
+                (Arc::new(cached), unit_of_work)
+            }
+            None => (self.repo, self.unit_of_work),
+        };
+        let organizations: DynOrganizationRepository = self
+            .organizations
+            .unwrap_or_else(|| Arc::new(InMemoryOrganizationRepository::new()));
+        let mut accounts = AccountService::new(repo.clone()).with_quotas(self.quotas);
+        if let Some(factory) = unit_of_work {
+            accounts = accounts.with_unit_of_work(factory);
+        }
+        if let Some(publisher) = self.publisher {
+            accounts = accounts.with_events(publisher);
+        }
+
+        AppState {
+            accounts,
+            account_repository: repo,
+            list_caching: self.list_caching,
+            exchange_rates: self
+                .exchange_rates
+                .unwrap_or_else(|| Arc::new(InMemoryExchangeRateRepository::new())),
+            cash_counts: self
+                .cash_counts
+                .unwrap_or_else(|| Arc::new(InMemoryCashCountRepository::new())),
+            webhooks: self
+                .webhooks
+                .unwrap_or_else(|| Arc::new(InMemoryWebhookRepository::new())),
+            webhook_targets: self.webhook_targets,
+            organizations: organizations.clone(),
+            categories: self
+                .categories
+                .unwrap_or_else(|| Arc::new(InMemoryCategoryRepository::new())),
+            search,
+            fixed_assets: self
+                .fixed_assets
+                .unwrap_or_else(|| Arc::new(InMemoryFixedAssetRepository::new())),
+            counterparties: self
+                .counterparties
+                .unwrap_or_else(|| Arc::new(InMemoryCounterpartyRepository::new())),
+            month_closes: self
+                .month_closes
+                .unwrap_or_else(|| Arc::new(InMemoryMonthCloseRepository::new())),
+            standby: self.standby,
+            migration_pool: self.migration_pool,
+            settings: self.settings,
+            readiness: self.readiness,
+            build_info: self.build_info,
+            admin_token: self.admin_token,
+            // 無効化した組織のリクエストを拒否する
+            tenant_auth: self.tenant_auth.with_organizations(organizations),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AccountCategory, CreateAccountRequest};
+    use crate::events::InProcessEventPublisher;
+    use crate::repository::InMemoryAccountRepository;
+
+    #[tokio::test]
+    async fn test_builder_wires_events_and_cache() {
+        let publisher = InProcessEventPublisher::default();
+        let mut receiver = publisher.subscribe();
+        let state = AppState::builder(Arc::new(InMemoryAccountRepository::new()))
+            .with_events(Arc::new(publisher))
+            .with_cache_ttl(Duration::from_secs(60))
+            .build();
+
+        let account = AccountService::from_ref(&state)
+            .create(CreateAccountRequest {
+                code: "101".to_string(),
+                name: "現金".to_string(),
+                category: AccountCategory::Cash,
+                description: None,
+                display_order: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            receiver.recv().await.unwrap().event.account_id(),
+            account.id
+        );
+        let repo = DynAccountRepository::from_ref(&state);
+        assert_eq!(repo.find_by_id(account.id).await.unwrap(), Some(account));
+    }
+}
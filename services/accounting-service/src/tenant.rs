@@ -0,0 +1,363 @@
+use axum::{
+    async_trait,
+    extract::{FromRef, FromRequestParts, Request, State},
+    http::{request::Parts, HeaderMap},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use common::admin_auth::constant_time_eq;
+use common::error::AppError;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::domain::DEFAULT_ORGANIZATION_ID;
+use crate::repository::OrganizationRepository;
+use crate::service::AccountService;
+
+/// リクエストの組織を指定するヘッダー
+pub const ORG_ID_HEADER: &str = "x-org-id";
+/// 組織の API キーのヘッダー（マルチテナントのときに必須）
+pub const ORG_KEY_HEADER: &str = "x-org-key";
+/// 有効と確認した組織を再確認せずに受け付ける時間
+const ACTIVE_ORGANIZATION_TTL: Duration = Duration::from_secs(30);
+
+/// 組織の認証方式
+///
+/// 署名鍵（`TENANT_KEY_SECRET`）を設定するとマルチテナントになり、
+/// `X-Org-Id` と、その組織 ID から署名鍵で導いた API キー（`X-Org-Key`）の両方を要求する。
+/// 未設定なら単一組織で、すべてのリクエストを既定の組織として扱う。
+/// 組織のリポジトリを指定すると、存在しない組織や無効化した組織のリクエストを拒否する。
+#[derive(Clone, Default)]
+pub struct TenantAuth {
+    secret: Option<Arc<[u8]>>,
+    organizations: Option<ActiveOrganizations>,
+}
+
+/// 有効な組織の確認（確認済みの組織を短時間キャッシュする）
+#[derive(Clone)]
+struct ActiveOrganizations {
+    repo: Arc<dyn OrganizationRepository>,
+    verified: Arc<Mutex<HashMap<Uuid, Instant>>>,
+}
+
+impl fmt::Debug for TenantAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TenantAuth")
+            .field("multi_tenant", &self.is_multi_tenant())
+            .finish()
+    }
+}
+
+impl TenantAuth {
+    /// マルチテナント（組織の API キーの署名鍵）
+    pub fn with_secret(secret: &str) -> Self {
+        Self {
+            secret: Some(Arc::from(secret.as_bytes())),
+            organizations: None,
+        }
+    }
+
+    /// 組織が存在し有効かをリポジトリで確認する
+    pub fn with_organizations(mut self, repo: Arc<dyn OrganizationRepository>) -> Self {
+        self.organizations = Some(ActiveOrganizations {
+            repo,
+            verified: Arc::default(),
+        });
+        self
+    }
+
+    pub fn is_multi_tenant(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    /// 組織の API キー（単一組織なら None）
+    pub fn organization_key(&self, organization_id: Uuid) -> Option<String> {
+        let secret = self.secret.as_ref()?;
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any size");
+        mac.update(organization_id.to_string().as_bytes());
+        Some(hex::encode(mac.finalize().into_bytes()))
+    }
+
+    /// ヘッダーから組織を決める（マルチテナントで組織の指定がなければ None）
+    fn resolve(&self, headers: &HeaderMap) -> Result<Option<Uuid>, AppError> {
+        let Some(value) = headers.get(ORG_ID_HEADER) else {
+            return Ok((!self.is_multi_tenant()).then_some(DEFAULT_ORGANIZATION_ID));
+        };
+        let organization_id = value
+            .to_str()
+            .ok()
+            .and_then(|value| Uuid::parse_str(value.trim()).ok())
+            .ok_or_else(|| AppError::BadRequest {
+                code: "INVALID_ORGANIZATION",
+                message: format!("{} must be a UUID", ORG_ID_HEADER),
+            })?;
+
+        let Some(expected) = self.organization_key(organization_id) else {
+            if organization_id == DEFAULT_ORGANIZATION_ID {
+                return Ok(Some(organization_id));
+            }
+            return Err(AppError::BadRequest {
+                code: "MULTI_TENANCY_DISABLED",
+                message: format!(
+                    "{} is not accepted unless TENANT_KEY_SECRET is set",
+                    ORG_ID_HEADER
+                ),
+            });
+        };
+        let provided = headers
+            .get(ORG_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        if constant_time_eq(provided.as_bytes(), expected.as_bytes()) {
+            Ok(Some(organization_id))
+        } else {
+            Err(AppError::Unauthorized(
+                "Invalid organization key".to_string(),
+            ))
+        }
+    }
+
+    /// 組織が存在し有効か（既定の組織は常に有効）
+    async fn check_active(&self, organization_id: Uuid) -> Result<(), AppError> {
+        let Some(organizations) = &self.organizations else {
+            return Ok(());
+        };
+        if organization_id == DEFAULT_ORGANIZATION_ID || organizations.is_verified(organization_id)
+        {
+            return Ok(());
+        }
+
+        let organization = organizations
+            .repo
+            .find_by_id(organization_id)
+            .await
+            .map_err(|err| AppError::internal("DATABASE_ERROR", err.to_string()))?;
+        match organization {
+            Some(organization) if organization.is_active => {
+                organizations
+                    .verified()
+                    .insert(organization_id, Instant::now());
+                Ok(())
+            }
+            Some(_) => Err(AppError::Forbidden {
+                code: "ORGANIZATION_INACTIVE",
+                message: format!("Organization is deactivated: {}", organization_id),
+            }),
+            None => Err(AppError::Unauthorized(format!(
+                "Unknown organization: {}",
+                organization_id
+            ))),
+        }
+    }
+
+    /// 組織の変更後、次のリクエストで有効かを確認し直す
+    pub fn forget(&self, organization_id: Uuid) {
+        if let Some(organizations) = &self.organizations {
+            organizations.verified().remove(&organization_id);
+        }
+    }
+}
+
+impl ActiveOrganizations {
+    fn verified(&self) -> std::sync::MutexGuard<'_, HashMap<Uuid, Instant>> {
+        self.verified
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn is_verified(&self, organization_id: Uuid) -> bool {
+        let mut verified = self.verified();
+        match verified.get(&organization_id) {
+            Some(at) if at.elapsed() < ACTIVE_ORGANIZATION_TTL => true,
+            Some(_) => {
+                verified.remove(&organization_id);
+                false
+            }
+            None => false,
+        }
+    }
+}
+
+/// 認証済みのリクエストの組織（[`tenant_guard`] が設定する）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ResolvedOrganization(Uuid);
+
+/// リクエストの組織を認証し、ハンドラーが [`OrganizationId`] で取り出せるようにする
+///
+/// 組織を指定しないリクエスト（ヘルスチェックなど）はそのまま通し、
+/// 組織を必要とするハンドラーが拒否する。無効化した組織のキーは 403 で拒否する。
+pub async fn tenant_guard(
+    State(auth): State<TenantAuth>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let resolved = match auth.resolve(request.headers()) {
+        Ok(Some(organization_id)) => auth
+            .check_active(organization_id)
+            .await
+            .map(|()| Some(organization_id)),
+        other => other,
+    };
+    match resolved {
+        Ok(Some(organization_id)) => {
+            request
+                .extensions_mut()
+                .insert(ResolvedOrganization(organization_id));
+            next.run(request).await
+        }
+        Ok(None) => next.run(request).await,
+        Err(err) => err.into_response(),
+    }
+}
+
+/// リクエストの組織（[`tenant_guard`] で認証したもの）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrganizationId(pub Uuid);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for OrganizationId {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<ResolvedOrganization>()
+            .map(|organization| Self(organization.0))
+            .ok_or_else(|| {
+                AppError::Unauthorized(format!(
+                    "{} and {} are required",
+                    ORG_ID_HEADER, ORG_KEY_HEADER
+                ))
+            })
+    }
+}
+
+/// リクエストの組織に限定した勘定科目サービス
+pub struct OrganizationAccounts(pub AccountService);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for OrganizationAccounts
+where
+    AccountService: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let OrganizationId(organization_id) =
+            OrganizationId::from_request_parts(parts, state).await?;
+        let accounts = AccountService::from_ref(state);
+        Ok(Self(accounts.for_organization(organization_id)))
+    }
+}
+
+/// 組織間のデータ分離の方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TenantIsolation {
+    /// リポジトリのクエリで organization_id を絞り込む
+    #[default]
+    Application,
+    /// 加えて接続に `app.current_org` を設定し、PostgreSQL の行レベルセキュリティで分離する
+    Rls,
+}
+
+impl fmt::Display for TenantIsolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            TenantIsolation::Application => "application",
+            TenantIsolation::Rls => "rls",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// ハンドラー単体のテスト用：`X-Org-Id`（未指定なら既定の組織）をそのまま信頼する
+#[cfg(test)]
+pub(crate) fn trust_org_header(router: axum::Router) -> axum::Router {
+    async fn trust(mut request: Request, next: Next) -> Response {
+        let organization_id = request
+            .headers()
+            .get(ORG_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| Uuid::parse_str(value).ok())
+            .unwrap_or(DEFAULT_ORGANIZATION_ID);
+        request
+            .extensions_mut()
+            .insert(ResolvedOrganization(organization_id));
+        next.run(request).await
+    }
+    router.layer(axum::middleware::from_fn(trust))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(organization_id: &str, key: Option<&str>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            ORG_ID_HEADER,
+            HeaderValue::from_str(organization_id).unwrap(),
+        );
+        if let Some(key) = key {
+            headers.insert(ORG_KEY_HEADER, HeaderValue::from_str(key).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_single_tenant_uses_default_organization() {
+        let auth = TenantAuth::default();
+        assert_eq!(
+            auth.resolve(&HeaderMap::new()).unwrap(),
+            Some(DEFAULT_ORGANIZATION_ID)
+        );
+        assert!(auth.organization_key(DEFAULT_ORGANIZATION_ID).is_none());
+
+        assert!(matches!(
+            auth.resolve(&headers("not-a-uuid", None)),
+            Err(AppError::BadRequest {
+                code: "INVALID_ORGANIZATION",
+                ..
+            })
+        ));
+        let other = Uuid::new_v4().to_string();
+        assert!(matches!(
+            auth.resolve(&headers(&other, None)),
+            Err(AppError::BadRequest {
+                code: "MULTI_TENANCY_DISABLED",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_multi_tenant_requires_organization_key() {
+        let auth = TenantAuth::with_secret("secret");
+        let organization_id = Uuid::new_v4();
+        let key = auth.organization_key(organization_id).unwrap();
+        let other_key = auth.organization_key(Uuid::new_v4()).unwrap();
+
+        assert_eq!(auth.resolve(&HeaderMap::new()).unwrap(), None);
+        assert_eq!(
+            auth.resolve(&headers(&organization_id.to_string(), Some(&key)))
+                .unwrap(),
+            Some(organization_id)
+        );
+        for key in [None, Some(other_key.as_str()), Some("")] {
+            assert!(matches!(
+                auth.resolve(&headers(&organization_id.to_string(), key)),
+                Err(AppError::Unauthorized(_))
+            ));
+        }
+    }
+}
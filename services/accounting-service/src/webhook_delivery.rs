@@ -0,0 +1,525 @@
+use async_trait::async_trait;
+use common::jobs::{DynJobQueue, Job, JobError, JobHandler, NewJob, RetryPolicy};
+use hmac::{Hmac, Mac};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::domain::{Webhook, WebhookTargetPolicy};
+use crate::events::EventEnvelope;
+use crate::handlers::DynWebhookRepository;
+
+/// 署名ヘッダー（`sha256=<hex>`、本文の HMAC-SHA256）
+pub const SIGNATURE_HEADER: &str = "x-webhook-signature";
+/// イベント種別ヘッダー
+pub const EVENT_HEADER: &str = "x-webhook-event";
+/// イベント ID ヘッダー（受信側の重複排除用）
+pub const DELIVERY_ID_HEADER: &str = "x-webhook-id";
+
+/// 配信ジョブの種別
+pub const WEBHOOK_DELIVERY_JOB: &str = "webhook.deliver";
+
+/// 配信ジョブの内容
+#[derive(Debug, Serialize, Deserialize)]
+struct WebhookJobPayload {
+    webhook_id: Uuid,
+    envelope: EventEnvelope,
+}
+
+/// 本文の署名を計算する
+pub fn sign_payload(secret: &str, payload: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(payload);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// 名前解決の結果から配信を許可しないアドレスを除く（接続の直前に確認する）
+struct TargetResolver {
+    targets: WebhookTargetPolicy,
+}
+
+impl Resolve for TargetResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let targets = self.targets;
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| targets.allows_address(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} does not resolve to an allowed address", host).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// 配信用の HTTP クライアント（リダイレクトは追わない）
+fn build_client(targets: WebhookTargetPolicy) -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .redirect(reqwest::redirect::Policy::none())
+        .dns_resolver(Arc::new(TargetResolver { targets }))
+        .build()
+        .expect("Failed to build HTTP client")
+}
+
+/// 一度の試行の結果
+enum Attempt {
+    Delivered,
+    Retryable(String),
+    Rejected(String),
+}
+
+/// Webhook 配信処理
+pub struct WebhookDispatcher {
+    repo: DynWebhookRepository,
+    client: reqwest::Client,
+    targets: WebhookTargetPolicy,
+    retry: RetryPolicy,
+    jobs: Option<DynJobQueue>,
+}
+
+impl WebhookDispatcher {
+    pub fn new(repo: DynWebhookRepository) -> Self {
+        let targets = WebhookTargetPolicy::default();
+        Self {
+            repo,
+            client: build_client(targets),
+            targets,
+            retry: RetryPolicy::default(),
+            jobs: None,
+        }
+    }
+
+    /// 配信先の制限を指定（既定は https かつ公開アドレスのみ）
+    pub fn with_target_policy(mut self, targets: WebhookTargetPolicy) -> Self {
+        self.client = build_client(targets);
+        self.targets = targets;
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// 配信をジョブキューに登録する（再起動しても配信と再試行が失われない）
+    ///
+    /// 登録したジョブは [`WEBHOOK_DELIVERY_JOB`] としてこの配信処理を登録した
+    /// `JobRunner` が実行する。
+    pub fn with_job_queue(mut self, jobs: DynJobQueue) -> Self {
+        self.jobs = Some(jobs);
+        self
+    }
+
+    async fn attempt(
+        &self,
+        webhook: &Webhook,
+        envelope: &EventEnvelope,
+        payload: &[u8],
+    ) -> Attempt {
+        // 制限を入れる前に登録された購読先にも送らない
+        if let Err(error) = self.targets.check_url(&webhook.url) {
+            return Attempt::Rejected(error);
+        }
+        let response = self
+            .client
+            .post(&webhook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, envelope.event.event_type())
+            .header(DELIVERY_ID_HEADER, envelope.id.to_string())
+            .header(SIGNATURE_HEADER, sign_payload(&webhook.secret, payload))
+            .body(payload.to_vec())
+            .send()
+            .await;
+
+        match response {
+            Ok(response) if response.status().is_success() => Attempt::Delivered,
+            // 受信側の一時的な障害のみ再試行する
+            Ok(response)
+                if response.status().is_server_error()
+                    || response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS =>
+            {
+                Attempt::Retryable(format!("status {}", response.status()))
+            }
+            Ok(response) => Attempt::Rejected(format!("status {}", response.status())),
+            Err(err) => Attempt::Retryable(err.to_string()),
+        }
+    }
+
+    /// 1 件の購読先へ配信する（失敗時は指数バックオフで再試行）
+    pub async fn deliver(&self, webhook: &Webhook, envelope: &EventEnvelope) -> Result<(), String> {
+        let payload = serde_json::to_vec(envelope).map_err(|e| e.to_string())?;
+
+        let mut attempt = 1;
+        loop {
+            let error = match self.attempt(webhook, envelope, &payload).await {
+                Attempt::Delivered => return Ok(()),
+                Attempt::Rejected(error) => return Err(error),
+                Attempt::Retryable(error) => error,
+            };
+            if attempt >= self.retry.max_attempts {
+                return Err(format!("{} (gave up after {} attempts)", error, attempt));
+            }
+
+            let delay = self.retry.backoff(attempt);
+            tracing::debug!(
+                webhook_id = %webhook.id,
+                attempt,
+                "Webhook delivery failed: {}, retrying in {:?}",
+                error,
+                delay
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// イベントが発生した組織で、そのイベントを購読しているすべての Webhook へ配信する
+    pub async fn dispatch(self: &Arc<Self>, envelope: EventEnvelope) {
+        let webhooks = match self
+            .repo
+            .for_organization(envelope.organization_id)
+            .find_subscribers(envelope.event.event_type())
+            .await
+        {
+            Ok(webhooks) => webhooks,
+            Err(err) => {
+                tracing::error!(event_id = %envelope.id, "Failed to load webhooks: {}", err);
+                return;
+            }
+        };
+
+        if let Some(jobs) = &self.jobs {
+            for webhook in webhooks {
+                let job = NewJob::new(
+                    WEBHOOK_DELIVERY_JOB,
+                    serde_json::json!(WebhookJobPayload {
+                        webhook_id: webhook.id,
+                        envelope: envelope.clone(),
+                    }),
+                )
+                .unique_key(format!("webhook:{}:{}", webhook.id, envelope.id))
+                .max_attempts(i32::try_from(self.retry.max_attempts).unwrap_or(i32::MAX));
+                if let Err(err) = jobs.enqueue(job).await {
+                    tracing::error!(
+                        webhook_id = %webhook.id,
+                        event_id = %envelope.id,
+                        "Failed to enqueue webhook delivery: {}",
+                        err
+                    );
+                }
+            }
+            return;
+        }
+
+        let envelope = Arc::new(envelope);
+        for webhook in webhooks {
+            let dispatcher = Arc::clone(self);
+            let envelope = Arc::clone(&envelope);
+            // 購読先ごとに独立して再試行する
+            tokio::spawn(async move {
+                if let Err(err) = dispatcher.deliver(&webhook, &envelope).await {
+                    tracing::warn!(
+                        webhook_id = %webhook.id,
+                        event_id = %envelope.id,
+                        "Webhook delivery failed: {}",
+                        err
+                    );
+                }
+            });
+        }
+    }
+}
+
+/// キューに登録された配信を 1 回試行する（再試行はジョブキューに任せる）
+#[async_trait]
+impl JobHandler for WebhookDispatcher {
+    async fn run(&self, job: &Job) -> Result<(), JobError> {
+        let payload: WebhookJobPayload = serde_json::from_value(job.payload.clone())
+            .map_err(|e| JobError::Permanent(format!("Invalid payload: {}", e)))?;
+        let webhook = self
+            .repo
+            .for_organization(payload.envelope.organization_id)
+            .find_by_id(payload.webhook_id)
+            .await
+            .map_err(|e| JobError::Retryable(e.to_string()))?;
+        // 登録後に削除・無効化された購読先には送らない
+        let Some(webhook) = webhook.filter(|w| w.is_active) else {
+            return Err(JobError::Permanent(format!(
+                "Webhook is no longer active: {}",
+                payload.webhook_id
+            )));
+        };
+
+        let body = serde_json::to_vec(&payload.envelope)
+            .map_err(|e| JobError::Permanent(e.to_string()))?;
+        match self.attempt(&webhook, &payload.envelope, &body).await {
+            Attempt::Delivered => Ok(()),
+            Attempt::Retryable(error) => Err(JobError::Retryable(error)),
+            Attempt::Rejected(error) => Err(JobError::Permanent(error)),
+        }
+    }
+}
+
+/// イベントを受信して Webhook 配信するワーカーを起動する
+pub fn spawn_webhook_worker(
+    dispatcher: Arc<WebhookDispatcher>,
+    mut receiver: broadcast::Receiver<EventEnvelope>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(envelope) => dispatcher.dispatch(envelope).await,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Webhook worker lagged, events were dropped");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{CreateWebhookRequest, Webhook, DEFAULT_ORGANIZATION_ID};
+    use crate::events::AccountEvent;
+    use crate::repository::{InMemoryWebhookRepository, WebhookRepository};
+    use axum::{extract::State, http::HeaderMap, http::StatusCode, routing::post, Router};
+    use chrono::Utc;
+    use common::jobs::{InMemoryJobQueue, JobQueue, JobRunner};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    type Received = Arc<Mutex<Vec<(HeaderMap, Vec<u8>)>>>;
+
+    /// 最初の `failures` 回は 503 を返す受信サーバー
+    async fn start_receiver(failures: usize) -> (String, Received) {
+        let received: Received = Arc::default();
+        let app = Router::new()
+            .route(
+                "/hook",
+                post(
+                    move |State(received): State<Received>,
+                          headers: HeaderMap,
+                          body: axum::body::Bytes| async move {
+                        let mut received = received.lock().unwrap();
+                        received.push((headers, body.to_vec()));
+                        if received.len() <= failures {
+                            StatusCode::SERVICE_UNAVAILABLE
+                        } else {
+                            StatusCode::NO_CONTENT
+                        }
+                    },
+                ),
+            )
+            .with_state(received.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (format!("http://{}/hook", addr), received)
+    }
+
+    fn fast_retry() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+        }
+    }
+
+    /// テストの受信サーバー（http://127.0.0.1）へ送れる配信処理
+    fn local_dispatcher(repo: DynWebhookRepository) -> WebhookDispatcher {
+        WebhookDispatcher::new(repo).with_target_policy(WebhookTargetPolicy::AllowInsecure)
+    }
+
+    fn webhook(url: String) -> Webhook {
+        Webhook::new(
+            DEFAULT_ORGANIZATION_ID,
+            url,
+            vec!["account.deactivated".to_string()],
+            "0123456789abcdef".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(4), Duration::from_secs(8));
+        assert_eq!(policy.backoff(10), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_sign_payload() {
+        // RFC 4231 テストケース 2
+        assert_eq!(
+            sign_payload("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deliver_retries_until_success() {
+        let (url, received) = start_receiver(2).await;
+        let dispatcher = local_dispatcher(Arc::new(InMemoryWebhookRepository::new()))
+            .with_retry_policy(fast_retry());
+        let webhook = webhook(url);
+        let envelope = EventEnvelope::new(
+            DEFAULT_ORGANIZATION_ID,
+            AccountEvent::AccountDeactivated { id: Uuid::new_v4() },
+        );
+
+        dispatcher.deliver(&webhook, &envelope).await.unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 3);
+        let (headers, body) = &received[2];
+        assert_eq!(headers[EVENT_HEADER], "account.deactivated");
+        assert_eq!(headers[DELIVERY_ID_HEADER], envelope.id.to_string());
+        assert_eq!(
+            headers[SIGNATURE_HEADER],
+            sign_payload("0123456789abcdef", body).as_str()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deliver_gives_up_after_max_attempts() {
+        let (url, received) = start_receiver(usize::MAX).await;
+        let dispatcher = local_dispatcher(Arc::new(InMemoryWebhookRepository::new()))
+            .with_retry_policy(fast_retry());
+        let envelope = EventEnvelope::new(
+            DEFAULT_ORGANIZATION_ID,
+            AccountEvent::AccountDeactivated { id: Uuid::new_v4() },
+        );
+
+        assert!(dispatcher.deliver(&webhook(url), &envelope).await.is_err());
+        assert_eq!(received.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_refuses_internal_targets() {
+        let (url, received) = start_receiver(0).await;
+        let dispatcher = WebhookDispatcher::new(Arc::new(InMemoryWebhookRepository::new()))
+            .with_retry_policy(fast_retry());
+        let envelope = EventEnvelope::new(
+            DEFAULT_ORGANIZATION_ID,
+            AccountEvent::AccountDeactivated { id: Uuid::new_v4() },
+        );
+
+        assert!(dispatcher.deliver(&webhook(url), &envelope).await.is_err());
+        assert!(received.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resolver_drops_internal_addresses() {
+        let resolve = |targets| async move {
+            TargetResolver { targets }
+                .resolve("localhost".parse().unwrap())
+                .await
+                .map(|addrs| addrs.count())
+        };
+
+        assert!(resolve(WebhookTargetPolicy::PublicHttps).await.is_err());
+        assert!(resolve(WebhookTargetPolicy::AllowInsecure).await.unwrap() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_worker_delivers_to_subscribers() {
+        let (url, received) = start_receiver(0).await;
+        let repo = Arc::new(InMemoryWebhookRepository::new());
+        repo.create(CreateWebhookRequest {
+            url,
+            events: vec!["account.deactivated".to_string()],
+            secret: "0123456789abcdef".to_string(),
+        })
+        .await
+        .unwrap();
+        let (sender, receiver) = broadcast::channel(16);
+        let worker = spawn_webhook_worker(Arc::new(local_dispatcher(repo)), receiver);
+
+        sender
+            .send(EventEnvelope::new(
+                DEFAULT_ORGANIZATION_ID,
+                AccountEvent::AccountDeactivated { id: Uuid::new_v4() },
+            ))
+            .unwrap();
+        drop(sender);
+        worker.await.unwrap();
+
+        for _ in 0..100 {
+            if !received.lock().unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(received.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_with_job_queue_delivers_through_runner() {
+        let (url, received) = start_receiver(1).await;
+        let repo = Arc::new(InMemoryWebhookRepository::new());
+        let request = CreateWebhookRequest {
+            url,
+            events: vec!["account.deactivated".to_string()],
+            secret: "0123456789abcdef".to_string(),
+        };
+        repo.create(request.clone()).await.unwrap();
+        // 他組織の購読先には配信しない
+        repo.for_organization(Uuid::new_v4())
+            .create(request)
+            .await
+            .unwrap();
+        let queue = Arc::new(InMemoryJobQueue::new());
+        let dispatcher = Arc::new(local_dispatcher(repo).with_job_queue(queue.clone()));
+        let runner = JobRunner::new(queue.clone())
+            .register(WEBHOOK_DELIVERY_JOB, dispatcher.clone())
+            .with_retry_policy(fast_retry());
+
+        let envelope = EventEnvelope::new(
+            DEFAULT_ORGANIZATION_ID,
+            AccountEvent::AccountDeactivated { id: Uuid::new_v4() },
+        );
+        dispatcher.dispatch(envelope.clone()).await;
+        // 同じイベントを再度受け取ってもジョブは重複しない
+        dispatcher.dispatch(envelope).await;
+
+        let now = Utc::now();
+        assert_eq!(runner.run_once(now).await.unwrap(), 1);
+        assert_eq!(received.lock().unwrap().len(), 1);
+        // 一時的な失敗は待機時間の後に再試行する
+        assert_eq!(
+            runner
+                .run_once(now + chrono::Duration::seconds(1))
+                .await
+                .unwrap(),
+            1
+        );
+
+        // 配信済みのジョブは再び取り出されない
+        assert!(queue
+            .claim(
+                now + chrono::Duration::hours(1),
+                10,
+                chrono::Duration::zero()
+            )
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,45 @@
+//! インメモリ実装と、それを包むキャッシュの実装に共通の契約を適用する
+
+mod support;
+
+use accounting_service::domain::CodeReusePolicy;
+use accounting_service::repository::{
+    AccountRepository, CachedAccountRepository, InMemoryAccountRepository,
+};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use support::account_contract::account_repository_contract;
+
+fn in_memory(policy: CodeReusePolicy) -> Arc<dyn AccountRepository> {
+    Arc::new(InMemoryAccountRepository::new().with_code_reuse_policy(policy))
+}
+
+mod in_memory {
+    use super::*;
+
+    async fn with_repo<F, Fut>(policy: CodeReusePolicy, test: F)
+    where
+        F: FnOnce(Arc<dyn AccountRepository>) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        test(in_memory(policy)).await;
+    }
+
+    account_repository_contract!(with_repo);
+}
+
+mod cached {
+    use super::*;
+
+    async fn with_repo<F, Fut>(policy: CodeReusePolicy, test: F)
+    where
+        F: FnOnce(Arc<dyn AccountRepository>) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let repo = CachedAccountRepository::new(in_memory(policy), Duration::from_secs(60));
+        test(Arc::new(repo)).await;
+    }
+
+    account_repository_contract!(with_repo);
+}
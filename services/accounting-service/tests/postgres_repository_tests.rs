@@ -1,11 +1,74 @@
+mod support;
+
+use accounting_service::config::DatabaseConfig;
+use accounting_service::domain::{
+    parse_month, CashCountFilter, CreateCashCountRequest, CreateCategoryRequest,
+    CreateCounterpartyRequest, CreateExchangeRateRequest, CreateFixedAssetRequest,
+    CreateOrganizationRequest, CreateWebhookRequest, Currency, DenominationCount, EmailMessage,
+    EmailStatus, Money, MonthClose, SearchEntityType, SearchQuery, UpdateCounterpartyRequest,
+    UpdateMonthCloseRequest, UpdateOrganizationRequest, UpdateWebhookRequest,
+    DEFAULT_ORGANIZATION_ID,
+};
 use accounting_service::domain::{
-    AccountCategory, AccountType, CreateAccountRequest, UpdateAccountRequest,
+    Account, AccountCategory, AccountListQuery, AccountSortKey, AccountType, CodeReusePolicy,
+    CreateAccountRequest, SortOrder, UpdateAccountRequest,
 };
-use accounting_service::repository::{AccountRepository, PostgresAccountRepository, RepositoryError};
-use sqlx::PgPool;
+use accounting_service::metrics;
+use accounting_service::migrate::{self, MIGRATOR};
+use accounting_service::repository::{
+    AccountRepository, CashCountRepository, CategoryRepository, CounterpartyRepository,
+    EmailQueueRepository, ExchangeRateRepository, FixedAssetRepository, MonthCloseRepository,
+    OrganizationRepository, PostgresAccountRepository, PostgresCashCountRepository,
+    PostgresCategoryRepository, PostgresCounterpartyRepository, PostgresEmailQueueRepository,
+    PostgresExchangeRateRepository, PostgresFixedAssetRepository, PostgresJobQueue,
+    PostgresMonthCloseRepository, PostgresOrganizationRepository, PostgresSearchRepository,
+    PostgresWebhookRepository, RepositoryError, SearchRepository, UnitOfWorkFactory,
+    WebhookRepository,
+};
+use accounting_service::standby::{admin_router, StandbyMode};
+use accounting_service::tenant::TenantIsolation;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use chrono::{NaiveDate, TimeZone, Utc};
+use common::jobs::{JobQueue, JobStatus, NewJob};
+use common::lifecycle::Readiness;
+use common::patch::Patch;
+use http_body_util::BodyExt;
+use rust_decimal::Decimal;
+use sqlx::ConnectOptions;
+use std::future::Future;
+use std::sync::Arc;
+use test_support::{with_database, with_empty_database, with_repo};
+use tower::ServiceExt;
 use uuid::Uuid;
 
-static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+/// マイグレーション済みのデータベースの勘定科目リポジトリで `test` を実行する
+async fn with_account_repo<F, Fut>(test: F)
+where
+    F: FnOnce(PostgresAccountRepository) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    with_repo(&MIGRATOR, PostgresAccountRepository::new, test).await;
+}
+
+// 勘定科目リポジトリの共通の契約（tests/support/account_contract.rs）
+mod account_contract {
+    use super::*;
+    use support::account_contract::account_repository_contract;
+
+    async fn with_repo<F, Fut>(policy: CodeReusePolicy, test: F)
+    where
+        F: FnOnce(Arc<dyn AccountRepository>) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let new_repo = |pool| -> Arc<dyn AccountRepository> {
+            Arc::new(PostgresAccountRepository::new(pool).with_code_reuse_policy(policy))
+        };
+        test_support::with_repo(&MIGRATOR, new_repo, test).await;
+    }
+
+    account_repository_contract!(with_repo);
+}
 
 fn create_test_request(code: &str, name: &str, category: AccountCategory) -> CreateAccountRequest {
     CreateAccountRequest {
@@ -14,6 +77,7 @@ fn create_test_request(code: &str, name: &str, category: AccountCategory) -> Cre
         category,
         description: Some(format!("{name}の説明")),
         display_order: Some(1),
+        parent_id: None,
     }
 }
 
@@ -21,200 +85,1115 @@ fn default_request() -> CreateAccountRequest {
     create_test_request("101", "現金", AccountCategory::Cash)
 }
 
-// 1. 正常作成、全フィールド確認
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_create_account(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
-    let request = default_request();
+// 1. 為替レート作成・重複・有効レート取得
+#[tokio::test]
+async fn test_exchange_rates() {
+    with_database(&MIGRATOR, |pool| async move {
+        let repo = PostgresExchangeRateRepository::new(pool);
+        let request = |rate: i64, month: u32| CreateExchangeRateRequest {
+            base_currency: Currency::USD,
+            quote_currency: Currency::JPY,
+            rate: Decimal::new(rate, 2),
+            effective_date: NaiveDate::from_ymd_opt(2026, month, 1).unwrap(),
+        };
+
+        let created = repo.create(request(15025, 1)).await.unwrap();
+        let _ = repo.create(request(15500, 2)).await.unwrap();
+
+        assert_eq!(created.rate, Decimal::new(15025, 2));
+        assert!(matches!(
+            repo.create(request(15100, 1)).await,
+            Err(RepositoryError::Conflict(_))
+        ));
+        // 組織が違えば同じ通貨ペア・適用日でも登録でき、互いに見えない
+        let other = repo.for_organization(Uuid::new_v4());
+        let other_rate = other.create(request(15100, 1)).await.unwrap();
+        assert!(repo.find_by_id(other_rate.id).await.unwrap().is_none());
+        assert_eq!(other.find_all(None, None).await.unwrap().len(), 1);
+
+        let effective = repo
+            .find_effective(
+                Currency::USD,
+                Currency::JPY,
+                NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(effective.id, created.id);
+
+        let usd = repo.find_all(Some(Currency::USD), None).await.unwrap();
+        assert_eq!(usd.len(), 2);
+        assert!(repo
+            .find_all(Some(Currency::EUR), None)
+            .await
+            .unwrap()
+            .is_empty());
+
+        repo.delete(created.id).await.unwrap();
+        assert!(repo.find_by_id(created.id).await.unwrap().is_none());
+    })
+    .await;
+}
+
+// 2. 金種表の登録・明細の取得・金庫ごとの最新
+#[tokio::test]
+async fn test_cash_counts() {
+    with_database(&MIGRATOR, |pool| async move {
+        let repo = PostgresCashCountRepository::new(pool);
+        let request = |safe: &str, day: u32, quantity: i64| CreateCashCountRequest {
+            safe: safe.to_string(),
+            counted_at: Some(Utc.with_ymd_and_hms(2026, 3, day, 18, 0, 0).unwrap()),
+            lines: vec![
+                DenominationCount {
+                    denomination: 100,
+                    quantity: 3,
+                },
+                DenominationCount {
+                    denomination: 10000,
+                    quantity,
+                },
+            ],
+            book_balance: Some(20000),
+            counted_by: None,
+            note: None,
+        };
+
+        let first = repo.create(request("MAIN", 1, 2)).await.unwrap();
+        let second = repo.create(request("MAIN", 8, 5)).await.unwrap();
+        let _ = repo.create(request("PETTY", 8, 1)).await.unwrap();
+
+        let found = repo.find_by_id(first.id).await.unwrap().unwrap();
+        assert_eq!(found.total.amount, 20300);
+        assert_eq!(found.lines, first.lines);
+        assert_eq!(found.lines[0].denomination, 10000);
+        assert_eq!(found.difference(), Some(Money::new(300, Currency::JPY)));
 
-    let account = repo.create(request).await.unwrap();
+        let main = repo
+            .find_all(CashCountFilter {
+                safe: Some("MAIN".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(main.len(), 2);
+        assert_eq!(main[0].id, second.id);
+        assert_eq!(main[1].lines.len(), 2);
 
-    assert_eq!(account.code, "101");
-    assert_eq!(account.name, "現金");
-    assert_eq!(account.account_type, AccountType::Asset);
-    assert_eq!(account.category, AccountCategory::Cash);
-    assert_eq!(account.description, Some("現金の説明".to_string()));
-    assert!(account.is_active);
-    assert_eq!(account.display_order, 1);
+        let latest = repo.find_latest("MAIN").await.unwrap().unwrap();
+        assert_eq!(latest.total.amount, 50300);
+        assert!(repo.find_latest("NONE").await.unwrap().is_none());
+
+        let other = repo.for_organization(Uuid::new_v4());
+        assert!(other.find_latest("MAIN").await.unwrap().is_none());
+        assert!(other.find_by_id(first.id).await.unwrap().is_none());
+    })
+    .await;
 }
 
-// 2. 重複コード → DuplicateCode エラー
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_create_duplicate_code_fails(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
-    let request = default_request();
+// 3. Webhook の登録・更新・イベントごとの配信先
+#[tokio::test]
+async fn test_webhooks() {
+    with_database(&MIGRATOR, |pool| async move {
+        let repo = PostgresWebhookRepository::new(pool);
+        let request = |events: &[&str]| CreateWebhookRequest {
+            url: "https://example.com/hook".to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            secret: "0123456789abcdef".to_string(),
+        };
+
+        let created = repo.create(request(&["account.created"])).await.unwrap();
+        let other = repo
+            .create(request(&["account.created", "account.updated"]))
+            .await
+            .unwrap();
+        assert_eq!(created.events, vec!["account.created"]);
+        assert!(created.is_active);
+
+        let updated = repo
+            .update(
+                other.id,
+                UpdateWebhookRequest {
+                    is_active: Some(false),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert!(!updated.is_active);
+        assert_eq!(updated.events.len(), 2);
 
-    let _ = repo.create(request.clone()).await.unwrap();
-    let result = repo.create(request).await;
+        let subscribers = repo.find_subscribers("account.created").await.unwrap();
+        assert_eq!(subscribers.len(), 1);
+        assert_eq!(subscribers[0].id, created.id);
+        assert_eq!(subscribers[0].secret, "0123456789abcdef");
+        // 他組織のイベントの配信先にはならない
+        let other = repo.for_organization(Uuid::new_v4());
+        assert!(other
+            .find_subscribers("account.created")
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            other.delete(created.id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
 
-    assert!(matches!(result, Err(RepositoryError::DuplicateCode(_))));
+        repo.delete(created.id).await.unwrap();
+        assert!(matches!(
+            repo.delete(created.id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+        assert_eq!(repo.find_all().await.unwrap().len(), 1);
+    })
+    .await;
 }
 
-// 3. ID 検索
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_find_by_id(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
-    let created = repo.create(default_request()).await.unwrap();
+// 4. マイグレーションの取り消し・再適用と適用状況
+#[tokio::test]
+async fn test_migrate_commands() {
+    with_empty_database(|pool| async move {
+        migrate::up(&pool).await.unwrap();
+        let status = migrate::status(&pool).await.unwrap();
+        assert!(status.iter().all(|m| m.applied && !m.checksum_mismatch));
+        let latest = status.last().unwrap().version;
+        let previous = status[status.len() - 2].version;
 
-    let found = repo.find_by_id(created.id).await.unwrap();
+        let version = migrate::down(&pool, None).await.unwrap();
+        assert_eq!(version, previous);
+        let status = migrate::status(&pool).await.unwrap();
+        assert!(!status.last().unwrap().applied);
+        assert_eq!(
+            status.iter().filter(|m| m.applied).count(),
+            status.len() - 1
+        );
 
-    assert!(found.is_some());
-    let found = found.unwrap();
-    assert_eq!(found.id, created.id);
-    assert_eq!(found.code, "101");
+        migrate::down(&pool, Some(0)).await.unwrap();
+        assert!(migrate::status(&pool)
+            .await
+            .unwrap()
+            .iter()
+            .all(|m| !m.applied));
+
+        migrate::fresh(&pool).await.unwrap();
+        let status = migrate::status(&pool).await.unwrap();
+        assert_eq!(status.last().unwrap().version, latest);
+        assert!(status.iter().all(|m| m.applied));
+    })
+    .await;
 }
 
-// 4. 存在しない ID → None
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_find_by_id_not_found(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
+// 5. 組織の作成・更新・論理削除
+#[tokio::test]
+async fn test_organizations() {
+    with_database(&MIGRATOR, |pool| async move {
+        let repo = PostgresOrganizationRepository::new(pool);
+        let created = repo
+            .create(CreateOrganizationRequest {
+                name: "恵み教会".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(created.is_active);
 
-    let found = repo.find_by_id(Uuid::new_v4()).await.unwrap();
+        let updated = repo
+            .update(
+                created.id,
+                UpdateOrganizationRequest {
+                    name: Some("恵みキリスト教会".to_string()),
+                    is_active: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.name, "恵みキリスト教会");
+        assert_eq!(repo.find_all().await.unwrap().len(), 1);
 
-    assert!(found.is_none());
+        repo.soft_delete(created.id).await.unwrap();
+        assert!(repo.find_all().await.unwrap().is_empty());
+        assert!(
+            !repo
+                .find_by_id(created.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .is_active
+        );
+        assert!(matches!(
+            repo.soft_delete(Uuid::new_v4()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    })
+    .await;
 }
 
-// 5. コード検索
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_find_by_code(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
-    let _ = repo.create(default_request()).await.unwrap();
+// 6. メール送信キュー：取り出し中は再取得されず、失敗は再送待ち・打ち切りになる
+#[tokio::test]
+async fn test_email_queue() {
+    with_database(&MIGRATOR, |pool| async move {
+        let repo = PostgresEmailQueueRepository::new(pool);
+        let lease = chrono::Duration::minutes(5);
+        let message = |to: &str| EmailMessage {
+            to: to.to_string(),
+            subject: "お知らせ".to_string(),
+            body: "本文".to_string(),
+        };
+        let first = repo.enqueue(message("a@example.com")).await.unwrap();
+        let second = repo.enqueue(message("b@example.com")).await.unwrap();
+
+        let now = Utc::now();
+        let claimed = repo.claim_due(now, 10, lease).await.unwrap();
+        assert_eq!(claimed.len(), 2);
+        assert_eq!(claimed[0].message, message("a@example.com"));
+        assert!(repo.claim_due(now, 10, lease).await.unwrap().is_empty());
 
-    let found = repo.find_by_code("101").await.unwrap();
+        repo.mark_sent(first.id).await.unwrap();
+        repo.mark_failed(second.id, "timeout", Some(now))
+            .await
+            .unwrap();
+        let sent = repo.find_by_id(first.id).await.unwrap().unwrap();
+        assert_eq!(sent.status, EmailStatus::Sent);
+        assert!(sent.sent_at.is_some());
+        let retrying = repo.find_by_id(second.id).await.unwrap().unwrap();
+        assert_eq!(retrying.status, EmailStatus::Pending);
+        assert_eq!(retrying.attempts, 1);
+        assert_eq!(retrying.last_error.as_deref(), Some("timeout"));
 
-    assert!(found.is_some());
-    assert_eq!(found.unwrap().code, "101");
+        let claimed = repo.claim_due(Utc::now(), 10, lease).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        repo.mark_failed(second.id, "rejected", None).await.unwrap();
+        let failed = repo.find_by_id(second.id).await.unwrap().unwrap();
+        assert_eq!(failed.status, EmailStatus::Failed);
+        assert_eq!(failed.attempts, 2);
+        assert!(repo
+            .claim_due(Utc::now() + lease, 10, lease)
+            .await
+            .unwrap()
+            .is_empty());
+    })
+    .await;
 }
 
-// 6. 存在しないコード → None
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_find_by_code_not_found(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
+// 7. 組織定義のカテゴリ：組み込みは初期データとして登録され、定義した組織の科目でのみ使える
+#[tokio::test]
+async fn test_custom_categories() {
+    with_database(&MIGRATOR, |pool| async move {
+        let org = Uuid::new_v4();
+        let categories = PostgresCategoryRepository::new(pool.clone()).for_organization(org);
+        let accounts = PostgresAccountRepository::new(pool).for_organization(org);
 
-    let found = repo.find_by_code("999").await.unwrap();
+        let built_in = categories.find_all().await.unwrap();
+        assert_eq!(built_in.len(), AccountCategory::BUILT_IN.len());
+        assert_eq!(built_in[0].code, "cash");
+        assert!(built_in.iter().all(|c| c.is_built_in()));
 
-    assert!(found.is_none());
+        let request = |code: &str| CreateCategoryRequest {
+            code: code.to_string(),
+            name: "青年会費".to_string(),
+            account_type: AccountType::Expense,
+        };
+        assert!(matches!(
+            categories.create(request("cash")).await,
+            Err(RepositoryError::Conflict(_))
+        ));
+        let youth = categories.create(request("youth_ministry")).await.unwrap();
+        assert!(matches!(
+            categories.create(request("youth_ministry")).await,
+            Err(RepositoryError::Conflict(_))
+        ));
+        assert_eq!(categories.find_all().await.unwrap().last(), Some(&youth));
+
+        let custom = AccountCategory::Custom("youth_ministry".to_string());
+        let account = accounts
+            .create(create_test_request("510", "青年会", custom.clone()))
+            .await
+            .unwrap();
+        assert_eq!(account.account_type, AccountType::Expense);
+        let found = accounts.find_by_id(account.id).await.unwrap().unwrap();
+        assert_eq!(found.category, custom);
+
+        // 他組織・削除後は使えない
+        let other = accounts.for_organization(Uuid::new_v4());
+        assert!(matches!(
+            other
+                .create(create_test_request("510", "青年会", custom.clone()))
+                .await,
+            Err(RepositoryError::ValidationError(_))
+        ));
+        categories.soft_delete(youth.id).await.unwrap();
+        assert!(matches!(
+            accounts
+                .create(create_test_request("511", "青年会2", custom))
+                .await,
+            Err(RepositoryError::ValidationError(_))
+        ));
+        assert!(matches!(
+            categories.soft_delete(built_in[0].id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    })
+    .await;
 }
 
-// 7. 全件取得、display_order 順
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_find_all(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
+// 8. 一覧の並び替え・絞り込みを SQL で行う
+#[tokio::test]
+async fn test_list_sorted() {
+    with_account_repo(|repo| async move {
+        let mut ids = Vec::new();
+        for (code, name, category, display_order) in [
+            ("101", "現金", AccountCategory::Cash, 3),
+            ("102", "普通預金", AccountCategory::BankDeposit, 1),
+            ("401", "什一献金", AccountCategory::TitheOffering, 2),
+        ] {
+            let mut request = create_test_request(code, name, category);
+            request.display_order = Some(display_order);
+            ids.push(repo.create(request).await.unwrap().id);
+        }
+        repo.soft_delete(ids[1]).await.unwrap();
 
-    let req1 = CreateAccountRequest {
-        code: "401".to_string(),
-        name: "什一献金".to_string(),
-        category: AccountCategory::TitheOffering,
-        description: None,
-        display_order: Some(10),
-    };
-    let req2 = CreateAccountRequest {
-        code: "101".to_string(),
-        name: "現金".to_string(),
-        category: AccountCategory::Cash,
-        description: None,
-        display_order: Some(1),
-    };
+        let codes =
+            |accounts: Vec<Account>| accounts.into_iter().map(|a| a.code).collect::<Vec<_>>();
 
-    let _ = repo.create(req1).await.unwrap();
-    let _ = repo.create(req2).await.unwrap();
+        let default = repo.list(AccountListQuery::default()).await.unwrap();
+        assert_eq!(codes(default), ["401", "101"]);
 
-    let all = repo.find_all().await.unwrap();
+        let query = AccountListQuery {
+            include_inactive: true,
+            sort: AccountSortKey::Code,
+            order: SortOrder::Desc,
+            ..Default::default()
+        };
+        assert_eq!(
+            codes(repo.list(query).await.unwrap()),
+            ["401", "102", "101"]
+        );
 
-    assert_eq!(all.len(), 2);
-    assert_eq!(all[0].display_order, 1);
-    assert_eq!(all[1].display_order, 10);
+        let query = AccountListQuery {
+            account_type: Some(AccountType::Asset),
+            include_inactive: true,
+            sort: AccountSortKey::CreatedAt,
+            ..Default::default()
+        };
+        assert_eq!(codes(repo.list(query).await.unwrap()), ["101", "102"]);
+    })
+    .await;
 }
 
-// 8. 種別フィルタ
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_find_by_type(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
+// 9. 表示順の一括変更：見つからない科目があれば全体をロールバック
+#[tokio::test]
+async fn test_reorder() {
+    with_account_repo(|repo| async move {
+        let cash = repo.create(default_request()).await.unwrap();
+        let tithe = repo
+            .create(create_test_request(
+                "401",
+                "什一献金",
+                AccountCategory::TitheOffering,
+            ))
+            .await
+            .unwrap();
 
-    let asset_req = create_test_request("101", "現金", AccountCategory::Cash);
-    let revenue_req = create_test_request("401", "什一献金", AccountCategory::TitheOffering);
+        let reordered = repo.reorder(&[tithe.id, cash.id]).await.unwrap();
+        assert_eq!(reordered[0].id, tithe.id);
+        assert_eq!(reordered[0].display_order, 1);
+        assert_eq!(reordered[1].display_order, 2);
 
-    let _ = repo.create(asset_req).await.unwrap();
-    let _ = repo.create(revenue_req).await.unwrap();
+        let other = repo.for_organization(Uuid::new_v4());
+        assert!(matches!(
+            other.reorder(&[cash.id]).await,
+            Err(RepositoryError::NotFound(id)) if id == cash.id
+        ));
+
+        assert!(matches!(
+            repo.reorder(&[cash.id, Uuid::new_v4()]).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+        let found = repo.find_by_id(cash.id).await.unwrap().unwrap();
+        assert_eq!(found.display_order, 2);
+    })
+    .await;
+}
 
-    let assets = repo.find_by_type(AccountType::Asset).await.unwrap();
-    let revenues = repo.find_by_type(AccountType::Revenue).await.unwrap();
+// 10. 更新で null を指定した説明・親科目は消去される（省略時は変更しない）
+#[tokio::test]
+async fn test_update_clears_nullable_fields() {
+    with_account_repo(|repo| async move {
+        let parent = repo.create(default_request()).await.unwrap();
+        let mut request = create_test_request("102", "小口現金", AccountCategory::Cash);
+        request.parent_id = Some(parent.id);
+        let child = repo.create(request).await.unwrap();
 
-    assert_eq!(assets.len(), 1);
-    assert_eq!(revenues.len(), 1);
-    assert_eq!(assets[0].code, "101");
-    assert_eq!(revenues[0].code, "401");
+        let update = |description, parent_id| UpdateAccountRequest {
+            name: None,
+            description,
+            display_order: None,
+            is_active: None,
+            parent_id,
+        };
+
+        let updated = repo
+            .update(child.id, update(Patch::Absent, Patch::Absent))
+            .await
+            .unwrap();
+        assert_eq!(updated.description.as_deref(), Some("小口現金の説明"));
+        assert_eq!(updated.parent_id, Some(parent.id));
+
+        let updated = repo
+            .update(child.id, update(Patch::Null, Patch::Null))
+            .await
+            .unwrap();
+        assert_eq!(updated.description, None);
+        assert_eq!(updated.parent_id, None);
+    })
+    .await;
 }
 
-// 9. 名前・説明更新、updated_at 更新確認
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_update_account(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
-    let created = repo.create(default_request()).await.unwrap();
+// 11. 横断検索：科目名の一致を説明の一致より上位に、無効化済み・他組織は対象外
+#[tokio::test]
+async fn test_search_accounts() {
+    with_database(&MIGRATOR, |pool| async move {
+        let accounts = PostgresAccountRepository::new(pool.clone());
+        let search = PostgresSearchRepository::new(pool);
+
+        let cash = accounts.create(default_request()).await.unwrap();
+        let mut petty = create_test_request("102", "小口現金", AccountCategory::Cash);
+        petty.description = None;
+        let petty = accounts.create(petty).await.unwrap();
+        let mut tithe = create_test_request("401", "什一献金", AccountCategory::TitheOffering);
+        tithe.description = Some("現金と振込 100% を含む".to_string());
+        accounts.create(tithe).await.unwrap();
+        accounts.soft_delete(petty.id).await.unwrap();
 
-    let update_request = UpdateAccountRequest {
-        name: Some("小口現金".to_string()),
-        description: Some("小口経費用".to_string()),
-        display_order: None,
-        is_active: None,
-    };
+        let query = |q: &str| SearchQuery {
+            q: q.to_string(),
+            entity_type: None,
+            limit: None,
+        };
 
-    let updated = repo.update(created.id, update_request).await.unwrap();
+        let results = search.search(&query("現金")).await.unwrap();
+        let titles: Vec<&str> = results.hits.iter().map(|h| h.title.as_str()).collect();
+        assert_eq!(titles, ["現金", "什一献金"]);
+        assert_eq!(results.hits[0].id, cash.id);
+        assert!(results.hits[0].rank > results.hits[1].rank);
+        assert_eq!(results.facets.len(), 1);
+        assert_eq!(results.facets[0].entity_type, SearchEntityType::Account);
+        assert_eq!(results.facets[0].count, 2);
 
-    assert_eq!(updated.name, "小口現金");
-    assert_eq!(updated.description, Some("小口経費用".to_string()));
-    assert_eq!(updated.code, "101");
-    assert!(updated.updated_at >= created.updated_at);
+        // 科目コードの前方一致、LIKE のワイルドカードは文字として扱う
+        let results = search.search(&query("40")).await.unwrap();
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(search.search(&query("100%")).await.unwrap().hits.len(), 1);
+        assert_eq!(search.search(&query("%")).await.unwrap().hits.len(), 1);
+
+        let other = search.for_organization(Uuid::new_v4());
+        let results = other.search(&query("現金")).await.unwrap();
+        assert!(results.hits.is_empty());
+        assert!(results.facets.is_empty());
+    })
+    .await;
 }
 
-// 10. 存在しない ID → NotFound エラー
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_update_not_found(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
+// 12. ジョブキュー：重複キーは登録せず、取り出し中は可視性タイムアウトまで他に渡さない
+#[tokio::test]
+async fn test_job_queue_claim_and_retry() {
+    with_database(&MIGRATOR, |pool| async move {
+        let queue = PostgresJobQueue::new(pool);
+        let now = Utc::now();
+        let new_job = NewJob::new("report", serde_json::json!({"period": "2026-01"}))
+            .run_at(now)
+            .unique_key("report:2026-01");
+
+        let job = queue.enqueue(new_job.clone()).await.unwrap().unwrap();
+        assert!(queue.enqueue(new_job).await.unwrap().is_none());
 
-    let update_request = UpdateAccountRequest {
-        name: Some("テスト".to_string()),
-        description: None,
-        display_order: None,
-        is_active: None,
-    };
+        let timeout = chrono::Duration::seconds(30);
+        let claimed = queue.claim(now, 10, timeout).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].attempts, 1);
+        assert_eq!(claimed[0].payload["period"], "2026-01");
+        assert!(queue.claim(now, 10, timeout).await.unwrap().is_empty());
 
-    let result = repo.update(Uuid::new_v4(), update_request).await;
+        // 期限を過ぎても完了していなければ再び取り出される
+        let later = now + chrono::Duration::seconds(31);
+        let reclaimed = queue.claim(later, 10, timeout).await.unwrap();
+        assert_eq!(reclaimed[0].attempts, 2);
 
-    assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+        let retry_at = later + chrono::Duration::minutes(1);
+        queue.fail(job.id, "timeout", Some(retry_at)).await.unwrap();
+        let failed = queue.find_by_id(job.id).await.unwrap().unwrap();
+        assert_eq!(failed.status, JobStatus::Pending);
+        assert_eq!(failed.last_error.as_deref(), Some("timeout"));
+        assert!(queue.claim(later, 10, timeout).await.unwrap().is_empty());
+
+        queue.complete(job.id).await.unwrap();
+        let done = queue.find_by_id(job.id).await.unwrap().unwrap();
+        assert_eq!(done.status, JobStatus::Succeeded);
+        assert_eq!(done.last_error, None);
+        assert!(queue.claim(retry_at, 10, timeout).await.unwrap().is_empty());
+    })
+    .await;
 }
 
-// 11. 論理削除 (is_active=false)
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_soft_delete(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
-    let created = repo.create(default_request()).await.unwrap();
+// 13. 時点指定：トリガーで記録した履歴から、その時点の勘定科目を返す
+#[tokio::test]
+async fn test_find_as_of() {
+    with_database(&MIGRATOR, |pool| async move {
+        let repo = PostgresAccountRepository::new(pool.clone());
+        let now = || async {
+            sqlx::query_scalar::<_, chrono::DateTime<Utc>>("SELECT NOW()")
+                .fetch_one(&pool)
+                .await
+                .unwrap()
+        };
+
+        let before_create = now().await;
+        let created = repo.create(default_request()).await.unwrap();
+        let after_create = now().await;
+        let rename = UpdateAccountRequest {
+            name: Some("手許現金".to_string()),
+            description: Patch::Absent,
+            display_order: None,
+            is_active: None,
+            parent_id: Patch::Absent,
+        };
+        repo.update(created.id, rename).await.unwrap();
+        repo.soft_delete(created.id).await.unwrap();
 
-    let result = repo.soft_delete(created.id).await;
-    assert!(result.is_ok());
+        assert!(repo
+            .find_as_of(created.id, before_create)
+            .await
+            .unwrap()
+            .is_none());
+        let original = repo
+            .find_as_of(created.id, after_create)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(original.name, "現金");
+        assert!(original.is_active);
 
-    let found = repo.find_by_id(created.id).await.unwrap().unwrap();
-    assert!(!found.is_active);
+        let latest = repo
+            .find_as_of(created.id, now().await)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.name, "手許現金");
+        assert!(!latest.is_active);
+
+        let other = repo.for_organization(Uuid::new_v4());
+        assert!(other
+            .find_as_of(created.id, now().await)
+            .await
+            .unwrap()
+            .is_none());
+    })
+    .await;
 }
 
-// 12. 存在しない ID → NotFound エラー
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_soft_delete_not_found(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
+// 14. 固定資産：組織ごとに分離し、除却済みは再び除却できない
+#[tokio::test]
+async fn test_fixed_asset_register() {
+    with_database(&MIGRATOR, |pool| async move {
+        let accounts = PostgresAccountRepository::new(pool.clone());
+        let expense = accounts
+            .create(create_test_request(
+                "801",
+                "営繕費",
+                AccountCategory::MaintenanceExpense,
+            ))
+            .await
+            .unwrap();
+        let repo = PostgresFixedAssetRepository::new(pool);
+
+        let created = repo
+            .create(CreateFixedAssetRequest {
+                name: "音響設備".to_string(),
+                acquisition_date: NaiveDate::from_ymd_opt(2026, 4, 15).unwrap(),
+                acquisition_cost: 1_200_000,
+                salvage_value: Some(1),
+                useful_life_months: 60,
+                expense_account_id: expense.id,
+                note: None,
+            })
+            .await
+            .unwrap();
+        let found = repo.find_by_id(created.id).await.unwrap().unwrap();
+        assert_eq!(found.name, created.name);
+        assert_eq!(found.salvage_value.amount, 1);
+        assert_eq!(found.depreciation_schedule().unwrap().len(), 60);
+
+        let other = repo.for_organization(Uuid::new_v4());
+        assert!(other.find_by_id(created.id).await.unwrap().is_none());
+        assert!(other.find_all().await.unwrap().is_empty());
+        assert!(matches!(
+            other.dispose(created.id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+
+        repo.dispose(created.id).await.unwrap();
+        assert!(matches!(
+            repo.dispose(created.id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+        let assets = repo.find_all().await.unwrap();
+        assert_eq!(assets.len(), 1);
+        assert!(!assets[0].is_active);
+    })
+    .await;
+}
+
+// 15. 取引先：省略した項目は残し、null の項目は消去する。論理削除は一覧から除外
+#[tokio::test]
+async fn test_counterparty_update_and_soft_delete() {
+    with_database(&MIGRATOR, |pool| async move {
+        let repo = PostgresCounterpartyRepository::new(pool);
+        let created = repo
+            .create(CreateCounterpartyRequest {
+                name: "山田電気".to_string(),
+                contact: Some("03-0000-0000".to_string()),
+                note: Some("照明工事".to_string()),
+            })
+            .await
+            .unwrap();
+
+        let updated = repo
+            .update(
+                created.id,
+                UpdateCounterpartyRequest {
+                    name: Some("山田電気商会".to_string()),
+                    contact: Patch::Null,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.name, "山田電気商会");
+        assert_eq!(updated.contact, None);
+        assert_eq!(updated.note.as_deref(), Some("照明工事"));
+
+        let other = repo.for_organization(Uuid::new_v4());
+        assert!(other.find_by_id(created.id).await.unwrap().is_none());
+        assert!(matches!(
+            other.soft_delete(created.id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+
+        repo.soft_delete(created.id).await.unwrap();
+        assert!(repo.find_all().await.unwrap().is_empty());
+        let deleted = repo.find_by_id(created.id).await.unwrap().unwrap();
+        assert!(!deleted.is_active);
+    })
+    .await;
+}
+
+// 16. マイグレーションの適用状況 API：管理テーブルを作らずに未適用を報告する
+#[tokio::test]
+async fn test_ready_reports_pool_stats() {
+    with_empty_database(|pool| async move {
+        let app = metrics::health_router(Readiness::ready(), Some(pool.clone()));
+        let get = |uri: &'static str| {
+            let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(request).await.unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                response.into_body().collect().await.unwrap().to_bytes()
+            }
+        };
+
+        let ready: serde_json::Value = serde_json::from_slice(&get("/ready").await).unwrap();
+        assert_eq!(ready["status"], "ok");
+        assert_eq!(
+            ready["pool"]["max_connections"],
+            pool.options().get_max_connections()
+        );
+
+        let metrics = String::from_utf8(get("/metrics").await.to_vec()).unwrap();
+        assert!(metrics.contains("# TYPE db_pool_size gauge"));
+        assert!(metrics.contains("db_pool_acquire_wait_seconds "));
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_migrations_endpoint() {
+    with_empty_database(|pool| async move {
+        let app = migrate::migrations_router(pool.clone());
+        let get_migrations = || async {
+            let request = Request::builder()
+                .uri("/api/admin/migrations")
+                .body(Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+        };
+
+        let before = get_migrations().await;
+        assert_eq!(before["schema_version"], serde_json::Value::Null);
+        assert!(before["pending"].as_u64().unwrap() > 0);
+        assert_eq!(
+            before["pending"],
+            before["migrations"].as_array().unwrap().len()
+        );
+        let has_table: bool =
+            sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert!(!has_table);
+
+        migrate::up(&pool).await.unwrap();
+        let after = get_migrations().await;
+        assert_eq!(after["pending"], 0);
+        assert_eq!(after["schema_version"], after["latest_version"]);
+        assert!(after["migrations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|m| m["state"] == "applied"));
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_promote_requires_migrations() {
+    with_empty_database(|pool| async move {
+        let mode = StandbyMode::new(true);
+        let app = admin_router(mode.clone(), Some(pool.clone()));
+        let promote = || async {
+            let request = Request::post("/api/admin/promote")
+                .body(Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (
+                status,
+                serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            )
+        };
+
+        let (status, error) = promote().await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(error["code"], "MIGRATIONS_PENDING");
+        assert!(mode.is_read_only());
+
+        migrate::up(&pool).await.unwrap();
+        let (status, role) = promote().await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(role["role"], "primary");
+    })
+    .await;
+}
+
+// 17. 行レベルセキュリティ：接続に設定した組織の行だけが見え（未設定なら何も見えない）、返却時に設定を戻す
+#[tokio::test]
+async fn test_row_level_security() {
+    with_database(&MIGRATOR, |pool| async move {
+        // スーパーユーザーは RLS を素通りするため、一般ロールで接続する
+        sqlx::query(
+            r#"
+            DO $$
+            BEGIN
+                IF NOT EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'rls_tester') THEN
+                    CREATE ROLE rls_tester LOGIN PASSWORD 'rls_tester';
+                END IF;
+            END
+            $$
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("GRANT ALL ON ALL TABLES IN SCHEMA public TO rls_tester")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("GRANT ALL ON ALL SEQUENCES IN SCHEMA public TO rls_tester")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let other_org = Uuid::new_v4();
+        let admin = PostgresAccountRepository::new(pool.clone());
+        admin
+            .create(create_test_request("101", "現金", AccountCategory::Cash))
+            .await
+            .unwrap();
+        admin
+            .for_organization(other_org)
+            .create(create_test_request("101", "現金", AccountCategory::Cash))
+            .await
+            .unwrap();
+
+        // 接続を 1 本にして、返却後の接続が再利用されることを確かめる
+        let url = (*pool.connect_options())
+            .clone()
+            .username("rls_tester")
+            .password("rls_tester")
+            .to_url_lossy()
+            .to_string();
+        let rls_config = DatabaseConfig {
+            url,
+            max_connections: 1,
+            min_connections: 0,
+            acquire_timeout_secs: 5,
+            statement_timeout_ms: None,
+            log_statements: log::LevelFilter::Debug,
+            slow_statement_threshold: std::time::Duration::from_secs(1),
+            ssl_mode: None,
+            connect_max_retries: 0,
+            tenant_isolation: TenantIsolation::Rls,
+        };
+        let rls_pool = rls_config.create_pool().await.unwrap();
+        // テーブルの所有者（ここではマイグレーションを適用したスーパーユーザー）では起動させない
+        rls_config.check_rls_role(&rls_pool).await.unwrap();
+        assert!(rls_config.check_rls_role(&pool).await.is_err());
+
+        let repo = PostgresAccountRepository::new(rls_pool.clone())
+            .with_tenant_isolation(TenantIsolation::Rls)
+            .for_organization(other_org);
+        let created = repo
+            .create(create_test_request(
+                "102",
+                "普通預金",
+                AccountCategory::BankDeposit,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(created.organization_id, other_org);
+        assert_eq!(repo.find_all().await.unwrap().len(), 2);
+        assert_eq!(repo.reorder(&[created.id]).await.unwrap().len(), 1);
+        let rates = PostgresExchangeRateRepository::new(rls_pool.clone())
+            .with_tenant_isolation(TenantIsolation::Rls)
+            .for_organization(other_org);
+        rates
+            .create(CreateExchangeRateRequest {
+                base_currency: Currency::USD,
+                quote_currency: Currency::JPY,
+                rate: Decimal::new(150, 0),
+                effective_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(rates.find_all(None, None).await.unwrap().len(), 1);
+
+        // 組織を設定した接続では絞り込みのないクエリでも他組織の行は見えず、書き込めない
+        let mut conn = rls_pool.acquire().await.unwrap();
+        sqlx::query("SELECT set_config('app.current_org', $1, false)")
+            .bind(other_org.to_string())
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+        let visible: Vec<Uuid> = sqlx::query_scalar("SELECT DISTINCT organization_id FROM accounts")
+            .fetch_all(&mut *conn)
+            .await
+            .unwrap();
+        assert_eq!(visible, vec![other_org]);
+        let insert = sqlx::query(
+            "INSERT INTO accounts (organization_id, code, name, account_type, category) VALUES ($1, '103', '定期預金', 'asset', 'fixed_deposit')",
+        )
+        .bind(DEFAULT_ORGANIZATION_ID)
+        .execute(&mut *conn)
+        .await
+        .unwrap_err();
+        assert!(insert.to_string().contains("row-level security"));
+        drop(conn);
+
+        // 返却時に設定が戻るため、次の利用者には前の組織が残らない
+        let current: Option<String> =
+            sqlx::query_scalar("SELECT NULLIF(current_setting('app.current_org', true), '')")
+                .fetch_one(&rls_pool)
+                .await
+                .unwrap();
+        assert_eq!(current, None);
+
+        // 組織を設定していない接続からは 1 行も見えず、書き込めない
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM accounts")
+            .fetch_one(&rls_pool)
+            .await
+            .unwrap();
+        assert_eq!(total, 0);
+        let rates: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM exchange_rates")
+            .fetch_one(&rls_pool)
+            .await
+            .unwrap();
+        assert_eq!(rates, 0);
+        let insert = sqlx::query(
+            "INSERT INTO accounts (organization_id, code, name, account_type, category) VALUES ($1, '104', '定期預金', 'asset', 'fixed_deposit')",
+        )
+        .bind(other_org)
+        .execute(&rls_pool)
+        .await
+        .unwrap_err();
+        assert!(insert.to_string().contains("row-level security"));
+
+        // マイグレーションを適用したロール（ここではスーパーユーザー）は全組織の行を扱える
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM accounts")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(total, 3);
+    })
+    .await;
+}
+
+// 18. 勘定科目種別は列挙型、カテゴリは定義済みのものに限る（既存の未定義カテゴリは登録して移行）
+#[tokio::test]
+async fn test_account_type_and_category_constraints() {
+    with_database(&MIGRATOR, |pool| async move {
+        let org = Uuid::new_v4();
+        let insert = |account_type: &'static str, category: &'static str, code: &'static str| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query(&format!(
+                    "INSERT INTO accounts (organization_id, code, name, account_type, category) VALUES ($1, '{code}', '科目', '{account_type}', '{category}')"
+                ))
+                .bind(org)
+                .execute(&pool)
+                .await
+            }
+        };
+
+        // 制約を入れる前の状態に戻し、定義のないカテゴリを持つ勘定科目を作る
+        migrate::down(&pool, Some(20260213000016)).await.unwrap();
+        insert("expense", "choir_expense", "520").await.unwrap();
+        migrate::up(&pool).await.unwrap();
+
+        let categories = PostgresCategoryRepository::new(pool.clone()).for_organization(org);
+        let migrated = categories.find_by_code("choir_expense").await.unwrap().unwrap();
+        assert_eq!(migrated.organization_id, Some(org));
+        assert_eq!(migrated.account_type, AccountType::Expense);
+        let accounts = PostgresAccountRepository::new(pool.clone()).for_organization(org);
+        let account = accounts.find_by_code("520").await.unwrap().unwrap();
+        assert_eq!(account.account_type, AccountType::Expense);
+
+        let err = insert("Expense", "other_expense", "521").await.unwrap_err();
+        assert!(err.to_string().contains("invalid input value for enum"));
+        let err = insert("expense", "unknown_expense", "522").await.unwrap_err();
+        assert!(err.to_string().contains("unknown account category"));
+        // 他組織の定義は使えない
+        let other = Uuid::new_v4();
+        let err = sqlx::query(
+            "UPDATE accounts SET organization_id = $1 WHERE organization_id = $2 AND code = '520'",
+        )
+        .bind(other)
+        .bind(org)
+        .execute(&pool)
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("unknown account category"));
+        insert("expense", "other_expense", "523").await.unwrap();
+    })
+    .await;
+}
+
+// 19. 作業単位：勘定科目とアウトボックスへの書き込みをまとめて確定・取り消しする
+#[tokio::test]
+async fn test_unit_of_work() {
+    with_database(&MIGRATOR, |pool| async move {
+        let org = Uuid::new_v4();
+        let factory = PostgresAccountRepository::new(pool.clone());
+        let accounts = PostgresAccountRepository::new(pool.clone()).for_organization(org);
+        let email_queue = PostgresEmailQueueRepository::new(pool.clone());
+        let message = EmailMessage {
+            to: "treasurer@example.com".to_string(),
+            subject: "勘定科目を追加しました".to_string(),
+            body: "101 現金".to_string(),
+        };
+
+        let uow = factory.begin(org).await.unwrap();
+        let cash = uow
+            .accounts()
+            .create(create_test_request("101", "現金", AccountCategory::Cash))
+            .await
+            .unwrap();
+        let queued = uow.email_queue().enqueue(message.clone()).await.unwrap();
+        assert_eq!(cash.organization_id, org);
+        assert!(accounts.find_by_id(cash.id).await.unwrap().is_none());
+        assert!(email_queue.find_by_id(queued.id).await.unwrap().is_none());
+        uow.commit().await.unwrap();
+        assert_eq!(
+            accounts.find_by_id(cash.id).await.unwrap(),
+            Some(cash.clone())
+        );
+        assert!(email_queue.find_by_id(queued.id).await.unwrap().is_some());
+
+        // 途中で失敗した並べ替え（セーブポイント）は作業単位の他の変更を巻き込まない
+        let uow = factory.begin(org).await.unwrap();
+        let repo = uow.accounts();
+        let bank = repo
+            .create(create_test_request(
+                "102",
+                "普通預金",
+                AccountCategory::BankDeposit,
+            ))
+            .await
+            .unwrap();
+        assert!(matches!(
+            repo.reorder(&[bank.id, Uuid::new_v4()]).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+        assert_eq!(repo.reorder(&[bank.id, cash.id]).await.unwrap().len(), 2);
+        let queued = uow.email_queue().enqueue(message).await.unwrap();
+        uow.rollback().await.unwrap();
 
-    let result = repo.soft_delete(Uuid::new_v4()).await;
+        assert!(accounts.find_by_id(bank.id).await.unwrap().is_none());
+        assert_eq!(accounts.find_by_id(cash.id).await.unwrap(), Some(cash));
+        assert!(email_queue.find_by_id(queued.id).await.unwrap().is_none());
+        assert!(matches!(
+            repo.find_all().await,
+            Err(RepositoryError::DatabaseError(_))
+        ));
 
-    assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+        // 同じ組織の作業単位は先のものが終わるまで開始しない（他組織は待たない）
+        let first = factory.begin(org).await.unwrap();
+        factory.begin(Uuid::new_v4()).await.unwrap();
+        let second =
+            tokio::time::timeout(std::time::Duration::from_millis(200), factory.begin(org)).await;
+        assert!(second.is_err());
+        first.commit().await.unwrap();
+        factory.begin(org).await.unwrap();
+    })
+    .await;
 }
 
-// 13. 存在チェック
-#[sqlx::test(migrator = "MIGRATOR")]
-async fn test_exists_by_code(pool: PgPool) {
-    let repo = PostgresAccountRepository::new(pool);
+// 20. 月次締め：組織・月ごとに 1 件を上書き保存する
+#[tokio::test]
+async fn test_month_close_upsert() {
+    with_database(&MIGRATOR, |pool| async move {
+        let org = Uuid::new_v4();
+        let repo = PostgresMonthCloseRepository::new(pool).for_organization(org);
+        let month = parse_month("2026-04").unwrap();
+        assert!(repo.find(month).await.unwrap().is_none());
 
-    assert!(!repo.exists_by_code("101").await.unwrap());
+        let mut close = MonthClose::new(org, month);
+        close.apply(UpdateMonthCloseRequest {
+            bank_reconciled: Some(true),
+            ..Default::default()
+        });
+        repo.save(&close).await.unwrap();
 
-    let _ = repo.create(default_request()).await.unwrap();
+        close.apply(UpdateMonthCloseRequest {
+            offerings_posted: Some(true),
+            reports_generated: Some(true),
+            ..Default::default()
+        });
+        let saved = repo.save(&close).await.unwrap();
+        assert!(saved.is_complete());
+        assert!(saved.is_locked());
 
-    assert!(repo.exists_by_code("101").await.unwrap());
+        let found = repo.find(month).await.unwrap().unwrap();
+        assert_eq!(found.organization_id, org);
+        assert!(found.bank_reconciled && found.locked_at.is_some());
+        assert!(repo
+            .for_organization(Uuid::new_v4())
+            .find(month)
+            .await
+            .unwrap()
+            .is_none());
+    })
+    .await;
 }
@@ -0,0 +1,433 @@
+//! 本番と同じルーター（`build_router`）をインメモリの状態で動かす
+
+use accounting_service::app::build_router;
+use accounting_service::repository::InMemoryAccountRepository;
+use accounting_service::service::Quotas;
+use accounting_service::standby::StandbyMode;
+use accounting_service::state::AppState;
+use accounting_service::tenant::TenantAuth;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::startup::ConfigEntry;
+use http_body_util::BodyExt;
+use std::sync::Arc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_app(standby: StandbyMode) -> Router {
+    build_router(
+        AppState::builder(Arc::new(InMemoryAccountRepository::new()))
+            .with_standby(standby)
+            .with_admin_token(ADMIN_TOKEN.to_string())
+            .build(),
+    )
+}
+
+const ADMIN_TOKEN: &str = "secret";
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    body: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("x-org-id", Uuid::nil().to_string())
+        .body(body.map_or(Body::empty(), |b| Body::from(b.to_string())))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    assert!(response.headers().contains_key("x-request-id"));
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+#[tokio::test]
+async fn test_account_routes_through_production_router() {
+    let app = create_app(StandbyMode::default());
+
+    let (status, _) = send(&app, "GET", "/health", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, ready) = send(&app, "GET", "/ready", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(ready["status"], "ok");
+
+    let body = serde_json::json!({ "code": "101", "name": "現金", "category": "cash" });
+    let (status, created) = send(&app, "POST", "/api/accounts", Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let uri = format!("/api/accounts/{}", created["id"].as_str().unwrap());
+    let (status, account) = send(&app, "GET", &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(account["code"], "101");
+
+    let query = serde_json::json!({ "query": "{ accounts { code } }" });
+    let (status, result) = send(&app, "POST", "/graphql", Some(query)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["accounts"][0]["code"], "101");
+}
+
+#[tokio::test]
+async fn test_standby_rejects_writes_except_graphql() {
+    let app = create_app(StandbyMode::new(true));
+
+    let body = serde_json::json!({ "code": "101", "name": "現金", "category": "cash" });
+    let (status, error) = send(&app, "POST", "/api/accounts", Some(body.clone())).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(error["code"], "READ_ONLY");
+
+    let (status, _) = send(&app, "GET", "/api/accounts", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let query = serde_json::json!({ "query": "{ accounts { code } }" });
+    let (status, _) = send(&app, "POST", "/graphql", Some(query)).await;
+    assert_eq!(status, StatusCode::OK);
+    let draft = serde_json::json!({ "lines": [] });
+    let (status, _) = send(&app, "POST", "/api/journal-entries/validate", Some(draft)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(&app, "POST", "/api/admin/promote", None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let request = Request::post("/api/admin/promote")
+        .header("Authorization", format!("Bearer {}", ADMIN_TOKEN))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let (status, _) = send(&app, "POST", "/api/accounts", Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_admin_info_reports_build() {
+    let app = create_app(StandbyMode::default());
+
+    let (status, _) = send(&app, "GET", "/api/admin/info", None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let request = Request::get("/api/admin/info")
+        .header("Authorization", format!("Bearer {}", ADMIN_TOKEN))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let info: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(info["service"], "accounting-service");
+    assert_eq!(info["version"], env!("CARGO_PKG_VERSION"));
+    assert!(!info["git_sha"].as_str().unwrap().is_empty());
+    assert!(
+        chrono::DateTime::parse_from_rfc3339(info["build_timestamp"].as_str().unwrap()).is_ok()
+    );
+    assert_eq!(info["repository"], "in-memory");
+    assert_eq!(info["features"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_log_level_requires_admin_token() {
+    let app = build_router(
+        AppState::builder(Arc::new(InMemoryAccountRepository::new()))
+            .with_admin_token("secret".to_string())
+            .build(),
+    );
+
+    let body = serde_json::json!({ "filter": "debug" });
+    let (status, error) = send(&app, "PUT", "/api/admin/log-level", Some(body)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(error["code"], "UNAUTHORIZED");
+
+    let request = Request::get("/api/admin/log-level")
+        .header("Authorization", "Bearer secret")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_msgpack_bodies_for_service_to_service_calls() {
+    let app = create_app(StandbyMode::default());
+
+    let body = serde_json::json!({ "code": "101", "name": "現金", "category": "cash" });
+    let request = Request::post("/api/accounts")
+        .header("Content-Type", "application/msgpack")
+        .header("x-org-id", Uuid::nil().to_string())
+        .body(Body::from(common::msgpack::to_vec(&body).unwrap()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let request = Request::get("/api/accounts")
+        .header("Accept", "application/msgpack")
+        .header("x-org-id", Uuid::nil().to_string())
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        common::msgpack::MSGPACK_CONTENT_TYPE
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let accounts: serde_json::Value = common::msgpack::from_slice(&body).unwrap();
+    assert_eq!(accounts[0]["code"], "101");
+}
+
+#[tokio::test]
+async fn test_account_quota_is_forbidden() {
+    let app = build_router(
+        AppState::builder(Arc::new(InMemoryAccountRepository::new()))
+            .with_quotas(Quotas::default().with_max_accounts(1))
+            .build(),
+    );
+
+    let body = serde_json::json!({ "code": "101", "name": "現金", "category": "cash" });
+    let (status, _) = send(&app, "POST", "/api/accounts", Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let body = serde_json::json!({ "code": "102", "name": "普通預金", "category": "bank_deposit" });
+    let (status, error) = send(&app, "POST", "/api/accounts", Some(body)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(error["code"], "QUOTA_EXCEEDED");
+}
+
+#[tokio::test]
+async fn test_import_accounts_from_freee_csv() {
+    let app = create_app(StandbyMode::default());
+
+    let body = serde_json::json!({
+        "content": "勘定科目,小カテゴリー\n普通預金,現金・預金\n献金収入,売上高\n交際費,経費\n",
+        "mapping": { "献金収入": "tithe_offering" },
+    });
+    let (status, result) = send(
+        &app,
+        "POST",
+        "/api/import/accounts?format=freee",
+        Some(body),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(result["created"].as_array().unwrap().len(), 2);
+    assert_eq!(result["created"][1]["category"], "tithe_offering");
+    assert_eq!(result["skipped"][0]["name"], "交際費");
+
+    let (status, error) = send(
+        &app,
+        "POST",
+        "/api/import/accounts?format=mf",
+        Some(serde_json::json!({ "content": "勘定科目\n現金\n" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error["code"], "UNSUPPORTED_FORMAT");
+}
+
+#[tokio::test]
+async fn test_validate_journal_entry_without_saving() {
+    let app = create_app(StandbyMode::default());
+
+    let mut ids = Vec::new();
+    for (code, name, category) in [
+        ("101", "現金", "cash"),
+        ("401", "什一献金", "tithe_offering"),
+        ("402", "特別献金", "special_offering"),
+    ] {
+        let body = serde_json::json!({ "code": code, "name": name, "category": category });
+        let (_, created) = send(&app, "POST", "/api/accounts", Some(body)).await;
+        ids.push(created["id"].as_str().unwrap().to_string());
+    }
+    let uri = format!("/api/accounts/{}", ids[2]);
+    let (status, _) = send(
+        &app,
+        "PUT",
+        &uri,
+        Some(serde_json::json!({ "is_active": false })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let draft = serde_json::json!({
+        "date": "2026-04-05",
+        "lines": [
+            { "account_id": ids[0], "debit": 30000 },
+            { "account_id": ids[1], "credit": 30000 },
+        ],
+    });
+    let (status, result) = send(&app, "POST", "/api/journal-entries/validate", Some(draft)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["valid"], true);
+    assert_eq!(result["debit_total"], 30000);
+
+    let draft = serde_json::json!({
+        "date": "2026-04-05",
+        "lines": [
+            { "account_id": ids[0], "debit": 30000 },
+            { "account_id": ids[2], "credit": 20000 },
+            { "account_id": Uuid::new_v4(), "credit": 5000 },
+        ],
+    });
+    let (status, result) = send(&app, "POST", "/api/journal-entries/validate", Some(draft)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["valid"], false);
+    let violations: Vec<(&str, &str)> = result["violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| (v["field"].as_str().unwrap(), v["code"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        violations,
+        vec![
+            ("lines", "unbalanced"),
+            ("lines[1].account_id", "account_inactive"),
+            ("lines[2].account_id", "account_not_found"),
+        ]
+    );
+
+    let (_, accounts) = send(&app, "GET", "/api/accounts", None).await;
+    assert_eq!(accounts.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_multi_tenant_requires_organization_key() {
+    let tenant_auth = TenantAuth::with_secret("tenant-secret");
+    let app = build_router(
+        AppState::builder(Arc::new(InMemoryAccountRepository::new()))
+            .with_admin_token(ADMIN_TOKEN.to_string())
+            .with_tenant_auth(tenant_auth.clone())
+            .build(),
+    );
+    let request = |org: Option<Uuid>, key: Option<String>| {
+        let mut builder = Request::get("/api/accounts");
+        if let Some(org) = org {
+            builder = builder.header("x-org-id", org.to_string());
+        }
+        if let Some(key) = key {
+            builder = builder.header("x-org-key", key);
+        }
+        builder.body(Body::empty()).unwrap()
+    };
+    let status = |request: Request<Body>| {
+        let app = app.clone();
+        async move { app.oneshot(request).await.unwrap().status() }
+    };
+
+    let org = Uuid::new_v4();
+    let key = tenant_auth.organization_key(org);
+    let other_key = tenant_auth.organization_key(Uuid::new_v4());
+    assert_eq!(status(request(None, None)).await, StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        status(request(Some(org), None)).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        status(request(Some(org), other_key)).await,
+        StatusCode::UNAUTHORIZED
+    );
+    // キーが正しくても、存在しない組織は受け付けない
+    assert_eq!(
+        status(request(Some(org), key)).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    // 組織の作成は運用者だけが行い、応答で API キーを受け取る
+    let (created_status, _) = send(
+        &app,
+        "POST",
+        "/api/organizations",
+        Some(serde_json::json!({ "name": "恵み教会" })),
+    )
+    .await;
+    assert_eq!(created_status, StatusCode::UNAUTHORIZED);
+    let create = Request::post("/api/organizations")
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", ADMIN_TOKEN))
+        .body(Body::from(
+            serde_json::json!({ "name": "恵み教会" }).to_string(),
+        ))
+        .unwrap();
+    let response = app.clone().oneshot(create).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let org: Uuid = created["id"].as_str().unwrap().parse().unwrap();
+    assert_eq!(
+        created["organization_key"].as_str(),
+        tenant_auth.organization_key(org).as_deref()
+    );
+    let key = created["organization_key"].as_str().map(str::to_string);
+    assert_eq!(
+        status(request(Some(org), key.clone())).await,
+        StatusCode::OK
+    );
+
+    // 削除した組織のキーは以後拒否する
+    let delete = Request::delete(format!("/api/organizations/{}", org))
+        .header("Authorization", format!("Bearer {}", ADMIN_TOKEN))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(delete).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(status(request(Some(org), key)).await, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_multi_tenant_handover_leaves_out_operator_settings() {
+    let tenant_auth = TenantAuth::with_secret("tenant-secret");
+    let app = build_router(
+        AppState::builder(Arc::new(InMemoryAccountRepository::new()))
+            .with_admin_token(ADMIN_TOKEN.to_string())
+            .with_tenant_auth(tenant_auth.clone())
+            .with_settings(vec![ConfigEntry::new(
+                "database.url",
+                "postgres://app:s3cret@db/accounting",
+            )])
+            .build(),
+    );
+
+    let request = Request::get("/api/handover")
+        .header("x-org-id", Uuid::nil().to_string())
+        .header(
+            "x-org-key",
+            tenant_auth.organization_key(Uuid::nil()).unwrap(),
+        )
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(body.as_ref()));
+    let paths: Vec<String> = archive
+        .entries()
+        .unwrap()
+        .map(|entry| entry.unwrap().path().unwrap().to_string_lossy().to_string())
+        .collect();
+    assert!(paths.contains(&"handover/accounts.json".to_string()));
+    assert!(!paths.contains(&"handover/settings.json".to_string()));
+
+    // 運用者は管理トークンで設定を確認できる
+    let request = |token: Option<&str>| {
+        let mut builder = Request::get("/api/admin/settings");
+        if let Some(token) = token {
+            builder = builder.header("Authorization", format!("Bearer {}", token));
+        }
+        builder.body(Body::empty()).unwrap()
+    };
+    let response = app.clone().oneshot(request(None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = app
+        .clone()
+        .oneshot(request(Some(ADMIN_TOKEN)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let settings: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        settings["database.url"],
+        "postgres://app:****@db/accounting"
+    );
+}
@@ -0,0 +1,463 @@
+//! `AccountRepository` の実装が満たすべき振る舞い（実装をまたいで共通のテスト）
+//!
+//! 新しい実装は、コード再利用ポリシーを受け取ってリポジトリを渡す関数を用意し、
+//! `account_repository_contract!` で展開する。
+//!
+//! ```ignore
+//! async fn with_repo<F, Fut>(policy: CodeReusePolicy, test: F)
+//! where
+//!     F: FnOnce(Arc<dyn AccountRepository>) -> Fut,
+//!     Fut: Future<Output = ()>,
+//! {
+//!     test(Arc::new(InMemoryAccountRepository::new().with_code_reuse_policy(policy))).await;
+//! }
+//!
+//! account_repository_contract!(with_repo);
+//! ```
+
+use accounting_service::domain::{
+    AccountCategory, AccountType, CreateAccountRequest, UpdateAccountRequest,
+};
+use accounting_service::repository::{AccountRepository, RepositoryError};
+use common::patch::Patch;
+use std::sync::Arc;
+use uuid::Uuid;
+
+type Repo = Arc<dyn AccountRepository>;
+
+/// 契約をテストとして展開する（`$with_repo` は各テストで新しいリポジトリを渡す）
+macro_rules! account_repository_contract {
+    ($with_repo:path) => {
+        account_repository_contract!(@tests $with_repo;
+            create_account => Reject,
+            create_duplicate_code_fails => Reject,
+            find_by_id => Reject,
+            find_by_id_not_found => Reject,
+            find_by_code => Reject,
+            find_by_code_not_found => Reject,
+            find_all => Reject,
+            find_by_type => Reject,
+            update_account => Reject,
+            update_not_found => Reject,
+            update_if_unmodified => Reject,
+            soft_delete => Reject,
+            soft_delete_not_found => Reject,
+            soft_delete_if_unmodified => Reject,
+            exists_by_code => Reject,
+            code_reuse_policy_reject => Reject,
+            code_reuse_policy_allow => Allow,
+            code_reuse_policy_revive => Revive,
+            find_children_and_tree => Reject,
+            parent_not_found_rejected => Reject,
+            parent_cycle_rejected => Reject,
+            parent_type_mismatch_rejected => Reject,
+            organization_isolation => Reject,
+        );
+    };
+    (@tests $with_repo:path; $($name:ident => $policy:ident,)*) => {
+        $(
+            #[tokio::test]
+            async fn $name() {
+                $with_repo(
+                    accounting_service::domain::CodeReusePolicy::$policy,
+                    crate::support::account_contract::$name,
+                )
+                .await;
+            }
+        )*
+    };
+}
+pub(crate) use account_repository_contract;
+
+fn create_test_request(code: &str, name: &str, category: AccountCategory) -> CreateAccountRequest {
+    CreateAccountRequest {
+        code: code.to_string(),
+        name: name.to_string(),
+        category,
+        description: Some(format!("{name}の説明")),
+        display_order: Some(1),
+        parent_id: None,
+    }
+}
+
+fn default_request() -> CreateAccountRequest {
+    create_test_request("101", "現金", AccountCategory::Cash)
+}
+
+fn rename(name: &str) -> UpdateAccountRequest {
+    UpdateAccountRequest {
+        name: Some(name.to_string()),
+        description: Patch::Absent,
+        display_order: None,
+        is_active: None,
+        parent_id: Patch::Absent,
+    }
+}
+
+/// 正常作成、全フィールド確認
+pub async fn create_account(repo: Repo) {
+    let account = repo.create(default_request()).await.unwrap();
+
+    assert_eq!(account.code, "101");
+    assert_eq!(account.name, "現金");
+    assert_eq!(account.account_type, AccountType::Asset);
+    assert_eq!(account.category, AccountCategory::Cash);
+    assert_eq!(account.description, Some("現金の説明".to_string()));
+    assert!(account.is_active);
+    assert_eq!(account.display_order, 1);
+}
+
+/// 重複コード → DuplicateCode エラー
+pub async fn create_duplicate_code_fails(repo: Repo) {
+    let _ = repo.create(default_request()).await.unwrap();
+    let result = repo.create(default_request()).await;
+
+    assert!(matches!(result, Err(RepositoryError::DuplicateCode(_))));
+}
+
+/// ID 検索
+pub async fn find_by_id(repo: Repo) {
+    let created = repo.create(default_request()).await.unwrap();
+
+    let found = repo.find_by_id(created.id).await.unwrap().unwrap();
+
+    assert_eq!(found.id, created.id);
+    assert_eq!(found.code, "101");
+}
+
+/// 存在しない ID → None
+pub async fn find_by_id_not_found(repo: Repo) {
+    assert!(repo.find_by_id(Uuid::new_v4()).await.unwrap().is_none());
+}
+
+/// コード検索
+pub async fn find_by_code(repo: Repo) {
+    let _ = repo.create(default_request()).await.unwrap();
+
+    let found = repo.find_by_code("101").await.unwrap();
+
+    assert_eq!(found.unwrap().code, "101");
+}
+
+/// 存在しないコード → None
+pub async fn find_by_code_not_found(repo: Repo) {
+    assert!(repo.find_by_code("999").await.unwrap().is_none());
+}
+
+/// 全件取得、display_order 順（有効のみの取得も確認）
+pub async fn find_all(repo: Repo) {
+    let mut tithe = create_test_request("401", "什一献金", AccountCategory::TitheOffering);
+    tithe.display_order = Some(10);
+    let tithe = repo.create(tithe).await.unwrap();
+    let _ = repo.create(default_request()).await.unwrap();
+
+    let all = repo.find_all().await.unwrap();
+
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[0].display_order, 1);
+    assert_eq!(all[1].display_order, 10);
+
+    // 無効化済みは find_all にのみ含まれる
+    repo.soft_delete(tithe.id).await.unwrap();
+    assert_eq!(repo.find_all().await.unwrap().len(), 2);
+    let active = repo.find_active().await.unwrap();
+    assert_eq!(active.len(), 1);
+    assert_eq!(active[0].code, "101");
+    assert!(repo
+        .find_by_type(AccountType::Revenue)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+/// 種別フィルタ
+pub async fn find_by_type(repo: Repo) {
+    let _ = repo.create(default_request()).await.unwrap();
+    let _ = repo
+        .create(create_test_request(
+            "401",
+            "什一献金",
+            AccountCategory::TitheOffering,
+        ))
+        .await
+        .unwrap();
+
+    let assets = repo.find_by_type(AccountType::Asset).await.unwrap();
+    let revenues = repo.find_by_type(AccountType::Revenue).await.unwrap();
+
+    assert_eq!(assets.len(), 1);
+    assert_eq!(revenues.len(), 1);
+    assert_eq!(assets[0].code, "101");
+    assert_eq!(revenues[0].code, "401");
+}
+
+/// 名前・説明更新、updated_at 更新確認
+pub async fn update_account(repo: Repo) {
+    let created = repo.create(default_request()).await.unwrap();
+
+    let update_request = UpdateAccountRequest {
+        name: Some("小口現金".to_string()),
+        description: Patch::Value("小口経費用".to_string()),
+        display_order: None,
+        is_active: None,
+        parent_id: Patch::Absent,
+    };
+    let updated = repo.update(created.id, update_request).await.unwrap();
+
+    assert_eq!(updated.name, "小口現金");
+    assert_eq!(updated.description, Some("小口経費用".to_string()));
+    assert_eq!(updated.code, "101"); // codeは変更されない
+    assert!(updated.updated_at >= created.updated_at);
+}
+
+/// 存在しない ID の更新 → NotFound エラー
+pub async fn update_not_found(repo: Repo) {
+    let result = repo.update(Uuid::new_v4(), rename("テスト")).await;
+
+    assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+}
+
+/// 版を指定した更新は現在の版と一致する場合だけ → 不一致は Modified、未存在は NotFound
+pub async fn update_if_unmodified(repo: Repo) {
+    let created = repo.create(default_request()).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+    let renamed = repo.update(created.id, rename("小口現金")).await.unwrap();
+
+    let result = repo
+        .update_if_unmodified(created.id, rename("テスト"), &[created.updated_at])
+        .await;
+    assert!(matches!(result, Err(RepositoryError::Modified(id)) if id == created.id));
+    let found = repo.find_by_id(created.id).await.unwrap().unwrap();
+    assert_eq!(found.name, "小口現金");
+
+    let updated = repo
+        .update_if_unmodified(
+            created.id,
+            rename("テスト"),
+            &[created.updated_at, renamed.updated_at],
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.name, "テスト");
+
+    let result = repo
+        .update_if_unmodified(Uuid::new_v4(), rename("テスト"), &[created.updated_at])
+        .await;
+    assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+}
+
+/// 論理削除 (is_active=false)、有効な科目の一覧・ツリーからは除外
+pub async fn soft_delete(repo: Repo) {
+    let created = repo.create(default_request()).await.unwrap();
+
+    repo.soft_delete(created.id).await.unwrap();
+
+    let found = repo.find_by_id(created.id).await.unwrap().unwrap();
+    assert!(!found.is_active);
+    assert_eq!(repo.find_all().await.unwrap().len(), 1);
+    assert!(repo.find_active().await.unwrap().is_empty());
+    assert!(repo.find_tree().await.unwrap().is_empty());
+}
+
+/// 存在しない ID の論理削除 → NotFound エラー
+pub async fn soft_delete_not_found(repo: Repo) {
+    let result = repo.soft_delete(Uuid::new_v4()).await;
+
+    assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+}
+
+/// 版を指定した論理削除は現在の版と一致する場合だけ
+pub async fn soft_delete_if_unmodified(repo: Repo) {
+    let created = repo.create(default_request()).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+    let renamed = repo.update(created.id, rename("小口現金")).await.unwrap();
+
+    let result = repo
+        .soft_delete_if_unmodified(created.id, &[created.updated_at])
+        .await;
+    assert!(matches!(result, Err(RepositoryError::Modified(_))));
+    assert!(
+        repo.find_by_id(created.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .is_active
+    );
+
+    repo.soft_delete_if_unmodified(created.id, &[renamed.updated_at])
+        .await
+        .unwrap();
+    assert!(
+        !repo
+            .find_by_id(created.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .is_active
+    );
+
+    let result = repo
+        .soft_delete_if_unmodified(Uuid::new_v4(), &[created.updated_at])
+        .await;
+    assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+}
+
+/// 存在チェック
+pub async fn exists_by_code(repo: Repo) {
+    assert!(!repo.exists_by_code("101").await.unwrap());
+
+    let _ = repo.create(default_request()).await.unwrap();
+
+    assert!(repo.exists_by_code("101").await.unwrap());
+}
+
+/// 論理削除済みコードの再利用（Reject）→ DuplicateCode エラー
+pub async fn code_reuse_policy_reject(repo: Repo) {
+    let created = repo.create(default_request()).await.unwrap();
+    repo.soft_delete(created.id).await.unwrap();
+
+    let result = repo.create(default_request()).await;
+
+    assert!(matches!(result, Err(RepositoryError::DuplicateCode(_))));
+}
+
+/// 論理削除済みコードの再利用（Allow）→ 新規作成、有効な科目とは重複不可
+pub async fn code_reuse_policy_allow(repo: Repo) {
+    let created = repo.create(default_request()).await.unwrap();
+
+    // 有効な科目との重複は常に拒否
+    let result = repo.create(default_request()).await;
+    assert!(matches!(result, Err(RepositoryError::DuplicateCode(_))));
+
+    repo.soft_delete(created.id).await.unwrap();
+    let recreated = repo.create(default_request()).await.unwrap();
+
+    assert_ne!(recreated.id, created.id);
+    let found = repo.find_by_code("101").await.unwrap().unwrap();
+    assert_eq!(found.id, recreated.id);
+
+    // 旧科目の再有効化はコード重複
+    let reactivate = UpdateAccountRequest {
+        name: None,
+        description: Patch::Absent,
+        display_order: None,
+        is_active: Some(true),
+        parent_id: Patch::Absent,
+    };
+    let result = repo.update(created.id, reactivate).await;
+    assert!(matches!(result, Err(RepositoryError::DuplicateCode(_))));
+}
+
+/// 論理削除済みコードの再利用（Revive）→ 既存レコードを復活
+pub async fn code_reuse_policy_revive(repo: Repo) {
+    let created = repo.create(default_request()).await.unwrap();
+    repo.soft_delete(created.id).await.unwrap();
+
+    let revived = repo
+        .create(create_test_request(
+            "101",
+            "手許現金",
+            AccountCategory::Cash,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(revived.id, created.id);
+    assert_eq!(revived.name, "手許現金");
+    assert!(revived.is_active);
+
+    let result = repo.create(default_request()).await;
+    assert!(matches!(result, Err(RepositoryError::DuplicateCode(_))));
+}
+
+/// 子科目・ツリー取得
+pub async fn find_children_and_tree(repo: Repo) {
+    let parent = repo.create(default_request()).await.unwrap();
+
+    let mut child_request = create_test_request("102", "小口現金", AccountCategory::Cash);
+    child_request.parent_id = Some(parent.id);
+    let child = repo.create(child_request).await.unwrap();
+
+    assert_eq!(child.parent_id, Some(parent.id));
+
+    let children = repo.find_children(parent.id).await.unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].id, child.id);
+
+    let tree = repo.find_tree().await.unwrap();
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].children[0].account.id, child.id);
+}
+
+/// 存在しない親 → ValidationError
+pub async fn parent_not_found_rejected(repo: Repo) {
+    let mut request = default_request();
+    request.parent_id = Some(Uuid::new_v4());
+
+    let result = repo.create(request).await;
+
+    assert!(matches!(result, Err(RepositoryError::ValidationError(_))));
+}
+
+/// 親子関係の循環 → ValidationError
+pub async fn parent_cycle_rejected(repo: Repo) {
+    let parent = repo.create(default_request()).await.unwrap();
+
+    let mut child_request = create_test_request("102", "小口現金", AccountCategory::Cash);
+    child_request.parent_id = Some(parent.id);
+    let child = repo.create(child_request).await.unwrap();
+
+    let update_request = UpdateAccountRequest {
+        name: None,
+        description: Patch::Absent,
+        display_order: None,
+        is_active: None,
+        parent_id: Patch::Value(child.id),
+    };
+    let result = repo.update(parent.id, update_request).await;
+
+    assert!(matches!(result, Err(RepositoryError::ValidationError(_))));
+}
+
+/// 種別の異なる親 → ValidationError
+pub async fn parent_type_mismatch_rejected(repo: Repo) {
+    let parent = repo.create(default_request()).await.unwrap();
+
+    let mut request = create_test_request("401", "什一献金", AccountCategory::TitheOffering);
+    request.parent_id = Some(parent.id);
+    let result = repo.create(request).await;
+
+    assert!(matches!(result, Err(RepositoryError::ValidationError(_))));
+}
+
+/// 組織ごとの分離：他組織の科目は参照・更新できず、科目コードは組織ごとに一意
+pub async fn organization_isolation(repo: Repo) {
+    let org_a = repo.for_organization(Uuid::new_v4());
+    let org_b = repo.for_organization(Uuid::new_v4());
+
+    let account = org_a.create(default_request()).await.unwrap();
+    let other = org_b.create(default_request()).await.unwrap();
+    assert_ne!(account.organization_id, other.organization_id);
+
+    assert!(org_b.find_by_id(account.id).await.unwrap().is_none());
+    assert_eq!(
+        org_b.find_by_code("101").await.unwrap().unwrap().id,
+        other.id
+    );
+    assert_eq!(org_b.find_all().await.unwrap().len(), 1);
+    assert!(repo.find_all().await.unwrap().is_empty());
+    assert_eq!(org_b.last_modified().await.unwrap(), Some(other.updated_at));
+    assert!(repo.last_modified().await.unwrap().is_none());
+    assert!(matches!(
+        org_b.soft_delete(account.id).await,
+        Err(RepositoryError::NotFound(_))
+    ));
+
+    // 他組織の科目を親にはできない
+    let mut request = create_test_request("102", "小口現金", AccountCategory::Cash);
+    request.parent_id = Some(account.id);
+    assert!(matches!(
+        org_b.create(request).await,
+        Err(RepositoryError::ValidationError(_))
+    ));
+}
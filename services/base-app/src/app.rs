@@ -0,0 +1,106 @@
+use axum::{middleware, routing::get, Json, Router};
+use common::lifecycle::{readiness_router, Readiness};
+use common::panic::catch_panic_layer;
+use common::reporting::error_reporting_middleware;
+
+/// モノリスモードで accounting-service を組み込むパス
+pub const ACCOUNTING_PREFIX: &str = "/accounting";
+/// モノリスモードで echo-service を組み込むパス
+pub const ECHO_PREFIX: &str = "/echo";
+
+/// 本番と同じルーター（結合テストでもこれを使う）
+///
+/// レスポンスの圧縮は含まない。main.rs が組み込んだサービスを含む全体に掛ける。
+pub fn build_router(readiness: Readiness) -> Router {
+    Router::new()
+        .route("/", get(root))
+        .route("/health", get(health))
+        .merge(readiness_router(readiness))
+        .layer(middleware::from_fn(error_reporting_middleware))
+        .layer(catch_panic_layer())
+}
+
+/// 各サービスのルーターをパスの下に組み込んだルーター（`MONOLITH=true`）
+///
+/// HTTP で中継せず同じプロセスで処理するため、1 台の PC で 1 つのバイナリだけを動かせる。
+/// readiness は accounting-service のもの（起動処理の完了で立つ）を全体で共有する。
+/// エラー通知などのミドルウェアは各サービスのルーターが持つため、ここでは重ねない。
+pub fn build_monolith_router(
+    accounting: accounting_service::state::AppState,
+    echo: &echo_service::config::AppConfig,
+) -> Router {
+    let readiness = accounting.readiness.clone();
+    build_router(readiness.clone())
+        .nest(
+            ACCOUNTING_PREFIX,
+            accounting_service::app::build_router(accounting),
+        )
+        .nest(
+            ECHO_PREFIX,
+            echo_service::app::build_router(readiness, echo),
+        )
+}
+
+async fn root() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "message": "Hello from base-app!",
+        "version": env!("CARGO_PKG_VERSION")
+    }))
+}
+
+async fn health() -> &'static str {
+    "OK"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use accounting_service::repository::InMemoryAccountRepository;
+    use accounting_service::state::AppState;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use http_body_util::BodyExt;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    async fn send(app: &Router, method: &str, uri: &str, body: &str) -> (StatusCode, String) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_monolith_mounts_services_under_prefixes() {
+        let app = build_monolith_router(
+            AppState::builder(Arc::new(InMemoryAccountRepository::new())).build(),
+            &echo_service::config::AppConfig::default(),
+        );
+
+        assert_eq!(send(&app, "GET", "/health", "").await.0, StatusCode::OK);
+        assert_eq!(
+            send(&app, "GET", "/accounting/health", "").await.0,
+            StatusCode::OK
+        );
+        assert_eq!(send(&app, "GET", "/ready", "").await.0, StatusCode::OK);
+
+        let account = r#"{ "code": "101", "name": "現金", "category": "cash" }"#;
+        let (status, _) = send(&app, "POST", "/accounting/api/accounts", account).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (status, body) = send(&app, "GET", "/accounting/api/accounts", "").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("101"));
+
+        let (status, body) = send(&app, "POST", "/echo/echo", r#"{ "message": "hi" }"#).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("hi"));
+    }
+}
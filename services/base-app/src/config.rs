@@ -0,0 +1,22 @@
+use common::config::bool_from_str_or_int;
+use serde::Deserialize;
+use validator::Validate;
+
+/// base-app の設定（環境変数・設定ファイルから読み込む）
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+pub struct AppConfig {
+    /// `MONOLITH=true` で accounting-service と echo-service を同じプロセスに組み込む
+    #[serde(default, deserialize_with = "bool_from_str_or_int")]
+    pub monolith: bool,
+    /// 停止シグナルを受けてから新規リクエストの受け付けを止めるまでの待ち時間（秒、既定は 0）
+    pub shutdown_delay: Option<u64>,
+    /// gzip / brotli で圧縮する応答の最小サイズ（バイト、既定は 1024）
+    pub compression_min_size: Option<u16>,
+}
+
+impl AppConfig {
+    /// 環境変数と `CONFIG_FILE` から読み込む
+    pub fn load() -> Result<Self, common::config::ConfigError> {
+        common::config::load()
+    }
+}
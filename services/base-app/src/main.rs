@@ -1,32 +1,107 @@
-use axum::{routing::get, Json, Router};
+use base_app::app::{build_monolith_router, build_router};
+use base_app::config::AppConfig;
+use clap::Parser;
+use common::compression::{compression_layer, DEFAULT_COMPRESSION_MIN_SIZE};
+use common::lifecycle::{shutdown_signal, Readiness};
+use common::startup::{log_startup, ConfigEntry};
 use std::net::SocketAddr;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use std::time::Duration;
+
+/// ゲートウェイ（`MONOLITH=true` で各サービスを組み込む）
+#[derive(Debug, Parser)]
+#[command(name = "base-app", version)]
+struct Cli {
+    /// 設定を検証して終了する（サーバーは起動しない）
+    #[arg(long)]
+    config_check: bool,
+}
 
 #[tokio::main]
 async fn main() {
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::fmt::layer())
-        .with(tracing_subscriber::EnvFilter::from_default_env())
-        .init();
+    // RUST_LOG や SENTRY_DSN も .env から読むため、ログとエラー通知の初期化より先に読み込む
+    let _ = dotenvy::dotenv();
+    let cli = Cli::parse();
+    common::init_tracing();
+    let _error_reporting = common::reporting::init_error_reporting(concat!(
+        env!("CARGO_PKG_NAME"),
+        "@",
+        env!("CARGO_PKG_VERSION")
+    ));
 
-    let app = Router::new()
-        .route("/", get(root))
-        .route("/health", get(health));
+    let config = match AppConfig::load() {
+        Ok(config) => config,
+        Err(err) => {
+            tracing::error!("{}", err);
+            std::process::exit(1);
+        }
+    };
+    // 各サービスの設定は単体で起動する場合と同じ環境変数から読む
+    let accounting_config = if config.monolith {
+        match accounting_service::config::AppConfig::load() {
+            Ok(config) => Some(config),
+            Err(err) => {
+                tracing::error!("{}", err);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        None
+    };
+    let echo_config = if config.monolith {
+        match echo_service::config::AppConfig::load() {
+            Ok(config) => config,
+            Err(err) => {
+                tracing::error!("{}", err);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        echo_service::config::AppConfig::default()
+    };
+    if cli.config_check {
+        println!("Configuration OK");
+        return;
+    }
 
     let addr = SocketAddr::from(([0, 0, 0, 0], 8080));
+    let shutdown_delay = Duration::from_secs(config.shutdown_delay.unwrap_or_default());
+    let compression_min_size = config
+        .compression_min_size
+        .unwrap_or(DEFAULT_COMPRESSION_MIN_SIZE);
+    log_startup(
+        "base-app",
+        env!("CARGO_PKG_VERSION"),
+        &[
+            ConfigEntry::new("listen_addr", addr),
+            ConfigEntry::new("monolith", config.monolith),
+            ConfigEntry::new("shutdown_delay", format!("{}s", shutdown_delay.as_secs())),
+            ConfigEntry::new("compression_min_size", compression_min_size),
+        ],
+    );
+
+    let (app, readiness) = match accounting_config {
+        Some(accounting_config) => {
+            let accounting = accounting_service::app::initialize(
+                accounting_config,
+                accounting_service::cli::ServeArgs::default(),
+                addr,
+            )
+            .await;
+            let readiness = accounting.readiness.clone();
+            (build_monolith_router(accounting, &echo_config), readiness)
+        }
+        None => {
+            let readiness = Readiness::ready();
+            (build_router(readiness.clone()), readiness)
+        }
+    };
+    // 組み込んだサービスの応答もここでまとめて圧縮する
+    let app = app.layer(compression_layer(compression_min_size));
     tracing::info!("listening on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
-}
-
-async fn root() -> Json<serde_json::Value> {
-    Json(serde_json::json!({
-        "message": "Hello from base-app!",
-        "version": env!("CARGO_PKG_VERSION")
-    }))
-}
-
-async fn health() -> &'static str {
-    "OK"
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal(readiness, shutdown_delay))
+        .await
+        .unwrap();
 }
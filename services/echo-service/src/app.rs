@@ -0,0 +1,221 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use common::error::AppError;
+use common::json::HardenedJson;
+use common::lifecycle::{readiness_router, Readiness};
+use common::panic::catch_panic_layer;
+use common::reporting::error_reporting_middleware;
+use common::ErrorResponse;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::config::AppConfig;
+
+/// 注入できる遅延の上限
+const MAX_DELAY_MS: u64 = 30_000;
+
+#[derive(Debug, Deserialize)]
+struct EchoRequest {
+    message: String,
+}
+
+#[derive(Debug, Serialize)]
+struct EchoResponse {
+    reply: String,
+}
+
+/// 障害注入パラメーター（`/echo?delay_ms=500&fail_rate=0.2&status=503`）
+#[derive(Debug, Default, Deserialize)]
+struct FaultInjection {
+    delay_ms: Option<u64>,
+    /// 失敗させる確率（0.0〜1.0）
+    fail_rate: Option<f64>,
+    /// 失敗時のステータス（既定は 503）
+    status: Option<u16>,
+}
+
+impl FaultInjection {
+    fn is_requested(&self) -> bool {
+        self.delay_ms.is_some() || self.fail_rate.is_some() || self.status.is_some()
+    }
+
+    fn validate(&self) -> Result<(), AppError> {
+        if self.delay_ms.is_some_and(|d| d > MAX_DELAY_MS) {
+            return Err(AppError::validation(format!(
+                "delay_ms must be at most {}",
+                MAX_DELAY_MS
+            )));
+        }
+        if self.fail_rate.is_some_and(|r| !(0.0..=1.0).contains(&r)) {
+            return Err(AppError::validation("fail_rate must be between 0 and 1"));
+        }
+        if self.status.is_some_and(|s| !(400..=599).contains(&s)) {
+            return Err(AppError::validation("status must be between 400 and 599"));
+        }
+        Ok(())
+    }
+
+    /// roll（0.0〜1.0 の乱数）に対して失敗させるか
+    fn should_fail(&self, roll: f64) -> bool {
+        roll < self.fail_rate.unwrap_or(0.0)
+    }
+
+    fn failure_status(&self) -> StatusCode {
+        self.status
+            .and_then(|s| StatusCode::from_u16(s).ok())
+            .unwrap_or(StatusCode::SERVICE_UNAVAILABLE)
+    }
+}
+
+/// 本番と同じルーター（結合テストやゲートウェイへの組み込みでもこれを使う）
+///
+/// レスポンスの圧縮は含まない。サーバーを起動する側（main.rs・base-app）が掛ける。
+/// 障害注入は `FAULT_INJECTION_ENABLED` を設定した場合だけ受け付ける。
+pub fn build_router(readiness: Readiness, config: &AppConfig) -> Router {
+    Router::new()
+        .route("/echo", post(echo))
+        .with_state(config.fault_injection_enabled)
+        .route("/health", get(health))
+        .merge(readiness_router(readiness))
+        .layer(middleware::from_fn(error_reporting_middleware))
+        .layer(catch_panic_layer())
+}
+
+async fn echo(
+    State(fault_injection_enabled): State<bool>,
+    Query(fault): Query<FaultInjection>,
+    HardenedJson(payload, _): HardenedJson<EchoRequest>,
+) -> Result<Response, AppError> {
+    if fault.is_requested() && !fault_injection_enabled {
+        return Err(AppError::BadRequest {
+            code: "FAULT_INJECTION_DISABLED",
+            message: "Fault injection is not enabled (set FAULT_INJECTION_ENABLED)".to_string(),
+        });
+    }
+    fault.validate()?;
+
+    if let Some(delay_ms) = fault.delay_ms {
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+    }
+    if fault.should_fail(rand::thread_rng().gen()) {
+        let status = fault.failure_status();
+        tracing::debug!(%status, "Injecting failure");
+        let body = ErrorResponse::new("Injected failure", "INJECTED_FAILURE");
+        return Ok((status, Json(body)).into_response());
+    }
+
+    Ok(Json(EchoResponse {
+        reply: format!("Hello {}", payload.message),
+    })
+    .into_response())
+}
+
+async fn health() -> &'static str {
+    "OK"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, extract::Request};
+    use tower::ServiceExt;
+
+    async fn post_echo(uri: &str) -> Response {
+        let config = AppConfig {
+            fault_injection_enabled: true,
+            ..Default::default()
+        };
+        post_echo_with(&config, uri).await
+    }
+
+    async fn post_echo_with(config: &AppConfig, uri: &str) -> Response {
+        build_router(Readiness::ready(), config)
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(r#"{"message":"world"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_should_fail() {
+        let fault = FaultInjection {
+            fail_rate: Some(0.2),
+            ..Default::default()
+        };
+
+        assert!(fault.should_fail(0.1));
+        assert!(!fault.should_fail(0.2));
+        assert!(!FaultInjection::default().should_fail(0.0));
+    }
+
+    #[tokio::test]
+    async fn test_echo_injects_failure() {
+        let response = post_echo("/echo?fail_rate=1&status=502").await;
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+
+        let response = post_echo("/echo?fail_rate=1").await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let response = post_echo("/echo?delay_ms=1").await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_echo_rejects_invalid_parameters() {
+        for uri in [
+            "/echo?fail_rate=1.5",
+            "/echo?status=200",
+            "/echo?delay_ms=60000",
+        ] {
+            let response = post_echo(uri).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_echo_rejects_fault_injection_when_disabled() {
+        let config = AppConfig::default();
+        for uri in ["/echo?fail_rate=1", "/echo?delay_ms=1", "/echo?status=502"] {
+            let response = post_echo_with(&config, uri).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", uri);
+        }
+
+        let response = post_echo_with(&config, "/echo").await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_echo_rejects_malformed_body_with_error_response() {
+        let response = build_router(Readiness::ready(), &AppConfig::default())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/echo")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from("{\"message\":"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "INVALID_JSON");
+    }
+}
@@ -0,0 +1,22 @@
+use common::config::bool_from_str_or_int;
+use serde::Deserialize;
+use validator::Validate;
+
+/// echo-service の設定（環境変数・設定ファイルから読み込む）
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+pub struct AppConfig {
+    /// 停止シグナルを受けてから新規リクエストの受け付けを止めるまでの待ち時間（秒、既定は 0）
+    pub shutdown_delay: Option<u64>,
+    /// gzip / brotli で圧縮する応答の最小サイズ（バイト、既定は 1024）
+    pub compression_min_size: Option<u16>,
+    /// `/echo` の障害注入パラメーター（`delay_ms`・`fail_rate`・`status`）を受け付ける（試験環境用、既定は無効）
+    #[serde(default, deserialize_with = "bool_from_str_or_int")]
+    pub fault_injection_enabled: bool,
+}
+
+impl AppConfig {
+    /// 環境変数と `CONFIG_FILE` から読み込む
+    pub fn load() -> Result<Self, common::config::ConfigError> {
+        common::config::load()
+    }
+}
@@ -1,38 +1,74 @@
-use axum::{routing::{get, post}, Json, Router};
-use serde::{Deserialize, Serialize};
+use clap::Parser;
+use common::compression::{compression_layer, DEFAULT_COMPRESSION_MIN_SIZE};
+use common::lifecycle::{shutdown_signal, Readiness};
+use echo_service::app::build_router;
+use echo_service::config::AppConfig;
 use std::net::SocketAddr;
+use std::time::Duration;
 
-#[derive(Debug, Deserialize)]
-struct EchoRequest {
-    message: String,
-}
-
-#[derive(Debug, Serialize)]
-struct EchoResponse {
-    reply: String,
+/// エコーサービス
+#[derive(Debug, Parser)]
+#[command(name = "echo-service", version)]
+struct Cli {
+    /// 設定を検証して終了する（サーバーは起動しない）
+    #[arg(long)]
+    config_check: bool,
 }
 
 #[tokio::main]
 async fn main() {
+    // RUST_LOG や SENTRY_DSN も .env から読むため、ログとエラー通知の初期化より先に読み込む
+    let _ = dotenvy::dotenv();
+    let cli = Cli::parse();
     common::init_tracing();
+    let _error_reporting = common::reporting::init_error_reporting(concat!(
+        env!("CARGO_PKG_NAME"),
+        "@",
+        env!("CARGO_PKG_VERSION")
+    ));
 
-    let app = Router::new()
-        .route("/echo", post(echo))
-        .route("/health", get(health));
+    let config = match AppConfig::load() {
+        Ok(config) => config,
+        Err(err) => {
+            tracing::error!("{}", err);
+            std::process::exit(1);
+        }
+    };
+    if cli.config_check {
+        println!("Configuration OK");
+        return;
+    }
+
+    let compression_min_size = config
+        .compression_min_size
+        .unwrap_or(DEFAULT_COMPRESSION_MIN_SIZE);
+    let readiness = Readiness::ready();
+    let app =
+        build_router(readiness.clone(), &config).layer(compression_layer(compression_min_size));
 
     let addr = SocketAddr::from(([0, 0, 0, 0], 8081));
+    let shutdown_delay = Duration::from_secs(config.shutdown_delay.unwrap_or_default());
+    common::startup::log_startup(
+        "echo-service",
+        env!("CARGO_PKG_VERSION"),
+        &[
+            common::startup::ConfigEntry::new("listen_addr", addr),
+            common::startup::ConfigEntry::new(
+                "shutdown_delay",
+                format!("{}s", shutdown_delay.as_secs()),
+            ),
+            common::startup::ConfigEntry::new("compression_min_size", compression_min_size),
+            common::startup::ConfigEntry::new(
+                "fault_injection_enabled",
+                config.fault_injection_enabled,
+            ),
+        ],
+    );
     tracing::info!("echo-service listening on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
-}
-
-async fn echo(Json(payload): Json<EchoRequest>) -> Json<EchoResponse> {
-    Json(EchoResponse {
-        reply: format!("Hello {}", payload.message),
-    })
-}
-
-async fn health() -> &'static str {
-    "OK"
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal(readiness, shutdown_delay))
+        .await
+        .unwrap();
 }